@@ -0,0 +1,100 @@
+use std::io;
+
+use requestty::{
+    prompt::{self, events::KeyCode, Answer, Answers, Backend, EventIterator},
+    Question,
+};
+use ui::{layout::Layout, style::Stylize, widgets, Validation, Widget};
+
+/// A prompt where the user picks a number with the arrow keys.
+#[derive(Debug)]
+struct Counter {
+    start: i64,
+}
+
+struct CounterWidget {
+    prompt: widgets::Prompt<String>,
+    count: i64,
+}
+
+impl Widget for CounterWidget {
+    fn render<B: Backend>(&mut self, layout: &mut Layout, b: &mut B) -> io::Result<()> {
+        self.prompt.render(layout, b)?;
+
+        let count = self.count.to_string();
+        layout.line_offset += count.len() as u16;
+        write!(b, "{}", count)
+    }
+
+    fn height(&mut self, layout: &mut Layout) -> u16 {
+        self.prompt.height(layout)
+    }
+
+    fn handle_key(&mut self, key: prompt::events::KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Up => self.count += 1,
+            KeyCode::Down => self.count -= 1,
+            _ => return false,
+        }
+
+        true
+    }
+
+    fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+        let (x, y) = self.prompt.cursor_pos(layout);
+        (x + self.count.to_string().len() as u16, y)
+    }
+}
+
+impl ui::Prompt for CounterWidget {
+    type ValidateErr = &'static str;
+    type Output = i64;
+
+    fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
+        if self.count < 0 {
+            Err("The count cannot be negative")
+        } else {
+            Ok(Validation::Finish)
+        }
+    }
+
+    fn finish(self) -> Self::Output {
+        self.count
+    }
+}
+
+impl prompt::Prompt for Counter {
+    fn ask(
+        self,
+        message: String,
+        _: &Answers,
+        mut backend: &mut dyn Backend,
+        events: &mut dyn EventIterator,
+    ) -> requestty::Result<Option<Answer>> {
+        let widget = CounterWidget {
+            prompt: widgets::Prompt::new(message.clone()).with_hint("Use arrow keys"),
+            count: self.start,
+        };
+
+        // `run_widget` renders the widget and handles the events until it is finished
+        let count = prompt::run_widget(widget, backend, events)?;
+
+        widgets::Prompt::write_finished_message(&message, count.is_none(), &mut backend)?;
+        match count {
+            Some(count) => backend.write_styled(&count.to_string().cyan())?,
+            None => backend.write_styled(&"Skipped".dark_grey())?,
+        }
+        backend.write_all(b"\n")?;
+        backend.flush()?;
+
+        Ok(count.map(Answer::Int))
+    }
+}
+
+fn main() {
+    let question = Question::custom("count", Counter { start: 3 })
+        .message("How many do you want?")
+        .build();
+
+    println!("{:#?}", requestty::prompt_one(question));
+}
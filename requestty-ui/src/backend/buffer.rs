@@ -0,0 +1,342 @@
+use std::io::{self, Write};
+
+use super::{Backend, ClearType, MoveDirection, Size};
+use crate::style::{Attributes, Color};
+
+/// A backend that records everything written to it in an in-memory buffer.
+///
+/// Unlike [`TestBackend`], it does not interpret the output as a grid of cells. Text is stored
+/// as is, and all the terminal operations are stored as the ANSI escape sequences which would be
+/// sent to a terminal. This makes it useful to capture and diff the raw output of a prompt.
+///
+/// Since there is no terminal to query, the size is fixed when it is created, and the cursor
+/// position is tracked from the text and movements written to it. Enabling and disabling raw
+/// mode does nothing.
+///
+/// [`TestBackend`]: super::TestBackend
+///
+/// # Example
+///
+/// ```
+/// use std::io::Write;
+/// use requestty_ui::backend::{Backend, BufferBackend, Size};
+///
+/// let mut backend = BufferBackend::new(Size::from((20, 5)));
+/// backend.hide_cursor().unwrap();
+/// write!(backend, "hello").unwrap();
+///
+/// assert_eq!(backend.get_cursor_pos().unwrap(), (5, 0));
+/// assert_eq!(backend.into_bytes(), b"\x1b[?25lhello");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BufferBackend {
+    buffer: Vec<u8>,
+    size: Size,
+    x: u16,
+    y: u16,
+    attributes: Attributes,
+}
+
+impl BufferBackend {
+    /// Creates a new, empty `BufferBackend` which reports the given size.
+    pub fn new(size: Size) -> Self {
+        Self {
+            buffer: Vec::new(),
+            size,
+            x: 0,
+            y: 0,
+            attributes: Attributes::empty(),
+        }
+    }
+
+    /// The bytes written so far.
+    pub fn bytes(&self) -> &[u8] {
+        &self.buffer
+    }
+
+    /// Consumes the backend, returning the bytes written to it.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.buffer
+    }
+
+    fn csi(&mut self, args: std::fmt::Arguments<'_>) -> io::Result<()> {
+        self.buffer.extend_from_slice(b"\x1b[");
+        self.buffer.write_fmt(args)
+    }
+
+    fn sgr(&mut self, code: u8) -> io::Result<()> {
+        self.csi(format_args!("{}m", code))
+    }
+
+    fn move_x(&mut self, x: u16) {
+        self.x = x.min(self.size.width.saturating_sub(1));
+    }
+
+    fn move_y(&mut self, y: u16) {
+        self.y = y.min(self.size.height.saturating_sub(1));
+    }
+
+    fn put_char(&mut self, c: char) {
+        match c {
+            '\n' => {
+                self.x = 0;
+                self.move_y(self.y.saturating_add(1));
+            }
+            '\r' => self.x = 0,
+            c => {
+                let mut buf = [0; 4];
+                let width = textwrap::core::display_width(c.encode_utf8(&mut buf)) as u16;
+
+                if self.x + width > self.size.width {
+                    self.x = 0;
+                    self.move_y(self.y.saturating_add(1));
+                }
+
+                self.x += width;
+            }
+        }
+    }
+}
+
+impl Write for BufferBackend {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        std::str::from_utf8(buf)
+            .map_err(|_| io::ErrorKind::InvalidInput)?
+            .chars()
+            .for_each(|c| self.put_char(c));
+
+        self.buffer.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Backend for BufferBackend {
+    fn enable_raw_mode(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn disable_raw_mode(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        self.csi(format_args!("?25l"))
+    }
+
+    fn show_cursor(&mut self) -> io::Result<()> {
+        self.csi(format_args!("?25h"))
+    }
+
+    fn get_cursor_pos(&mut self) -> io::Result<(u16, u16)> {
+        Ok((self.x, self.y))
+    }
+
+    fn move_cursor_to(&mut self, x: u16, y: u16) -> io::Result<()> {
+        self.move_x(x);
+        self.move_y(y);
+        self.csi(format_args!("{};{}H", y + 1, x + 1))
+    }
+
+    fn move_cursor(&mut self, direction: MoveDirection) -> io::Result<()> {
+        match direction {
+            MoveDirection::Up(n) => {
+                self.move_y(self.y.saturating_sub(n));
+                self.csi(format_args!("{}A", n))
+            }
+            MoveDirection::Down(n) => {
+                self.move_y(self.y.saturating_add(n));
+                self.csi(format_args!("{}B", n))
+            }
+            MoveDirection::Right(n) => {
+                self.move_x(self.x.saturating_add(n));
+                self.csi(format_args!("{}C", n))
+            }
+            MoveDirection::Left(n) => {
+                self.move_x(self.x.saturating_sub(n));
+                self.csi(format_args!("{}D", n))
+            }
+            MoveDirection::NextLine(n) => {
+                self.x = 0;
+                self.move_y(self.y.saturating_add(n));
+                self.csi(format_args!("{}E", n))
+            }
+            MoveDirection::Column(n) => {
+                self.move_x(n);
+                self.csi(format_args!("{}G", n + 1))
+            }
+            MoveDirection::PrevLine(n) => {
+                self.x = 0;
+                self.move_y(self.y.saturating_sub(n));
+                self.csi(format_args!("{}F", n))
+            }
+        }
+    }
+
+    fn scroll(&mut self, dist: i16) -> io::Result<()> {
+        if dist > 0 {
+            self.csi(format_args!("{}T", dist))
+        } else if dist < 0 {
+            self.csi(format_args!("{}S", -(dist as i32)))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn set_attributes(&mut self, attributes: Attributes) -> io::Result<()> {
+        let diff = self.attributes.diff(attributes);
+
+        if diff.to_remove.contains(Attributes::REVERSED) {
+            self.sgr(27)?;
+        }
+        if diff.to_remove.contains(Attributes::BOLD) {
+            self.sgr(22)?;
+            if attributes.contains(Attributes::DIM) {
+                self.sgr(2)?;
+            }
+        }
+        if diff.to_remove.contains(Attributes::ITALIC) {
+            self.sgr(23)?;
+        }
+        if diff.to_remove.contains(Attributes::UNDERLINED) {
+            self.sgr(24)?;
+        }
+        if diff.to_remove.contains(Attributes::DIM) {
+            self.sgr(22)?;
+        }
+        if diff.to_remove.contains(Attributes::CROSSED_OUT) {
+            self.sgr(29)?;
+        }
+        if diff.to_remove.contains(Attributes::SLOW_BLINK)
+            || diff.to_remove.contains(Attributes::RAPID_BLINK)
+        {
+            self.sgr(25)?;
+        }
+
+        if diff.to_add.contains(Attributes::REVERSED) {
+            self.sgr(7)?;
+        }
+        if diff.to_add.contains(Attributes::BOLD) {
+            self.sgr(1)?;
+        }
+        if diff.to_add.contains(Attributes::ITALIC) {
+            self.sgr(3)?;
+        }
+        if diff.to_add.contains(Attributes::UNDERLINED) {
+            self.sgr(4)?;
+        }
+        if diff.to_add.contains(Attributes::DIM) {
+            self.sgr(2)?;
+        }
+        if diff.to_add.contains(Attributes::CROSSED_OUT) {
+            self.sgr(9)?;
+        }
+        if diff.to_add.contains(Attributes::SLOW_BLINK) {
+            self.sgr(5)?;
+        }
+        if diff.to_add.contains(Attributes::RAPID_BLINK) {
+            self.sgr(6)?;
+        }
+
+        self.attributes = attributes;
+        Ok(())
+    }
+
+    fn set_fg(&mut self, color: Color) -> io::Result<()> {
+        write_color(&mut self.buffer, color, 0)
+    }
+
+    fn set_bg(&mut self, color: Color) -> io::Result<()> {
+        write_color(&mut self.buffer, color, 10)
+    }
+
+    fn clear(&mut self, clear_type: ClearType) -> io::Result<()> {
+        match clear_type {
+            ClearType::All => self.csi(format_args!("2J")),
+            ClearType::FromCursorDown => self.csi(format_args!("J")),
+            ClearType::FromCursorUp => self.csi(format_args!("1J")),
+            ClearType::CurrentLine => self.csi(format_args!("2K")),
+            ClearType::UntilNewLine => self.csi(format_args!("K")),
+        }
+    }
+
+    fn size(&self) -> io::Result<Size> {
+        Ok(self.size)
+    }
+}
+
+/// Writes the SGR sequence for a foreground color. `offset` is added to every code, so an offset
+/// of 10 gives the background color instead.
+fn write_color(buffer: &mut Vec<u8>, color: Color, offset: u8) -> io::Result<()> {
+    let code = match color {
+        Color::Reset => 39,
+        Color::Black => 30,
+        Color::Red => 31,
+        Color::Green => 32,
+        Color::Yellow => 33,
+        Color::Blue => 34,
+        Color::Magenta => 35,
+        Color::Cyan => 36,
+        Color::Grey => 37,
+        Color::DarkGrey => 90,
+        Color::LightRed => 91,
+        Color::LightGreen => 92,
+        Color::LightYellow => 93,
+        Color::LightBlue => 94,
+        Color::LightMagenta => 95,
+        Color::LightCyan => 96,
+        Color::White => 97,
+        Color::Ansi(i) => return write!(buffer, "\x1b[{};5;{}m", 38 + offset, i),
+        Color::Rgb(r, g, b) => return write!(buffer, "\x1b[{};2;{};{};{}m", 38 + offset, r, g, b),
+    };
+
+    write!(buffer, "\x1b[{}m", code + offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_sequences() {
+        let mut backend = BufferBackend::new((10, 5).into());
+
+        backend.move_cursor_to(2, 1).unwrap();
+        backend.set_fg(Color::LightRed).unwrap();
+        backend.set_bg(Color::Rgb(1, 2, 3)).unwrap();
+        backend.set_attributes(Attributes::BOLD).unwrap();
+        backend.set_attributes(Attributes::empty()).unwrap();
+        backend.move_cursor(MoveDirection::Column(0)).unwrap();
+        backend.clear(ClearType::FromCursorDown).unwrap();
+
+        assert_eq!(
+            backend.bytes(),
+            &b"\x1b[2;3H\x1b[91m\x1b[48;2;1;2;3m\x1b[1m\x1b[22m\x1b[1G\x1b[J"[..]
+        );
+    }
+
+    #[test]
+    fn test_cursor_pos() {
+        let mut backend = BufferBackend::new((10, 5).into());
+
+        write!(backend, "hello\nworld").unwrap();
+        assert_eq!(backend.get_cursor_pos().unwrap(), (5, 1));
+
+        write!(backend, " wraps").unwrap();
+        assert_eq!(backend.get_cursor_pos().unwrap(), (1, 2));
+
+        backend.move_cursor(MoveDirection::PrevLine(1)).unwrap();
+        assert_eq!(backend.get_cursor_pos().unwrap(), (0, 1));
+
+        backend.move_cursor_to(20, 20).unwrap();
+        assert_eq!(backend.get_cursor_pos().unwrap(), (9, 4));
+
+        write!(backend, "ab").unwrap();
+        assert_eq!(backend.get_cursor_pos().unwrap(), (1, 4));
+
+        assert!(backend.into_bytes().ends_with(b"ab"));
+    }
+}
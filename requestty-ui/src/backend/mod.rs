@@ -20,6 +20,9 @@ pub fn get_backend<W: io::Write>(buf: W) -> impl Backend {
 mod test_backend;
 pub use test_backend::TestBackend;
 
+mod buffer;
+pub use buffer::BufferBackend;
+
 #[cfg(feature = "termion")]
 mod termion;
 
@@ -132,6 +135,9 @@ pub trait Backend: io::Write {
     /// Clears the cells given by clear_type
     fn clear(&mut self, clear_type: ClearType) -> io::Result<()>;
     /// Gets the size of the terminal in rows and columns.
+    ///
+    /// The size can change while a prompt is shown if the terminal is resized, so it should be
+    /// fetched again before every render instead of being stored.
     fn size(&self) -> io::Result<Size>;
 }
 
@@ -160,7 +166,7 @@ fn default_move_cursor<B: Backend + ?Sized>(
     backend.move_cursor_to(x, y)
 }
 
-impl<'a, B: Backend> Backend for &'a mut B {
+impl<'a, B: Backend + ?Sized> Backend for &'a mut B {
     fn enable_raw_mode(&mut self) -> io::Result<()> {
         (**self).enable_raw_mode()
     }
@@ -143,6 +143,33 @@ impl TestBackend {
         self.move_y(layout.offset_y);
     }
 
+    /// Returns the text visible on row `y` of the viewport, ignoring all styling. Trailing
+    /// whitespace is trimmed.
+    ///
+    /// This is meant for tests that check a single line of output, without needing a snapshot.
+    ///
+    /// # Panics
+    ///
+    /// It panics if `y` is not less than the height of the backend.
+    pub fn row_text(&self, y: u16) -> String {
+        assert!(
+            y < self.size.height,
+            "row {} is outside the backend of height {}",
+            y,
+            self.size.height
+        );
+
+        let width = self.size.width as usize;
+        let start = y as usize * width;
+
+        let row: String = self.viewport()[start..(start + width)]
+            .iter()
+            .map(|cell| cell.value.unwrap_or(' '))
+            .collect();
+
+        row.trim_end().to_owned()
+    }
+
     fn viewport(&self) -> &[Cell] {
         &self.cells[self.viewport_start..(self.viewport_start + self.size.area() as usize)]
     }
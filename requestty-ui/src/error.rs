@@ -14,13 +14,39 @@ pub enum ErrorKind {
     Eof,
     /// The user aborted the question with `Esc`
     Aborted,
+    /// The user asked to go back to the previous question with `Esc`, see
+    /// [`OnEsc::GoBack`](crate::OnEsc::GoBack).
+    GoBack,
+    /// The user did not respond within the timeout of the prompt, and it has no default answer to
+    /// submit instead. See [`Input::timeout`](crate::Input::timeout).
+    TimedOut,
+    /// This occurs when a prompt has a list of choices, none of which can be selected.
+    NoSelectableChoices,
+    /// This occurs when a question is answered non-interactively, but no answer was given for it
+    /// and it has no default. It contains the name of the question.
+    MissingAnswer(String),
+    /// This occurs when a question is answered non-interactively, but the given answer is not
+    /// valid for it.
+    InvalidAnswer {
+        /// The name of the question.
+        name: String,
+        /// Why the answer is not valid.
+        message: String,
+    },
 }
 
 impl std::error::Error for ErrorKind {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             ErrorKind::IoError(e) => Some(e),
-            ErrorKind::Interrupted | ErrorKind::Eof | ErrorKind::Aborted => None,
+            ErrorKind::Interrupted
+            | ErrorKind::Eof
+            | ErrorKind::Aborted
+            | ErrorKind::GoBack
+            | ErrorKind::TimedOut
+            | ErrorKind::NoSelectableChoices
+            | ErrorKind::MissingAnswer(_)
+            | ErrorKind::InvalidAnswer { .. } => None,
         }
     }
 }
@@ -31,7 +57,14 @@ impl fmt::Display for ErrorKind {
             ErrorKind::IoError(e) => write!(fmt, "IoError: {}", e),
             ErrorKind::Interrupted => write!(fmt, "CTRL+C"),
             ErrorKind::Aborted => write!(fmt, "ESC"),
+            ErrorKind::GoBack => write!(fmt, "ESC (go back)"),
+            ErrorKind::TimedOut => write!(fmt, "timed out"),
             ErrorKind::Eof => write!(fmt, "EOF"),
+            ErrorKind::NoSelectableChoices => write!(fmt, "no selectable choices"),
+            ErrorKind::MissingAnswer(name) => write!(fmt, "no answer for `{}`", name),
+            ErrorKind::InvalidAnswer { name, message } => {
+                write!(fmt, "invalid answer for `{}`: {}", name, message)
+            }
         }
     }
 }
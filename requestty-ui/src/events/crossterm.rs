@@ -1,8 +1,14 @@
+use std::time::{Duration, Instant};
+
 use crossterm::event;
 
 use super::EventIterator;
 
 /// An iterator over the input keys using the `crossterm` crate
+///
+/// Resizes of the terminal are sent as [`KeyCode::Resize`].
+///
+/// [`KeyCode::Resize`]: super::KeyCode::Resize
 #[derive(Debug, Default)]
 #[cfg_attr(docsrs, doc(cfg(feature = "crossterm")))]
 #[non_exhaustive]
@@ -18,10 +24,52 @@ impl CrosstermEvents {
 impl EventIterator for CrosstermEvents {
     fn next_event(&mut self) -> std::io::Result<super::KeyEvent> {
         loop {
-            if let event::Event::Key(k) = event::read()? {
-                return Ok(k.into());
+            match event::read()? {
+                event::Event::Key(k) => return Ok(k.into()),
+                event::Event::Resize(width, height) => {
+                    return Ok(super::KeyCode::Resize(width, height).into())
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn poll_event(&mut self) -> std::io::Result<Option<super::KeyEvent>> {
+        while event::poll(Duration::from_secs(0))? {
+            match event::read()? {
+                event::Event::Key(k) => return Ok(Some(k.into())),
+                event::Event::Resize(width, height) => {
+                    return Ok(Some(super::KeyCode::Resize(width, height).into()))
+                }
+                _ => {}
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn next_event_timeout(
+        &mut self,
+        timeout: Duration,
+    ) -> std::io::Result<Option<super::KeyEvent>> {
+        let deadline = Instant::now() + timeout;
+
+        // Events which are not keys or resizes are skipped without restarting the wait
+        while event::poll(deadline.saturating_duration_since(Instant::now()))? {
+            match event::read()? {
+                event::Event::Key(k) => return Ok(Some(k.into())),
+                event::Event::Resize(width, height) => {
+                    return Ok(Some(super::KeyCode::Resize(width, height).into()))
+                }
+                _ => {}
             }
         }
+
+        Ok(None)
+    }
+
+    fn supports_timeout(&self) -> bool {
+        true
     }
 }
 
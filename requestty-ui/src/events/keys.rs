@@ -78,4 +78,12 @@ pub enum KeyCode {
     Null,
     /// Escape key.
     Esc,
+    /// The terminal was resized to the given width and height.
+    ///
+    /// This is not a key, but it is sent with the other events so that [`Input`] can render the
+    /// prompt again with the new size. It is never passed to the prompt. Event sources which cannot
+    /// detect resizes never send it.
+    ///
+    /// [`Input`]: crate::Input
+    Resize(u16, u16),
 }
@@ -1,6 +1,6 @@
 //! A module for handling key events
 
-use std::io;
+use std::{io, time::Duration};
 
 #[cfg(feature = "crossterm")]
 mod crossterm;
@@ -37,7 +37,70 @@ pub fn get_events() -> impl EventIterator {
 /// A trait to represent a source of [`KeyEvent`]s.
 pub trait EventIterator {
     /// Get the next event
+    ///
+    /// This blocks until an event is available.
     fn next_event(&mut self) -> io::Result<KeyEvent>;
+
+    /// Get the next event if it is immediately available, without blocking.
+    ///
+    /// This is used by [`Input::coalesce_events`] to handle all the pending events before rendering
+    /// again, which prevents excessive renders when events come in quickly, for example when a key
+    /// is held down. The default implementation never returns an event, so every event is rendered.
+    ///
+    /// It can also be used by prompts which need to do other work while waiting for input, for
+    /// example to animate a [`Spinner`]. Since it returns immediately, calling it in a loop keeps
+    /// a core busy. Instead, wait between the polls, for example for the [`FRAME_INTERVAL`] of the
+    /// spinner, or use [`next_event`](EventIterator::next_event) once there is nothing else to do.
+    ///
+    /// [`Input::coalesce_events`]: crate::Input::coalesce_events
+    /// [`Spinner`]: crate::widgets::Spinner
+    /// [`FRAME_INTERVAL`]: crate::widgets::FRAME_INTERVAL
+    fn poll_event(&mut self) -> io::Result<Option<KeyEvent>> {
+        Ok(None)
+    }
+
+    /// Get the next event, waiting at most `timeout` for it. If no event comes in by then, `None`
+    /// is returned.
+    ///
+    /// This is used by prompts which act once the user stops typing, see [`Prompt::timeout`]. The
+    /// default implementation cannot wait, so it returns `None` immediately as if the time had
+    /// already passed. Such prompts then act after every burst of events instead.
+    ///
+    /// [`Prompt::timeout`]: crate::Prompt::timeout
+    fn next_event_timeout(&mut self, timeout: Duration) -> io::Result<Option<KeyEvent>> {
+        let _ = timeout;
+        Ok(None)
+    }
+
+    /// Whether [`next_event_timeout`](EventIterator::next_event_timeout) really waits for the
+    /// `timeout`, instead of returning `None` straight away.
+    ///
+    /// A timeout for the user to answer, see [`Input::timeout`], only applies if this is `true`.
+    /// Otherwise the prompt would give up on the user as soon as it is shown. The default
+    /// implementation returns `false`, so it must be overridden along with `next_event_timeout`.
+    ///
+    /// [`Input::timeout`]: crate::Input::timeout
+    fn supports_timeout(&self) -> bool {
+        false
+    }
+}
+
+impl<E: EventIterator + ?Sized> EventIterator for &mut E {
+    fn next_event(&mut self) -> io::Result<KeyEvent> {
+        (**self).next_event()
+    }
+
+    fn poll_event(&mut self) -> io::Result<Option<KeyEvent>> {
+        (**self).poll_event()
+    }
+
+    fn next_event_timeout(&mut self, timeout: Duration) -> io::Result<Option<KeyEvent>> {
+        (**self).next_event_timeout(timeout)
+    }
+
+    fn supports_timeout(&self) -> bool {
+        (**self).supports_timeout()
+    }
 }
 
 /// A simple wrapper around a [`KeyEvent`] iterator that can be used in tests.
@@ -47,15 +110,21 @@ pub trait EventIterator {
 ///
 /// It will also check that the internal iterator is fully exhausted on [`Drop`].
 ///
+/// By default, [`poll_event`] never returns an event, so that the prompt is rendered after every
+/// event. Use [`with_poll`] to make all the events immediately available instead.
+///
 /// # Panics
 ///
 /// It will panic if the events run out [`next_event`] is called, or if there are events remaining
 /// when dropped.
 ///
 /// [`next_event`]: TestEvents::next_event
+/// [`poll_event`]: EventIterator::poll_event
+/// [`with_poll`]: TestEvents::with_poll
 #[derive(Debug, Clone)]
 pub struct TestEvents<E: Iterator<Item = KeyEvent>> {
     events: E,
+    poll: bool,
 }
 
 impl<E: Iterator<Item = KeyEvent>> TestEvents<E> {
@@ -63,16 +132,73 @@ impl<E: Iterator<Item = KeyEvent>> TestEvents<E> {
     pub fn new<I: IntoIterator<IntoIter = E, Item = KeyEvent>>(iter: I) -> Self {
         Self {
             events: iter.into_iter(),
+            poll: false,
         }
     }
+
+    /// Whether [`poll_event`] returns the remaining events, as if they were all immediately
+    /// available.
+    ///
+    /// If `with_poll` is not called, it will default to `false`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "ignore this line for doc test as requestty_ui should be used")]
+    /// use requestty::prompt::events::{EventIterator, KeyCode, TestEvents};
+    /// # use requestty_ui::events::{EventIterator, KeyCode, TestEvents};
+    ///
+    /// let mut events = TestEvents::from_chars("h").with_poll(true);
+    ///
+    /// assert_eq!(events.poll_event().unwrap(), Some(KeyCode::Char('h').into()));
+    /// assert_eq!(events.poll_event().unwrap(), None);
+    /// ```
+    ///
+    /// [`poll_event`]: EventIterator::poll_event
+    pub fn with_poll(mut self, poll: bool) -> Self {
+        self.poll = poll;
+        self
+    }
+}
+
+impl TestEvents<std::vec::IntoIter<KeyEvent>> {
+    /// Create a new `TestEvents` which types the characters of the given string
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "ignore this line for doc test as requestty_ui should be used")]
+    /// use requestty::prompt::events::{EventIterator, KeyCode, TestEvents};
+    /// # use requestty_ui::events::{EventIterator, KeyCode, TestEvents};
+    ///
+    /// let mut events = TestEvents::from_chars("hi").then(KeyCode::Enter);
+    ///
+    /// assert_eq!(events.next_event().unwrap(), KeyCode::Char('h').into());
+    /// assert_eq!(events.next_event().unwrap(), KeyCode::Char('i').into());
+    /// assert_eq!(events.next_event().unwrap(), KeyCode::Enter.into());
+    /// ```
+    pub fn from_chars(s: &str) -> Self {
+        Self::new(
+            s.chars()
+                .map(|c| KeyEvent::from(KeyCode::Char(c)))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// Add an event after the remaining events, for example an `Enter` to submit the prompt after
+    /// typing the answer.
+    pub fn then<K: Into<KeyEvent>>(mut self, key: K) -> Self {
+        let mut events: Vec<_> = self.events.by_ref().collect();
+        events.push(key.into());
+        self.events = events.into_iter();
+        self
+    }
 }
 
 impl TestEvents<std::iter::Empty<KeyEvent>> {
     /// Create a new `TestEvents` which yields no events
     pub fn empty() -> Self {
-        Self {
-            events: std::iter::empty(),
-        }
+        Self::new(std::iter::empty())
     }
 }
 
@@ -83,6 +209,14 @@ impl<E: Iterator<Item = KeyEvent>> EventIterator for TestEvents<E> {
             .next()
             .expect("Events ran out, but another one was requested"))
     }
+
+    fn poll_event(&mut self) -> io::Result<Option<KeyEvent>> {
+        if self.poll {
+            Ok(self.events.next())
+        } else {
+            Ok(None)
+        }
+    }
 }
 
 impl<E: Iterator<Item = KeyEvent>> Drop for TestEvents<E> {
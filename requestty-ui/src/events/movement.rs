@@ -189,3 +189,57 @@ fn test_movement() {
         Some(Movement::End)
     );
 }
+
+#[test]
+fn test_word_movement_modifiers() {
+    // Other modifiers held along with `ctrl` or `alt` still move by word
+    assert_eq!(
+        Movement::try_from_key(KeyEvent::new(
+            KeyCode::Left,
+            KeyModifiers::CONTROL | KeyModifiers::SHIFT
+        )),
+        Some(Movement::PrevWord)
+    );
+    assert_eq!(
+        Movement::try_from_key(KeyEvent::new(
+            KeyCode::Right,
+            KeyModifiers::CONTROL | KeyModifiers::SHIFT
+        )),
+        Some(Movement::NextWord)
+    );
+    assert_eq!(
+        Movement::try_from_key(KeyEvent::new(
+            KeyCode::Right,
+            KeyModifiers::CONTROL | KeyModifiers::ALT
+        )),
+        Some(Movement::NextWord)
+    );
+
+    // Only the left and right arrows move by word
+    assert_eq!(
+        Movement::try_from_key(KeyEvent::new(KeyCode::Up, KeyModifiers::CONTROL)),
+        Some(Movement::Up)
+    );
+    assert_eq!(
+        Movement::try_from_key(KeyEvent::new(KeyCode::Down, KeyModifiers::CONTROL)),
+        Some(Movement::Down)
+    );
+    assert_eq!(
+        Movement::try_from_key(KeyEvent::new(KeyCode::Home, KeyModifiers::CONTROL)),
+        Some(Movement::Home)
+    );
+    assert_eq!(
+        Movement::try_from_key(KeyEvent::new(KeyCode::End, KeyModifiers::CONTROL)),
+        Some(Movement::End)
+    );
+
+    // `shift` alone is a plain movement
+    assert_eq!(
+        Movement::try_from_key(KeyEvent::new(KeyCode::Left, KeyModifiers::SHIFT)),
+        Some(Movement::Left)
+    );
+    assert_eq!(
+        Movement::try_from_key(KeyEvent::new(KeyCode::Right, KeyModifiers::SHIFT)),
+        Some(Movement::Right)
+    );
+}
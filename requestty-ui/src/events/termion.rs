@@ -12,6 +12,14 @@ use termion::{
 use super::EventIterator;
 
 /// An iterator over the input keys using the `termion` crate
+///
+/// `termion` cannot detect when the terminal is resized, so [`KeyCode::Resize`] is never sent.
+/// Reading from stdin always blocks, so [`poll_event`] and [`next_event_timeout`] never return an
+/// event.
+///
+/// [`KeyCode::Resize`]: super::KeyCode::Resize
+/// [`poll_event`]: EventIterator::poll_event
+/// [`next_event_timeout`]: EventIterator::next_event_timeout
 #[cfg_attr(docsrs, doc(cfg(feature = "termion")))]
 pub struct TermionEvents {
     events: input::Keys<Stdin>,
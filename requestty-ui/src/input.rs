@@ -1,6 +1,7 @@
 use std::{
     io,
     ops::{Deref, DerefMut},
+    time::{Duration, Instant},
 };
 
 use super::Widget;
@@ -37,6 +38,11 @@ pub enum OnEsc {
     SkipQuestion,
     /// Pressing `Esc` will not do anything, and will be ignored. This is the default behaviour.
     Ignore,
+    /// Go back to the previous question with [`ErrorKind::GoBack`](crate::ErrorKind::GoBack). The
+    /// `PromptModule` forgets the answer to the previous question, and asks it again if it can
+    /// create the questions again. If there is no previous question, this is the same as
+    /// [`OnEsc::Terminate`].
+    GoBack,
 }
 
 /// This trait should be implemented by all 'root' widgets.
@@ -63,6 +69,40 @@ pub trait Prompt: Widget {
     /// The value to return from [`Input::run`]. This will only be called once validation returns
     /// [`Validation::Finish`]
     fn finish(self) -> Self::Output;
+
+    /// Whether the prompt currently handles `Esc` itself, for example to cancel a step of the
+    /// prompt. If it does, `Esc` is passed to [`handle_key`] regardless of the [`OnEsc`] set on the
+    /// [`Input`]. It is called whenever the user presses the escape key.
+    ///
+    /// [`handle_key`]: Widget::handle_key
+    fn handles_esc(&self) -> bool {
+        false
+    }
+
+    /// How long to wait for the next event before [`handle_timeout`] is called, or `None` to wait
+    /// until there is an event. It is called before waiting for every event.
+    ///
+    /// If the [`EventIterator`] cannot wait for a limited time, [`handle_timeout`] is called as soon
+    /// as there are no more pending events. See [`EventIterator::next_event_timeout`].
+    ///
+    /// [`handle_timeout`]: Prompt::handle_timeout
+    fn timeout(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Called when no event came in within the [`timeout`]. Returns whether the prompt needs to be
+    /// rendered again.
+    ///
+    /// [`timeout`]: Prompt::timeout
+    fn handle_timeout(&mut self) -> bool {
+        false
+    }
+
+    /// Whether the prompt has a default answer, which it submits if `Enter` is pressed before
+    /// anything else. It is used when the user does not respond within the [`Input::timeout`].
+    fn has_default(&self) -> bool {
+        false
+    }
 }
 
 /// A ui runner which implements the [render cycle].
@@ -73,13 +113,19 @@ pub trait Prompt: Widget {
 ///
 /// [render cycle]: widgets/trait.Widget.html#render-cycle
 #[derive(Debug)]
-pub struct Input<P, B: Backend> {
+pub struct Input<P, B: Backend, F = fn()> {
     prompt: P,
     on_esc: OnEsc,
+    timeout: Option<Duration>,
+    on_validation_failed: F,
+    coalesce_events: bool,
     backend: TerminalState<B>,
     base_row: u16,
     size: Size,
     render_overflow: bool,
+    /// The height of the prompt when it was last rendered, if nothing else has been rendered below
+    /// it since
+    rendered_height: Option<u16>,
 }
 
 impl<P, B: Backend> Input<P, B> {
@@ -92,13 +138,19 @@ impl<P, B: Backend> Input<P, B> {
         Input {
             prompt,
             on_esc: OnEsc::Ignore,
+            timeout: None,
+            on_validation_failed: || {},
+            coalesce_events: false,
             backend: TerminalState::new(backend, false),
             base_row: 0,
             size: Size::default(),
             render_overflow: false,
+            rendered_height: None,
         }
     }
+}
 
+impl<P, B: Backend, F> Input<P, B, F> {
     /// Hides the cursor while running the input. This won't do anything until it is [run](Input::run).
     pub fn hide_cursor(mut self) -> Self {
         self.backend.hide_cursor = true;
@@ -112,13 +164,62 @@ impl<P, B: Backend> Input<P, B> {
     /// will be returned.
     /// For [`OnEsc::Ignore`] - no special behaviour will be applied to the `Esc` key. Like other
     /// keys, the `Esc` key will be passed to the prompt to handle.
+    /// For [`OnEsc::GoBack`] - the currently shown prompt will be cleared, and an
+    /// [`Error::GoBack`](error::ErrorKind::GoBack) will be returned.
     pub fn on_esc(mut self, on_esc: OnEsc) -> Self {
         self.on_esc = on_esc;
         self
     }
+
+    /// How long to wait for the user to respond before giving up on them, or `None` to wait
+    /// forever. This is the default.
+    ///
+    /// If no key is pressed within the timeout, the prompt is submitted as if `Enter` was pressed
+    /// when it [has a default](Prompt::has_default). Otherwise, the prompt is cleared and an
+    /// [`Error::TimedOut`](error::ErrorKind::TimedOut) is returned. Once a key is pressed, the
+    /// timeout no longer applies.
+    ///
+    /// The timeout is ignored if the [`EventIterator`] given to [`run`](Input::run) cannot wait
+    /// for a limited time, see [`EventIterator::supports_timeout`].
+    pub fn timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Whether to handle all the events which are already available before rendering again.
+    ///
+    /// When a key is held down, events can arrive faster than the prompt is rendered. With
+    /// `coalesce_events`, the prompt is only rendered once after such a burst of events, instead of
+    /// after every event. The events are taken with [`EventIterator::poll_event`], so this has no
+    /// effect if the [`EventIterator`] does not support polling.
+    ///
+    /// If `coalesce_events` is not called, it will default to `false`.
+    pub fn coalesce_events(mut self, coalesce_events: bool) -> Self {
+        self.coalesce_events = coalesce_events;
+        self
+    }
+
+    /// Calls `on_validation_failed` every time the user tries to submit the prompt, but it fails
+    /// [validation](Prompt::validate).
+    ///
+    /// This happens before the error is shown to the user.
+    pub fn on_validation_failed<G: FnMut()>(self, on_validation_failed: G) -> Input<P, B, G> {
+        Input {
+            prompt: self.prompt,
+            on_esc: self.on_esc,
+            timeout: self.timeout,
+            on_validation_failed,
+            coalesce_events: self.coalesce_events,
+            backend: self.backend,
+            base_row: self.base_row,
+            size: self.size,
+            render_overflow: self.render_overflow,
+            rendered_height: self.rendered_height,
+        }
+    }
 }
 
-impl<P: Prompt, B: Backend> Input<P, B> {
+impl<P: Prompt, B: Backend, F: FnMut()> Input<P, B, F> {
     fn layout(&self) -> Layout {
         Layout::new(0, self.size).with_offset(0, self.base_row)
     }
@@ -194,13 +295,27 @@ impl<P: Prompt, B: Backend> Input<P, B> {
     }
 
     fn render(&mut self) -> io::Result<()> {
+        let prev_size = self.size;
         self.update_size()?;
         let height = self.prompt.height(&mut self.layout());
+
+        // If nothing has moved, the prompt may be able to render only what has changed
+        if self.rendered_height == Some(height)
+            && self.size == prev_size
+            && !self.render_overflow
+            && self
+                .prompt
+                .render_changes(&mut self.layout(), &mut *self.backend)?
+        {
+            return self.flush();
+        }
+
         self.base_row = self.adjust_scrollback(height)?;
         self.clear()?;
 
         self.prompt.render(&mut self.layout(), &mut *self.backend)?;
         self.render_overflow = height > self.size.height;
+        self.rendered_height = Some(height);
 
         if self.render_overflow {
             self.backend.move_cursor_to(0, self.size.height - 1)?;
@@ -221,6 +336,8 @@ impl<P: Prompt, B: Backend> Input<P, B> {
     }
 
     fn print_error(&mut self, mut e: P::ValidateErr) -> io::Result<()> {
+        // The error is rendered below the prompt, so the next render has to clear it
+        self.rendered_height = None;
         self.update_size()?;
         let height = self.prompt.height(&mut self.layout());
         self.base_row = self.adjust_scrollback(height + 1)?;
@@ -235,11 +352,11 @@ impl<P: Prompt, B: Backend> Input<P, B> {
 
         if self.render_overflow {
             self.backend
-                .move_cursor_to(0, self.size.height - err_height - 1)?;
+                .move_cursor_to(0, self.size.height.saturating_sub(err_height + 1))?;
             self.backend.clear(ClearType::FromCursorDown)?;
             self.render_cutoff_msg()?;
             self.backend
-                .move_cursor_to(0, self.size.height - err_height)?;
+                .move_cursor_to(0, self.size.height.saturating_sub(err_height))?;
         }
 
         self.backend
@@ -267,44 +384,120 @@ impl<P: Prompt, B: Backend> Input<P, B> {
     {
         self.init()?;
 
-        loop {
-            let e = events.next_event()?;
+        // Without a way to wait for a limited time, the prompt would give up straight away
+        let mut deadline = self
+            .timeout
+            .filter(|_| events.supports_timeout())
+            .map(|timeout| Instant::now() + timeout);
 
-            let key_handled = match e.code {
-                KeyCode::Char('c') if e.modifiers.contains(KeyModifiers::CONTROL) => {
-                    self.exit()?;
-                    return Err(error::ErrorKind::Interrupted);
-                }
-                KeyCode::Null => {
-                    self.exit()?;
-                    return Err(error::ErrorKind::Eof);
-                }
-                KeyCode::Esc if self.on_esc == OnEsc::Terminate => {
-                    self.exit()?;
-                    return Err(error::ErrorKind::Aborted);
-                }
-                KeyCode::Esc if self.on_esc == OnEsc::SkipQuestion => {
-                    self.clear()?;
-                    self.backend.reset()?;
+        loop {
+            let answer_timeout =
+                deadline.map(|deadline| deadline.saturating_duration_since(Instant::now()));
 
-                    return Ok(None);
+            let timeout = match (self.prompt.timeout(), answer_timeout) {
+                (Some(prompt_timeout), Some(answer_timeout)) => {
+                    Some(prompt_timeout.min(answer_timeout))
                 }
-                KeyCode::Enter => match self.prompt.validate() {
-                    Ok(Validation::Finish) => {
-                        self.clear()?;
-                        self.backend.reset()?;
+                (prompt_timeout, answer_timeout) => prompt_timeout.or(answer_timeout),
+            };
 
-                        return Ok(Some(self.prompt.finish()));
+            let mut e = match timeout {
+                Some(timeout) => match events.next_event_timeout(timeout)? {
+                    Some(e) => {
+                        if !matches!(e.code, KeyCode::Resize(..)) {
+                            deadline = None;
+                        }
+                        e
                     }
-                    Ok(Validation::Continue) => true,
-                    Err(e) => {
-                        self.print_error(e)?;
+                    // The answer timeout is only set if the event iterator can wait, so the
+                    // shorter timeout has passed
+                    None if answer_timeout == Some(timeout) => {
+                        deadline = None;
 
+                        if !self.prompt.has_default() {
+                            self.clear()?;
+                            self.backend.reset()?;
+
+                            return Err(error::ErrorKind::TimedOut);
+                        }
+
+                        KeyCode::Enter.into()
+                    }
+                    None => {
+                        if self.prompt.handle_timeout() {
+                            self.render()?;
+                        }
                         continue;
                     }
                 },
-                _ => self.prompt.handle_key(e),
+                None => events.next_event()?,
             };
+            let mut key_handled = false;
+
+            // If coalescing, all the events that are already available are handled before
+            // rendering again, so that a burst of events (e.g. a held down key) only causes a
+            // single render
+            loop {
+                key_handled = match e.code {
+                    KeyCode::Char('c') if e.modifiers.contains(KeyModifiers::CONTROL) => {
+                        self.exit()?;
+                        return Err(error::ErrorKind::Interrupted);
+                    }
+                    KeyCode::Null => {
+                        self.exit()?;
+                        return Err(error::ErrorKind::Eof);
+                    }
+                    // The size is fetched again when rendering, so the prompt only needs to be
+                    // rendered again
+                    KeyCode::Resize(..) => true,
+                    KeyCode::Esc if self.prompt.handles_esc() => {
+                        self.prompt.handle_key(e) || key_handled
+                    }
+                    KeyCode::Esc if self.on_esc == OnEsc::Terminate => {
+                        self.exit()?;
+                        return Err(error::ErrorKind::Aborted);
+                    }
+                    KeyCode::Esc if self.on_esc == OnEsc::SkipQuestion => {
+                        self.clear()?;
+                        self.backend.reset()?;
+
+                        return Ok(None);
+                    }
+                    KeyCode::Esc if self.on_esc == OnEsc::GoBack => {
+                        self.clear()?;
+                        self.backend.reset()?;
+
+                        return Err(error::ErrorKind::GoBack);
+                    }
+                    KeyCode::Enter => match self.prompt.validate() {
+                        Ok(Validation::Finish) => {
+                            self.clear()?;
+                            self.backend.reset()?;
+
+                            return Ok(Some(self.prompt.finish()));
+                        }
+                        Ok(Validation::Continue) => true,
+                        Err(e) => {
+                            (self.on_validation_failed)();
+
+                            // The prompt has already been rendered along with the error
+                            self.print_error(e)?;
+
+                            false
+                        }
+                    },
+                    _ => self.prompt.handle_key(e) || key_handled,
+                };
+
+                if !self.coalesce_events {
+                    break;
+                }
+
+                match events.poll_event()? {
+                    Some(next) => e = next,
+                    None => break,
+                }
+            }
 
             if key_handled {
                 self.render()?;
@@ -380,10 +573,19 @@ mod tests {
     #[derive(Debug, Default, Clone, Copy)]
     struct TestPrompt {
         height: u16,
+        /// Fail validation until a key is pressed
+        require_key: bool,
+        /// How long to wait for after every key before timing out
+        key_timeout: Option<Duration>,
+        timeout_pending: bool,
+        keys: usize,
+        renders: usize,
+        timeouts: usize,
     }
 
     impl Widget for TestPrompt {
         fn render<B: Backend>(&mut self, layout: &mut Layout, backend: &mut B) -> io::Result<()> {
+            self.renders += 1;
             for i in 0..self.height(layout) {
                 // Not the most efficient but this is a test, and it makes assertions easier
                 backend.write_all(format!("Line {}", i).as_bytes())?;
@@ -401,17 +603,87 @@ mod tests {
             layout.offset_cursor((0, self.height))
         }
 
-        fn handle_key(&mut self, key: crate::events::KeyEvent) -> bool {
-            todo!("{:?}", key)
+        fn handle_key(&mut self, _: crate::events::KeyEvent) -> bool {
+            self.keys += 1;
+            self.timeout_pending = true;
+            true
         }
     }
 
     impl Prompt for TestPrompt {
         type ValidateErr = &'static str;
 
-        type Output = ();
+        type Output = Self;
+
+        fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
+            if self.require_key && self.keys == 0 {
+                Err("press a key")
+            } else {
+                Ok(Validation::Finish)
+            }
+        }
+
+        fn finish(self) -> Self::Output {
+            self
+        }
+
+        fn timeout(&self) -> Option<Duration> {
+            self.key_timeout.filter(|_| self.timeout_pending)
+        }
+
+        fn handle_timeout(&mut self) -> bool {
+            self.timeout_pending = false;
+            self.timeouts += 1;
+            true
+        }
+    }
+
+    /// Yields the events in bursts, where the events of a burst are all immediately available, and
+    /// an empty burst is a pause long enough for a timeout.
+    struct ScriptedEvents(
+        std::vec::IntoIter<Vec<crate::events::KeyEvent>>,
+        Vec<crate::events::KeyEvent>,
+    );
+
+    impl ScriptedEvents {
+        fn new(bursts: Vec<Vec<crate::events::KeyEvent>>) -> Self {
+            Self(bursts.into_iter(), Vec::new())
+        }
+
+        fn next_burst(&mut self) {
+            self.1 = self.0.next().expect("Events ran out");
+            self.1.reverse();
+        }
+    }
+
+    impl EventIterator for ScriptedEvents {
+        fn next_event(&mut self) -> io::Result<crate::events::KeyEvent> {
+            // Nothing is waiting for a timeout, so the pauses pass without one
+            while self.1.is_empty() {
+                self.next_burst();
+            }
+
+            Ok(self.1.pop().unwrap())
+        }
+
+        fn next_event_timeout(
+            &mut self,
+            _: Duration,
+        ) -> io::Result<Option<crate::events::KeyEvent>> {
+            if self.1.is_empty() {
+                self.next_burst();
+            }
+
+            Ok(self.1.pop())
+        }
+
+        fn poll_event(&mut self) -> io::Result<Option<crate::events::KeyEvent>> {
+            Ok(self.1.pop())
+        }
 
-        fn finish(self) -> Self::Output {}
+        fn supports_timeout(&self) -> bool {
+            true
+        }
     }
 
     #[test]
@@ -438,10 +710,14 @@ mod tests {
             Input {
                 prompt,
                 on_esc: OnEsc::Ignore,
+                timeout: None,
+                on_validation_failed: || {},
+                coalesce_events: false,
                 backend: TerminalState::new(&mut backend, false),
                 base_row: 14,
                 size,
                 render_overflow: false,
+                rendered_height: None,
             }
             .adjust_scrollback(3)
             .unwrap(),
@@ -454,10 +730,14 @@ mod tests {
             Input {
                 prompt,
                 on_esc: OnEsc::Ignore,
+                timeout: None,
+                on_validation_failed: || {},
+                coalesce_events: false,
                 backend: TerminalState::new(&mut backend, false),
                 base_row: 14,
                 size,
                 render_overflow: false,
+                rendered_height: None,
             }
             .adjust_scrollback(6)
             .unwrap(),
@@ -469,10 +749,14 @@ mod tests {
             Input {
                 prompt,
                 on_esc: OnEsc::Ignore,
+                timeout: None,
+                on_validation_failed: || {},
+                coalesce_events: false,
                 backend: TerminalState::new(&mut backend, false),
                 base_row: 14,
                 size,
                 render_overflow: false,
+                rendered_height: None,
             }
             .adjust_scrollback(10)
             .unwrap(),
@@ -483,7 +767,10 @@ mod tests {
 
     #[test]
     fn test_render() {
-        let prompt = TestPrompt { height: 5 };
+        let prompt = TestPrompt {
+            height: 5,
+            ..TestPrompt::default()
+        };
         let size = (100, 20).into();
         let mut backend = TestBackend::new(size);
         backend.move_cursor_to(0, 5).unwrap();
@@ -491,10 +778,14 @@ mod tests {
         assert!(Input {
             prompt,
             on_esc: OnEsc::Ignore,
+            timeout: None,
+            on_validation_failed: || {},
+            coalesce_events: false,
             backend: TerminalState::new(&mut backend, false),
             size,
             base_row: 5,
             render_overflow: false,
+            rendered_height: None,
         }
         .render()
         .is_ok());
@@ -511,10 +802,14 @@ mod tests {
         let mut input = Input {
             prompt: TestPrompt::default(),
             on_esc: OnEsc::Ignore,
+            timeout: None,
+            on_validation_failed: || {},
+            coalesce_events: false,
             backend: TerminalState::new(&mut backend, false),
             size,
             base_row: 15,
             render_overflow: false,
+            rendered_height: None,
         };
 
         assert!(input.goto_last_line(9).is_ok());
@@ -531,12 +826,19 @@ mod tests {
         let mut backend = TestBackend::new(size);
 
         assert!(Input {
-            prompt: TestPrompt { height: 5 },
+            prompt: TestPrompt {
+                height: 5,
+                ..TestPrompt::default()
+            },
             on_esc: OnEsc::Ignore,
+            timeout: None,
+            on_validation_failed: || {},
+            coalesce_events: false,
             backend: TerminalState::new(&mut backend, true),
             base_row: 0,
             size,
             render_overflow: false,
+            rendered_height: None,
         }
         .print_error(error)
         .is_ok());
@@ -544,6 +846,22 @@ mod tests {
         crate::assert_backend_snapshot!(backend);
     }
 
+    #[test]
+    fn test_resize() {
+        let mut backend = TestBackend::new((100, 20).into());
+        let prompt = Input::new(TestPrompt::default(), &mut backend)
+            .run(&mut TestEvents::new(vec![
+                KeyCode::Resize(80, 20).into(),
+                KeyCode::Enter.into(),
+            ]))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(prompt.keys, 0);
+        // Rendered once at the start, and again after the resize
+        assert_eq!(prompt.renders, 2);
+    }
+
     #[test]
     fn test_zero_size() {
         let mut backend = TestBackend::new((20, 0).into());
@@ -578,4 +896,143 @@ mod tests {
             "Invalid terminal Size { width: 0, height: 20 }. Both width and height must be larger than 0"
         );
     }
+
+    #[test]
+    fn test_coalesce_events() {
+        let mut backend = TestBackend::new((20, 10).into());
+        let events = || {
+            ScriptedEvents::new(vec![
+                vec![KeyCode::Down.into(); 10],
+                vec![KeyCode::Enter.into()],
+            ])
+        };
+
+        let prompt = Input::new(TestPrompt::default(), &mut backend)
+            .coalesce_events(true)
+            .run(&mut events())
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(prompt.keys, 10);
+        // Once when initialising and once after the burst
+        assert_eq!(prompt.renders, 2);
+
+        let prompt = Input::new(TestPrompt::default(), &mut backend)
+            .run(&mut events())
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(prompt.keys, 10);
+        // Once when initialising and once after every key
+        assert_eq!(prompt.renders, 11);
+
+        // The `Enter` is handled in the same burst, so the prompt finishes without rendering the
+        // keys
+        let prompt = Input::new(TestPrompt::default(), &mut backend)
+            .coalesce_events(true)
+            .run(
+                &mut TestEvents::from_chars("aaa")
+                    .then(KeyCode::Enter)
+                    .with_poll(true),
+            )
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(prompt.keys, 3);
+        assert_eq!(prompt.renders, 1);
+    }
+
+    fn timeout_prompt() -> TestPrompt {
+        TestPrompt {
+            key_timeout: Some(Duration::from_millis(300)),
+            ..TestPrompt::default()
+        }
+    }
+
+    #[test]
+    fn test_timeout() {
+        let mut backend = TestBackend::new((20, 10).into());
+        let a = || vec![KeyCode::Char('a').into()];
+        let mut events = ScriptedEvents::new(vec![
+            vec![],
+            a(),
+            a(),
+            a(),
+            vec![],
+            vec![],
+            a(),
+            vec![KeyCode::Enter.into()],
+        ]);
+
+        let prompt = Input::new(timeout_prompt(), &mut backend)
+            .run(&mut events)
+            .unwrap()
+            .unwrap();
+        // Only the pause after the first 3 keys times out
+        assert_eq!(prompt.timeouts, 1);
+
+        // Without support for waiting, the timeout is handled after every event
+        let prompt = Input::new(timeout_prompt(), &mut backend)
+            .run(&mut TestEvents::from_chars("aaa").then(KeyCode::Enter))
+            .unwrap()
+            .unwrap();
+        assert_eq!(prompt.timeouts, 3);
+    }
+
+    #[test]
+    fn test_answer_timeout() {
+        let mut backend = TestBackend::new((20, 10).into());
+        let timeout = Some(Duration::from_secs(1));
+
+        // The prompt has no default to submit
+        let res = Input::new(timeout_prompt(), &mut backend)
+            .timeout(timeout)
+            .run(&mut ScriptedEvents::new(vec![vec![]]));
+        assert!(matches!(res, Err(crate::ErrorKind::TimedOut)));
+
+        // Once a key is pressed, the prompt waits for the user again
+        let mut events = ScriptedEvents::new(vec![
+            vec![KeyCode::Char('a').into()],
+            vec![],
+            vec![],
+            vec![KeyCode::Enter.into()],
+        ]);
+        let prompt = Input::new(timeout_prompt(), &mut backend)
+            .timeout(timeout)
+            .run(&mut events)
+            .unwrap()
+            .unwrap();
+        // Only the timeout of the prompt is handled
+        assert_eq!(prompt.timeouts, 1);
+
+        // `TestEvents` cannot wait for a limited time, so the prompt waits for the user instead
+        let prompt = Input::new(timeout_prompt(), &mut backend)
+            .timeout(timeout)
+            .run(&mut TestEvents::new(Some(KeyCode::Enter.into())))
+            .unwrap()
+            .unwrap();
+        assert_eq!(prompt.timeouts, 0);
+    }
+
+    #[test]
+    fn test_on_validation_failed() {
+        let mut backend = TestBackend::new((20, 10).into());
+        let mut failures = 0;
+
+        let prompt = TestPrompt {
+            require_key: true,
+            ..TestPrompt::default()
+        };
+        let res = Input::new(prompt, &mut backend)
+            .on_validation_failed(|| failures += 1)
+            .run(&mut TestEvents::new(vec![
+                KeyCode::Enter.into(),
+                KeyCode::Enter.into(),
+                KeyCode::Char('a').into(),
+                KeyCode::Enter.into(),
+            ]));
+
+        assert_eq!(res.unwrap().unwrap().keys, 1);
+        assert_eq!(failures, 2);
+    }
 }
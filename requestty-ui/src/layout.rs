@@ -27,6 +27,12 @@ impl Default for RenderRegion {
 /// |  ██████████|
 /// '------------'
 /// ```
+///
+/// The `width` and `height` are the size of the terminal. [`Input`] creates a new `Layout` with the
+/// current size of the terminal for every render, so they can change between renders if the
+/// terminal is resized while the prompt is shown.
+///
+/// [`Input`]: crate::Input
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
 pub struct Layout {
     /// ```text
@@ -162,8 +168,10 @@ impl Layout {
     /// |  ██████████|
     /// '------------'
     /// ```
+    ///
+    /// It is 0 if there is no space left on the line, which can happen on very narrow terminals.
     pub fn line_width(&self) -> u16 {
-        self.available_width() - self.line_offset
+        self.available_width().saturating_sub(self.line_offset)
     }
 
     /// Gets the width of renderable space on subsequent lines.
@@ -177,7 +185,23 @@ impl Layout {
     /// '------------'
     /// ```
     pub fn available_width(&self) -> u16 {
-        self.width - self.offset_x
+        self.width.saturating_sub(self.offset_x)
+    }
+
+    /// Gets the number of lines from `offset_y` to the bottom of the screen.
+    ///
+    /// ```text
+    ///  ____________
+    /// |            |
+    /// |     ███████| <.
+    /// |  ██████████| <+-- available_height
+    /// |  ██████████| <'
+    /// '------------'
+    /// ```
+    ///
+    /// Unlike `max_height`, this is not limited by the region given to the widget.
+    pub fn available_height(&self) -> u16 {
+        self.height.saturating_sub(self.offset_y)
     }
 
     /// Gets the starting line number for the given `height` taking into account the `max_height`
@@ -220,3 +244,16 @@ fn test_layout() {
         5
     );
 }
+
+#[test]
+fn test_available_size() {
+    let layout = Layout::new(3, (100, 5).into()).with_offset(2, 1);
+    assert_eq!(layout.line_width(), 95);
+    assert_eq!(layout.available_width(), 98);
+    assert_eq!(layout.available_height(), 4);
+
+    let layout = layout.with_offset(101, 6);
+    assert_eq!(layout.line_width(), 0);
+    assert_eq!(layout.available_width(), 0);
+    assert_eq!(layout.available_height(), 0);
+}
@@ -24,6 +24,7 @@
 )]
 #![warn(rust_2018_idioms)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
+#![cfg_attr(all(test, nightly), feature(test))]
 
 pub use error::{ErrorKind, Result};
 pub use input::{Input, OnEsc, Prompt, Validation};
@@ -37,10 +38,13 @@ mod input;
 pub mod layout;
 mod prompt;
 mod select;
+mod spinner;
 mod string_input;
 pub mod style;
 pub mod symbols;
 mod text;
+mod text_area;
+pub mod theme;
 pub mod widgets;
 
 #[doc(hidden)]
@@ -1,10 +1,10 @@
-use std::{convert::TryFrom, io};
+use std::{borrow::Cow, convert::TryFrom, io, ops::Range};
 
 use crate::{
     backend::Backend,
     events,
     layout::Layout,
-    style::{Color, Stylize},
+    style::{Attributes, Color, StyledStr, StyledString, Stylize},
     Widget,
 };
 
@@ -38,22 +38,93 @@ impl From<Delimiter> for Option<(char, char)> {
     }
 }
 
+/// A message that can be rendered by a [`Prompt`].
+///
+/// It is implemented for strings, and for [`StyledString`] to highlight parts of the message.
+pub trait PromptMessage {
+    /// Borrows the message as a [`StyledStr`].
+    fn as_styled_str(&self) -> StyledStr<'_>;
+}
+
+impl PromptMessage for str {
+    fn as_styled_str(&self) -> StyledStr<'_> {
+        self.into()
+    }
+}
+
+impl PromptMessage for String {
+    fn as_styled_str(&self) -> StyledStr<'_> {
+        self.as_str().into()
+    }
+}
+
+impl PromptMessage for Cow<'_, str> {
+    fn as_styled_str(&self) -> StyledStr<'_> {
+        (**self).into()
+    }
+}
+
+impl PromptMessage for StyledString {
+    fn as_styled_str(&self) -> StyledStr<'_> {
+        StyledString::as_styled_str(self)
+    }
+}
+
+impl PromptMessage for StyledStr<'_> {
+    fn as_styled_str(&self) -> StyledStr<'_> {
+        *self
+    }
+}
+
+impl<M: PromptMessage + ?Sized> PromptMessage for &M {
+    fn as_styled_str(&self) -> StyledStr<'_> {
+        (**self).as_styled_str()
+    }
+}
+
+/// The byte ranges of the lines in `s`.
+fn line_ranges(s: &str) -> impl Iterator<Item = Range<usize>> + '_ {
+    let mut start = 0;
+    s.split('\n').map(move |line| {
+        let range = start..start + line.len();
+        start = range.end + 1;
+        range
+    })
+}
+
 /// A generic prompt that renders a message and an optional hint.
+///
+/// The message can span multiple lines, in which case every line after the first is indented to
+/// line up with the first one. The hint is rendered after the last line.
 #[derive(Debug, Clone)]
 pub struct Prompt<M, H = &'static str> {
     message: M,
     hint: Option<H>,
     delim: Delimiter,
+    /// The widths of all the lines of the message except the last one.
+    leading_lens: Vec<u16>,
     message_len: u16,
     hint_len: u16,
 }
 
-impl<M: AsRef<str>, H: AsRef<str>> Prompt<M, H> {
+fn display_len(s: &str) -> u16 {
+    u16::try_from(textwrap::core::display_width(s)).expect("message must fit within a u16")
+}
+
+impl<M: PromptMessage, H: AsRef<str>> Prompt<M, H> {
     /// Creates a new `Prompt`
     pub fn new(message: M) -> Self {
+        let mut leading_lens: Vec<_> = message
+            .as_styled_str()
+            .text()
+            .split('\n')
+            .map(display_len)
+            .collect();
+        let message_len = leading_lens.pop().unwrap_or(0);
+
         Self {
-            message_len: u16::try_from(textwrap::core::display_width(message.as_ref()))
-                .expect("message must fit within a u16"),
+            leading_lens,
+            message_len,
             message,
             hint: None,
             delim: Delimiter::Parentheses,
@@ -113,7 +184,8 @@ impl<M: AsRef<str>, H: AsRef<str>> Prompt<M, H> {
         (self.message, self.hint)
     }
 
-    /// The character length of the message
+    /// The character length of the message. If the message spans multiple lines, this is the
+    /// length of the last line.
     pub fn message_len(&self) -> u16 {
         self.message_len
     }
@@ -130,7 +202,8 @@ impl<M: AsRef<str>, H: AsRef<str>> Prompt<M, H> {
         }
     }
 
-    /// The character length of the fully rendered prompt
+    /// The character length of the fully rendered prompt. If the message spans multiple lines,
+    /// this is the length of the last line along with the hint.
     pub fn width(&self) -> u16 {
         if self.hint.is_some() {
             // `? <message> <hint> `
@@ -141,7 +214,28 @@ impl<M: AsRef<str>, H: AsRef<str>> Prompt<M, H> {
         }
     }
 
-    fn cursor_pos_impl(&self, layout: Layout) -> (u16, u16) {
+    /// The number of rows taken by all the lines of the message except the last one.
+    fn leading_height(&self, layout: Layout) -> u16 {
+        let mut line_offset = layout.line_offset;
+
+        self.leading_lens
+            .iter()
+            .map(|&len| {
+                // `? <line>` or `  <line>`
+                let width = line_offset + 2 + len;
+                line_offset = 0;
+                (width - 1) / layout.width + 1
+            })
+            .sum()
+    }
+
+    fn cursor_pos_impl(&self, mut layout: Layout) -> (u16, u16) {
+        let leading_height = self.leading_height(layout);
+        if leading_height > 0 {
+            layout.offset_y += leading_height;
+            layout.line_offset = 0;
+        }
+
         let mut width = self.width();
         let relative_pos = if width > layout.line_width() {
             width -= layout.line_width();
@@ -155,7 +249,7 @@ impl<M: AsRef<str>, H: AsRef<str>> Prompt<M, H> {
     }
 }
 
-impl<M: AsRef<str>> Prompt<M, &'static str> {
+impl<M: PromptMessage> Prompt<M, &'static str> {
     /// The end prompt to be printed once the question is answered.
     pub fn write_finished_message<B: Backend>(
         message: &M,
@@ -169,20 +263,38 @@ impl<M: AsRef<str>> Prompt<M, &'static str> {
             backend.write_styled(&symbol_set.completed.light_green())?;
         }
         backend.write_all(b" ")?;
-        backend.write_styled(&message.as_ref().bold())?;
+        let message = message.as_styled_str();
+        message.write_range(0..message.text().len(), Attributes::BOLD, backend)?;
         backend.write_all(b" ")?;
         backend.write_styled(&symbol_set.middle_dot.dark_grey())?;
         backend.write_all(b" ")
     }
 }
 
-impl<M: AsRef<str>, H: AsRef<str>> Widget for Prompt<M, H> {
+impl<M: PromptMessage, H: AsRef<str>> Widget for Prompt<M, H> {
     fn render<B: Backend>(&mut self, layout: &mut Layout, b: &mut B) -> io::Result<()> {
         b.write_styled(&"? ".light_green())?;
-        b.write_styled(&self.message.as_ref().bold())?;
+
+        let message = self.message.as_styled_str();
+        let mut lines = line_ranges(message.text());
+        let mut line_offset = layout.line_offset;
+        let mut offset_y = layout.offset_y;
+
+        for (&len, line) in self.leading_lens.iter().zip(lines.by_ref()) {
+            message.write_range(line, Attributes::BOLD, b)?;
+
+            offset_y += (line_offset + 2 + len - 1) / layout.width + 1;
+            line_offset = 0;
+            b.move_cursor_to(layout.offset_x, offset_y)?;
+            b.write_all(b"  ")?;
+        }
+
+        if let Some(line) = lines.next() {
+            message.write_range(line, Attributes::BOLD, b)?;
+        }
         b.write_all(b" ")?;
 
-        b.set_fg(Color::DarkGrey)?;
+        b.set_fg(crate::theme::current().hint)?;
 
         match (&self.hint, self.delim.into()) {
             (Some(hint), Some((start, end))) => write!(b, "{}{}{}", start, hint.as_ref(), end)?,
@@ -221,7 +333,10 @@ impl<M: AsRef<str>, H: AsRef<str>> Widget for Prompt<M, H> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{backend::TestBackend, test_consts::*};
+    use crate::{
+        backend::{BufferBackend, TestBackend},
+        test_consts::*,
+    };
 
     use super::*;
 
@@ -241,6 +356,26 @@ mod tests {
         assert_eq!(Prompt::new(LOREM).with_hint(UNICODE).width(), 946);
     }
 
+    #[test]
+    fn test_styled_message() {
+        let message = StyledString::new()
+            .with("Hello ")
+            .with("world".light_cyan())
+            .with("\nagain");
+        let mut prompt = super::Prompt::<_, &'static str>::new(&message);
+        assert_eq!(prompt.message_len(), 5);
+
+        let size = (100, 20).into();
+        let mut backend = BufferBackend::new(size);
+        prompt
+            .render(&mut Layout::new(0, size), &mut backend)
+            .unwrap();
+
+        let rendered = String::from_utf8(backend.into_bytes()).unwrap();
+        assert!(rendered.contains("\x1b[1mHello \x1b[22m\x1b[96m\x1b[1mworld\x1b[39m\x1b[22m"));
+        assert!(rendered.contains("\x1b[1magain\x1b[22m"));
+    }
+
     #[test]
     fn test_render() {
         fn test(
@@ -364,4 +499,27 @@ mod tests {
             (51, 12)
         );
     }
+
+    #[test]
+    fn test_multiline() {
+        let size = (20, 20).into();
+        let layout = Layout::new(5, size);
+
+        // The second line is long enough to wrap
+        let mut prompt = Prompt::new("Hello\nthis line is too long\nworld").with_hint("hint");
+
+        assert_eq!(prompt.width(), 2 + 5 + 1 + 6 + 1);
+        assert_eq!(prompt.leading_height(layout), 3);
+        assert_eq!(prompt.cursor_pos(layout), (15, 3));
+        assert_eq!(prompt.height(&mut layout.clone()), 4);
+
+        let mut render_layout = layout;
+        let mut backend = TestBackend::new_with_layout(size, layout);
+        prompt.render(&mut render_layout, &mut backend).unwrap();
+        crate::assert_backend_snapshot!(backend);
+
+        let mut height_layout = layout;
+        prompt.height(&mut height_layout);
+        assert_eq!(render_layout, height_layout);
+    }
 }
@@ -1,13 +1,13 @@
 use std::{
     io,
-    ops::{Index, IndexMut},
+    ops::{Index, IndexMut, Range},
 };
 
 use crate::{
-    backend::Backend,
+    backend::{Backend, ClearType},
     events::{KeyEvent, Movement},
     layout::{Layout, RenderRegion},
-    style::Stylize,
+    style::Color,
 };
 
 #[cfg(test)]
@@ -44,7 +44,8 @@ pub trait List {
 
     /// The maximum height that can be taken by the list.
     ///
-    /// If the total height exceeds the page size, the list will be scrollable.
+    /// If the total height exceeds the page size, the list will be scrollable. If the terminal is
+    /// not tall enough to show the full page, a smaller page size is used instead.
     fn page_size(&self) -> usize;
 
     /// Whether to wrap around when user gets to the last element.
@@ -55,6 +56,15 @@ pub trait List {
     /// The height of the element at an index will take to render
     fn height_at(&mut self, index: usize, layout: Layout) -> u16;
 
+    /// The height of every element, if they all take the same height whatever the layout.
+    ///
+    /// If this is `Some`, [`height_at`](List::height_at) is not called, so the heights of all the
+    /// elements don't have to be computed and stored whenever the layout changes. This matters for
+    /// very long lists. The default is `None`.
+    fn fixed_height(&self) -> Option<u16> {
+        None
+    }
+
     /// The length of the list
     fn len(&self) -> usize;
 
@@ -66,10 +76,37 @@ pub trait List {
 
 #[derive(Debug, Clone)]
 struct Heights {
+    /// The height of every element. It is empty if the list has a fixed height
     heights: Vec<u16>,
+    fixed: Option<u16>,
     prev_layout: Layout,
 }
 
+impl Heights {
+    fn get(&self, index: usize) -> u16 {
+        match self.fixed {
+            Some(height) => height,
+            None => self.heights[index],
+        }
+    }
+
+    /// The total height of the elements in `range`
+    fn sum(&self, range: Range<usize>) -> u16 {
+        match self.fixed {
+            Some(height) => height.saturating_mul(range.len().min(u16::MAX as usize) as u16),
+            None => self.heights[range].iter().sum(),
+        }
+    }
+}
+
+/// The state of the last render, used to only render the elements whose hover changed
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Rendered {
+    layout: Layout,
+    at: usize,
+    page: (usize, usize, u16, u16),
+}
+
 /// A widget to select a single item from a list.
 ///
 /// The list must implement the [`List`] trait.
@@ -84,6 +121,7 @@ pub struct Select<L> {
     page_end_height: u16,
     height: u16,
     heights: Option<Heights>,
+    rendered: Option<Rendered>,
     /// The underlying list
     pub list: L,
 }
@@ -112,6 +150,7 @@ impl<L: List> Select<L> {
             page_start_height: u16::MAX,
             page_end_height: u16::MAX,
             heights: None,
+            rendered: None,
             at: first_selectable,
             page_start: 0,
             page_end: usize::MAX,
@@ -146,6 +185,58 @@ impl<L: List> Select<L> {
         }
     }
 
+    /// Set the element that is currently being hovered to the selectable element nearest to `at`.
+    ///
+    /// Unlike [`set_at`](Select::set_at), this can be given any index. If `at` is not selectable,
+    /// for example if it is a separator, the closest selectable element is hovered instead,
+    /// preferring the one after it when two are equally close. If `at` is beyond the end of the
+    /// list, the last selectable element is hovered.
+    ///
+    /// This can be used to set the initially hovered element before the first render.
+    pub fn set_at_nearest(&mut self, at: usize) {
+        let at = if at >= self.last_selectable {
+            self.last_selectable
+        } else if at <= self.first_selectable {
+            self.first_selectable
+        } else {
+            // There is always a selectable element on both sides of `at`
+            (0..)
+                .flat_map(|d| [at + d, at - d])
+                .find(|&i| self.list.is_selectable(i))
+                .unwrap()
+        };
+
+        self.set_at(at);
+    }
+
+    /// Resets the hovered element and the page after the elements of the list have changed.
+    ///
+    /// Unlike [`refresh_heights`](Select::refresh_heights), this can be used when the length of
+    /// the list changes, for example when the list is filtered. The first selectable element is
+    /// hovered and everything is rendered again on the next render.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no selectable items.
+    pub fn reset(&mut self) {
+        self.first_selectable = (0..self.list.len())
+            .position(|i| self.list.is_selectable(i))
+            .expect("there must be at least one selectable item");
+
+        self.last_selectable = (0..self.list.len())
+            .rposition(|i| self.list.is_selectable(i))
+            .unwrap();
+
+        self.at = self.first_selectable;
+        self.page_start = 0;
+        self.page_end = usize::MAX;
+        self.page_start_height = u16::MAX;
+        self.page_end_height = u16::MAX;
+        self.height = u16::MAX;
+        self.heights = None;
+        self.rendered = None;
+    }
+
     /// Consumes the [`Select`] returning the original list.
     pub fn into_inner(self) -> L {
         self.list
@@ -191,36 +282,134 @@ impl<L: List> Select<L> {
         at
     }
 
-    fn maybe_update_heights(&mut self, mut layout: Layout) {
-        let heights = match self.heights {
-            Some(ref mut heights) if heights.prev_layout != layout => {
-                heights.heights.clear();
-                heights.prev_layout = layout;
-                &mut heights.heights
-            }
-            None => {
-                self.heights = Some(Heights {
-                    heights: Vec::with_capacity(self.list.len()),
-                    prev_layout: layout,
-                });
+    fn maybe_update_heights(&mut self, layout: Layout) {
+        match self.heights {
+            Some(ref heights) if heights.prev_layout == layout => {}
+            _ => {
+                let prev_page_size = self.page_size();
+                self.update_heights(layout);
+
+                // If the terminal was resized, the current page may no longer fit. The page will
+                // be initialised on the first render otherwise.
+                if self.page_size() != prev_page_size && self.page_end != usize::MAX {
+                    self.init_page();
 
-                &mut self.heights.as_mut().unwrap().heights
+                    if self.is_paginating() && self.at >= self.page_end {
+                        self.adjust_page(Movement::Down);
+                    }
+                }
             }
-            _ => return,
-        };
+        }
+    }
 
+    fn update_heights(&mut self, mut layout: Layout) {
+        let len = self.list.len();
+        let fixed = self.list.fixed_height();
+        let heights = self.heights.get_or_insert_with(|| Heights {
+            heights: Vec::new(),
+            fixed,
+            prev_layout: layout,
+        });
+
+        heights.heights.clear();
+        heights.fixed = fixed;
+        heights.prev_layout = layout;
+
+        if fixed.is_some() {
+            self.height = heights.sum(0..len);
+            return;
+        }
+
+        heights.heights.reserve(len);
         layout.line_offset = 0;
 
         self.height = 0;
         for i in 0..self.list.len() {
             let height = self.list.height_at(i, layout);
-            self.height += height;
-            heights.push(height);
+            // The total height is only compared with the page size, so it is fine for it to
+            // saturate with very long lists
+            self.height = self.height.saturating_add(height);
+            heights.heights.push(height);
+        }
+    }
+
+    /// Recomputes the heights of the elements of the list.
+    ///
+    /// This needs to be called when the heights of the elements change without the layout
+    /// changing, for example when the elements of the list are reordered. If the current page no
+    /// longer fits with the new heights, it is adjusted to keep the hovered element visible.
+    pub fn refresh_heights(&mut self) {
+        let layout = match self.heights {
+            Some(ref heights) => heights.prev_layout,
+            // The heights will be calculated on the first render anyway
+            None => return,
+        };
+
+        let prev_page_height = self.page_height();
+        self.update_heights(layout);
+
+        // The page will be initialised on the first render
+        if self.page_end == usize::MAX {
+            return;
+        }
+
+        if !self.is_paginating() {
+            self.init_page();
+            return;
+        }
+
+        let heights = self.heights.as_ref().unwrap();
+
+        if self.page_start_height <= heights.get(self.page_start)
+            && self.page_end_height <= heights.get(self.page_end)
+            && self.page_height() == prev_page_height
+        {
+            return;
+        }
+
+        if self.page_start == 0 && !self.list.should_loop() {
+            self.init_page();
+
+            if self.at < self.page_end {
+                return;
+            }
         }
+
+        self.adjust_page(Movement::Down);
+    }
+
+    /// The number of lines taken by the elements on the current page
+    fn page_height(&self) -> u16 {
+        let heights = match self.heights {
+            Some(ref heights) if self.page_end != usize::MAX => heights,
+            _ => return 0,
+        };
+
+        if self.page_start == self.page_end {
+            return self.page_start_height;
+        }
+
+        let inner: u16 = if self.page_end < self.page_start {
+            heights.sum(self.page_start + 1..self.list.len()) + heights.sum(0..self.page_end)
+        } else {
+            heights.sum(self.page_start + 1..self.page_end)
+        };
+
+        self.page_start_height + inner + self.page_end_height
     }
 
+    /// The page size of the list, clamped so that the page fits within the height of the terminal
+    /// along with a line for the prompt message.
     fn page_size(&self) -> u16 {
-        self.list.page_size() as u16
+        let page_size = self.list.page_size() as u16;
+
+        match self.heights {
+            Some(ref heights) => page_size
+                .min(heights.prev_layout.height.saturating_sub(1))
+                // There must be space for at least one element and the message at the end
+                .max(2),
+            None => page_size,
+        }
     }
 
     fn is_paginating(&self) -> bool {
@@ -276,11 +465,10 @@ impl<L: List> Select<L> {
             _ => unreachable!(),
         };
 
-        let heights = &self
+        let heights = self
             .heights
             .as_ref()
-            .expect("`adjust_page` called before `height` or `render`")
-            .heights[..];
+            .expect("`adjust_page` called before `height` or `render`");
 
         // -1 since the message at the end takes one line
         let max_height = self.page_size() - 1;
@@ -320,10 +508,10 @@ impl<L: List> Select<L> {
 
         // these variables have opposite meaning based on the direction, but they store
         // the (index, height) of either the page_start or the page_end
-        let mut bound_a = (self.at, heights[self.at]);
-        let mut bound_b = (self.at, heights[self.at]);
+        let mut bound_a = (self.at, heights.get(self.at));
+        let mut bound_b = (self.at, heights.get(self.at));
 
-        let mut height = heights[self.at];
+        let mut height = heights.get(self.at);
 
         for (height_index, opposite_dir) in iter {
             if height >= max_height {
@@ -338,7 +526,7 @@ impl<L: List> Select<L> {
                 // from the one rendered previously
                 1
             } else {
-                (height + heights[height_index]).min(max_height) - height
+                (height + heights.get(height_index)).min(max_height) - height
             };
 
             // If you see the creation of iter, this special cases the second element in
@@ -383,34 +571,83 @@ impl<L: List> Select<L> {
     }
 
     fn init_page(&mut self) {
-        let heights = &self
+        let heights = self
             .heights
             .as_ref()
-            .expect("`init_page` called before `height` or `render`")
-            .heights[..];
+            .expect("`init_page` called before `height` or `render`");
 
         self.page_start = 0;
-        self.page_start_height = heights[self.page_start];
+        self.page_start_height = heights.get(self.page_start);
 
         if self.is_paginating() {
-            let mut height = heights[0];
+            let mut height = heights.get(0);
             // -1 since the message at the end takes one line
             let max_height = self.page_size() - 1;
 
-            #[allow(clippy::needless_range_loop)]
-            for i in 1..heights.len() {
+            for i in 1..self.list.len() {
                 if height >= max_height {
                     break;
                 }
                 self.page_end = i;
-                self.page_end_height = (height + heights[i]).min(max_height) - height;
+                self.page_end_height = (height + heights.get(i)).min(max_height) - height;
 
-                height += heights[i];
+                height += heights.get(i);
             }
         } else {
             self.page_end = self.list.len() - 1;
-            self.page_end_height = heights[self.page_end];
+            self.page_end_height = heights.get(self.page_end);
+        }
+    }
+
+    fn page(&self) -> (usize, usize, u16, u16) {
+        (
+            self.page_start,
+            self.page_end,
+            self.page_start_height,
+            self.page_end_height,
+        )
+    }
+
+    /// Renders the elements on the current page, followed by the message at the end if
+    /// paginating.
+    ///
+    /// If `only` is given, the other elements are skipped and left as they are on the screen.
+    fn render_page<B: Backend>(
+        &mut self,
+        layout: &mut Layout,
+        b: &mut B,
+        only: Option<[usize; 2]>,
+    ) -> io::Result<()> {
+        if layout.line_offset != 0 {
+            layout.line_offset = 0;
+            layout.offset_y += 1;
+            b.move_cursor_to(layout.offset_x, layout.offset_y)?;
+        }
+
+        if self.page_end < self.page_start {
+            self.render_in(
+                (self.page_start..self.list.len()).chain(0..=self.page_end),
+                layout,
+                b,
+                only,
+            )?;
+        } else {
+            self.render_in(self.page_start..=self.page_end, layout, b, only)?;
+        }
+
+        if self.is_paginating() {
+            // This is the message at the end that other places refer to
+            if only.is_none() {
+                b.set_fg(crate::theme::current().hint)?;
+                b.write_all(b"(Move up and down to reveal more choices)")?;
+                b.set_fg(Color::Reset)?;
+            }
+            layout.offset_y += 1;
+
+            b.move_cursor_to(layout.offset_x, layout.offset_y)?;
         }
+
+        Ok(())
     }
 
     /// Renders the lines in a given iterator
@@ -419,12 +656,12 @@ impl<L: List> Select<L> {
         iter: I,
         old_layout: &mut Layout,
         b: &mut B,
+        only: Option<[usize; 2]>,
     ) -> io::Result<()> {
-        let heights = &self
+        let heights = self
             .heights
             .as_ref()
-            .expect("`render_in` called from someplace other than `render`")
-            .heights[..];
+            .expect("`render_in` called from someplace other than `render`");
 
         // Create a new local copy of the layout to operate on to avoid changes in max_height and
         // render_region to be reflected upstream
@@ -438,10 +675,23 @@ impl<L: List> Select<L> {
                 layout.max_height = self.page_end_height;
                 layout.render_region = RenderRegion::Top;
             } else {
-                layout.max_height = heights[i];
+                layout.max_height = heights.get(i);
             }
 
-            self.list.render_item(i, i == self.at, layout, b)?;
+            match only {
+                Some(only) if !only.contains(&i) => {}
+                Some(_) => {
+                    // The previous render of the element has to be cleared first
+                    for line in 0..layout.max_height {
+                        b.move_cursor_to(layout.offset_x, layout.offset_y + line)?;
+                        b.clear(ClearType::UntilNewLine)?;
+                    }
+                    b.move_cursor_to(layout.offset_x, layout.offset_y)?;
+
+                    self.list.render_item(i, i == self.at, layout, b)?;
+                }
+                None => self.list.render_item(i, i == self.at, layout, b)?,
+            }
             layout.offset_y += layout.max_height;
 
             b.move_cursor_to(layout.offset_x, layout.offset_y)?;
@@ -594,33 +844,52 @@ impl<L: List> super::Widget for Select<L> {
         // this is the first render, so we need to set page_end
         if self.page_end == usize::MAX {
             self.init_page();
-        }
 
-        if layout.line_offset != 0 {
-            layout.line_offset = 0;
-            layout.offset_y += 1;
-            b.move_cursor_to(layout.offset_x, layout.offset_y)?;
+            // `at` may have been set beyond the first page before the first render
+            if self.is_paginating() && self.at > self.page_end {
+                self.adjust_page(Movement::Down);
+            }
         }
 
-        if self.page_end < self.page_start {
-            self.render_in(
-                (self.page_start..self.list.len()).chain(0..=self.page_end),
-                layout,
-                b,
-            )?;
-        } else {
-            self.render_in(self.page_start..=self.page_end, layout, b)?;
-        }
+        self.rendered = Some(Rendered {
+            layout: *layout,
+            at: self.at,
+            page: self.page(),
+        });
 
-        if self.is_paginating() {
-            // This is the message at the end that other places refer to
-            b.write_styled(&"(Move up and down to reveal more choices)".dark_grey())?;
-            layout.offset_y += 1;
+        self.render_page(layout, b, None)
+    }
 
-            b.move_cursor_to(layout.offset_x, layout.offset_y)?;
+    /// Only renders the previously hovered and the currently hovered elements, if the hover is the
+    /// only thing that changed since the last render.
+    ///
+    /// Changes to the elements of the list itself are not tracked, so if an element changes
+    /// without the hover moving, this always falls back to a full render.
+    fn render_changes<B: Backend>(&mut self, layout: &mut Layout, b: &mut B) -> io::Result<bool> {
+        let prev_at = match self.rendered {
+            Some(rendered)
+                if rendered.layout == *layout
+                    && rendered.page == self.page()
+                    && rendered.at != self.at =>
+            {
+                rendered.at
+            }
+            _ => return Ok(false),
+        };
+
+        match self.heights {
+            Some(ref heights) if heights.prev_layout == *layout => {}
+            _ => return Ok(false),
         }
 
-        Ok(())
+        self.rendered = Some(Rendered {
+            layout: *layout,
+            at: self.at,
+            page: self.page(),
+        });
+
+        self.render_page(layout, b, Some([prev_at, self.at]))?;
+        Ok(true)
     }
 
     /// Returns the starting location of the layout. It should not be relied upon for a sensible
@@ -632,6 +901,15 @@ impl<L: List> super::Widget for Select<L> {
     fn height(&mut self, layout: &mut Layout) -> u16 {
         self.maybe_update_heights(*layout);
 
+        let at_height = if self.at < self.list.len() {
+            self.heights
+                .as_ref()
+                .expect("`maybe_update_heights` should set `self.heights` if missing")
+                .get(self.at)
+        } else {
+            0
+        };
+
         let height = (layout.line_offset != 0) as u16 // Add one if we go to the next line
             // Try to show everything
             + self
@@ -639,16 +917,8 @@ impl<L: List> super::Widget for Select<L> {
                 // otherwise show whatever is possible
                 .min(self.page_size())
                 // but do not show less than a single element
-                .max(
-                    self.heights
-                    .as_ref()
-                    .expect("`maybe_update_heights` should set `self.heights` if missing")
-                    .heights
-                    .get(self.at)
-                    .unwrap_or(&0)
-                    // +1 if paginating since the message at the end takes one line
-                    + self.is_paginating() as u16,
-                );
+                // +1 if paginating since the message at the end takes one line
+                .max(at_height + self.is_paginating() as u16);
 
         layout.line_offset = 0;
         layout.offset_y += height;
@@ -11,6 +11,8 @@ struct List<T> {
     selectable: Vec<bool>,
     page_size: usize,
     should_loop: bool,
+    /// The number of times an element has been rendered
+    rendered: usize,
 }
 
 impl<T> List<T> {
@@ -20,6 +22,7 @@ impl<T> List<T> {
             selectable: Vec::new(),
             page_size: 15,
             should_loop: true,
+            rendered: 0,
         }
     }
 
@@ -48,6 +51,8 @@ impl<T: Widget> super::List for List<T> {
         mut layout: Layout,
         backend: &mut B,
     ) -> io::Result<()> {
+        self.rendered += 1;
+
         if hovered {
             backend.set_fg(Color::Cyan)?;
         }
@@ -163,6 +168,78 @@ fn test_selectable() {
     assert_eq!(select.next_selectable(), 9);
 }
 
+#[test]
+fn test_set_at_nearest() {
+    let list = List::new(single_line_vec(11)).with_selectable(vec![
+        false, true, true, false, false, false, true, false, false, true, false,
+    ]);
+
+    let mut select = Select::new(list);
+
+    for &(at, nearest) in [
+        (0, 1),
+        (2, 2),
+        (3, 2),
+        (4, 6),
+        (5, 6),
+        (7, 6),
+        (8, 9),
+        (10, 9),
+        (100, 9),
+    ]
+    .iter()
+    {
+        select.set_at_nearest(at);
+        assert_eq!(select.get_at(), nearest, "nearest to {}", at);
+    }
+
+    // It can be set before the first render
+    let list = List::new(single_line_vec(30)).with_page_size(10);
+    let mut select = Select::new(list);
+    select.set_at_nearest(25);
+
+    let mut layout = Layout::new(0, (100, 20).into());
+    let mut backend = TestBackend::new((100, 20).into());
+    select.render(&mut layout, &mut backend).unwrap();
+
+    assert_eq!(select.get_at(), 25);
+    assert!(
+        select.page_start <= 25 && 25 < select.page_end,
+        "{} {}",
+        select.page_start,
+        select.page_end
+    );
+}
+
+#[test]
+fn test_reset() {
+    let mut select = Select::new(List::new(single_line_vec(30)).with_page_size(10));
+    let mut layout = Layout::new(0, (100, 20).into());
+    let mut backend = TestBackend::new((100, 20).into());
+
+    select.set_at(25);
+    select.render(&mut layout, &mut backend).unwrap();
+    assert!(select.is_paginating());
+
+    // Shrink the list so that it is no longer paginated, and the previous hover is out of range
+    select.list.vec.truncate(5);
+    select.list.selectable = vec![false, true, true, false, false];
+    select.reset();
+
+    assert_eq!(select.get_at(), 1);
+    assert_eq!(select.last_selectable, 2);
+    assert!(select.heights.is_none());
+
+    let mut layout = Layout::new(0, (100, 20).into());
+    backend.reset_with_layout(layout);
+    assert!(!select.render_changes(&mut layout, &mut backend).unwrap());
+    select.render(&mut layout, &mut backend).unwrap();
+
+    assert!(!select.is_paginating());
+    assert_eq!((select.page_start, select.page_end), (0, 4));
+    assert_eq!(layout.offset_y, 5);
+}
+
 #[test]
 fn test_update_heights() {
     let layout = Layout::new(0, (100, 20).into());
@@ -184,6 +261,78 @@ fn test_update_heights() {
     assert!(heights[1..9].iter().all(|&h| h == 2));
 }
 
+/// A list of single line elements, which does not know the heights of its elements
+struct FixedHeightList(usize);
+
+impl super::List for FixedHeightList {
+    fn render_item<B: Backend>(
+        &mut self,
+        index: usize,
+        _: bool,
+        _: Layout,
+        backend: &mut B,
+    ) -> io::Result<()> {
+        write!(backend, "{}", index)
+    }
+
+    fn is_selectable(&self, _: usize) -> bool {
+        true
+    }
+
+    fn page_size(&self) -> usize {
+        15
+    }
+
+    fn should_loop(&self) -> bool {
+        true
+    }
+
+    fn height_at(&mut self, _: usize, _: Layout) -> u16 {
+        unreachable!("the height is fixed")
+    }
+
+    fn fixed_height(&self) -> Option<u16> {
+        Some(1)
+    }
+
+    fn len(&self) -> usize {
+        self.0
+    }
+}
+
+#[test]
+fn test_fixed_height() {
+    let mut layout = Layout::new(0, (100, 20).into());
+
+    let mut select = Select::new(FixedHeightList(1_000_000));
+    assert_eq!(select.height(&mut layout.clone()), 15);
+    assert!(select.heights.as_ref().unwrap().heights.is_empty());
+    assert_eq!(select.height, u16::MAX);
+
+    select
+        .render(&mut layout, &mut TestBackend::new((100, 20).into()))
+        .unwrap();
+
+    // The pages are the same as those of a list which knows the heights of its elements
+    let layout = Layout::new(0, (100, 20).into());
+    let mut fixed = Select::new(FixedHeightList(20));
+    let mut list = Select::new(List::new(single_line_vec(20)));
+    fixed.maybe_update_heights(layout);
+    list.maybe_update_heights(layout);
+    fixed.init_page();
+    list.init_page();
+    assert_eq!(fixed.height, list.height);
+    assert_eq!(fixed.page(), list.page());
+
+    for &key in [KeyCode::Up, KeyCode::Up, KeyCode::Down, KeyCode::PageDown].iter() {
+        fixed.handle_key(key.into());
+        list.handle_key(key.into());
+        assert_eq!(fixed.at, list.at);
+        assert_eq!(fixed.page(), list.page());
+        assert_eq!(fixed.page_height(), list.page_height());
+    }
+}
+
 #[test]
 fn test_at_outside_page() {
     let mut select = Select::new(List::new(single_line_vec(20)).with_page_size(10));
@@ -592,3 +741,96 @@ fn test_render() {
     crate::assert_backend_snapshot!(backend);
     assert_eq!(layout, base_layout.with_offset(20, 31));
 }
+
+#[test]
+fn test_render_changes() {
+    fn test<T: Widget>(mut select: Select<List<T>>, layout: Layout, keys: &[KeyCode]) {
+        let size = (layout.width, layout.height).into();
+        let mut backend = TestBackend::new_with_layout(size, layout);
+        select.render(&mut layout.clone(), &mut backend).unwrap();
+
+        // Nothing has changed
+        assert!(!select
+            .render_changes(&mut layout.clone(), &mut backend)
+            .unwrap());
+
+        for &key in keys {
+            select.handle_key(key.into());
+
+            let rendered = select.list.rendered;
+            let mut changes_layout = layout;
+            assert!(select
+                .render_changes(&mut changes_layout, &mut backend)
+                .unwrap());
+            // Only the previously hovered and the newly hovered elements are rendered
+            assert_eq!(select.list.rendered, rendered + 2);
+
+            let mut full_backend = TestBackend::new_with_layout(size, layout);
+            let mut full_layout = layout;
+            select.render(&mut full_layout, &mut full_backend).unwrap();
+            backend.assert_eq(&full_backend);
+            assert_eq!(changes_layout, full_layout);
+        }
+    }
+
+    let size = (100, 20).into();
+    let layout = Layout::new(0, size);
+
+    test(
+        Select::new(List::new(single_line_vec(5))),
+        layout,
+        &[KeyCode::Down, KeyCode::Down, KeyCode::Up, KeyCode::End],
+    );
+    test(
+        Select::new(List::new(multi_line_list(10)).with_page_size(20)),
+        layout.with_line_offset(10),
+        &[KeyCode::Down, KeyCode::Down, KeyCode::Up],
+    );
+
+    // The page changes, so everything has to be rendered
+    let mut select = Select::new(List::new(single_line_vec(20)).with_page_size(10));
+    let mut backend = TestBackend::new_with_layout(size, layout);
+    select.render(&mut layout.clone(), &mut backend).unwrap();
+    select.handle_key(KeyCode::End.into());
+    assert!(!select
+        .render_changes(&mut layout.clone(), &mut backend)
+        .unwrap());
+
+    // The layout changes, so everything has to be rendered
+    select.handle_key(KeyCode::Up.into());
+    assert!(!select
+        .render_changes(&mut layout.with_line_offset(5), &mut backend)
+        .unwrap());
+}
+
+#[test]
+fn test_page_size_clamped() {
+    // Only 8 lines are available, so the page size of 15 cannot be used
+    let size = (100, 8).into();
+    let base_layout = Layout::new(0, size).with_offset(0, 1);
+    let mut layout = base_layout;
+    let mut backend = TestBackend::new_with_layout(size, layout);
+
+    let mut select = Select::new(List::new(single_line_vec(20)));
+    assert_eq!(select.height(&mut layout.clone()), 7);
+
+    select.render(&mut layout, &mut backend).unwrap();
+    crate::assert_backend_snapshot!(backend);
+    assert_eq!(layout, base_layout.with_offset(0, 8));
+
+    // The page is adjusted when the terminal shrinks
+    let mut layout = Layout::new(0, (100, 20).into());
+    let mut select = Select::new(List::new(single_line_vec(20)));
+    select
+        .render(&mut layout, &mut TestBackend::new((100, 20).into()))
+        .unwrap();
+    select.set_at(12);
+
+    layout = base_layout;
+    backend.reset_with_layout(layout);
+    select.render(&mut layout, &mut backend).unwrap();
+    assert_eq!(select.page_size(), 7);
+    assert!(!select.at_outside_page());
+    crate::assert_backend_snapshot!(backend);
+    assert_eq!(layout, base_layout.with_offset(0, 8));
+}
@@ -0,0 +1,338 @@
+use std::{
+    future::Future,
+    io,
+    sync::Arc,
+    task::{Context, Poll, Wake},
+    thread::{self, Thread},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    backend::{Backend, ClearType},
+    layout::Layout,
+    style::Color,
+};
+
+const UNICODE_FRAMES: &[char] = &['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+const ASCII_FRAMES: &[char] = &['|', '/', '-', '\\'];
+
+/// The time between two frames of the spinner when it is [run](Spinner::run).
+pub const FRAME_INTERVAL: Duration = Duration::from_millis(80);
+
+/// A widget that shows an animated spinner followed by a message.
+///
+/// The spinner only moves to the next frame when [`tick`](Spinner::tick) is called, so it can be
+/// driven by any timer. Alternatively, [`run`](Spinner::run) can be used to show the spinner while
+/// waiting for a future to complete.
+///
+/// Unless other frames are given with [`with_frames`](Spinner::with_frames), the frames only use
+/// ascii characters if the current [`symbols`](crate::symbols) are
+/// [`ASCII`](crate::symbols::ASCII).
+#[derive(Debug, Clone)]
+pub struct Spinner<M> {
+    message: M,
+    frame: usize,
+    frames: Option<Vec<char>>,
+}
+
+impl<M: AsRef<str>> Spinner<M> {
+    /// Creates a new [`Spinner`] with the given message.
+    pub fn new(message: M) -> Self {
+        Self {
+            message,
+            frame: 0,
+            frames: None,
+        }
+    }
+
+    /// Creates a new [`Spinner`] which cycles through the given frames instead of the default ones.
+    ///
+    /// # Panics
+    ///
+    /// It will panic if there are no frames.
+    pub fn with_frames<I: IntoIterator<Item = char>>(mut self, frames: I) -> Self {
+        let frames: Vec<_> = frames.into_iter().collect();
+        assert!(!frames.is_empty(), "a spinner needs at least one frame");
+
+        self.frame = 0;
+        self.frames = Some(frames);
+        self
+    }
+
+    /// The message shown after the spinner.
+    pub fn message(&self) -> &M {
+        &self.message
+    }
+
+    /// Moves the spinner to the next frame.
+    pub fn tick(&mut self) {
+        self.frame = (self.frame + 1) % self.frames().len();
+    }
+
+    fn frames(&self) -> &[char] {
+        match self.frames {
+            Some(ref frames) => frames,
+            None => default_frames(),
+        }
+    }
+
+    fn width(&self) -> u16 {
+        // The frame and the space after it
+        2 + textwrap::core::display_width(self.message.as_ref()) as u16
+    }
+
+    /// Shows the spinner until the given future completes, returning the output of the future.
+    ///
+    /// The future is run on the current thread, and the spinner is moved to the next frame every
+    /// [`FRAME_INTERVAL`]. Once the future completes, the spinner is cleared, so that the result
+    /// can be printed in its place.
+    ///
+    /// The terminal is restored even if rendering fails, in which case the error is returned and
+    /// the future is dropped.
+    pub fn run<B: Backend, F: Future>(
+        mut self,
+        backend: &mut B,
+        future: F,
+    ) -> io::Result<F::Output> {
+        backend.hide_cursor()?;
+        let res = backend
+            .enable_raw_mode()
+            .and_then(|_| self.run_inner(backend, future));
+
+        let reset = backend
+            .disable_raw_mode()
+            .and_then(|_| backend.show_cursor())
+            .and_then(|_| backend.flush());
+
+        let output = res?;
+        reset?;
+        Ok(output)
+    }
+
+    fn run_inner<B: Backend, F: Future>(
+        &mut self,
+        backend: &mut B,
+        future: F,
+    ) -> io::Result<F::Output> {
+        let base_row = backend.get_cursor_pos()?.1;
+        let waker = Arc::new(ThreadWaker(thread::current())).into();
+        let mut cx = Context::from_waker(&waker);
+        let mut future = Box::pin(future);
+        let mut next_frame = Instant::now();
+
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                backend.move_cursor_to(0, base_row)?;
+                backend.clear(ClearType::FromCursorDown)?;
+                return Ok(output);
+            }
+
+            let now = Instant::now();
+            if now >= next_frame {
+                let mut layout = Layout::new(0, backend.size()?).with_offset(0, base_row);
+                backend.move_cursor_to(0, base_row)?;
+                backend.clear(ClearType::FromCursorDown)?;
+                crate::Widget::render(self, &mut layout, backend)?;
+                backend.flush()?;
+
+                self.tick();
+                next_frame = now + FRAME_INTERVAL;
+            }
+
+            // Woken either by the future or when the next frame is due
+            thread::park_timeout(next_frame.saturating_duration_since(Instant::now()));
+        }
+    }
+}
+
+fn default_frames() -> &'static [char] {
+    if crate::symbols::current() == crate::symbols::ASCII {
+        ASCII_FRAMES
+    } else {
+        UNICODE_FRAMES
+    }
+}
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+impl<M: AsRef<str>> super::Widget for Spinner<M> {
+    fn render<B: Backend>(&mut self, layout: &mut Layout, backend: &mut B) -> io::Result<()> {
+        let frames = self.frames();
+
+        backend.set_fg(Color::Cyan)?;
+        write!(backend, "{}", frames[self.frame % frames.len()])?;
+        backend.set_fg(Color::Reset)?;
+        write!(backend, " {}", self.message.as_ref())?;
+
+        self.height(layout);
+        Ok(())
+    }
+
+    fn height(&mut self, layout: &mut Layout) -> u16 {
+        let mut width = self.width();
+
+        if width > layout.line_width() {
+            width -= layout.line_width();
+
+            layout.line_offset = width % layout.width;
+            layout.offset_y += 1 + width / layout.width;
+
+            2 + width / layout.width
+        } else {
+            layout.line_offset += width;
+            1
+        }
+    }
+
+    fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+        let mut end = layout;
+        self.height(&mut end);
+        (end.offset_x + end.line_offset, end.offset_y)
+    }
+
+    fn handle_key(&mut self, _: crate::events::KeyEvent) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        pin::Pin,
+        sync::{mpsc, Mutex},
+        task::Waker,
+    };
+
+    use super::*;
+    use crate::{backend::TestBackend, Widget};
+
+    /// A future which resolves to the value sent from another thread.
+    struct Received<T> {
+        rx: mpsc::Receiver<T>,
+        waker: Arc<Mutex<Option<Waker>>>,
+    }
+
+    impl<T> Future for Received<T> {
+        type Output = T;
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+            // The waker is stored before checking, so a value sent after the check wakes it
+            *self.waker.lock().unwrap() = Some(cx.waker().clone());
+
+            match self.rx.try_recv() {
+                Ok(value) => Poll::Ready(value),
+                Err(_) => Poll::Pending,
+            }
+        }
+    }
+
+    /// Sends `value` to the returned future from another thread after `delay`.
+    fn send_after<T: Send + 'static>(
+        value: T,
+        delay: Duration,
+    ) -> (Received<T>, thread::JoinHandle<()>) {
+        let (tx, rx) = mpsc::channel();
+        let waker = Arc::new(Mutex::new(None::<Waker>));
+        let sender_waker = Arc::clone(&waker);
+
+        let sender = thread::spawn(move || {
+            thread::sleep(delay);
+            tx.send(value).unwrap();
+            if let Some(waker) = sender_waker.lock().unwrap().take() {
+                waker.wake();
+            }
+        });
+
+        (Received { rx, waker }, sender)
+    }
+
+    #[test]
+    fn test_tick() {
+        let mut spinner = Spinner::new("message");
+
+        for _ in 0..default_frames().len() - 1 {
+            spinner.tick();
+        }
+        assert_eq!(spinner.frame, default_frames().len() - 1);
+
+        spinner.tick();
+        assert_eq!(spinner.frame, 0);
+    }
+
+    #[test]
+    fn test_with_frames() {
+        let size = (20, 10).into();
+        let mut backend = TestBackend::new(size);
+        let mut spinner = Spinner::new("message").with_frames(vec!['.', 'o', 'O']);
+
+        let mut rendered = Vec::new();
+        for _ in 0..4 {
+            backend.reset_with_layout(Layout::new(0, size));
+            assert!(spinner
+                .render(&mut Layout::new(0, size), &mut backend)
+                .is_ok());
+            rendered.push(backend.row_text(0));
+            spinner.tick();
+        }
+
+        assert_eq!(
+            rendered,
+            [". message", "o message", "O message", ". message"]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "a spinner needs at least one frame")]
+    fn test_no_frames() {
+        Spinner::new("message").with_frames(Vec::new());
+    }
+
+    #[test]
+    fn test_render() {
+        let size = (20, 10).into();
+        let layout = Layout::new(5, size);
+        let mut backend = TestBackend::new_with_layout(size, layout);
+        let mut spinner = Spinner::new("loading the things");
+
+        let mut render_layout = layout;
+        let mut height_layout = layout;
+
+        spinner.tick();
+        assert!(spinner.render(&mut render_layout, &mut backend).is_ok());
+        crate::assert_backend_snapshot!(backend);
+
+        assert_eq!(spinner.height(&mut height_layout), 2);
+        assert_eq!(render_layout, height_layout);
+        assert_eq!(spinner.cursor_pos(layout), (5, 1));
+    }
+
+    #[test]
+    fn test_run_resolves() {
+        let mut backend = TestBackend::new((20, 10).into());
+        let (future, sender) = send_after(42, FRAME_INTERVAL * 3);
+
+        let res = Spinner::new("message").run(&mut backend, future);
+        sender.join().unwrap();
+
+        assert_eq!(res.unwrap(), 42);
+        // The spinner is cleared once the future completes
+        crate::assert_backend_snapshot!(backend);
+    }
+
+    #[test]
+    fn test_run_error() {
+        let mut backend = TestBackend::new((20, 10).into());
+        let future = std::future::ready(Err::<i32, _>("failed"));
+
+        assert_eq!(
+            Spinner::new("message").run(&mut backend, future).unwrap(),
+            Err("failed")
+        );
+    }
+}
@@ -16,21 +16,68 @@ use crate::{
 /// A `filter_map` function can optionally be provided to limit and change the characters allowed,
 /// similar to [`Iterator::filter_map`].
 ///
-/// If only a single character is required, use [`CharInput`].
+/// If only a single character is required, use [`CharInput`]. Input which follows a fixed template,
+/// such as a phone number or a date, is handled by the `masked` question in [`requestty`].
 ///
 /// [`CharInput`]: crate::widgets::CharInput
+/// [`requestty`]: https://crates.io/crates/requestty
 #[derive(Debug, Clone)]
 pub struct StringInput<F = super::widgets::FilterMapChar> {
     value: String,
     mask: Option<char>,
     hide_output: bool,
-    /// The character length of the string
+    /// Whether the actual characters are rendered even if there is a mask or the output is hidden
+    revealed: bool,
+    /// The maximum number of graphemes that can be typed
+    max_length: Option<usize>,
+    /// The length of the string in graphemes
     value_len: usize,
-    /// The position of the 'cursor' in characters
+    /// The position of the 'cursor' in graphemes
     at: usize,
+    /// The position of the 'cursor' in bytes, kept in sync with `at`
+    byte_at: usize,
+    /// Previous entries which can be recalled with `Up` and `Down`, from oldest to newest
+    history: Vec<String>,
+    /// The index of the recalled entry. It is `history.len()` when no entry is recalled
+    history_at: usize,
+    /// The value being edited before an entry was recalled, restored when moving past the newest
+    /// entry
+    draft: String,
+    /// The previous values which can be restored with `Ctrl+Z` and `Ctrl+Y`, if undo is enabled
+    undo: Option<UndoHistory>,
     filter_map: F,
 }
 
+/// The maximum number of edits which can be undone.
+const UNDO_LIMIT: usize = 100;
+
+/// The values of a [`StringInput`] before the edits which can be undone and redone, along with the
+/// position of the 'cursor' in graphemes.
+#[derive(Debug, Clone, Default)]
+struct UndoHistory {
+    undo: Vec<(String, usize)>,
+    redo: Vec<(String, usize)>,
+    /// Whether the last edit typed a character, in which case more typed characters are part of
+    /// the same edit
+    typing: bool,
+}
+
+impl UndoHistory {
+    /// Records an edit which changed the value from `before`. A run of typed characters is
+    /// recorded as a single edit.
+    fn record(&mut self, before: (String, usize), typing: bool) {
+        if !(typing && self.typing) {
+            if self.undo.len() == UNDO_LIMIT {
+                self.undo.remove(0);
+            }
+            self.undo.push(before);
+        }
+
+        self.redo.clear();
+        self.typing = typing;
+    }
+}
+
 impl StringInput {
     /// Creates a new [`StringInput`] which accepts all characters.
     pub fn new() -> Self {
@@ -45,9 +92,16 @@ impl<F> StringInput<F> {
             value: String::new(),
             value_len: 0,
             at: 0,
+            byte_at: 0,
+            history: Vec::new(),
+            history_at: 0,
+            draft: String::new(),
+            undo: None,
             filter_map,
             mask: None,
             hide_output: false,
+            revealed: false,
+            max_length: None,
         }
     }
 
@@ -67,6 +121,43 @@ impl<F> StringInput<F> {
         self
     }
 
+    /// Previous entries, from oldest to newest, which the user can recall.
+    ///
+    /// `Up` replaces the value with the previous entry, and `Down` with the next one. Moving down
+    /// past the newest entry restores the value which was being edited before any entry was
+    /// recalled.
+    pub fn with_history(mut self, history: Vec<String>) -> Self {
+        self.history_at = history.len();
+        self.history = history;
+        self
+    }
+
+    /// Allows the user to undo edits with `Ctrl+Z`, and redo them with `Ctrl+Y`.
+    ///
+    /// A run of typed characters is undone at once, while every other edit, such as deleting a
+    /// word or pasting, is undone on its own. Only the last 100 edits are kept. Values set with
+    /// [`set_value`] and [`replace_with`] are not recorded as edits.
+    ///
+    /// [`set_value`]: StringInput::set_value
+    /// [`replace_with`]: StringInput::replace_with
+    pub fn with_undo(mut self) -> Self {
+        self.undo = Some(UndoHistory::default());
+        self
+    }
+
+    /// The maximum length of the value, counted in graphemes.
+    ///
+    /// Once the value is this long, typed or inserted characters are rejected. A character which
+    /// combines with the one before it, such as a combining accent, does not add a grapheme, so it
+    /// can still be typed. Values set with [`set_value`] and [`replace_with`] are not limited.
+    ///
+    /// [`set_value`]: StringInput::set_value
+    /// [`replace_with`]: StringInput::replace_with
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
     /// A helper that sets mask if mask is some, otherwise hides the output
     pub fn password(self, mask: Option<char>) -> Self {
         match mask {
@@ -75,14 +166,52 @@ impl<F> StringInput<F> {
         }
     }
 
-    /// Gets the location of the 'cursor' in characters.
+    /// Toggles between rendering the actual characters and rendering the mask, or nothing if the
+    /// output is hidden.
+    ///
+    /// This does nothing if there is neither a mask nor hidden output.
+    pub fn toggle_reveal(&mut self) {
+        self.revealed = !self.revealed;
+    }
+
+    /// Whether the actual characters are currently rendered in place of the mask or hidden output.
+    pub fn is_revealed(&self) -> bool {
+        self.revealed
+    }
+
+    /// The mask to render with, which is `None` while the value is revealed.
+    fn current_mask(&self) -> Option<char> {
+        if self.revealed {
+            None
+        } else {
+            self.mask
+        }
+    }
+
+    /// Whether nothing is rendered, which is never the case while the value is revealed.
+    fn output_hidden(&self) -> bool {
+        self.hide_output && !self.revealed
+    }
+
+    /// Gets the location of the 'cursor' in graphemes.
     pub fn get_at(&self) -> usize {
         self.at
     }
 
-    /// Sets the location of the 'cursor' in characters.
+    /// Sets the location of the 'cursor' in graphemes.
+    ///
+    /// The 'cursor' is always between graphemes, so that a grapheme made of multiple characters,
+    /// such as an emoji with a skin tone, is moved over and deleted as a whole.
     pub fn set_at(&mut self, at: usize) {
-        self.at = at.min(self.value_len);
+        let at = at.min(self.value_len);
+
+        if at >= self.at {
+            self.byte_at += self.byte_len_right(at - self.at);
+        } else {
+            self.byte_at -= self.byte_len_left(self.at - at);
+        }
+
+        self.at = at;
     }
 
     /// The value of the `StringInput`
@@ -90,23 +219,99 @@ impl<F> StringInput<F> {
         &self.value
     }
 
+    /// The length of the value in graphemes, which is what [`max_length`] is counted in.
+    ///
+    /// This can be less than the number of characters, as a single grapheme can be made of
+    /// multiple characters, such as a letter followed by a combining accent.
+    ///
+    /// [`max_length`]: StringInput::max_length
+    pub fn grapheme_len(&self) -> usize {
+        self.value_len
+    }
+
+    /// Whether the value is longer than [`max_length`](StringInput::max_length). The graphemes are
+    /// counted again, since this is checked before `value_len` is updated.
+    fn exceeds_max_length(&self) -> bool {
+        matches!(self.max_length, Some(max_length) if self.value.graphemes(true).count() > max_length)
+    }
+
     /// Sets the value
     pub fn set_value(&mut self, value: String) {
-        self.value_len = value.chars().count();
+        self.value_len = value.graphemes(true).count();
         self.value = value;
-        self.set_at(self.at);
+        self.reset_at(self.at);
     }
 
     /// Replaces the value with the result of the function
     pub fn replace_with<W: FnOnce(String) -> String>(&mut self, with: W) {
         self.value = with(std::mem::take(&mut self.value));
         let old_len = self.value_len;
-        self.value_len = self.value.chars().count();
+        self.value_len = self.value.graphemes(true).count();
         if self.at == old_len {
             self.at = self.value_len;
+            self.byte_at = self.value.len();
         } else {
-            self.set_at(self.at);
+            self.reset_at(self.at);
+        }
+    }
+
+    /// Inserts a string at the 'cursor', for example when text is pasted.
+    ///
+    /// Each character is passed through the `filter_map` function, and the characters it rejects
+    /// are dropped without affecting the rest of the string. If there is a [`max_length`], only as
+    /// much of the string as fits is inserted. The 'cursor' is moved to the end of the inserted
+    /// string. Returns whether any character was inserted.
+    ///
+    /// [`max_length`]: StringInput::max_length
+    pub fn insert_str(&mut self, s: &str) -> bool
+    where
+        F: Fn(char) -> Option<char>,
+    {
+        let before = self.undo.as_ref().map(|_| (self.value.clone(), self.at));
+
+        let mut inserted: String = s.chars().filter_map(&self.filter_map).collect();
+
+        if let Some(max_length) = self.max_length {
+            // The grapheme before the 'cursor' is counted along with the string, in case the start
+            // of the string merges into it
+            let prev = &self.value[self.byte_at - self.byte_len_left(1)..self.byte_at];
+            let remaining =
+                max_length.saturating_sub(self.value_len) + usize::from(!prev.is_empty());
+            let joined = format!("{}{}", prev, inserted);
+
+            if let Some((end, _)) = joined.grapheme_indices(true).nth(remaining) {
+                inserted.truncate(end.saturating_sub(prev.len()));
+            }
+        }
+
+        if inserted.is_empty() {
+            return false;
+        }
+
+        self.value.insert_str(self.byte_at, &inserted);
+
+        // The end of the string may still change the graphemes after the 'cursor', in which case
+        // the last grapheme is dropped until the value fits
+        while !inserted.is_empty() && self.exceeds_max_length() {
+            let last = inserted.graphemes(true).next_back().map_or(0, str::len);
+            let len = inserted.len() - last;
+            self.value
+                .replace_range(self.byte_at + len..self.byte_at + inserted.len(), "");
+            inserted.truncate(len);
+        }
+
+        if inserted.is_empty() {
+            return false;
         }
+
+        self.byte_at += inserted.len();
+        self.recount();
+
+        if let (Some(undo), Some(before)) = (&mut self.undo, before) {
+            undo.record(before, false);
+        }
+
+        true
     }
 
     /// Returns the inputted string
@@ -114,21 +319,103 @@ impl<F> StringInput<F> {
         self.value
     }
 
-    /// Gets the byte index of a given char index
-    fn get_byte_i(&self, index: usize) -> usize {
-        self.value
-            .char_indices()
-            .nth(index)
+    /// Recalls the history entry at `history_at`, or the draft if it is past the newest entry.
+    /// The 'cursor' is placed at the end.
+    fn recall(&mut self, history_at: usize) {
+        let value = match self.history.get(history_at) {
+            Some(entry) => entry.clone(),
+            None => std::mem::take(&mut self.draft),
+        };
+
+        if self.history_at == self.history.len() {
+            self.draft = std::mem::take(&mut self.value);
+        }
+
+        self.history_at = history_at;
+        self.set_value(value);
+        self.set_at(self.value_len);
+    }
+
+    /// Sets the 'cursor' after the value has been replaced, in which case `byte_at` cannot be
+    /// used as a starting point
+    fn reset_at(&mut self, at: usize) {
+        self.at = 0;
+        self.byte_at = 0;
+        self.set_at(at);
+    }
+
+    /// The position just after the first `n` graphemes of the value, which end at `byte_i`, as
+    /// they are rendered. If there is a mask, the mask is rendered in place of every grapheme.
+    ///
+    /// The position is relative to the line the value starts on, and is moved to the start of the
+    /// next line if it is past the end of the current one.
+    fn rendered_pos(&self, n: usize, byte_i: usize, layout: &Layout) -> (u16, u16) {
+        match self.current_mask() {
+            Some(mask) => {
+                let width = char_width(mask);
+                wrap_widths((0..n).map(|_| width), layout)
+            }
+            None => wrap_widths(self.value[..byte_i].chars().map(char_width), layout),
+        }
+    }
+
+    /// Counts the graphemes again after the value was edited at `byte_at`, which is then moved to
+    /// the end of the grapheme it is in.
+    ///
+    /// Characters can combine with the graphemes around them, for example a skin tone typed after
+    /// an emoji, so the graphemes cannot simply be counted as they are inserted and deleted.
+    fn recount(&mut self) {
+        let mut at = None;
+        self.value_len = 0;
+
+        for (byte_i, _) in self.value.grapheme_indices(true) {
+            if at.is_none() && byte_i >= self.byte_at {
+                at = Some((self.value_len, byte_i));
+            }
+            self.value_len += 1;
+        }
+
+        let (at, byte_at) = at.unwrap_or((self.value_len, self.value.len()));
+        self.at = at;
+        self.byte_at = byte_at;
+    }
+
+    /// Gets the byte length of the next `n` graphemes after the 'cursor'
+    fn byte_len_right(&self, n: usize) -> usize {
+        let after = &self.value[self.byte_at..];
+
+        after
+            .grapheme_indices(true)
+            .nth(n)
             .map(|(i, _)| i)
-            .unwrap_or_else(|| self.value.len())
+            .unwrap_or_else(|| after.len())
     }
 
-    /// Gets the char index of a given byte index
-    fn get_char_i(&self, byte_i: usize) -> usize {
-        self.value
-            .char_indices()
-            .position(|(i, _)| i == byte_i)
-            .unwrap_or_else(|| self.value.char_indices().count())
+    /// Gets the byte length of the previous `n` graphemes before the 'cursor'
+    fn byte_len_left(&self, n: usize) -> usize {
+        if n == 0 {
+            return 0;
+        }
+
+        let before = &self.value[..self.byte_at];
+
+        before
+            .grapheme_indices(true)
+            .rev()
+            .nth(n - 1)
+            .map(|(i, _)| before.len() - i)
+            .unwrap_or_else(|| before.len())
+    }
+
+    /// Moves the 'cursor' to the given byte index, which must lie on a grapheme boundary
+    fn set_byte_at(&mut self, byte_i: usize) {
+        if byte_i < self.byte_at {
+            self.at -= self.value[byte_i..self.byte_at].graphemes(true).count();
+        } else {
+            self.at += self.value[self.byte_at..byte_i].graphemes(true).count();
+        }
+
+        self.byte_at = byte_i;
     }
 
     /// Get the word bound iterator for a given range
@@ -180,72 +467,54 @@ impl<F> StringInput<F> {
             _ => None,
         }
     }
-}
 
-impl<F> super::Widget for StringInput<F>
-where
-    F: Fn(char) -> Option<char>,
-{
-    fn handle_key(&mut self, key: KeyEvent) -> bool {
-        if let Some(movement) = self.get_delete_movement(key) {
-            match movement {
-                Movement::Home => {
-                    let byte_i = self.get_byte_i(self.at);
-                    self.value_len -= self.at;
-                    self.at = 0;
-                    self.value.replace_range(..byte_i, "");
-                    return true;
-                }
-                Movement::PrevWord => {
-                    let was_at = self.at;
-                    let byte_i = self.get_byte_i(self.at);
-                    let prev_word = self.find_word_left(byte_i);
-                    self.at = self.get_char_i(prev_word);
-                    self.value_len -= was_at - self.at;
-                    self.value.replace_range(prev_word..byte_i, "");
-                    return true;
-                }
-                Movement::Left if self.at == self.value_len => {
-                    self.at -= 1;
-                    self.value_len -= 1;
-                    self.value.pop();
-                    return true;
-                }
-                Movement::Left => {
-                    self.at -= 1;
-                    let byte_i = self.get_byte_i(self.at);
-                    self.value_len -= 1;
-                    self.value.remove(byte_i);
-                    return true;
-                }
+    /// Restores the last value from `from`, saving the current value in `to`. This is used for
+    /// both undo and redo. Returns whether there was a value to restore.
+    fn restore(
+        &mut self,
+        from: fn(&mut UndoHistory) -> &mut Vec<(String, usize)>,
+        to: fn(&mut UndoHistory) -> &mut Vec<(String, usize)>,
+    ) -> bool {
+        let undo = match self.undo {
+            Some(ref mut undo) => undo,
+            None => return false,
+        };
 
-                Movement::End => {
-                    let byte_i = self.get_byte_i(self.at);
-                    self.value_len = self.at;
-                    self.value.truncate(byte_i);
-                    return true;
-                }
-                Movement::NextWord => {
-                    let byte_i = self.get_byte_i(self.at);
-                    let next_word = self.find_word_right(byte_i);
-                    self.value_len -= self.get_char_i(next_word) - self.at;
-                    self.value.replace_range(byte_i..next_word, "");
-                    return true;
-                }
-                Movement::Right if self.at == self.value_len - 1 => {
-                    self.value_len -= 1;
-                    self.value.pop();
-                    return true;
-                }
-                Movement::Right => {
-                    let byte_i = self.get_byte_i(self.at);
-                    self.value_len -= 1;
-                    self.value.remove(byte_i);
-                    return true;
-                }
+        let (value, at) = match from(undo).pop() {
+            Some(state) => state,
+            None => return false,
+        };
 
-                _ => {}
-            }
+        to(undo).push((std::mem::replace(&mut self.value, value), self.at));
+        undo.typing = false;
+
+        self.value_len = self.value.graphemes(true).count();
+        self.reset_at(at);
+        true
+    }
+
+    /// Handles a key which is not undo or redo.
+    fn handle_edit_key(&mut self, key: KeyEvent) -> bool
+    where
+        F: Fn(char) -> Option<char>,
+    {
+        if let Some(movement) = self.get_delete_movement(key) {
+            let deleted = match movement {
+                Movement::Home => 0..self.byte_at,
+                Movement::PrevWord => self.find_word_left(self.byte_at)..self.byte_at,
+                Movement::Left => self.byte_at - self.byte_len_left(1)..self.byte_at,
+
+                Movement::End => self.byte_at..self.value.len(),
+                Movement::NextWord => self.byte_at..self.find_word_right(self.byte_at),
+                Movement::Right => self.byte_at..self.byte_at + self.byte_len_right(1),
+
+                _ => unreachable!(),
+            };
+
+            self.byte_at = deleted.start;
+            self.value.replace_range(deleted, "");
+            self.recount();
+            return true;
         }
 
         match key.code {
@@ -257,58 +526,116 @@ where
                     .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
             {
                 if let Some(c) = (self.filter_map)(c) {
-                    if self.at == self.value_len {
-                        self.value.push(c);
-                    } else {
-                        let byte_i = self.get_byte_i(self.at);
-                        self.value.insert(byte_i, c);
-                    };
-
-                    self.at += 1;
-                    self.value_len += 1;
+                    self.value.insert(self.byte_at, c);
+
+                    if self.exceeds_max_length() {
+                        self.value.remove(self.byte_at);
+                        return false;
+                    }
+
+                    self.byte_at += c.len_utf8();
+                    self.recount();
                     return true;
                 }
             }
 
+            KeyCode::Up if self.history_at != 0 => {
+                self.recall(self.history_at - 1);
+                return true;
+            }
+            KeyCode::Down if self.history_at < self.history.len() => {
+                self.recall(self.history_at + 1);
+                return true;
+            }
+
             _ => {}
         }
 
         match Movement::try_from_key(key) {
             Some(Movement::PrevWord) if self.at != 0 => {
-                self.at = self.get_char_i(self.find_word_left(self.get_byte_i(self.at)));
+                self.set_byte_at(self.find_word_left(self.byte_at));
             }
             Some(Movement::Left) if self.at != 0 => {
-                self.at -= 1;
+                self.set_at(self.at - 1);
             }
 
             Some(Movement::NextWord) if self.at != self.value_len => {
-                self.at = self.get_char_i(self.find_word_right(self.get_byte_i(self.at)));
+                self.set_byte_at(self.find_word_right(self.byte_at));
             }
             Some(Movement::Right) if self.at != self.value_len => {
-                self.at += 1;
+                self.set_at(self.at + 1);
             }
 
             Some(Movement::Home) if self.at != 0 => {
                 self.at = 0;
+                self.byte_at = 0;
             }
             Some(Movement::End) if self.at != self.value_len => {
                 self.at = self.value_len;
+                self.byte_at = self.value.len();
             }
             _ => return false,
         }
 
         true
     }
+}
+
+impl<F> super::Widget for StringInput<F>
+where
+    F: Fn(char) -> Option<char>,
+{
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if self.undo.is_none() {
+            return self.handle_edit_key(key);
+        }
+
+        if key.modifiers.contains(KeyModifiers::CONTROL) {
+            match key.code {
+                KeyCode::Char('z') => return self.restore(|u| &mut u.undo, |u| &mut u.redo),
+                KeyCode::Char('y') => return self.restore(|u| &mut u.redo, |u| &mut u.undo),
+                _ => {}
+            }
+        }
+
+        let typing = matches!(key.code, KeyCode::Char(_))
+            && !key
+                .modifiers
+                .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT);
+
+        // Only the keys which can change the value need a copy of it
+        let before = if typing
+            || self.get_delete_movement(key).is_some()
+            || matches!(key.code, KeyCode::Up | KeyCode::Down)
+        {
+            Some((self.value.clone(), self.at))
+        } else {
+            None
+        };
+
+        let handled = self.handle_edit_key(key);
+
+        if let Some(ref mut undo) = self.undo {
+            match before {
+                Some(before) if self.value != before.0 => undo.record(before, typing),
+                // Moving the 'cursor' ends a run of typed characters
+                _ if handled => undo.typing = false,
+                _ => {}
+            }
+        }
+
+        handled
+    }
 
     /// This widget ignores [`layout.offset_x`] and wraps around in the terminal.
     ///
     /// [`layout.offset_x`]: Layout.offset_x
     fn render<B: Backend>(&mut self, layout: &mut Layout, backend: &mut B) -> io::Result<()> {
-        if self.hide_output {
+        if self.output_hidden() {
             return Ok(());
         }
 
-        if let Some(mask) = self.mask {
+        if let Some(mask) = self.current_mask() {
             print_mask(self.value_len, mask, backend)?;
         } else {
             // Terminal takes care of wrapping in case of large strings
@@ -322,39 +649,26 @@ where
     }
 
     fn height(&mut self, layout: &mut Layout) -> u16 {
-        if self.hide_output {
+        if self.output_hidden() {
             return 1;
         }
 
-        let mut width = textwrap::core::display_width(&self.value) as u16;
-
-        if width > layout.line_width() {
-            width -= layout.line_width();
+        // If the value exactly fills the line, the cursor is placed at the start of the next line
+        // (see `cursor_pos`), so that line must be counted as well
+        let (line_offset, lines) = self.rendered_pos(self.value_len, self.value.len(), layout);
 
-            layout.line_offset = width % layout.width;
-            layout.offset_y += 1 + width / layout.width;
+        layout.line_offset = line_offset;
+        layout.offset_y += lines;
 
-            2 + width / layout.width
-        } else {
-            layout.line_offset += width;
-            1
-        }
+        1 + lines
     }
 
     fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
-        let display_at =
-            textwrap::core::display_width(&self.value[..self.get_byte_i(self.at)]) as u16;
-
-        let relative_pos = if self.hide_output {
+        let relative_pos = if self.output_hidden() {
             // Nothing will be outputted so no need to move the cursor
             (layout.line_offset, 0)
-        } else if layout.line_width() > display_at {
-            // It is in the same line as the prompt
-            (layout.line_offset + display_at, 0)
         } else {
-            let at = display_at - layout.line_width();
-
-            (at % layout.width, 1 + at / layout.width)
+            self.rendered_pos(self.at, self.byte_at, &layout)
         };
 
         layout.offset_cursor(relative_pos)
@@ -367,15 +681,57 @@ impl Default for StringInput {
     }
 }
 
-fn print_mask<W: Write>(len: usize, mask: char, w: &mut W) -> io::Result<()> {
+/// The number of columns the character takes up in the terminal.
+fn char_width(c: char) -> u16 {
     let mut buf = [0; 4];
-    let mask = mask.encode_utf8(&mut buf[..]);
+    textwrap::core::display_width(c.encode_utf8(&mut buf)) as u16
+}
+
+/// The position just after characters of the given widths once they are written to the terminal,
+/// starting at `layout.line_offset`. See [`StringInput::rendered_pos`].
+///
+/// A wide character which does not fit at the end of a line is moved to the next line by the
+/// terminal, leaving the rest of the line empty, so the widths cannot simply be added up.
+fn wrap_widths<I: Iterator<Item = u16>>(widths: I, layout: &Layout) -> (u16, u16) {
+    let mut x = layout.line_offset;
+    let mut y = 0;
+    let mut line_end = layout.line_offset + layout.line_width();
+
+    for width in widths {
+        if x + width > line_end {
+            x = 0;
+            y += 1;
+            line_end = layout.width;
+        }
+
+        x += width;
+    }
+
+    if x >= line_end {
+        (0, y + 1)
+    } else {
+        (x, y)
+    }
+}
+
+/// The number of mask characters written at once by [`print_mask`].
+const MASK_CHUNK_LEN: usize = 64;
+
+fn print_mask<W: Write>(len: usize, mask: char, w: &mut W) -> io::Result<()> {
+    // Long values would otherwise need a write for every character, so the mask is repeated in a
+    // buffer which is written in bulk
+    let mut buf = [0; 4 * MASK_CHUNK_LEN];
+    let mask_len = mask.encode_utf8(&mut buf[..]).len();
 
-    for _ in 0..len {
-        w.write_all(mask.as_bytes())?;
+    for i in 1..MASK_CHUNK_LEN.min(len) {
+        buf.copy_within(0..mask_len, i * mask_len);
     }
 
-    Ok(())
+    for _ in 0..len / MASK_CHUNK_LEN {
+        w.write_all(&buf[..MASK_CHUNK_LEN * mask_len])?;
+    }
+
+    w.write_all(&buf[..(len % MASK_CHUNK_LEN) * mask_len])
 }
 
 #[cfg(test)]
@@ -398,6 +754,17 @@ mod tests {
 
         test('*');
         test('‣');
+
+        for &len in [0, MASK_CHUNK_LEN, MASK_CHUNK_LEN + 1, 10_000].iter() {
+            for &mask in ['*', '‣', '🔒'].iter() {
+                let mut buf = Vec::new();
+                print_mask(len, mask, &mut buf).unwrap();
+                assert_eq!(
+                    String::from_utf8(buf).unwrap(),
+                    mask.to_string().repeat(len)
+                );
+            }
+        }
     }
 
     #[test]
@@ -520,6 +887,48 @@ mod tests {
         test(UNICODE, 70, 4);
     }
 
+    #[test]
+    fn test_render_narrow() {
+        fn test(input: &mut StringInput, expected: &[&str]) {
+            let size = (10, 5).into();
+            let layout = Layout::new(0, size).with_line_offset(5);
+            let mut render_layout = layout;
+
+            let mut backend = TestBackend::new_with_layout(size, layout);
+            input.render(&mut render_layout, &mut backend).unwrap();
+
+            let mut expected_backend = TestBackend::new(size);
+            expected_backend.move_cursor_to(5, 0).unwrap();
+            for (i, line) in expected.iter().enumerate() {
+                if i > 0 {
+                    expected_backend.move_cursor_to(0, i as u16).unwrap();
+                }
+                expected_backend.write_all(line.as_bytes()).unwrap();
+            }
+            backend.assert_eq(&expected_backend);
+
+            // The layout and cursor follow the wrapped value
+            let end = (render_layout.line_offset, render_layout.offset_y);
+            assert_eq!(backend.get_cursor_pos().unwrap(), end);
+            input.set_at(input.value().chars().count());
+            assert_eq!(input.cursor_pos(layout), end);
+            input.set_at(7);
+            assert_eq!(input.cursor_pos(layout), (2, 1));
+        }
+
+        let mut input = StringInput::default();
+        input.set_value("Hello, World!".into());
+        test(&mut input, &["Hello", ", World!"]);
+
+        let mut input = StringInput::default().mask('*');
+        input.set_value("Hello, World!".into());
+        test(&mut input, &["*****", "********"]);
+
+        let mut input = StringInput::default();
+        input.set_value("abcdefghijklmnopqrstuvwxyz".into());
+        test(&mut input, &["abcde", "fghijklmno", "pqrstuvwxy", "z"]);
+    }
+
     #[test]
     fn test_handle_key() {
         let mut input = StringInput::with_filter_map(|c| if c == 'i' { None } else { Some(c) });
@@ -623,6 +1032,127 @@ mod tests {
         assert_eq!(input.value().chars().count(), 386);
     }
 
+    #[test]
+    fn test_byte_at() {
+        fn assert_byte_at(input: &StringInput) {
+            let byte_i = input
+                .value()
+                .char_indices()
+                .nth(input.get_at())
+                .map(|(i, _)| i)
+                .unwrap_or_else(|| input.value().len());
+
+            assert_eq!(input.byte_at, byte_i);
+            assert_eq!(input.value_len, input.value().chars().count());
+        }
+
+        let mut input = StringInput::default();
+        input.set_value(UNICODE.repeat(200));
+        input.set_at(50_000);
+        assert_byte_at(&input);
+
+        let keys = [
+            KeyEvent::new(KeyCode::Left, KeyModifiers::empty()),
+            KeyEvent::new(KeyCode::Char('漢'), KeyModifiers::empty()),
+            KeyEvent::new(KeyCode::Left, KeyModifiers::CONTROL),
+            KeyEvent::new(KeyCode::Backspace, KeyModifiers::empty()),
+            KeyEvent::new(KeyCode::Right, KeyModifiers::CONTROL),
+            KeyEvent::new(KeyCode::Delete, KeyModifiers::empty()),
+            KeyEvent::new(KeyCode::Backspace, KeyModifiers::ALT),
+            KeyEvent::new(KeyCode::Right, KeyModifiers::empty()),
+            KeyEvent::new(KeyCode::Delete, KeyModifiers::ALT),
+        ];
+
+        for key in keys.iter().cycle().take(1000) {
+            input.handle_key(*key);
+            assert_byte_at(&input);
+        }
+
+        input.handle_key(KeyEvent::new(KeyCode::Char('k'), KeyModifiers::CONTROL));
+        assert_byte_at(&input);
+        input.handle_key(KeyEvent::new(KeyCode::Backspace, KeyModifiers::empty()));
+        assert_byte_at(&input);
+        input.handle_key(KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL));
+        assert_byte_at(&input);
+
+        input.set_value(UNICODE.into());
+        input.set_at(300);
+        input.replace_with(|value| value.chars().skip(20).collect());
+        assert_byte_at(&input);
+    }
+
+    #[test]
+    fn test_insert_str() {
+        let mut input = StringInput::with_filter_map(|c| if c == 'i' { None } else { Some(c) });
+        input.set_value(UNICODE.into());
+        input.set_at(10);
+
+        assert!(input.insert_str("lorem ipsum 漢字"));
+        assert_eq!(input.get_at(), 23);
+        assert_eq!(input.value_len, input.value().chars().count());
+        assert_eq!(
+            input.byte_at,
+            input.value().char_indices().nth(23).unwrap().0
+        );
+
+        let expected: String = UNICODE
+            .chars()
+            .take(10)
+            .chain("lorem psum 漢字".chars())
+            .chain(UNICODE.chars().skip(10))
+            .collect();
+        assert_eq!(input.value(), expected);
+
+        assert!(!input.insert_str("iii"));
+        assert!(!input.insert_str(""));
+        assert_eq!(input.value(), expected);
+
+        let long = "https://example.com/".repeat(500);
+        input.handle_key(KeyEvent::new(KeyCode::End, KeyModifiers::empty()));
+        assert!(input.insert_str(&long));
+        assert_eq!(input.get_at(), input.value().chars().count());
+        assert_eq!(input.byte_at, input.value().len());
+        assert!(input.value().ends_with(&long));
+    }
+
+    #[test]
+    fn test_history() {
+        let up = KeyEvent::from(KeyCode::Up);
+        let down = KeyEvent::from(KeyCode::Down);
+
+        let mut input = StringInput::default();
+        assert!(!input.handle_key(up));
+        assert!(!input.handle_key(down));
+
+        let mut input = StringInput::default().with_history(vec!["first".into(), UNICODE.into()]);
+        input.set_value("draft".into());
+        input.set_at(2);
+
+        assert!(!input.handle_key(down));
+
+        assert!(input.handle_key(up));
+        assert_eq!(input.value(), UNICODE);
+        assert_eq!(input.get_at(), UNICODE.chars().count());
+        assert_eq!(input.byte_at, UNICODE.len());
+
+        assert!(input.handle_key(up));
+        assert_eq!(input.value(), "first");
+        assert_eq!(input.get_at(), 5);
+        assert!(!input.handle_key(up));
+
+        // Edits to a recalled entry do not change the history
+        input.handle_key(KeyCode::Backspace.into());
+        assert!(input.handle_key(down));
+        assert!(input.handle_key(up));
+        assert_eq!(input.value(), "first");
+
+        assert!(input.handle_key(down));
+        assert!(input.handle_key(down));
+        assert_eq!(input.value(), "draft");
+        assert_eq!(input.get_at(), 5);
+        assert!(!input.handle_key(down));
+    }
+
     #[test]
     fn test_height() {
         fn test(text: &str, indent: usize, max_width: usize, height: u16) {
@@ -638,6 +1168,11 @@ mod tests {
 
         test(LOREM, 0, 100, 5);
         test(LOREM, 40, 100, 6);
+
+        // The cursor moves to the next line when the value exactly fills the line
+        test("Hello", 0, 5, 2);
+        test("Hello", 2, 7, 2);
+        test("", 7, 7, 2);
     }
 
     #[test]
@@ -686,5 +1221,397 @@ mod tests {
 
         layout.offset_y = 3;
         assert_eq!(input.cursor_pos(layout), (35, 4));
+
+        // The cursor is kept within the line where the value ends
+        let layout = Layout::new(5, (10, 20).into());
+        input.set_value("Hello".into());
+        input.set_at(4);
+        assert_eq!(input.cursor_pos(layout), (9, 0));
+        input.set_at(5);
+        assert_eq!(input.cursor_pos(layout), (0, 1));
+
+        let mut height_layout = layout;
+        assert_eq!(input.height(&mut height_layout), 2);
+        assert_eq!(
+            input.cursor_pos(layout),
+            (height_layout.line_offset, height_layout.offset_y)
+        );
+    }
+
+    #[test]
+    fn test_wide_cursor_pos() {
+        let layout = Layout::new(5, (10, 20).into());
+        let mut input = StringInput::default();
+
+        // The CJK character and the emoji are 2 columns wide, and the emoji does not fit at the end
+        // of the first line
+        input.set_value("a你b🦀c".into());
+        let columns = [5, 6, 8, 9, 2, 3];
+        let lines = [0, 0, 0, 0, 1, 1];
+        for (at, (&x, &y)) in columns.iter().zip(lines.iter()).enumerate() {
+            input.set_at(at);
+            assert_eq!(input.cursor_pos(layout), (x, y), "at {}", at);
+        }
+
+        input.set_value("abcd你".into());
+        input.set_at(4);
+        assert_eq!(input.cursor_pos(layout), (9, 0));
+        input.set_at(5);
+        assert_eq!(input.cursor_pos(layout), (2, 1));
+
+        let mut height_layout = layout;
+        assert_eq!(input.height(&mut height_layout), 2);
+        assert_eq!(height_layout, layout.with_line_offset(2).with_offset(0, 1));
+
+        // The gap is only left on the line where it happens
+        input.set_value("abcd你好世界你好x".into());
+        input.set_at(input.value().chars().count());
+        assert_eq!(input.cursor_pos(layout), (3, 2));
+        assert_eq!(input.height(&mut layout.clone()), 3);
+    }
+
+    #[test]
+    fn test_mask_width() {
+        let layout = Layout::new(5, (10, 20).into());
+        // Every character is 2 columns wide, but only the mask is rendered
+        let mut input = StringInput::default().mask('*');
+        input.set_value("你好".into());
+        input.set_at(2);
+
+        assert_eq!(input.cursor_pos(layout), (7, 0));
+        assert_eq!(input.height(&mut layout.clone()), 1);
+
+        input.set_value("你好世界你".into());
+        input.set_at(5);
+        assert_eq!(input.cursor_pos(layout), (0, 1));
+        assert_eq!(input.height(&mut layout.clone()), 2);
+    }
+
+    #[test]
+    fn test_toggle_reveal() {
+        let layout = Layout::new(0, (20, 5).into());
+
+        let render = |input: &mut StringInput| {
+            let mut backend = TestBackend::new((20, 5).into());
+            input.render(&mut layout.clone(), &mut backend).unwrap();
+            backend.row_text(0)
+        };
+
+        let mut input = StringInput::default().mask('*');
+        input.set_value("你好".into());
+        input.set_at(2);
+        assert_eq!(render(&mut input), "**");
+        assert_eq!(input.cursor_pos(layout), (2, 0));
+
+        input.toggle_reveal();
+        assert!(input.is_revealed());
+        assert_eq!(render(&mut input), "你好");
+        assert_eq!(input.cursor_pos(layout), (4, 0));
+
+        input.toggle_reveal();
+        assert!(!input.is_revealed());
+        assert_eq!(render(&mut input), "**");
+
+        let mut input = StringInput::default().hide_output();
+        input.set_value("secret".into());
+        input.set_at(6);
+        assert_eq!(render(&mut input), "");
+        assert_eq!(input.cursor_pos(layout), (0, 0));
+
+        input.toggle_reveal();
+        assert_eq!(render(&mut input), "secret");
+        assert_eq!(input.cursor_pos(layout), (6, 0));
+        assert_eq!(input.height(&mut layout.clone()), 1);
+    }
+
+    #[test]
+    fn test_max_length() {
+        let mut input = StringInput::default().max_length(3);
+        let key = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::empty());
+
+        assert!(input.handle_key(key('a')));
+        assert!(input.handle_key(key('b')));
+        assert!(input.handle_key(key('c')));
+        assert!(!input.handle_key(key('d')));
+        assert_eq!(input.value(), "abc");
+        assert_eq!(input.get_at(), 3);
+
+        // A combining accent is part of the grapheme before it, so it still fits
+        assert!(input.handle_key(key('\u{301}')));
+        assert_eq!(input.value(), "abc\u{301}");
+        assert_eq!(input.value().chars().count(), 4);
+        assert_eq!(input.grapheme_len(), 3);
+
+        // Rejected in the middle of the value as well
+        input.set_at(1);
+        assert!(!input.handle_key(key('x')));
+        assert_eq!(input.value(), "abc\u{301}");
+        assert_eq!(input.get_at(), 1);
+
+        // Moving and deleting are not affected
+        assert!(input.handle_key(KeyCode::End.into()));
+        assert!(input.handle_key(KeyCode::Home.into()));
+        assert!(input.handle_key(KeyCode::Delete.into()));
+        assert_eq!(input.value(), "bc\u{301}");
+        assert!(input.handle_key(key('x')));
+        assert_eq!(input.value(), "xbc\u{301}");
+
+        // Only as much as fits is inserted
+        let mut input = StringInput::default().max_length(4);
+        input.set_value("ab".into());
+        input.set_at(1);
+        assert!(input.insert_str("xyz"));
+        assert_eq!(input.value(), "axyb");
+        assert_eq!(input.get_at(), 3);
+        assert!(!input.insert_str("z"));
+        assert_eq!(input.value(), "axyb");
+
+        input.set_at(4);
+        assert!(!input.insert_str("e\u{301}"));
+        assert_eq!(input.value(), "axyb");
+        assert!(input.handle_key(KeyCode::Backspace.into()));
+        assert!(input.insert_str("e\u{301}"));
+        assert_eq!(input.value(), "axye\u{301}");
+        assert_eq!(input.get_at(), 4);
+
+        // Combining characters at the start merge into the grapheme before the 'cursor'
+        let mut input = StringInput::default().max_length(3);
+        input.set_value("ab".into());
+        input.set_at(2);
+        assert!(input.insert_str("\u{301}cd"));
+        assert_eq!(input.value(), "ab\u{301}c");
+        assert_eq!(input.get_at(), 3);
+    }
+
+    #[test]
+    fn test_graphemes() {
+        // The flag is two regional indicators, and the thumbs up has a skin tone
+        let flag = "\u{1F1F3}\u{1F1F4}";
+        let thumbs_up = "\u{1F44D}\u{1F3FD}";
+
+        let mut input = StringInput::default();
+        input.set_value(format!("a{}b{}", flag, thumbs_up));
+        assert_eq!(input.grapheme_len(), 4);
+        input.set_at(4);
+        assert_eq!(input.byte_at, input.value().len());
+
+        assert!(input.handle_key(KeyCode::Backspace.into()));
+        assert_eq!(input.value(), format!("a{}b", flag));
+        assert_eq!(input.get_at(), 3);
+
+        assert!(input.handle_key(KeyCode::Left.into()));
+        assert!(input.handle_key(KeyCode::Backspace.into()));
+        assert_eq!(input.value(), "ab");
+        assert_eq!(input.get_at(), 1);
+        assert_eq!(input.byte_at, 1);
+
+        // Moving and deleting forwards also skip whole graphemes
+        input.set_value(format!("{}{}x", flag, thumbs_up));
+        input.set_at(0);
+        assert!(input.handle_key(KeyCode::Right.into()));
+        assert_eq!(input.byte_at, flag.len());
+        assert!(input.handle_key(KeyCode::Delete.into()));
+        assert_eq!(input.value(), format!("{}x", flag));
+        assert_eq!(input.grapheme_len(), 2);
+
+        // A typed character which combines with the one before it does not move the 'cursor'
+        let mut input = StringInput::default();
+        for c in "\u{1F44D}\u{1F3FD}e\u{301}".chars() {
+            assert!(input.handle_key(KeyCode::Char(c).into()));
+        }
+        assert_eq!(input.get_at(), 2);
+        assert_eq!(input.grapheme_len(), 2);
+        assert!(input.handle_key(KeyCode::Home.into()));
+        assert!(input.handle_key(KeyCode::Delete.into()));
+        assert_eq!(input.value(), "e\u{301}");
+
+        // The mask is rendered once per grapheme
+        let layout = Layout::new(0, (100, 20).into());
+        let mut input = StringInput::default().mask('*');
+        input.set_value(format!("{}{}", flag, thumbs_up));
+        input.set_at(2);
+        assert_eq!(input.cursor_pos(layout), (2, 0));
+    }
+
+    #[test]
+    fn test_undo() {
+        let ctrl = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL);
+        let type_str = |input: &mut StringInput, s: &str| {
+            for c in s.chars() {
+                assert!(input.handle_key(KeyCode::Char(c).into()));
+            }
+        };
+
+        // Undo is disabled by default, so the keys are ignored
+        let mut input = StringInput::default();
+        type_str(&mut input, "hello");
+        assert!(!input.handle_key(ctrl('z')));
+        assert_eq!(input.value(), "hello");
+
+        let mut input = StringInput::default().with_undo();
+        assert!(!input.handle_key(ctrl('z')));
+        assert!(!input.handle_key(ctrl('y')));
+
+        // A run of typed characters is a single edit
+        type_str(&mut input, "hello world");
+        assert!(input.handle_key(KeyEvent::new(KeyCode::Backspace, KeyModifiers::ALT)));
+        assert_eq!(input.value(), "hello ");
+
+        assert!(input.handle_key(ctrl('z')));
+        assert_eq!(input.value(), "hello world");
+        assert_eq!(input.get_at(), 11);
+        assert_eq!(input.byte_at, 11);
+
+        assert!(input.handle_key(ctrl('z')));
+        assert_eq!(input.value(), "");
+        assert_eq!(input.get_at(), 0);
+        assert!(!input.handle_key(ctrl('z')));
+
+        assert!(input.handle_key(ctrl('y')));
+        assert_eq!(input.value(), "hello world");
+        assert!(input.handle_key(ctrl('y')));
+        assert_eq!(input.value(), "hello ");
+        assert_eq!(input.get_at(), 6);
+        assert!(!input.handle_key(ctrl('y')));
+
+        // Deleting to the start is restored along with the 'cursor'
+        type_str(&mut input, UNICODE);
+        input.set_at(8);
+        assert!(input.handle_key(ctrl('u')));
+        assert_eq!(input.get_at(), 0);
+        assert!(input.handle_key(ctrl('z')));
+        assert_eq!(input.value(), format!("hello {}", UNICODE));
+        assert_eq!(input.get_at(), 8);
+        assert_eq!(
+            input.byte_at,
+            input.value().char_indices().nth(8).unwrap().0
+        );
+
+        // Moving the cursor ends a run of typed characters, and a new edit forgets the redo
+        assert!(input.handle_key(KeyCode::Home.into()));
+        type_str(&mut input, "ab");
+        assert!(input.handle_key(KeyCode::Right.into()));
+        type_str(&mut input, "c");
+        assert!(input.value().starts_with("abhcello"));
+        assert!(input.handle_key(ctrl('z')));
+        assert!(input.value().starts_with("abhello"));
+        assert!(input.handle_key(ctrl('z')));
+        assert!(input.value().starts_with("hello"));
+        assert!(input.handle_key(ctrl('y')));
+        assert!(input.value().starts_with("abhello"));
+        type_str(&mut input, "x");
+        assert!(!input.handle_key(ctrl('y')));
+
+        // Pasting is a single edit
+        let mut input = StringInput::default().with_undo();
+        type_str(&mut input, "a");
+        assert!(input.insert_str("bcd"));
+        assert!(input.handle_key(ctrl('z')));
+        assert_eq!(input.value(), "a");
+
+        // Only the latest edits are kept
+        let mut input = StringInput::default().with_undo();
+        for _ in 0..UNDO_LIMIT + 10 {
+            assert!(input.insert_str("a"));
+        }
+        for _ in 0..UNDO_LIMIT {
+            assert!(input.handle_key(ctrl('z')));
+        }
+        assert!(!input.handle_key(ctrl('z')));
+        assert_eq!(input.value(), "a".repeat(10));
+    }
+}
+
+// Run with `RUSTFLAGS="--cfg nightly" cargo +nightly bench -p requestty-ui`
+#[cfg(all(test, nightly))]
+mod benches {
+    extern crate test;
+
+    use test::Bencher;
+
+    use super::*;
+    use crate::{
+        backend::{BufferBackend, Size},
+        events::KeyCode,
+        Widget,
+    };
+
+    const LONG_VALUE_LEN: usize = 100_000;
+    const MASKED_VALUE_LEN: usize = 10_000;
+
+    fn long_input() -> StringInput {
+        let mut input = StringInput::new();
+        input.set_value("ab‣".repeat(LONG_VALUE_LEN / 3));
+        input.set_at(LONG_VALUE_LEN / 2);
+        input
+    }
+
+    #[bench]
+    fn bench_move_cursor(b: &mut Bencher) {
+        let mut input = long_input();
+
+        b.iter(|| {
+            input.handle_key(KeyCode::Left.into());
+            input.handle_key(KeyCode::Right.into());
+        });
+    }
+
+    // What every key press used to cost before the byte index of the cursor was cached.
+    #[bench]
+    fn bench_uncached_byte_index(b: &mut Bencher) {
+        let input = long_input();
+
+        b.iter(|| {
+            for at in [input.at - 1, input.at].iter() {
+                test::black_box(
+                    input
+                        .value
+                        .char_indices()
+                        .nth(*at)
+                        .map(|(i, _)| i)
+                        .unwrap_or_else(|| input.value.len()),
+                );
+            }
+        });
+    }
+
+    #[bench]
+    fn bench_print_mask(b: &mut Bencher) {
+        let mut buf = Vec::with_capacity(4 * MASKED_VALUE_LEN);
+
+        b.iter(|| {
+            buf.clear();
+            print_mask(MASKED_VALUE_LEN, '‣', &mut buf).unwrap();
+        });
+    }
+
+    // How the mask used to be printed, with a write for every character.
+    #[bench]
+    fn bench_print_mask_per_char(b: &mut Bencher) {
+        let mut buf = Vec::with_capacity(4 * MASKED_VALUE_LEN);
+        let mut mask = [0; 4];
+        let mask = '‣'.encode_utf8(&mut mask);
+
+        b.iter(|| {
+            buf.clear();
+            for _ in 0..MASKED_VALUE_LEN {
+                buf.write_all(mask.as_bytes()).unwrap();
+            }
+        });
+    }
+
+    #[bench]
+    fn bench_render_masked(b: &mut Bencher) {
+        let size = Size::from((100, 200));
+        let mut input = StringInput::new().mask('*');
+        input.set_value("a".repeat(MASKED_VALUE_LEN));
+
+        b.iter(|| {
+            let mut backend = BufferBackend::new(size);
+            input
+                .render(&mut Layout::new(0, size), &mut backend)
+                .unwrap();
+            backend
+        });
     }
 }
@@ -1,6 +1,6 @@
 //! A module to control the looks of text.
 
-use std::{fmt::Display, io};
+use std::{fmt::Display, io, ops::Range};
 
 /// Some content with a particular style applied.
 ///
@@ -63,6 +63,181 @@ impl<T: Display> From<T> for Styled<T> {
     }
 }
 
+/// A string made up of parts which can each have their own style.
+///
+/// Parts without any style of their own take the style of the place where the string is rendered.
+/// For example, the message of a [`Prompt`] is bold, so the plain parts of a styled message are
+/// bold, while a part with a color is both bold and colored.
+///
+/// [`Prompt`]: crate::widgets::Prompt
+///
+/// # Example
+///
+/// ```
+/// use requestty_ui::style::{StyledString, Stylize};
+///
+/// let message = StyledString::new()
+///     .with("Overwrite ")
+///     .with("config.toml".light_cyan())
+///     .with("?");
+///
+/// assert_eq!(message.text(), "Overwrite config.toml?");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct StyledString {
+    text: String,
+    parts: Vec<Part>,
+}
+
+/// The style of the text in a [`StyledString`] up to `end`, starting where the previous part
+/// ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Part {
+    end: usize,
+    fg: Option<Color>,
+    bg: Option<Color>,
+    attributes: Attributes,
+}
+
+impl StyledString {
+    /// Creates a new, empty [`StyledString`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a part to the end of the string.
+    pub fn push<T: Display, S: Into<Styled<T>>>(&mut self, part: S) {
+        let part = part.into();
+
+        use std::fmt::Write;
+        // writing to a String never fails
+        let _ = write!(self.text, "{}", part.content);
+
+        self.parts.push(Part {
+            end: self.text.len(),
+            fg: part.fg,
+            bg: part.bg,
+            attributes: part.attributes,
+        });
+    }
+
+    /// Appends a part to the end of the string, returning the string.
+    pub fn with<T: Display, S: Into<Styled<T>>>(mut self, part: S) -> Self {
+        self.push(part);
+        self
+    }
+
+    /// The text of the string without any styles.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Consumes the string, returning the text without any styles.
+    pub fn into_text(self) -> String {
+        self.text
+    }
+
+    /// Borrows the string as a [`StyledStr`].
+    pub fn as_styled_str(&self) -> StyledStr<'_> {
+        StyledStr {
+            text: &self.text,
+            parts: &self.parts,
+        }
+    }
+}
+
+impl From<String> for StyledString {
+    fn from(text: String) -> Self {
+        Self {
+            parts: Vec::new(),
+            text,
+        }
+    }
+}
+
+impl From<&str> for StyledString {
+    fn from(text: &str) -> Self {
+        text.to_owned().into()
+    }
+}
+
+impl<T: Display> From<Styled<T>> for StyledString {
+    fn from(styled: Styled<T>) -> Self {
+        Self::new().with(styled)
+    }
+}
+
+/// A borrowed [`StyledString`].
+///
+/// A plain [`str`] can also be turned into a `StyledStr` without any styles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StyledStr<'a> {
+    text: &'a str,
+    /// If this is empty, the entire text is unstyled.
+    parts: &'a [Part],
+}
+
+impl<'a> StyledStr<'a> {
+    /// The text of the string without any styles.
+    pub fn text(self) -> &'a str {
+        self.text
+    }
+
+    /// Writes the text in the byte range `range` to the backend. The given `attributes` are
+    /// applied on top of the attributes of every part.
+    pub fn write_range<B: crate::backend::Backend + ?Sized>(
+        self,
+        range: Range<usize>,
+        attributes: Attributes,
+        backend: &mut B,
+    ) -> io::Result<()> {
+        if self.parts.is_empty() {
+            let mut styled = Styled::new(&self.text[range]);
+            styled.attributes = attributes;
+            return styled.write(backend);
+        }
+
+        let mut start = 0;
+
+        for part in self.parts {
+            let part_range = start.max(range.start)..part.end.min(range.end);
+            start = part.end;
+
+            if part_range.is_empty() {
+                continue;
+            }
+
+            Styled {
+                fg: part.fg,
+                bg: part.bg,
+                attributes: part.attributes | attributes,
+                content: &self.text[part_range],
+            }
+            .write(backend)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> From<&'a str> for StyledStr<'a> {
+    fn from(text: &'a str) -> Self {
+        Self { text, parts: &[] }
+    }
+}
+
+impl<'a> From<&'a String> for StyledStr<'a> {
+    fn from(text: &'a String) -> Self {
+        text.as_str().into()
+    }
+}
+
+impl<'a> From<&'a StyledString> for StyledStr<'a> {
+    fn from(styled: &'a StyledString) -> Self {
+        styled.as_styled_str()
+    }
+}
+
 /// Represents a color. See the underlying terminal library documentation for information on
 /// terminal compatibility.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
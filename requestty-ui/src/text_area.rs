@@ -0,0 +1,499 @@
+use std::{io, ops::Range};
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{
+    backend::Backend,
+    events::{KeyCode, KeyEvent, KeyModifiers, Movement},
+    layout::Layout,
+};
+
+/// A widget that inputs multi-line text.
+///
+/// The text is word wrapped to the available width, and `Enter` inserts a new line. Apart from the
+/// movements supported by [`StringInput`], the 'cursor' can also be moved up and down between the
+/// rows of the wrapped text.
+///
+/// Unlike [`StringInput`], it always starts rendering on a new line.
+///
+/// [`StringInput`]: crate::widgets::StringInput
+#[derive(Debug, Clone, Default)]
+pub struct TextArea {
+    value: String,
+    /// The position of the 'cursor' in bytes
+    at: usize,
+    /// The width the rows were last wrapped to, which is required to move between rows
+    width: u16,
+}
+
+impl TextArea {
+    /// Creates a new, empty [`TextArea`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The value of the `TextArea`
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Sets the value, and moves the 'cursor' to the end of it
+    pub fn set_value(&mut self, value: String) {
+        self.at = value.len();
+        self.value = value;
+    }
+
+    /// Returns the inputted text
+    pub fn finish(self) -> String {
+        self.value
+    }
+
+    /// The position of the 'cursor' in bytes
+    pub fn get_at(&self) -> usize {
+        self.at
+    }
+
+    /// Splits the value into the byte ranges of the rows it is rendered in.
+    ///
+    /// Rows are broken after whitespace where possible, and a column is left free at the end of
+    /// each row so that the 'cursor' can always be placed after the last character.
+    fn rows(&self, width: u16) -> Vec<Range<usize>> {
+        let width = width.saturating_sub(1).max(1);
+        let mut rows = Vec::new();
+        let mut line_start = 0;
+
+        for line in self.value.split('\n') {
+            let line_end = line_start + line.len();
+            let mut start = line_start;
+
+            loop {
+                let mut end = line_end;
+                let mut row_width = 0;
+                let mut last_break = None;
+
+                for (i, c) in self.value[start..line_end].char_indices() {
+                    let c_width = char_width(c);
+
+                    if row_width + c_width > width && i > 0 {
+                        end = last_break.unwrap_or(start + i);
+                        break;
+                    }
+
+                    row_width += c_width;
+                    if c.is_whitespace() {
+                        last_break = Some(start + i + c.len_utf8());
+                    }
+                }
+
+                rows.push(start..end);
+
+                if end == line_end {
+                    break;
+                }
+                start = end;
+            }
+
+            line_start = line_end + 1;
+        }
+
+        rows
+    }
+
+    /// The row and column of the 'cursor'.
+    fn cursor_row_col(&self, rows: &[Range<usize>]) -> (usize, u16) {
+        // If the cursor is at the end of a wrapped row, it is shown at the start of the next row
+        let row = rows
+            .iter()
+            .rposition(|row| row.start <= self.at)
+            .unwrap_or(0);
+
+        let col = textwrap::core::display_width(&self.value[rows[row].start..self.at]) as u16;
+
+        (row, col)
+    }
+
+    /// The first row which is rendered, such that the 'cursor' is always visible.
+    fn first_row(&self, rows: &[Range<usize>], max_height: u16) -> usize {
+        let (row, _) = self.cursor_row_col(rows);
+        (row + 1).saturating_sub(max_height.max(1) as usize)
+    }
+
+    /// Moves the 'cursor' to the given row, as close to the given column as possible.
+    fn move_to_row(&mut self, rows: &[Range<usize>], row: usize, col: u16) {
+        let Range { start, end } = rows[row];
+        let mut at = start;
+        let mut width = 0;
+
+        for (i, c) in self.value[start..end].char_indices() {
+            width += char_width(c);
+            if width > col {
+                break;
+            }
+            at = start + i + c.len_utf8();
+        }
+
+        // The end of a wrapped row is the same position as the start of the next row, so the
+        // 'cursor' is kept before the last character to stay on this row
+        if at == end && rows.get(row + 1).map(|next| next.start) == Some(end) {
+            at = self.value[..end]
+                .char_indices()
+                .next_back()
+                .map(|(i, _)| i)
+                .filter(|&i| i >= start)
+                .unwrap_or(start);
+        }
+
+        self.at = at;
+    }
+
+    fn prev_char_boundary(&self) -> usize {
+        self.value[..self.at]
+            .char_indices()
+            .next_back()
+            .map(|(i, _)| i)
+            .unwrap_or(0)
+    }
+
+    fn next_char_boundary(&self) -> usize {
+        self.value[self.at..]
+            .chars()
+            .next()
+            .map(|c| self.at + c.len_utf8())
+            .unwrap_or_else(|| self.value.len())
+    }
+
+    /// Get the word bound iterator for a given range
+    fn word_iter(&self, r: Range<usize>) -> impl DoubleEndedIterator<Item = (usize, &str)> {
+        self.value[r]
+            .split_word_bound_indices()
+            .filter(|(_, s)| !s.chars().next().map(char::is_whitespace).unwrap_or(true))
+    }
+}
+
+impl super::Widget for TextArea {
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Char(c)
+                if !key
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                self.value.insert(self.at, c);
+                self.at += c.len_utf8();
+                return true;
+            }
+            KeyCode::Enter => {
+                self.value.insert(self.at, '\n');
+                self.at += 1;
+                return true;
+            }
+            KeyCode::Backspace if self.at != 0 => {
+                let prev = self.prev_char_boundary();
+                self.value.replace_range(prev..self.at, "");
+                self.at = prev;
+                return true;
+            }
+            KeyCode::Delete if self.at != self.value.len() => {
+                let next = self.next_char_boundary();
+                self.value.replace_range(self.at..next, "");
+                return true;
+            }
+            _ => {}
+        }
+
+        match Movement::try_from_key(key) {
+            Some(Movement::Left) if self.at != 0 => {
+                self.at = self.prev_char_boundary();
+            }
+            Some(Movement::Right) if self.at != self.value.len() => {
+                self.at = self.next_char_boundary();
+            }
+
+            Some(Movement::PrevWord) if self.at != 0 => {
+                let at = self
+                    .word_iter(0..self.at)
+                    .next_back()
+                    .map(|(i, _)| i)
+                    .unwrap_or(0);
+                self.at = at;
+            }
+            Some(Movement::NextWord) if self.at != self.value.len() => {
+                let at = self
+                    .word_iter(self.at..self.value.len())
+                    .nth(1)
+                    .map(|(i, _)| self.at + i)
+                    .unwrap_or_else(|| self.value.len());
+                self.at = at;
+            }
+
+            Some(movement @ Movement::Up) | Some(movement @ Movement::Down) => {
+                let rows = self.rows(self.width);
+                let (row, col) = self.cursor_row_col(&rows);
+
+                let row = match movement {
+                    Movement::Up if row != 0 => row - 1,
+                    Movement::Down if row + 1 != rows.len() => row + 1,
+                    _ => return false,
+                };
+
+                self.move_to_row(&rows, row, col);
+            }
+
+            // Home and End move within the current line
+            Some(Movement::Home) => {
+                let home = self.value[..self.at]
+                    .rfind('\n')
+                    .map(|i| i + 1)
+                    .unwrap_or(0);
+                if home == self.at {
+                    return false;
+                }
+                self.at = home;
+            }
+            Some(Movement::End) => {
+                let end = self.value[self.at..]
+                    .find('\n')
+                    .map(|i| self.at + i)
+                    .unwrap_or_else(|| self.value.len());
+                if end == self.at {
+                    return false;
+                }
+                self.at = end;
+            }
+
+            _ => return false,
+        }
+
+        true
+    }
+
+    /// Renders the rows of the text starting from the next line, and moves to the line after it.
+    ///
+    /// If the text does not fit in [`layout.max_height`], only the rows around the 'cursor' are
+    /// rendered.
+    ///
+    /// [`layout.max_height`]: Layout::max_height
+    fn render<B: Backend>(&mut self, layout: &mut Layout, backend: &mut B) -> io::Result<()> {
+        if layout.line_offset != 0 {
+            layout.line_offset = 0;
+            layout.offset_y += 1;
+        }
+
+        self.width = layout.available_width();
+        let rows = self.rows(self.width);
+        let first_row = self.first_row(&rows, layout.max_height);
+
+        let mut height = 0;
+        for row in rows.iter().skip(first_row).take(layout.max_height as usize) {
+            backend.move_cursor_to(layout.offset_x, layout.offset_y + height)?;
+            // Trailing whitespace of a wrapped row may not fit in the row
+            backend.write_all(self.value[row.clone()].trim_end().as_bytes())?;
+            height += 1;
+        }
+
+        layout.offset_y += height.max(1);
+        backend.move_cursor_to(layout.offset_x, layout.offset_y)
+    }
+
+    fn height(&mut self, layout: &mut Layout) -> u16 {
+        let mut height = (layout.line_offset != 0) as u16; // Add one if we go to the next line
+
+        self.width = layout.available_width();
+        height += (self.rows(self.width).len() as u16).min(layout.max_height.max(1));
+
+        layout.line_offset = 0;
+        layout.offset_y += height;
+
+        height
+    }
+
+    fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+        let rows = self.rows(layout.available_width());
+        let (row, col) = self.cursor_row_col(&rows);
+        let first_row = self.first_row(&rows, layout.max_height);
+
+        layout.offset_cursor((
+            col,
+            (layout.line_offset != 0) as u16 + (row - first_row) as u16,
+        ))
+    }
+}
+
+fn char_width(c: char) -> u16 {
+    let mut buf = [0u8; 4];
+    textwrap::core::display_width(c.encode_utf8(&mut buf)) as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{backend::TestBackend, test_consts::*, Widget};
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::empty())
+    }
+
+    fn text_area(value: &str) -> TextArea {
+        let mut text_area = TextArea::new();
+        text_area.set_value(value.into());
+        text_area
+    }
+
+    fn row_strs(text_area: &TextArea, width: u16) -> Vec<&str> {
+        text_area
+            .rows(width)
+            .into_iter()
+            .map(|row| &text_area.value()[row])
+            .collect()
+    }
+
+    #[test]
+    fn test_rows() {
+        assert_eq!(row_strs(&text_area(""), 10), vec![""]);
+        assert_eq!(row_strs(&text_area("a\n\nb"), 10), vec!["a", "", "b"]);
+        assert_eq!(row_strs(&text_area("a\n"), 10), vec!["a", ""]);
+
+        // Words are moved to the next row
+        assert_eq!(
+            row_strs(&text_area("the quick brown fox"), 11),
+            vec!["the quick ", "brown fox"]
+        );
+        // Long words are broken
+        assert_eq!(
+            row_strs(&text_area("abcdefghijklmnopqrstuvwxyz"), 11),
+            vec!["abcdefghij", "klmnopqrst", "uvwxyz"]
+        );
+        // Wide characters take two columns
+        assert_eq!(row_strs(&text_area("漢字漢字"), 6), vec!["漢字", "漢字"]);
+
+        for &text in [LOREM, UNICODE].iter() {
+            let text_area = text_area(text);
+            let rows = text_area.rows(40);
+            assert_eq!(rows.first().unwrap().start, 0);
+            assert_eq!(rows.last().unwrap().end, text.len());
+
+            for (row, next) in rows.iter().zip(rows.iter().skip(1)) {
+                assert_eq!(row.end, next.start);
+                assert!(textwrap::core::display_width(&text[row.clone()]) <= 39);
+            }
+        }
+    }
+
+    #[test]
+    fn test_handle_key() {
+        let mut text_area = TextArea::new();
+
+        for c in "hello".chars() {
+            assert!(text_area.handle_key(key(KeyCode::Char(c))));
+        }
+        assert!(text_area.handle_key(key(KeyCode::Enter)));
+        for c in "wörld".chars() {
+            assert!(text_area.handle_key(key(KeyCode::Char(c))));
+        }
+        assert_eq!(text_area.value(), "hello\nwörld");
+
+        assert!(text_area.handle_key(key(KeyCode::Home)));
+        assert_eq!(text_area.get_at(), 6);
+        assert!(!text_area.handle_key(key(KeyCode::Home)));
+        assert!(text_area.handle_key(key(KeyCode::Backspace)));
+        assert_eq!(text_area.value(), "hellowörld");
+        assert_eq!(text_area.get_at(), 5);
+
+        assert!(text_area.handle_key(key(KeyCode::Right)));
+        assert!(text_area.handle_key(key(KeyCode::Right)));
+        assert_eq!(text_area.get_at(), 8);
+        assert!(text_area.handle_key(key(KeyCode::Delete)));
+        assert_eq!(text_area.value(), "hellowöld");
+
+        assert!(text_area.handle_key(key(KeyCode::End)));
+        assert_eq!(text_area.get_at(), text_area.value().len());
+        assert!(!text_area.handle_key(key(KeyCode::Delete)));
+
+        text_area.set_value("one two three".into());
+        assert!(text_area.handle_key(KeyEvent::new(KeyCode::Left, KeyModifiers::CONTROL)));
+        assert_eq!(text_area.get_at(), 8);
+        assert!(text_area.handle_key(KeyEvent::new(KeyCode::Left, KeyModifiers::CONTROL)));
+        assert_eq!(text_area.get_at(), 4);
+        assert!(text_area.handle_key(KeyEvent::new(KeyCode::Right, KeyModifiers::CONTROL)));
+        assert_eq!(text_area.get_at(), 8);
+    }
+
+    #[test]
+    fn test_up_down() {
+        let mut text_area = text_area("the quick brown fox\nshort\n\nend");
+        let layout = Layout::new(0, (11, 20).into());
+        text_area.height(&mut layout.clone());
+
+        // rows: "the quick ", "brown fox", "short", "", "end"
+        text_area.at = 2;
+        assert!(!text_area.handle_key(key(KeyCode::Up)));
+
+        assert!(text_area.handle_key(key(KeyCode::Down)));
+        assert_eq!(text_area.get_at(), 12);
+        assert_eq!(text_area.cursor_pos(layout), (2, 1));
+
+        assert!(text_area.handle_key(key(KeyCode::End)));
+        assert_eq!(text_area.cursor_pos(layout), (9, 1));
+        assert!(text_area.handle_key(key(KeyCode::Down)));
+        assert_eq!(text_area.cursor_pos(layout), (5, 2));
+        assert!(text_area.handle_key(key(KeyCode::Down)));
+        assert_eq!(text_area.cursor_pos(layout), (0, 3));
+        assert!(text_area.handle_key(key(KeyCode::Down)));
+        assert_eq!(text_area.cursor_pos(layout), (0, 4));
+        assert!(!text_area.handle_key(key(KeyCode::Down)));
+
+        // Moving up to the end of a wrapped row keeps the cursor on that row
+        text_area.set_value("abcdefghijklmnopqrst".into());
+        assert_eq!(text_area.cursor_pos(layout), (10, 1));
+        assert!(text_area.handle_key(key(KeyCode::Up)));
+        assert_eq!(text_area.cursor_pos(layout), (9, 0));
+    }
+
+    #[test]
+    fn test_render() {
+        let size = (11, 10).into();
+        let layout = Layout::new(5, size);
+
+        let mut text_area = text_area("the quick brown fox\n\nend");
+
+        let mut render_layout = layout;
+        let mut backend = TestBackend::new_with_layout(size, layout);
+        text_area.render(&mut render_layout, &mut backend).unwrap();
+
+        let mut height_layout = layout;
+        assert_eq!(text_area.height(&mut height_layout), 5);
+        assert_eq!(render_layout, height_layout);
+        assert_eq!(render_layout, layout.with_line_offset(0).with_offset(0, 5));
+
+        let mut expected =
+            TestBackend::from_lines(&["", "the quick", "brown fox", "", "end"], size);
+        expected.move_cursor_to(0, 5).unwrap();
+        backend.assert_eq(&expected);
+
+        assert_eq!(text_area.cursor_pos(layout), (3, 4));
+    }
+
+    #[test]
+    fn test_max_height() {
+        let size = (11, 20).into();
+        let layout = Layout::new(0, size).with_max_height(2);
+        let mut text_area = text_area("a\nb\nc\nd");
+
+        let mut height_layout = layout;
+        assert_eq!(text_area.height(&mut height_layout), 2);
+
+        // The rows around the cursor are rendered
+        let mut backend = TestBackend::new(size);
+        text_area.render(&mut layout.clone(), &mut backend).unwrap();
+        let mut expected = TestBackend::from_lines(&["c", "d"], size);
+        expected.move_cursor_to(0, 2).unwrap();
+        backend.assert_eq(&expected);
+        assert_eq!(backend.row_text(0), "c");
+        assert_eq!(backend.row_text(1), "d");
+        assert_eq!(backend.row_text(2), "");
+        assert_eq!(text_area.cursor_pos(layout), (1, 1));
+
+        text_area.at = 0;
+        assert_eq!(text_area.cursor_pos(layout), (0, 0));
+    }
+}
@@ -0,0 +1,98 @@
+//! Colours used for prompts/widgets.
+//!
+//! If a particular [`Theme`] is not set, the [`DEFAULT`] theme is used. The symbols drawn with these
+//! colours, such as the pointer and the ticks, are part of the [`SymbolSet`] instead.
+//!
+//! [`SymbolSet`]: crate::symbols::SymbolSet
+
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+
+use crate::style::{Attributes, Color};
+
+static THEME: Lazy<Mutex<Theme>> = Lazy::new(|| Mutex::new(DEFAULT));
+
+/// Get the current [`Theme`]
+///
+/// If not set, it defaults to the [`DEFAULT`] theme.
+///
+/// Also see [`theme::set`](set).
+///
+/// # Example
+///
+/// ```no_run
+/// # #[cfg(feature = "ignore this line for doc test as requestty_ui should be used")]
+/// use requestty::theme;
+/// # use requestty_ui::theme;
+///
+/// let theme = theme::current();
+/// println!("{:?}", theme.cursor);
+/// ```
+pub fn current() -> Theme {
+    *THEME.lock().expect("theme poisoned")
+}
+
+/// Set the current [`Theme`]
+///
+/// Also see [`theme::current`](current).
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "ignore this line for doc test as requestty_ui should be used")]
+/// use requestty::{prompt::style::Color, theme};
+/// # use requestty_ui::{style::Color, theme};
+///
+/// let new = theme::Theme {
+///     cursor: Color::Magenta,
+///     ..theme::DEFAULT
+/// };
+///
+/// theme::set(new);
+/// assert_eq!(theme::current(), new);
+/// ```
+pub fn set(new: Theme) {
+    *THEME.lock().expect("theme poisoned") = new;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The colours used by the prompts during rendering.
+pub struct Theme {
+    /// Used for the item the cursor is on.
+    ///
+    /// For example, this is used for the pointer and the hovered item in the various list prompts.
+    pub cursor: Color,
+    /// Used to mark an item as selected.
+    ///
+    /// For example, this is used for the ticks of the checked items in a `multi_select` prompt.
+    pub selected: Color,
+    /// Used for hints which help the user answer the prompt.
+    ///
+    /// For example, this is used for the hint after the message of a prompt.
+    pub hint: Color,
+    /// Used for items which cannot be picked.
+    ///
+    /// For example, this is used for separators, disabled choices, and the ticks of the unchecked
+    /// items in a `multi_select` prompt.
+    pub disabled: Color,
+    /// Used for the answer which is shown once a prompt is finished.
+    ///
+    /// For example, this is used for the chosen option after a `select` prompt is answered. It is
+    /// not used if the question has a `transform`.
+    pub answer: Color,
+    /// Applied along with [`answer`](Theme::answer) to the answer shown once a prompt is finished.
+    ///
+    /// For example, this can be set to [`Attributes::BOLD`] to make the answers stand out.
+    pub answer_attributes: Attributes,
+}
+
+/// The theme used if no other theme is set. This is how all the prompts look by default.
+pub const DEFAULT: Theme = Theme {
+    cursor: Color::Cyan,
+    selected: Color::LightGreen,
+    hint: Color::DarkGrey,
+    disabled: Color::DarkGrey,
+    answer: Color::Cyan,
+    answer_attributes: Attributes::empty(),
+};
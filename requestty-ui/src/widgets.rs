@@ -2,15 +2,15 @@
 
 use std::io;
 
-use textwrap::{core::Fragment, WordSeparator};
-
 use crate::{backend::Backend, events::KeyEvent, layout::Layout};
 
 pub use crate::char_input::CharInput;
-pub use crate::prompt::{Delimiter, Prompt};
+pub use crate::prompt::{Delimiter, Prompt, PromptMessage};
 pub use crate::select::{List, Select};
+pub use crate::spinner::{Spinner, FRAME_INTERVAL};
 pub use crate::string_input::StringInput;
 pub use crate::text::Text;
+pub use crate::text_area::TextArea;
 
 /// The default type for `filter_map` in [`StringInput`] and [`CharInput`]
 pub type FilterMapChar = fn(char) -> Option<char>;
@@ -62,11 +62,28 @@ pub trait Widget {
 
     /// Handle a key input. It should return whether key was handled.
     fn handle_key(&mut self, key: KeyEvent) -> bool;
+
+    /// Render only the parts of the widget which changed since it was last rendered, on top of
+    /// what is already on the screen. It should return whether the changes could be rendered.
+    ///
+    /// This is only called when the layout and the height of the widget are the same as the last
+    /// render. If it returns `false`, nothing should have been rendered, and the widget is cleared
+    /// and [rendered](Widget::render) from scratch instead. Like `render`, the widget is
+    /// responsible for updating the layout to reflect the space that it has used.
+    ///
+    /// The default implementation always returns `false`.
+    fn render_changes<B: Backend>(
+        &mut self,
+        _layout: &mut Layout,
+        _backend: &mut B,
+    ) -> io::Result<bool> {
+        Ok(false)
+    }
 }
 
 impl<T: std::ops::Deref<Target = str> + ?Sized> Widget for T {
-    /// Does not allow multi-line strings. If the string requires more than a single line, it adds
-    /// cuts it short and adds '...' to the end.
+    /// Does not allow multi-line strings. If the string requires more than a single line, it cuts
+    /// it short and adds '…' to the end.
     ///
     /// If a multi-line string is required, use the [`Text`](crate::widgets::Text) widget.
     fn render<B: Backend>(&mut self, layout: &mut Layout, backend: &mut B) -> io::Result<()> {
@@ -75,33 +92,13 @@ impl<T: std::ops::Deref<Target = str> + ?Sized> Widget for T {
         layout.offset_y += 1;
         layout.line_offset = 0;
 
-        if max_width <= 3 {
-            for _ in 0..max_width {
-                backend.write_all(b".")?;
-            }
-        } else if textwrap::core::display_width(self) > max_width {
-            let mut width = 0;
-            let mut prev_whitespace_len = 0;
-            let max_width = max_width - 3; // leave space for the '...'
-
-            for word in WordSeparator::UnicodeBreakProperties.find_words(self) {
-                width += word.width() as usize + prev_whitespace_len;
-                if width > max_width {
-                    break;
-                }
-
-                // Write out the whitespace only if the next word can also fit
-                for _ in 0..prev_whitespace_len {
-                    backend.write_all(b" ")?;
-                }
-                backend.write_all(word.as_bytes())?;
-
-                prev_whitespace_len = word.whitespace_width() as usize;
+        match truncate(self, max_width) {
+            Some(_) if max_width == 0 => {}
+            Some(truncated) => {
+                backend.write_all(truncated.as_bytes())?;
+                backend.write_all("…".as_bytes())?;
             }
-
-            backend.write_all(b"...")?;
-        } else {
-            backend.write_all(self.as_bytes())?;
+            None => backend.write_all(self.as_bytes())?,
         }
 
         backend
@@ -128,3 +125,67 @@ impl<T: std::ops::Deref<Target = str> + ?Sized> Widget for T {
         false
     }
 }
+
+/// Returns the longest prefix of `s` that fits within `max_width` columns along with a trailing
+/// '…', or `None` if `s` fits as is.
+fn truncate(s: &str, max_width: usize) -> Option<&str> {
+    if textwrap::core::display_width(s) <= max_width {
+        return None;
+    }
+
+    // leave space for the '…'
+    let max_width = max_width.saturating_sub(1);
+    let mut width = 0;
+    let mut buf = [0; 4];
+
+    let end = s
+        .char_indices()
+        .find(|&(_, c)| {
+            width += textwrap::core::display_width(c.encode_utf8(&mut buf));
+            width > max_width
+        })
+        .map_or(s.len(), |(i, _)| i);
+
+    Some(s[..end].trim_end())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::backend::TestBackend;
+
+    use super::*;
+
+    #[test]
+    fn test_truncate() {
+        assert_eq!(truncate("Hello world", 11), None);
+        assert_eq!(truncate("Hello world", 10), Some("Hello wor"));
+        assert_eq!(truncate("Hello world", 7), Some("Hello"));
+        assert_eq!(truncate("Hello world", 1), Some(""));
+        assert_eq!(truncate("Hello world", 0), Some(""));
+
+        // Every character is 2 columns wide
+        assert_eq!(truncate("你好世界", 8), None);
+        assert_eq!(truncate("你好世界", 7), Some("你好世"));
+        assert_eq!(truncate("你好世界", 6), Some("你好"));
+        assert_eq!(truncate("你好世界", 4), Some("你"));
+        assert_eq!(truncate("你好世界", 2), Some(""));
+    }
+
+    #[test]
+    fn test_render_truncated() {
+        fn test(mut text: &str, line_offset: u16, width: u16) {
+            let size = (width, 2).into();
+            let mut layout = Layout::new(line_offset, size);
+            let mut backend = TestBackend::new_with_layout(size, layout);
+
+            assert!(text.render(&mut layout, &mut backend).is_ok());
+            assert_eq!(layout, Layout::new(0, size).with_offset(0, 1));
+            crate::assert_backend_snapshot!(backend);
+        }
+
+        test("Hello world", 0, 8);
+        test("你好世界", 2, 8);
+        test("你好世界", 0, 3);
+        test("Hello world", 2, 3);
+    }
+}
@@ -1,5 +1,9 @@
 use std::{
-    collections::hash_map::{Entry, HashMap, IntoIter},
+    collections::{
+        hash_map::{Entry, HashMap, IntoIter},
+        BTreeMap, HashSet,
+    },
+    fmt,
     hash::Hash,
     iter::FromIterator,
     ops::{Deref, DerefMut},
@@ -9,27 +13,64 @@ use crate::question::OrderSelectItem;
 
 /// The different answer types that can be returned by the [`Question`]s
 ///
+/// # Serialization
+///
+/// With the `serde` feature, an answer is serialized as its contents, without the name of the
+/// variant. In JSON:
+///
+/// - [`String`], [`Int`], [`Float`] and [`Bool`] are strings, numbers and booleans.
+/// - [`ListItem`] is an object with the `index` and `text` of the choice, e.g.
+///   `{ "index": 2, "text": "Large" }`.
+/// - [`ExpandItem`] is an object with the `key` and `text` of the choice, e.g.
+///   `{ "key": "y", "text": "Yes" }`.
+/// - [`ListItems`] is an array of [`ListItem`] objects, in the same order as the answer.
+/// - [`Date`] is an object with the `year`, `month` and `day`, and [`Time`] is an object with the
+///   `hour`, `minute` and `second`.
+/// - [`Strings`] and [`Bools`] are arrays of strings and booleans.
+/// - [`Map`] is an object with a string for each field.
+///
+/// When deserializing, the first variant in the order they are declared which matches is used. For
+/// example, an empty array is always deserialized as [`ListItems`].
+///
 /// [`Question`]: crate::question::Question
+/// [`String`]: Answer::String
+/// [`Int`]: Answer::Int
+/// [`Float`]: Answer::Float
+/// [`Bool`]: Answer::Bool
+/// [`ListItem`]: Answer::ListItem
+/// [`ExpandItem`]: Answer::ExpandItem
+/// [`ListItems`]: Answer::ListItems
+/// [`Date`]: Answer::Date
+/// [`Time`]: Answer::Time
+/// [`Strings`]: Answer::Strings
+/// [`Bools`]: Answer::Bools
+/// [`Map`]: Answer::Map
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
 pub enum Answer {
-    /// Strings will be returned by [`input`], [`password`] and [`editor`].
+    /// Strings will be returned by [`input`], [`password`], [`editor`], [`text`] and [`masked`].
     ///
     /// [`input`]: crate::question::Question::input
     /// [`password`]: crate::question::Question::password
     /// [`editor`]: crate::question::Question::editor
+    /// [`text`]: crate::question::Question::text
+    /// [`masked`]: crate::question::Question::masked
     String(String),
-    /// ListItems will be returned by [`select`] and [`raw_select`].
+    /// ListItems will be returned by [`select`], [`raw_select`] and [`select_lazy`].
     ///
     /// [`select`]: crate::question::Question::select
     /// [`raw_select`]: crate::question::Question::raw_select
+    /// [`select_lazy`]: crate::question::Question::select_lazy
     ListItem(ListItem),
     /// ExpandItems will be returned by [`expand`].
     ///
     /// [`expand`]: crate::question::Question::expand
     ExpandItem(ExpandItem),
-    /// Ints will be returned by [`int`].
+    /// Ints will be returned by [`int`] and [`slider`].
     ///
     /// [`int`]: crate::question::Question::int
+    /// [`slider`]: crate::question::Question::slider
     Int(i64),
     /// Floats will be returned by [`float`].
     ///
@@ -39,11 +80,38 @@ pub enum Answer {
     ///
     /// [`confirm`]: crate::question::Question::confirm
     Bool(bool),
-    /// ListItems will be returned by [`multi_select`] and [`order_select`].
+    /// ListItems will be returned by [`multi_select`], [`order_select`] and [`tree`].
+    ///
+    /// For [`order_select`], the items are in the order chosen by the user, and the index of each
+    /// item is its index in the original list of choices.
+    ///
+    /// For [`tree`], the items are the path from the root to the selected leaf, and the index of
+    /// each item is its index among its siblings.
     ///
     /// [`multi_select`]: crate::question::Question::multi_select
-    /// [`multi_select`]: crate::question::Question::order_select
+    /// [`order_select`]: crate::question::Question::order_select
+    /// [`tree`]: crate::question::Question::tree
     ListItems(Vec<ListItem>),
+    /// Dates will be returned by [`date`].
+    ///
+    /// [`date`]: crate::question::Question::date
+    Date(Date),
+    /// Times will be returned by [`time`].
+    ///
+    /// [`time`]: crate::question::Question::time
+    Time(Time),
+    /// Strings will be returned by [`tags`].
+    ///
+    /// [`tags`]: crate::question::Question::tags
+    Strings(Vec<String>),
+    /// Bools will be returned by [`batch_confirm`].
+    ///
+    /// [`batch_confirm`]: crate::question::Question::batch_confirm
+    Bools(Vec<bool>),
+    /// A map from the name of each field to its value will be returned by [`form`].
+    ///
+    /// [`form`]: crate::question::Question::form
+    Map(BTreeMap<String, String>),
 }
 
 impl Answer {
@@ -193,6 +261,140 @@ impl Answer {
             _ => Err(self),
         }
     }
+
+    /// Returns the indices of the selected choices.
+    ///
+    /// For an [`Answer::ListItems`], these are the indices of all the items, in order. For an
+    /// [`Answer::ListItem`], it is the index of the item. Any other answer has no selected
+    /// choices, so an empty [`Vec`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Answer, ListItem};
+    ///
+    /// let answer = Answer::ListItems(vec![
+    ///     ListItem::from((0, "pepperoni")),
+    ///     ListItem::from((2, "olives")),
+    /// ]);
+    /// assert_eq!(answer.selected_indices(), vec![0, 2]);
+    ///
+    /// let answer = Answer::ListItem(ListItem::from((1, "mushrooms")));
+    /// assert_eq!(answer.selected_indices(), vec![1]);
+    /// ```
+    pub fn selected_indices(&self) -> Vec<usize> {
+        match self {
+            Self::ListItems(v) => v.iter().map(|item| item.index).collect(),
+            Self::ListItem(item) => vec![item.index],
+            _ => Vec::new(),
+        }
+    }
+
+    /// Returns `true` if the answer is [`Answer::Date`].
+    pub fn is_date(&self) -> bool {
+        matches!(self, Self::Date(..))
+    }
+
+    /// Returns [`Some`] if it is [`Answer::Date`], otherwise returns [`None`].
+    pub fn as_date(&self) -> Option<Date> {
+        match self {
+            Self::Date(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// Returns the `Ok(Date)` if it is one, otherwise returns itself as an [`Err`].
+    pub fn try_into_date(self) -> Result<Date, Self> {
+        match self {
+            Self::Date(v) => Ok(v),
+            _ => Err(self),
+        }
+    }
+
+    /// Returns `true` if the answer is [`Answer::Time`].
+    pub fn is_time(&self) -> bool {
+        matches!(self, Self::Time(..))
+    }
+
+    /// Returns [`Some`] if it is [`Answer::Time`], otherwise returns [`None`].
+    pub fn as_time(&self) -> Option<Time> {
+        match self {
+            Self::Time(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// Returns the `Ok(Time)` if it is one, otherwise returns itself as an [`Err`].
+    pub fn try_into_time(self) -> Result<Time, Self> {
+        match self {
+            Self::Time(v) => Ok(v),
+            _ => Err(self),
+        }
+    }
+
+    /// Returns `true` if the answer is [`Answer::Strings`].
+    pub fn is_strings(&self) -> bool {
+        matches!(self, Self::Strings(..))
+    }
+
+    /// Returns [`Some`] if it is [`Answer::Strings`], otherwise returns [`None`].
+    pub fn as_strings(&self) -> Option<&[String]> {
+        match self {
+            Self::Strings(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Returns the `Ok(Vec<String>)` if it is one, otherwise returns itself as an [`Err`].
+    pub fn try_into_strings(self) -> Result<Vec<String>, Self> {
+        match self {
+            Self::Strings(v) => Ok(v),
+            _ => Err(self),
+        }
+    }
+
+    /// Returns `true` if the answer is [`Answer::Bools`].
+    pub fn is_bools(&self) -> bool {
+        matches!(self, Self::Bools(..))
+    }
+
+    /// Returns [`Some`] if it is [`Answer::Bools`], otherwise returns [`None`].
+    pub fn as_bools(&self) -> Option<&[bool]> {
+        match self {
+            Self::Bools(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Returns the `Ok(Vec<bool>)` if it is one, otherwise returns itself as an [`Err`].
+    pub fn try_into_bools(self) -> Result<Vec<bool>, Self> {
+        match self {
+            Self::Bools(v) => Ok(v),
+            _ => Err(self),
+        }
+    }
+
+    /// Returns `true` if the answer is [`Answer::Map`].
+    pub fn is_map(&self) -> bool {
+        matches!(self, Self::Map(..))
+    }
+
+    /// Returns [`Some`] if it is [`Answer::Map`], otherwise returns [`None`].
+    pub fn as_map(&self) -> Option<&BTreeMap<String, String>> {
+        match self {
+            Self::Map(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Returns the `Ok(BTreeMap<String, String>)` if it is one, otherwise returns itself as an
+    /// [`Err`].
+    pub fn try_into_map(self) -> Result<BTreeMap<String, String>, Self> {
+        match self {
+            Self::Map(v) => Ok(v),
+            _ => Err(self),
+        }
+    }
 }
 
 macro_rules! impl_from {
@@ -212,25 +414,30 @@ impl_from!(bool => Bool);
 impl_from!(ExpandItem => ExpandItem);
 impl_from!(ListItem => ListItem);
 impl_from!(Vec<ListItem> => ListItems);
+impl_from!(Date => Date);
+impl_from!(Time => Time);
+impl_from!(Vec<String> => Strings);
+impl_from!(Vec<bool> => Bools);
+impl_from!(BTreeMap<String, String> => Map);
 
 impl From<Vec<OrderSelectItem>> for Answer {
     fn from(v: Vec<OrderSelectItem>) -> Self {
-        Answer::ListItems(
-            v.into_iter()
-                .map(|o| o.into())
-                .collect()
-        )
+        Answer::ListItems(v.into_iter().map(|o| o.into()).collect())
     }
 }
 
 /// A representation of a [`Choice`] at a particular index.
 ///
-/// It will be returned by [`select`] and [`raw_select`].
+/// It will be returned by [`select`] and [`raw_select`]. A list of them will be returned by
+/// [`multi_select`] and [`order_select`].
 ///
 /// [`Choice`]: crate::Choice
 /// [`select`]: crate::question::Question::select
 /// [`raw_select`]: crate::question::Question::raw_select
+/// [`multi_select`]: crate::question::Question::multi_select
+/// [`order_select`]: crate::question::Question::order_select
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ListItem {
     /// The index of the choice
     pub index: usize,
@@ -247,9 +454,19 @@ impl<I: Into<String>> From<(usize, I)> for ListItem {
     }
 }
 
+impl fmt::Display for ListItem {
+    /// Formats the item as its text.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
 impl From<OrderSelectItem> for ListItem {
     fn from(o: OrderSelectItem) -> Self {
-        Self { index: o.initial_index(), text: o.text().to_string() }
+        Self {
+            index: o.initial_index(),
+            text: o.text().to_string(),
+        }
     }
 }
 
@@ -260,6 +477,7 @@ impl From<OrderSelectItem> for ListItem {
 /// [`Choice`]: crate::Choice
 /// [`expand`]: crate::question::Question::expand
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExpandItem {
     /// The key associated with the choice
     pub key: char,
@@ -276,12 +494,97 @@ impl<I: Into<String>> From<(char, I)> for ExpandItem {
     }
 }
 
+/// A calendar date.
+///
+/// It will be returned by [`date`]. It can be converted to and from a `(year, month, day)` tuple.
+///
+/// [`date`]: crate::question::Question::date
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Date {
+    /// The year
+    pub year: i32,
+    /// The month, from 1 (January) to 12 (December)
+    pub month: u32,
+    /// The day of the month, starting from 1
+    pub day: u32,
+}
+
+impl From<(i32, u32, u32)> for Date {
+    fn from((year, month, day): (i32, u32, u32)) -> Self {
+        Self { year, month, day }
+    }
+}
+
+impl From<Date> for (i32, u32, u32) {
+    fn from(date: Date) -> Self {
+        (date.year, date.month, date.day)
+    }
+}
+
+impl fmt::Display for Date {
+    /// Formats the date as `YYYY-MM-DD`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// A time of the day.
+///
+/// It will be returned by [`time`]. It can be converted to and from a `(hour, minute, second)`
+/// tuple.
+///
+/// [`time`]: crate::question::Question::time
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Time {
+    /// The hour, from 0 to 23
+    pub hour: u32,
+    /// The minute, from 0 to 59
+    pub minute: u32,
+    /// The second, from 0 to 59
+    pub second: u32,
+}
+
+impl From<(u32, u32, u32)> for Time {
+    fn from((hour, minute, second): (u32, u32, u32)) -> Self {
+        Self {
+            hour,
+            minute,
+            second,
+        }
+    }
+}
+
+impl From<Time> for (u32, u32, u32) {
+    fn from(time: Time) -> Self {
+        (time.hour, time.minute, time.second)
+    }
+}
+
+impl fmt::Display for Time {
+    /// Formats the time as `HH:MM:SS`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)
+    }
+}
+
 /// A collections of answers of previously asked [`Question`]s.
 ///
+/// With the `serde` feature, it is serialized as a map from the name of each question to its
+/// [`Answer`]. Deserialized answers can be given to [`PromptModule::with_answers`], so that the
+/// questions which were already answered are not asked again.
+///
 /// [`Question`]: crate::question::Question
+/// [`PromptModule::with_answers`]: crate::PromptModule::with_answers
 #[derive(Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 pub struct Answers {
     answers: HashMap<String, Answer>,
+    /// The names of the questions which were skipped by the user
+    #[cfg_attr(feature = "serde", serde(skip))]
+    skipped: HashSet<String>,
 }
 
 impl std::fmt::Debug for Answers {
@@ -292,6 +595,8 @@ impl std::fmt::Debug for Answers {
 
 impl Answers {
     pub(crate) fn insert(&mut self, name: String, answer: Answer) -> &mut Answer {
+        self.skipped.remove(&name);
+
         match self.answers.entry(name) {
             Entry::Occupied(entry) => {
                 let entry = entry.into_mut();
@@ -301,11 +606,78 @@ impl Answers {
             Entry::Vacant(entry) => entry.insert(answer),
         }
     }
+
+    /// Records that the user skipped the question, removing any previous answer to it.
+    pub(crate) fn skip(&mut self, name: String) {
+        self.answers.remove(&name);
+        self.skipped.insert(name);
+    }
+
+    /// Forgets the answer to the question, and whether it was skipped, so that it is asked again.
+    pub(crate) fn forget(&mut self, name: &str) {
+        self.answers.remove(name);
+        self.skipped.remove(name);
+    }
+
+    /// Whether the question with the given name was asked, but skipped by the user with
+    /// [`OnEsc::SkipQuestion`].
+    ///
+    /// Skipped questions have no answer. Questions which are not asked, for example because of
+    /// `when`, are not counted as skipped. Which questions were skipped is not serialized.
+    ///
+    /// [`OnEsc::SkipQuestion`]: crate::OnEsc::SkipQuestion
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Answers, OnEsc, PromptModule, Question};
+    /// # use requestty::prompt::{backend::TestBackend, events::{KeyCode, TestEvents}};
+    ///
+    /// let module = PromptModule::new(vec![
+    ///     Question::input("nickname").on_esc(OnEsc::SkipQuestion).build(),
+    /// ]);
+    ///
+    /// # let backend = &mut TestBackend::new((50, 5).into());
+    /// # let events = &mut TestEvents::new(Some(KeyCode::Esc.into()));
+    /// let answers = module.prompt_all_with(backend, events)?;
+    /// assert!(answers.is_skipped("nickname"));
+    /// assert!(!answers.contains_key("nickname"));
+    /// # Ok::<_, requestty::ErrorKind>(())
+    /// ```
+    pub fn is_skipped(&self, name: &str) -> bool {
+        self.skipped.contains(name)
+    }
+
+    /// Returns the items of the answer with the given name, if it is an [`Answer::ListItems`].
+    ///
+    /// Otherwise, or if there is no answer with the given name, [`None`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Answer, Answers, ListItem};
+    ///
+    /// let answers: Answers = vec![(
+    ///     "toppings".to_owned(),
+    ///     Answer::ListItems(vec![ListItem::from((2, "olives"))]),
+    /// )]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// assert_eq!(answers.get_list_items("toppings").unwrap()[0].text, "olives");
+    /// assert!(answers.get_list_items("size").is_none());
+    /// ```
+    pub fn get_list_items(&self, name: &str) -> Option<&[ListItem]> {
+        self.answers.get(name).and_then(Answer::as_list_items)
+    }
 }
 
 impl From<HashMap<String, Answer>> for Answers {
     fn from(answers: HashMap<String, Answer>) -> Self {
-        Self { answers }
+        Self {
+            answers,
+            skipped: HashSet::new(),
+        }
     }
 }
 
@@ -313,6 +685,7 @@ impl FromIterator<(String, Answer)> for Answers {
     fn from_iter<T: IntoIterator<Item = (String, Answer)>>(iter: T) -> Self {
         Self {
             answers: iter.into_iter().collect(),
+            skipped: HashSet::new(),
         }
     }
 }
@@ -115,6 +115,11 @@
 //! - `termion`: Enabling this feature will use the [`termion`](https://crates.io/crates/termion)
 //!   library for terminal interactions such as drawing and receiving events.
 //!
+//! - `serde`: Enabling this feature will allow [`Question`]s to be deserialized from a config
+//!   using [`serde`](https://crates.io/crates/serde). See the `Deserialize` implementation of
+//!   [`Question`] for the supported format. It also allows [`Answers`] to be serialized and
+//!   deserialized, see [`Answer`] for the format.
+//!
 //! [`SmallVec`]: https://docs.rs/smallvec/latest/smallvec/struct.SmallVec.html
 //! [auto completions]: crate::question::InputBuilder::auto_complete
 //!
@@ -146,8 +151,11 @@
 )]
 #![warn(rust_2018_idioms)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
+#![cfg_attr(nightly, feature(extend_one))]
+#![cfg_attr(all(test, nightly), feature(test))]
 
 mod answer;
+mod observer;
 mod prompt_module;
 pub mod question;
 
@@ -217,10 +225,11 @@ use ui::{backend::Backend, events::EventIterator};
 #[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
 pub use r#macro::questions;
 
-pub use answer::{Answer, Answers, ExpandItem, ListItem};
+pub use answer::{Answer, Answers, Date, ExpandItem, ListItem, Time};
+pub use observer::{Observer, PromptEvent};
 pub use prompt_module::PromptModule;
 pub use question::{Choice::Choice, Choice::DefaultSeparator, Choice::Separator, Question};
-pub use ui::{symbols, ErrorKind, OnEsc, Result};
+pub use ui::{symbols, theme, ErrorKind, OnEsc, Result};
 
 /// A module that re-exports all the things required for writing custom [`Prompt`]s.
 ///
@@ -232,6 +241,63 @@ pub mod prompt {
         events::{self, EventIterator},
         style,
     };
+
+    /// Run a [`ui::Prompt`] to completion with the standard event loop.
+    ///
+    /// This is the same loop used by the in-built questions, and is meant to be used in the
+    /// [`ask`](Prompt::ask) of custom prompts built with the `requestty-ui` crate. It takes care
+    /// of rendering the prompt, passing it the events, and validating it when `Enter` is pressed.
+    ///
+    /// It returns `None` if the prompt was skipped with `Esc`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::prompt::{self, Answer, Answers, Backend, EventIterator};
+    ///
+    /// #[derive(Debug)]
+    /// struct MyPrompt { /* ... */ }
+    ///
+    /// # impl MyPrompt {
+    /// #     fn widget(self, message: String) -> MyWidget {
+    /// #         todo!()
+    /// #     }
+    /// # }
+    /// # struct MyWidget;
+    /// # impl ui::Widget for MyWidget {
+    /// #     fn render<B: Backend>(&mut self, _: &mut ui::layout::Layout, _: &mut B) -> std::io::Result<()> { todo!() }
+    /// #     fn height(&mut self, _: &mut ui::layout::Layout) -> u16 { todo!() }
+    /// #     fn cursor_pos(&mut self, _: ui::layout::Layout) -> (u16, u16) { todo!() }
+    /// #     fn handle_key(&mut self, _: prompt::events::KeyEvent) -> bool { todo!() }
+    /// # }
+    /// # impl ui::Prompt for MyWidget {
+    /// #     type ValidateErr = &'static str;
+    /// #     type Output = i64;
+    /// #     fn finish(self) -> i64 { todo!() }
+    /// # }
+    ///
+    /// impl prompt::Prompt for MyPrompt {
+    ///     fn ask(
+    ///         self,
+    ///         message: String,
+    ///         answers: &Answers,
+    ///         backend: &mut dyn Backend,
+    ///         events: &mut dyn EventIterator,
+    ///     ) -> requestty::Result<Option<Answer>> {
+    ///         // `MyWidget` implements `ui::Prompt`
+    ///         let widget: MyWidget = self.widget(message);
+    ///
+    ///         Ok(prompt::run_widget(widget, backend, events)?.map(Answer::Int))
+    ///     }
+    /// }
+    /// ```
+    pub fn run_widget<P: ui::Prompt>(
+        prompt: P,
+        mut backend: &mut dyn Backend,
+        mut events: &mut dyn EventIterator,
+    ) -> crate::Result<Option<P::Output>> {
+        ui::Input::new(prompt, &mut backend).run(&mut events)
+    }
 }
 
 /// Prompt all the questions in the given iterator, with the default [`Backend`] and [`EventIterator`].
@@ -280,7 +346,71 @@ where
     B: Backend,
     E: EventIterator,
 {
-    let ans = question.into().ask(&Answers::default(), backend, events)?;
+    let ans = question
+        .into()
+        .ask(&Answers::default(), backend, events, &mut ())?;
 
     Ok(ans.expect("The question wasn't asked").1)
 }
+
+/// Render the first frame of the given question to the given [`Backend`], without waiting for any
+/// events.
+///
+/// This is useful to test how a question looks, for example by rendering it to a
+/// [`TestBackend`](ui::backend::TestBackend) and taking a snapshot. If the question would be
+/// skipped with the given `answers`, nothing is rendered.
+///
+/// # Examples
+///
+/// ```
+/// use requestty::{prompt::backend::TestBackend, Answers, Question};
+///
+/// let question = Question::input("name").message("What is your name?");
+/// let mut backend = TestBackend::new((50, 5).into());
+///
+/// requestty::render_once(question, &Answers::default(), &mut backend).unwrap();
+/// ```
+pub fn render_once<'a, Q, B>(question: Q, answers: &Answers, backend: &mut B) -> Result<()>
+where
+    Q: Into<Question<'a>>,
+    B: Backend,
+{
+    match question
+        .into()
+        .ask(answers, backend, &mut RenderOnceEvents, &mut ())
+    {
+        Err(ErrorKind::IoError(e)) if RenderOnceEvents::is_stop(&e) => Ok(()),
+        Err(e) => Err(e),
+        Ok(_) => Ok(()),
+    }
+}
+
+/// The events given to the question by [`render_once`]. The question is stopped with an error as
+/// soon as it asks for an event, which is after it has been rendered.
+struct RenderOnceEvents;
+
+#[derive(Debug)]
+struct RenderOnceStop;
+
+impl std::fmt::Display for RenderOnceStop {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("stopped after rendering once")
+    }
+}
+
+impl std::error::Error for RenderOnceStop {}
+
+impl RenderOnceEvents {
+    fn is_stop(e: &std::io::Error) -> bool {
+        matches!(e.get_ref(), Some(e) if e.is::<RenderOnceStop>())
+    }
+}
+
+impl EventIterator for RenderOnceEvents {
+    fn next_event(&mut self) -> std::io::Result<ui::events::KeyEvent> {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::Interrupted,
+            RenderOnceStop,
+        ))
+    }
+}
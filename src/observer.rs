@@ -0,0 +1,114 @@
+use std::{cell::RefCell, io, time::Duration};
+
+use ui::events::{EventIterator, KeyEvent};
+
+use crate::Answer;
+
+/// An event in the lifecycle of a question, given to an [`Observer`].
+///
+/// Each event has the `name` of the question it is for.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PromptEvent<'a> {
+    /// The question is about to be asked. This is not emitted for questions which are not asked
+    /// because of `when` or `ask_if_answered`.
+    Started {
+        /// The name of the question.
+        name: &'a str,
+    },
+    /// A key was received while the question was being asked.
+    Key {
+        /// The name of the question.
+        name: &'a str,
+        /// The key that was received.
+        key: KeyEvent,
+    },
+    /// The user tried to submit the question, but the answer failed validation. This is not
+    /// emitted for [custom prompts](crate::question::Prompt), which run their own [`Input`].
+    ///
+    /// [`Input`]: ui::Input
+    ValidationFailed {
+        /// The name of the question.
+        name: &'a str,
+    },
+    /// The question was answered.
+    Answered {
+        /// The name of the question.
+        name: &'a str,
+        /// The final answer to the question.
+        answer: &'a Answer,
+    },
+    /// The question was skipped by the user. See [`OnEsc::SkipQuestion`].
+    ///
+    /// [`OnEsc::SkipQuestion`]: crate::OnEsc::SkipQuestion
+    Skipped {
+        /// The name of the question.
+        name: &'a str,
+    },
+}
+
+/// An observer that receives the [`PromptEvent`]s of the questions asked by a [`PromptModule`].
+///
+/// This can be used for logging and debugging complex flows. It is implemented for all closures
+/// taking a `PromptEvent`, as well as for `()`, which ignores all events.
+///
+/// See [`PromptModule::observe`].
+///
+/// [`PromptModule`]: crate::PromptModule
+/// [`PromptModule::observe`]: crate::PromptModule::observe
+pub trait Observer {
+    /// Receive the next event.
+    fn observe(&mut self, event: PromptEvent<'_>);
+}
+
+impl Observer for () {
+    #[inline]
+    fn observe(&mut self, _: PromptEvent<'_>) {}
+}
+
+impl<F: FnMut(PromptEvent<'_>)> Observer for F {
+    fn observe(&mut self, event: PromptEvent<'_>) {
+        self(event)
+    }
+}
+
+/// An [`EventIterator`] which reports the keys of a question to an [`Observer`].
+///
+/// The observer is shared with the hook which reports failed validations, see
+/// [`InputOptions`](crate::question::InputOptions).
+pub(crate) struct ObservedEvents<'a, E, O> {
+    pub(crate) events: &'a mut E,
+    pub(crate) observer: &'a RefCell<&'a mut O>,
+    pub(crate) name: &'a str,
+}
+
+impl<E: EventIterator, O: Observer> ObservedEvents<'_, E, O> {
+    fn observe_key(&mut self, key: KeyEvent) -> KeyEvent {
+        self.observer.borrow_mut().observe(PromptEvent::Key {
+            name: self.name,
+            key,
+        });
+        key
+    }
+}
+
+impl<E: EventIterator, O: Observer> EventIterator for ObservedEvents<'_, E, O> {
+    fn next_event(&mut self) -> io::Result<KeyEvent> {
+        let key = self.events.next_event()?;
+        Ok(self.observe_key(key))
+    }
+
+    fn poll_event(&mut self) -> io::Result<Option<KeyEvent>> {
+        Ok(self.events.poll_event()?.map(|key| self.observe_key(key)))
+    }
+
+    fn next_event_timeout(&mut self, timeout: Duration) -> io::Result<Option<KeyEvent>> {
+        Ok(self
+            .events
+            .next_event_timeout(timeout)?
+            .map(|key| self.observe_key(key)))
+    }
+
+    fn supports_timeout(&self) -> bool {
+        self.events.supports_timeout()
+    }
+}
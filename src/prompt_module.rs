@@ -1,17 +1,68 @@
 use ui::{backend::Backend, events::EventIterator};
 
-use crate::{Answer, Answers, Question};
+use crate::{Answer, Answers, Observer, Question};
 
 /// A collection of questions and answers for previously answered questions.
 ///
 /// Unlike [`prompt`], this allows you to control how many questions you want to ask, and ask with
 /// previous answers as well.
 ///
+/// # Going back
+///
+/// If a question uses [`OnEsc::GoBack`], pressing `Esc` goes back to the previous question asked
+/// through the module, forgetting its answer. If no question has been asked yet,
+/// [`ErrorKind::Aborted`] is returned instead, the same as [`OnEsc::Terminate`].
+///
+/// Questions are consumed when they are asked, so to ask the previous question again, the module
+/// has to create the questions again. This is only possible for modules created with
+/// [`from_fn`]. The questions after the previous question are then asked as if for the first
+/// time: `when` is called again with the new answers, and questions skipped by the user are asked
+/// again. Questions which were not asked since they already had an answer, for example one given
+/// with [`with_answers`], are still skipped (unless [`ask_if_answered`] is set).
+///
+/// ```no_run
+/// use requestty::{OnEsc, PromptModule, Question};
+///
+/// let answers = PromptModule::from_fn(|| {
+///     vec![
+///         Question::input("name").build(),
+///         Question::int("age").on_esc(OnEsc::GoBack).build(),
+///     ]
+///     .into_iter()
+/// })
+/// .prompt_all()?;
+/// # Ok::<(), requestty::ErrorKind>(())
+/// ```
+///
+/// Other modules cannot go back by themselves. They forget the answer to the previous question
+/// and return [`ErrorKind::GoBack`], after which the questions can be given again with
+/// [`with_questions`].
+///
 /// [`prompt`]: crate::prompt()
+/// [`OnEsc::GoBack`]: crate::OnEsc::GoBack
+/// [`OnEsc::Terminate`]: crate::OnEsc::Terminate
+/// [`ErrorKind::GoBack`]: crate::ErrorKind::GoBack
+/// [`ErrorKind::Aborted`]: crate::ErrorKind::Aborted
+/// [`from_fn`]: PromptModule::from_fn
+/// [`with_answers`]: PromptModule::with_answers
+/// [`with_questions`]: PromptModule::with_questions
+/// [`ask_if_answered`]: crate::question::InputBuilder::ask_if_answered
 #[derive(Debug, Clone, PartialEq)]
-pub struct PromptModule<Q> {
+pub struct PromptModule<Q, O = (), F = fn() -> Q> {
     questions: Q,
+    /// Creates the questions again when going back, if the module was created with `from_fn`.
+    questions_fn: Option<F>,
     answers: Answers,
+    /// The names and positions of the questions asked through this module, in the order they were
+    /// asked. Questions which were not asked because of `when` or `ask_if_answered` are left out.
+    asked: Vec<(String, usize)>,
+    /// Whether a "Question n of total" header is shown above the questions.
+    show_progress: bool,
+    /// Whether questions are removed from the terminal once they are answered.
+    clear_answered: bool,
+    /// The number of questions taken from `questions`, including those which were skipped.
+    position: usize,
+    observer: O,
 }
 
 impl<'a, Q> PromptModule<Q>
@@ -26,10 +77,201 @@ where
         Self {
             answers: Answers::default(),
             questions: questions.into_iter(),
+            questions_fn: None,
+            asked: Vec::new(),
+            show_progress: false,
+            clear_answered: false,
+            position: 0,
+            observer: (),
+        }
+    }
+}
+
+impl<'a, Q, F> PromptModule<Q, (), F>
+where
+    Q: Iterator<Item = Question<'a>>,
+    F: FnMut() -> Q,
+{
+    /// Creates a new `PromptModule` with the questions created by `questions`.
+    ///
+    /// Unlike [`new`](PromptModule::new), the module can go back to a previous question by itself,
+    /// since `questions` is called again to create the questions anew. See the
+    /// [type level docs](PromptModule#going-back).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{OnEsc, PromptModule, Question};
+    ///
+    /// let module = PromptModule::from_fn(|| {
+    ///     vec![
+    ///         Question::input("name").build(),
+    ///         Question::int("age").on_esc(OnEsc::GoBack).build(),
+    ///     ]
+    ///     .into_iter()
+    /// });
+    /// ```
+    pub fn from_fn(mut questions: F) -> Self {
+        Self {
+            answers: Answers::default(),
+            questions: questions(),
+            questions_fn: Some(questions),
+            asked: Vec::new(),
+            show_progress: false,
+            clear_answered: false,
+            position: 0,
+            observer: (),
+        }
+    }
+}
+
+impl<'a, Q, O, F> PromptModule<Q, O, F>
+where
+    Q: Iterator<Item = Question<'a>>,
+    O: Observer,
+    F: FnMut() -> Q,
+{
+    /// Sets the [`Observer`] which receives the lifecycle events of the questions that are asked.
+    ///
+    /// By default, there is no observer and no events are created.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{PromptEvent, PromptModule, Question};
+    ///
+    /// let module = PromptModule::new(vec![Question::input("name").build()]).observe(
+    ///     |event: PromptEvent| {
+    ///         if let PromptEvent::ValidationFailed { name } = event {
+    ///             eprintln!("the answer to {} was invalid", name);
+    ///         }
+    ///     },
+    /// );
+    /// ```
+    pub fn observe<O2: Observer>(self, observer: O2) -> PromptModule<Q, O2, F> {
+        PromptModule {
+            questions: self.questions,
+            questions_fn: self.questions_fn,
+            answers: self.answers,
+            asked: self.asked,
+            show_progress: self.show_progress,
+            clear_answered: self.clear_answered,
+            position: self.position,
+            observer,
+        }
+    }
+
+    /// Replaces the remaining questions, keeping the answers and the [`Observer`].
+    ///
+    /// This is used to go back to a previous question in modules which cannot go back by
+    /// themselves, see the [type level docs](PromptModule#going-back). If the module was created
+    /// with [`from_fn`](PromptModule::from_fn), it can no longer go back by itself. If the
+    /// [progress](PromptModule::show_progress) is shown, the questions are counted from the start
+    /// of the new questions.
+    pub fn with_questions<I>(self, questions: I) -> PromptModule<I::IntoIter, O>
+    where
+        I: IntoIterator<Item = Question<'a>>,
+    {
+        PromptModule {
+            questions: questions.into_iter(),
+            questions_fn: None,
+            answers: self.answers,
+            asked: self.asked,
+            show_progress: self.show_progress,
+            clear_answered: self.clear_answered,
+            position: 0,
+            observer: self.observer,
+        }
+    }
+
+    /// Whether to show a `Question 3 of 7` header above each question that is asked.
+    ///
+    /// Every question is counted by its position in the questions of the module, including those
+    /// that are skipped because of `when` or because they are already answered. Whether a question
+    /// is skipped can depend on the answers to the previous questions, so it cannot be known in
+    /// advance. Skipped questions have no header, so the count can jump ahead.
+    ///
+    /// The total is only shown if the number of questions is known exactly, as it is for a `Vec`.
+    /// Otherwise, the header is just `Question 3`.
+    ///
+    /// If `show_progress` is not called, it will default to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{PromptModule, Question};
+    ///
+    /// let module = PromptModule::new(vec![
+    ///     Question::input("name").build(),
+    ///     Question::int("age").build(),
+    /// ])
+    /// .show_progress(true);
+    /// ```
+    pub fn show_progress(mut self, show_progress: bool) -> Self {
+        self.show_progress = show_progress;
+        self
+    }
+
+    /// Whether to remove each question from the terminal once it is finished.
+    ///
+    /// By default, a finished question leaves its message and answer behind. If `clear_answered`
+    /// is set, they are cleared instead, along with the [progress](PromptModule::show_progress)
+    /// header, so the next question is shown in its place. This only applies to questions which
+    /// are rendered with [`Input`](ui::Input), which all the built-in questions are.
+    ///
+    /// If `clear_answered` is not called, it will default to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{PromptModule, Question};
+    ///
+    /// let module = PromptModule::new(vec![
+    ///     Question::input("name").build(),
+    ///     Question::int("age").build(),
+    /// ])
+    /// .clear_answered(true);
+    /// ```
+    pub fn clear_answered(mut self, clear_answered: bool) -> Self {
+        self.clear_answered = clear_answered;
+        self
+    }
+
+    /// The header to show above the next question, which must be called before it is taken.
+    fn progress_header(&self) -> String {
+        let position = self.position + 1;
+
+        match self.questions.size_hint() {
+            (remaining, Some(upper)) if remaining == upper => {
+                format!("Question {} of {}", position, self.position + remaining)
+            }
+            _ => format!("Question {}", position),
         }
     }
 
     /// Creates a `PromptModule` with the given questions and answers
+    ///
+    /// The answers are treated as if the questions with those names had already been answered, so
+    /// they are not asked unless [`ask_if_answered`] is set. The answers are also passed to `when`
+    /// and the other closures of the questions, and are part of the answers returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Answer, Answers, PromptModule, Question};
+    ///
+    /// let mut answers = Answers::default();
+    /// answers.insert("name".to_owned(), Answer::String("Ferris".to_owned()));
+    ///
+    /// // Only `age` will be asked
+    /// let module = PromptModule::new(vec![
+    ///     Question::input("name").build(),
+    ///     Question::int("age").build(),
+    /// ])
+    /// .with_answers(answers);
+    /// ```
+    ///
+    /// [`ask_if_answered`]: crate::question::InputBuilder::ask_if_answered
     pub fn with_answers(mut self, answers: Answers) -> Self {
         self.answers = answers;
         self
@@ -61,9 +303,57 @@ where
         B: Backend,
         E: EventIterator,
     {
-        for question in self.questions.by_ref() {
-            if let Some((name, answer)) = question.ask(&self.answers, backend, events)? {
-                return Ok(Some(self.answers.insert(name, answer)));
+        loop {
+            let header = if self.show_progress {
+                Some(self.progress_header())
+            } else {
+                None
+            };
+
+            let question = match self.questions.next() {
+                Some(question) => question,
+                None => break,
+            };
+            self.position += 1;
+
+            match question.ask_with_header(
+                header.as_deref(),
+                self.clear_answered,
+                &self.answers,
+                backend,
+                events,
+                &mut self.observer,
+            ) {
+                Ok(Some((name, Some(answer)))) => {
+                    self.asked.push((name.clone(), self.position - 1));
+                    return Ok(Some(self.answers.insert(name, answer)));
+                }
+                Ok(Some((name, None))) => {
+                    self.asked.push((name.clone(), self.position - 1));
+                    self.answers.skip(name);
+                }
+                Ok(None) => {}
+                Err(crate::ErrorKind::GoBack) => {
+                    let (name, position) = match self.asked.pop() {
+                        Some(asked) => asked,
+                        None => return Err(crate::ErrorKind::Aborted),
+                    };
+                    self.answers.forget(&name);
+
+                    let questions_fn = match self.questions_fn {
+                        Some(ref mut questions_fn) => questions_fn,
+                        None => return Err(crate::ErrorKind::GoBack),
+                    };
+
+                    // The questions before the previous question were already dealt with, so
+                    // only the new questions from the previous question onwards are asked
+                    self.questions = questions_fn();
+                    for _ in 0..position {
+                        self.questions.next();
+                    }
+                    self.position = position;
+                }
+                Err(e) => return Err(e),
             }
         }
 
@@ -102,6 +392,64 @@ where
         Ok(self.answers)
     }
 
+    /// Answer all remaining questions with the given answers, without using the terminal.
+    ///
+    /// This is useful when there is no user to ask the questions to, for example in CI. Each
+    /// question takes the answer with the same name from `provided`. The answers go through the
+    /// same validation and filtering as answers given by a user, and the final answers are
+    /// returned. Like when prompting, `when` and `ask_if_answered` decide which questions are
+    /// answered, and those that are skipped don't need an answer.
+    ///
+    /// This is supported by [`input`], [`password`], [`text`], [`int`], [`float`], [`confirm`],
+    /// [`select`] and [`multi_select`] questions. Questions of other kinds take the given answer
+    /// as is.
+    ///
+    /// If a question has no answer in `provided` and no default, [`ErrorKind::MissingAnswer`] is
+    /// returned. If the answer is of the wrong type or does not pass validation,
+    /// [`ErrorKind::InvalidAnswer`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Answer, Answers, PromptModule, Question};
+    ///
+    /// let module = PromptModule::new(vec![
+    ///     Question::input("name").build(),
+    ///     Question::confirm("subscribe").default(false).build(),
+    /// ]);
+    ///
+    /// let provided: Answers = vec![("name".to_owned(), Answer::String("Ferris".into()))]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// let answers = module.prompt_non_interactive(provided).unwrap();
+    /// assert_eq!(answers["subscribe"], Answer::Bool(false));
+    /// ```
+    ///
+    /// [`input`]: crate::Question::input
+    /// [`password`]: crate::Question::password
+    /// [`text`]: crate::Question::text
+    /// [`int`]: crate::Question::int
+    /// [`float`]: crate::Question::float
+    /// [`confirm`]: crate::Question::confirm
+    /// [`select`]: crate::Question::select
+    /// [`multi_select`]: crate::Question::multi_select
+    /// [`ErrorKind::MissingAnswer`]: crate::ErrorKind::MissingAnswer
+    /// [`ErrorKind::InvalidAnswer`]: crate::ErrorKind::InvalidAnswer
+    pub fn prompt_non_interactive(mut self, mut provided: Answers) -> crate::Result<Answers> {
+        self.answers.reserve(self.questions.size_hint().0);
+
+        for question in self.questions {
+            if let Some((name, answer)) =
+                question.answer_non_interactive(&self.answers, &mut provided)?
+            {
+                self.answers.insert(name, answer);
+            }
+        }
+
+        Ok(self.answers)
+    }
+
     /// Consumes `self` returning the answers to the previously asked questions.
     pub fn into_answers(self) -> Answers {
         self.answers
@@ -0,0 +1,685 @@
+use std::{borrow::Cow, io};
+
+use ui::{
+    backend::Backend,
+    events::{EventIterator, KeyCode, KeyEvent},
+    style::{StyledStr, StyledString},
+    widgets, Prompt, Validation, Widget,
+};
+
+use super::{ChoiceList, Completions, Hint, Options, Suggestions, Transform, Validate};
+use crate::{Answer, Answers};
+
+#[derive(Debug)]
+pub(super) struct Autocomplete<'a> {
+    suggestions: Suggestions<'a, str>,
+    allow_free_text: bool,
+    page_size: usize,
+    should_loop: bool,
+    hint: Hint,
+    validate: Validate<'a, str>,
+    transform: Transform<'a, str>,
+}
+
+impl<'a> Default for Autocomplete<'a> {
+    fn default() -> Self {
+        Self {
+            suggestions: Suggestions::None,
+            allow_free_text: false,
+            page_size: 15,
+            should_loop: true,
+            hint: Hint::Default,
+            validate: Validate::None,
+            transform: Transform::None,
+        }
+    }
+}
+
+type SuggestionSelector = widgets::Select<ChoiceList<widgets::Text<String>>>;
+
+struct AutocompletePrompt<'s, 'a> {
+    prompt: widgets::Prompt<StyledStr<'a>, Cow<'static, str>>,
+    autocomplete: Autocomplete<'s>,
+    input: widgets::StringInput,
+    /// The suggestions for the current input. It is `None` if there are no suggestions.
+    select: Option<SuggestionSelector>,
+    /// The input for which the current suggestions were computed. Keys which do not change the
+    /// text (like moving the cursor) reuse the previous suggestions.
+    suggested_for: Option<String>,
+    answers: &'a Answers,
+}
+
+impl AutocompletePrompt<'_, '_> {
+    fn update_suggestions(&mut self) {
+        let Self {
+            autocomplete,
+            input,
+            select,
+            suggested_for,
+            answers,
+            ..
+        } = self;
+
+        if suggested_for.as_deref() == Some(input.value()) {
+            return;
+        }
+
+        match suggested_for {
+            Some(suggested_for) => {
+                suggested_for.clear();
+                suggested_for.push_str(input.value());
+            }
+            None => *suggested_for = Some(input.value().to_owned()),
+        }
+
+        let suggestions = match autocomplete.suggestions {
+            Suggestions::Sync(ref mut suggestions) => suggestions(input.value(), answers),
+            Suggestions::None => Completions::new(),
+        };
+
+        *select = if suggestions.is_empty() {
+            None
+        } else {
+            let mut choices: ChoiceList<_> =
+                suggestions.into_iter().map(widgets::Text::new).collect();
+            choices.set_page_size(autocomplete.page_size);
+            choices.set_should_loop(autocomplete.should_loop);
+
+            Some(widgets::Select::new(choices))
+        };
+    }
+
+    fn hovered(&self) -> Option<&str> {
+        hovered(&self.select)
+    }
+
+    /// The answer if the user were to submit now.
+    fn answer(&self) -> Option<&str> {
+        answer(self.autocomplete.allow_free_text, &self.input, &self.select)
+    }
+}
+
+fn hovered(select: &Option<SuggestionSelector>) -> Option<&str> {
+    select
+        .as_ref()
+        .map(|select| &*select.selected().as_ref().unwrap_choice().text)
+}
+
+fn answer<'a>(
+    allow_free_text: bool,
+    input: &'a widgets::StringInput,
+    select: &'a Option<SuggestionSelector>,
+) -> Option<&'a str> {
+    if allow_free_text {
+        Some(input.value())
+    } else {
+        hovered(select)
+    }
+}
+
+impl Widget for AutocompletePrompt<'_, '_> {
+    fn render<B: Backend>(&mut self, layout: &mut ui::layout::Layout, b: &mut B) -> io::Result<()> {
+        self.prompt.render(layout, b)?;
+        self.input.render(layout, b)?;
+
+        if let Some(ref mut select) = self.select {
+            select.render(layout, b)?;
+        }
+
+        Ok(())
+    }
+
+    fn height(&mut self, layout: &mut ui::layout::Layout) -> u16 {
+        let mut height = self.prompt.height(layout) + self.input.height(layout) - 1;
+
+        if let Some(ref mut select) = self.select {
+            height += select.height(layout) - 1;
+        }
+
+        height
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if key.code == KeyCode::Tab {
+            return match self.hovered().map(String::from) {
+                Some(suggestion) => {
+                    let len = suggestion.chars().count();
+                    self.input.set_value(suggestion);
+                    self.input.set_at(len);
+                    self.update_suggestions();
+                    true
+                }
+                None => false,
+            };
+        }
+
+        if self.input.handle_key(key) {
+            self.update_suggestions();
+            return true;
+        }
+
+        match self.select {
+            Some(ref mut select) => select.handle_key(key),
+            None => false,
+        }
+    }
+
+    fn cursor_pos(&mut self, layout: ui::layout::Layout) -> (u16, u16) {
+        self.input
+            .cursor_pos(layout.with_cursor_pos(self.prompt.cursor_pos(layout)))
+    }
+}
+
+impl Prompt for AutocompletePrompt<'_, '_> {
+    type ValidateErr = widgets::Text<String>;
+    type Output = String;
+
+    fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
+        let Self {
+            autocomplete,
+            input,
+            select,
+            answers,
+            ..
+        } = self;
+
+        let answer = match answer(autocomplete.allow_free_text, input, select) {
+            Some(answer) => answer,
+            None => return Err("Please select one of the suggestions".to_owned().into()),
+        };
+
+        if let Validate::Sync(ref mut validate) = autocomplete.validate {
+            validate(answer, answers)?;
+        }
+
+        Ok(Validation::Finish)
+    }
+
+    fn finish(self) -> Self::Output {
+        self.answer()
+            .expect("Validation would fail if there was no answer")
+            .to_owned()
+    }
+}
+
+impl<'s> Autocomplete<'s> {
+    fn into_autocomplete_prompt<'a>(
+        mut self,
+        message: impl Into<StyledStr<'a>>,
+        answers: &'a Answers,
+    ) -> AutocompletePrompt<'s, 'a> {
+        let hint =
+            std::mem::take(&mut self.hint).resolve(Some("Type to filter, press <tab> to complete"));
+
+        let mut prompt = AutocompletePrompt {
+            prompt: widgets::Prompt::new(message.into()).with_optional_hint(hint),
+            autocomplete: self,
+            input: widgets::StringInput::default(),
+            select: None,
+            suggested_for: None,
+            answers,
+        };
+
+        prompt.update_suggestions();
+        prompt
+    }
+
+    pub(crate) fn ask<B: Backend, E: EventIterator>(
+        mut self,
+        message: StyledString,
+        input_opts: super::InputOptions<'_>,
+        answers: &Answers,
+        b: &mut B,
+        events: &mut E,
+    ) -> ui::Result<Option<Answer>> {
+        let transform = self.transform.take();
+
+        let ans = ui::Input::new(self.into_autocomplete_prompt(&message, answers), b)
+            .on_esc(input_opts.on_esc)
+            .timeout(input_opts.timeout)
+            .on_validation_failed(input_opts.on_validation_failed)
+            .run(events)?;
+
+        crate::write_final!(transform, message, ans [ref], answers, b, |ans| b
+            .write_all(ans.as_bytes())?)
+    }
+}
+
+/// The builder for an [`autocomplete`] prompt.
+///
+/// The user types to filter a list of suggestions, and picks one of them with the arrow keys.
+/// Pressing `Tab` replaces the typed text with the selected suggestion.
+///
+/// The answer is an [`Answer::String`]. Unless [`allow_free_text`] is called, it is the selected
+/// suggestion.
+///
+/// See the various methods for more details on each available option.
+///
+/// # Examples
+///
+/// ```
+/// use requestty::Question;
+///
+/// let countries = ["France", "Germany", "Italy", "Spain"];
+///
+/// let autocomplete = Question::autocomplete("country")
+///     .message("Which country do you live in?")
+///     .suggestions(move |input, previous_answers| {
+///         countries
+///             .iter()
+///             .filter(|country| country.to_lowercase().starts_with(&input.to_lowercase()))
+///             .map(|country| country.to_string())
+///             .collect()
+///     })
+///     .build();
+/// ```
+///
+/// [`autocomplete`]: crate::question::Question::autocomplete
+/// [`Answer::String`]: crate::Answer::String
+/// [`allow_free_text`]: AutocompleteBuilder::allow_free_text
+#[derive(Debug)]
+pub struct AutocompleteBuilder<'a> {
+    opts: Options<'a>,
+    autocomplete: Autocomplete<'a>,
+}
+
+impl<'a> AutocompleteBuilder<'a> {
+    pub(crate) fn new(name: String) -> Self {
+        AutocompleteBuilder {
+            opts: Options::new(name),
+            autocomplete: Default::default(),
+        }
+    }
+
+    crate::impl_options_builder! {
+    message
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let autocomplete = Question::autocomplete("country")
+    ///     .message("Which country do you live in?")
+    ///     .build();
+    /// ```
+
+    when
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, Answers};
+    ///
+    /// let autocomplete = Question::autocomplete("country")
+    ///     .when(|previous_answers: &Answers| match previous_answers.get("anonymous") {
+    ///         Some(ans) => !ans.as_bool().unwrap(),
+    ///         None => true,
+    ///     })
+    ///     .build();
+    /// ```
+
+    ask_if_answered
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let autocomplete = Question::autocomplete("country")
+    ///     .ask_if_answered(true)
+    ///     .build();
+    /// ```
+
+    on_esc
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, OnEsc};
+    ///
+    /// let autocomplete = Question::autocomplete("country")
+    ///     .on_esc(OnEsc::Terminate)
+    ///     .build();
+    /// ```
+    }
+
+    /// Function to get the suggestions for the current input.
+    ///
+    /// It is a [`FnMut`] that is given the text typed by the user and the previous [`Answers`],
+    /// and should return the suggestions to show. It is called every time the text changes, and
+    /// the previous suggestions are reused for keys which only move the cursor.
+    ///
+    /// If the `smallvec` feature is enabled, [`Completions`] is a [`SmallVec`] which can hold a
+    /// single suggestion without allocating.
+    ///
+    /// If `suggestions` is not set, there are no suggestions.
+    ///
+    /// [`SmallVec`]: https://docs.rs/smallvec/latest/smallvec/struct.SmallVec.html
+    ///
+    /// [`Answers`]: crate::Answers
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let autocomplete = Question::autocomplete("command")
+    ///     .suggestions(|input, previous_answers| {
+    ///         ["build", "check", "test"]
+    ///             .iter()
+    ///             .filter(|command| command.contains(input))
+    ///             .map(|command| command.to_string())
+    ///             .collect()
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn suggestions<F>(mut self, suggestions: F) -> Self
+    where
+        F: FnMut(&str, &Answers) -> Completions<String> + 'a,
+    {
+        self.autocomplete.suggestions = Suggestions::Sync(Box::new(suggestions));
+        self
+    }
+
+    /// Allow the user to submit text which is not one of the suggestions.
+    ///
+    /// The answer is then the typed text instead of the selected suggestion. A suggestion can still
+    /// be used by pressing `Tab`.
+    ///
+    /// If `allow_free_text` is not called, the user must pick one of the suggestions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let autocomplete = Question::autocomplete("country")
+    ///     .allow_free_text()
+    ///     .build();
+    /// ```
+    pub fn allow_free_text(mut self) -> Self {
+        self.autocomplete.allow_free_text = true;
+        self
+    }
+
+    /// The maximum height that can be taken by the list of suggestions
+    ///
+    /// If the total height exceeds the page size, the list will be scrollable.
+    ///
+    /// The `page_size` must be a minimum of 5. If `page_size` is not set, it will default to 15.
+    ///
+    /// # Panics
+    ///
+    /// It will panic if the `page_size` is less than 5.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let autocomplete = Question::autocomplete("country")
+    ///     .page_size(10)
+    ///     .build();
+    /// ```
+    pub fn page_size(mut self, page_size: usize) -> Self {
+        assert!(page_size >= 5, "page size can be a minimum of 5");
+
+        self.autocomplete.page_size = page_size;
+        self
+    }
+
+    /// Whether to wrap around when user gets to the last suggestion.
+    ///
+    /// This only applies when the list is scrollable, i.e. page size > total height.
+    ///
+    /// If `should_loop` is not set, it will default to `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let autocomplete = Question::autocomplete("country")
+    ///     .should_loop(false)
+    ///     .build();
+    /// ```
+    pub fn should_loop(mut self, should_loop: bool) -> Self {
+        self.autocomplete.should_loop = should_loop;
+        self
+    }
+
+    crate::impl_validate_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let autocomplete = Question::autocomplete("country")
+    ///     .allow_free_text()
+    ///     .validate(|country, previous_answers| {
+    ///         if country.is_empty() {
+    ///             Err("Please enter a country".to_owned())
+    ///         } else {
+    ///             Ok(())
+    ///         }
+    ///     })
+    ///     .build();
+    /// ```
+    str; autocomplete
+    }
+
+    crate::impl_hint_builder! {
+    hint
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let autocomplete = Question::autocomplete("country")
+    ///     .hint("Start typing the name of your country")
+    ///     .build();
+    /// ```
+
+    no_hint
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let autocomplete = Question::autocomplete("country")
+    ///     .no_hint()
+    ///     .build();
+    /// ```
+    autocomplete
+    }
+
+    crate::impl_transform_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let autocomplete = Question::autocomplete("country")
+    ///     .transform(|country, previous_answers, backend| {
+    ///         write!(backend, "Welcome to {}!", country)
+    ///     })
+    ///     .build();
+    /// ```
+    str; autocomplete
+    }
+
+    /// Consumes the builder returning a [`Question`]
+    ///
+    /// [`Question`]: crate::question::Question
+    pub fn build(self) -> super::Question<'a> {
+        super::Question::new(
+            self.opts,
+            super::QuestionKind::Autocomplete(self.autocomplete),
+        )
+    }
+}
+
+impl<'a> From<AutocompleteBuilder<'a>> for super::Question<'a> {
+    /// Consumes the builder returning a [`Question`]
+    ///
+    /// [`Question`]: crate::question::Question
+    fn from(builder: AutocompleteBuilder<'a>) -> Self {
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ui::{backend::TestBackend, layout::Layout};
+
+    const FRUITS: [&str; 5] = ["apple", "apricot", "banana", "blueberry", "cherry"];
+
+    fn fruits<'a>(allow_free_text: bool) -> Autocomplete<'a> {
+        Autocomplete {
+            suggestions: Suggestions::Sync(Box::new(|input, _| {
+                FRUITS
+                    .iter()
+                    .filter(|fruit| fruit.starts_with(input))
+                    .map(|fruit| fruit.to_string())
+                    .collect()
+            })),
+            allow_free_text,
+            ..Default::default()
+        }
+    }
+
+    fn type_str(prompt: &mut AutocompletePrompt<'_, '_>, s: &str) {
+        for c in s.chars() {
+            assert!(prompt.handle_key(KeyCode::Char(c).into()));
+        }
+    }
+
+    fn suggestions(prompt: &AutocompletePrompt<'_, '_>) -> Vec<String> {
+        match prompt.select {
+            Some(ref select) => select
+                .list
+                .choices
+                .iter()
+                .map(|choice| choice.as_ref().unwrap_choice().text.clone())
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_filter_and_select() {
+        let answers = Answers::default();
+        let mut prompt = fruits(false).into_autocomplete_prompt("message", &answers);
+
+        assert_eq!(suggestions(&prompt), FRUITS);
+
+        type_str(&mut prompt, "ap");
+        assert_eq!(suggestions(&prompt), ["apple", "apricot"]);
+
+        assert!(prompt.handle_key(KeyCode::Down.into()));
+        assert_eq!(prompt.hovered(), Some("apricot"));
+        assert!(prompt.validate().is_ok());
+        assert_eq!(prompt.finish(), "apricot");
+    }
+
+    #[test]
+    fn test_no_suggestions() {
+        let answers = Answers::default();
+        let mut prompt = fruits(false).into_autocomplete_prompt("message", &answers);
+
+        type_str(&mut prompt, "x");
+        assert!(prompt.select.is_none());
+        assert!(!prompt.handle_key(KeyCode::Tab.into()));
+        assert!(prompt.validate().is_err());
+
+        assert!(prompt.handle_key(KeyCode::Backspace.into()));
+        type_str(&mut prompt, "c");
+        assert!(prompt.validate().is_ok());
+        assert_eq!(prompt.finish(), "cherry");
+    }
+
+    #[test]
+    fn test_free_text() {
+        let answers = Answers::default();
+        let mut prompt = fruits(true).into_autocomplete_prompt("message", &answers);
+
+        type_str(&mut prompt, "blu");
+        assert_eq!(suggestions(&prompt), ["blueberry"]);
+        assert!(prompt.validate().is_ok());
+        assert_eq!(prompt.answer(), Some("blu"));
+
+        assert!(prompt.handle_key(KeyCode::Tab.into()));
+        assert_eq!(prompt.input.value(), "blueberry");
+        assert_eq!(prompt.input.get_at(), "blueberry".len());
+
+        type_str(&mut prompt, "s");
+        assert!(prompt.select.is_none());
+        assert!(prompt.validate().is_ok());
+        assert_eq!(prompt.finish(), "blueberrys");
+    }
+
+    #[test]
+    fn test_suggestions_reused() {
+        let calls = std::rc::Rc::new(std::cell::Cell::new(0));
+        let answers = Answers::default();
+
+        let counter = calls.clone();
+        let mut suggest = fruits(false).suggestions;
+        let autocomplete = Autocomplete {
+            suggestions: Suggestions::Sync(Box::new(move |input, answers| {
+                counter.set(counter.get() + 1);
+                match suggest {
+                    Suggestions::Sync(ref mut suggest) => suggest(input, answers),
+                    Suggestions::None => unreachable!(),
+                }
+            })),
+            ..Default::default()
+        };
+        let mut prompt = autocomplete.into_autocomplete_prompt("message", &answers);
+        assert_eq!(calls.get(), 1);
+
+        type_str(&mut prompt, "ap");
+        assert_eq!(calls.get(), 3);
+
+        // Moving the cursor does not change the text
+        assert!(prompt.handle_key(KeyCode::Left.into()));
+        assert!(prompt.handle_key(KeyCode::Home.into()));
+        assert!(prompt.handle_key(KeyCode::End.into()));
+        assert_eq!(calls.get(), 3);
+        assert_eq!(suggestions(&prompt), ["apple", "apricot"]);
+
+        // The hovered suggestion is kept as well
+        assert!(prompt.handle_key(KeyCode::Down.into()));
+        assert!(prompt.handle_key(KeyCode::Left.into()));
+        assert_eq!(prompt.hovered(), Some("apricot"));
+        assert_eq!(calls.get(), 3);
+
+        assert!(prompt.handle_key(KeyCode::Backspace.into()));
+        assert_eq!(calls.get(), 4);
+        assert_eq!(prompt.input.value(), "p");
+        assert!(prompt.select.is_none());
+    }
+
+    #[test]
+    fn test_render() {
+        let size = (50, 20).into();
+        let base_layout = Layout::new(5, size);
+        let answers = Answers::default();
+
+        let mut prompt = fruits(false).into_autocomplete_prompt("message", &answers);
+        let mut backend = TestBackend::new_with_layout(size, base_layout);
+
+        for keys in ["", "b", "l"].iter() {
+            type_str(&mut prompt, keys);
+
+            let mut layout = base_layout;
+            assert!(prompt.render(&mut layout, &mut backend).is_ok());
+            ui::assert_backend_snapshot!(backend);
+
+            let mut height_layout = base_layout;
+            let height = prompt.height(&mut height_layout);
+            assert_eq!(height_layout, layout);
+            assert_eq!(height, layout.offset_y);
+
+            backend.reset_with_layout(base_layout);
+        }
+    }
+}
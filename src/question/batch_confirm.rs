@@ -0,0 +1,422 @@
+use std::io;
+
+use ui::{
+    backend::Backend,
+    events::{EventIterator, KeyEvent},
+    style::{Color, StyledStr, StyledString},
+    widgets::{self, Text},
+    Prompt, Validation, Widget,
+};
+
+use super::{Options, Transform};
+use crate::{Answer, Answers};
+
+#[derive(Debug, Default)]
+pub(super) struct BatchConfirm<'a> {
+    items: Vec<Text<String>>,
+    default: Option<bool>,
+    transform: Transform<'a, [bool]>,
+}
+
+struct BatchConfirmPrompt<'a> {
+    prompt: widgets::Prompt<StyledStr<'a>>,
+    batch_confirm: BatchConfirm<'a>,
+    input: widgets::CharInput,
+    decisions: Vec<bool>,
+}
+
+impl BatchConfirmPrompt<'_> {
+    /// The prefix shown before the current item, for example `[2/5] `.
+    fn progress(&self) -> String {
+        format!(
+            "[{}/{}] ",
+            self.decisions.len() + 1,
+            self.batch_confirm.items.len()
+        )
+    }
+}
+
+impl Widget for BatchConfirmPrompt<'_> {
+    fn render<B: Backend>(&mut self, layout: &mut ui::layout::Layout, b: &mut B) -> io::Result<()> {
+        self.prompt.render(layout, b)?;
+        self.input.render(layout, b)?;
+
+        layout.line_offset = 0;
+        layout.offset_y += 1;
+        b.move_cursor_to(layout.offset_x, layout.offset_y)?;
+
+        let progress = self.progress();
+        b.set_fg(Color::DarkGrey)?;
+        b.write_all(progress.as_bytes())?;
+        b.set_fg(Color::Reset)?;
+
+        layout.line_offset = progress.len() as u16;
+        self.batch_confirm.items[self.decisions.len()].render(layout, b)
+    }
+
+    fn height(&mut self, layout: &mut ui::layout::Layout) -> u16 {
+        let height = self.prompt.height(layout) + self.input.height(layout) - 1;
+
+        layout.line_offset = self.progress().len() as u16;
+        layout.offset_y += 1;
+
+        let height = height + self.batch_confirm.items[self.decisions.len()].height(layout);
+        layout.line_offset = 0;
+        height
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        self.input.handle_key(key)
+    }
+
+    fn cursor_pos(&mut self, layout: ui::layout::Layout) -> (u16, u16) {
+        self.input
+            .cursor_pos(layout.with_cursor_pos(self.prompt.cursor_pos(layout)))
+    }
+}
+
+fn only_ynas(c: char) -> Option<char> {
+    match c {
+        'y' | 'Y' | 'n' | 'N' | 'a' | 'A' | 's' | 'S' => Some(c.to_ascii_lowercase()),
+        _ => None,
+    }
+}
+
+impl Prompt for BatchConfirmPrompt<'_> {
+    type ValidateErr = &'static str;
+    type Output = Vec<bool>;
+
+    fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
+        let remaining = self.batch_confirm.items.len() - self.decisions.len();
+
+        let (decision, count) = match (self.input.value(), self.batch_confirm.default) {
+            (Some('y'), _) => (true, 1),
+            (Some('n'), _) => (false, 1),
+            (Some('a'), _) => (true, remaining),
+            (Some('s'), _) => (false, remaining),
+            (_, Some(default)) => (default, 1),
+            _ => return Err("Please enter y, n, a or s"),
+        };
+
+        let len = self.decisions.len();
+        self.decisions.resize(len + count, decision);
+        self.input.clear_value();
+
+        if self.decisions.len() == self.batch_confirm.items.len() {
+            Ok(Validation::Finish)
+        } else {
+            Ok(Validation::Continue)
+        }
+    }
+
+    fn finish(self) -> Self::Output {
+        self.decisions
+    }
+}
+
+impl<'a> BatchConfirm<'a> {
+    fn into_batch_confirm_prompt(
+        self,
+        message: impl Into<StyledStr<'a>>,
+    ) -> BatchConfirmPrompt<'a> {
+        let hint = match self.default {
+            Some(true) => "Y/n/a/s",
+            Some(false) => "y/N/a/s",
+            None => "y/n/a/s",
+        };
+
+        BatchConfirmPrompt {
+            prompt: widgets::Prompt::new(message.into()).with_hint(hint),
+            decisions: Vec::with_capacity(self.items.len()),
+            batch_confirm: self,
+            input: widgets::CharInput::with_filter_map(only_ynas),
+        }
+    }
+
+    pub(crate) fn ask<B: Backend, E: EventIterator>(
+        mut self,
+        message: StyledString,
+        input_opts: super::InputOptions<'_>,
+        answers: &Answers,
+        b: &mut B,
+        events: &mut E,
+    ) -> ui::Result<Option<Answer>> {
+        // There is nothing to confirm, so there is no need to show the prompt
+        if self.items.is_empty() {
+            return Ok(Some(Answer::Bools(Vec::new())));
+        }
+
+        let transform = self.transform.take();
+
+        let ans = ui::Input::new(self.into_batch_confirm_prompt(&message), b)
+            .on_esc(input_opts.on_esc)
+            .timeout(input_opts.timeout)
+            .on_validation_failed(input_opts.on_validation_failed)
+            .run(events)?;
+
+        crate::write_final!(transform, message, ans [ref], answers, b, |ans| {
+            let yes = ans.iter().filter(|&&decision| decision).count();
+            write!(b, "{} yes, {} no", yes, ans.len() - yes)?;
+        })
+    }
+}
+
+/// The builder for a [`batch_confirm`] prompt.
+///
+/// Each item is confirmed one at a time by entering one of:
+/// - `y`: yes for the current item
+/// - `n`: no for the current item
+/// - `a`: yes for the current item and all the remaining items
+/// - `s`: no for the current item and all the remaining items (skip the rest)
+///
+/// The answer is an [`Answer::Bools`] with a decision for every item. If there are no items, the
+/// prompt is not shown and the answer is empty.
+///
+/// See the various methods for more details on each available option.
+///
+/// # Examples
+///
+/// ```
+/// use requestty::Question;
+///
+/// let batch_confirm = Question::batch_confirm("overwrite")
+///     .message("Overwrite the existing file?")
+///     .items(vec!["Cargo.toml", "src/main.rs", "README.md"])
+///     .build();
+/// ```
+///
+/// [`batch_confirm`]: crate::question::Question::batch_confirm
+/// [`Answer::Bools`]: crate::Answer::Bools
+#[derive(Debug)]
+pub struct BatchConfirmBuilder<'a> {
+    opts: Options<'a>,
+    batch_confirm: BatchConfirm<'a>,
+}
+
+impl<'a> BatchConfirmBuilder<'a> {
+    pub(crate) fn new(name: String) -> Self {
+        BatchConfirmBuilder {
+            opts: Options::new(name),
+            batch_confirm: Default::default(),
+        }
+    }
+
+    crate::impl_options_builder! {
+    message
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let batch_confirm = Question::batch_confirm("overwrite")
+    ///     .message("Overwrite the existing file?")
+    ///     .build();
+    /// ```
+
+    when
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, Answers};
+    ///
+    /// let batch_confirm = Question::batch_confirm("overwrite")
+    ///     .when(|previous_answers: &Answers| match previous_answers.get("force") {
+    ///         Some(ans) => !ans.as_bool().unwrap(),
+    ///         None => true,
+    ///     })
+    ///     .build();
+    /// ```
+
+    ask_if_answered
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let batch_confirm = Question::batch_confirm("overwrite")
+    ///     .ask_if_answered(true)
+    ///     .build();
+    /// ```
+
+    on_esc
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, OnEsc};
+    ///
+    /// let batch_confirm = Question::batch_confirm("overwrite")
+    ///     .on_esc(OnEsc::Terminate)
+    ///     .build();
+    /// ```
+    }
+
+    /// Set a default decision for the items
+    ///
+    /// If the input text is empty, the `default` is taken as the decision for the current item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let batch_confirm = Question::batch_confirm("overwrite")
+    ///     .default(false)
+    ///     .build();
+    /// ```
+    pub fn default(mut self, default: bool) -> Self {
+        self.batch_confirm.default = Some(default);
+        self
+    }
+
+    /// Inserts an item to be confirmed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let batch_confirm = Question::batch_confirm("overwrite")
+    ///     .item("Cargo.toml")
+    ///     .build();
+    /// ```
+    pub fn item<I: Into<String>>(mut self, item: I) -> Self {
+        self.batch_confirm.items.push(Text::new(item.into()));
+        self
+    }
+
+    /// Extends the items to be confirmed with the given iterator
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let batch_confirm = Question::batch_confirm("overwrite")
+    ///     .items(vec!["Cargo.toml", "src/main.rs"])
+    ///     .build();
+    /// ```
+    pub fn items<I, T>(mut self, items: I) -> Self
+    where
+        T: Into<String>,
+        I: IntoIterator<Item = T>,
+    {
+        self.batch_confirm
+            .items
+            .extend(items.into_iter().map(|item| Text::new(item.into())));
+        self
+    }
+
+    crate::impl_transform_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let batch_confirm = Question::batch_confirm("overwrite")
+    ///     .transform(|decisions, previous_answers, backend| {
+    ///         let overwritten = decisions.iter().filter(|&&decision| decision).count();
+    ///         write!(backend, "Overwriting {} files", overwritten)
+    ///     })
+    ///     .build();
+    /// ```
+    [bool]; batch_confirm
+    }
+
+    /// Consumes the builder returning a [`Question`]
+    ///
+    /// [`Question`]: crate::question::Question
+    pub fn build(self) -> super::Question<'a> {
+        super::Question::new(
+            self.opts,
+            super::QuestionKind::BatchConfirm(self.batch_confirm),
+        )
+    }
+}
+
+impl<'a> From<BatchConfirmBuilder<'a>> for super::Question<'a> {
+    /// Consumes the builder returning a [`Question`]
+    ///
+    /// [`Question`]: crate::question::Question
+    fn from(builder: BatchConfirmBuilder<'a>) -> Self {
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ui::{backend::TestBackend, events::KeyCode, layout::Layout};
+
+    fn batch_confirm(default: Option<bool>) -> BatchConfirmPrompt<'static> {
+        BatchConfirm {
+            items: ["a.txt", "b.txt", "c.txt", "d.txt"]
+                .iter()
+                .map(|item| Text::new(item.to_string()))
+                .collect(),
+            default,
+            ..Default::default()
+        }
+        .into_batch_confirm_prompt("message")
+    }
+
+    fn decide(prompt: &mut BatchConfirmPrompt<'_>, c: char) -> Result<Validation, &'static str> {
+        prompt.handle_key(KeyCode::Char(c).into());
+        prompt.validate()
+    }
+
+    #[test]
+    fn test_yes_to_all() {
+        let mut prompt = batch_confirm(None);
+
+        assert_eq!(decide(&mut prompt, 'n'), Ok(Validation::Continue));
+        assert_eq!(decide(&mut prompt, 'A'), Ok(Validation::Finish));
+        assert_eq!(prompt.finish(), [false, true, true, true]);
+    }
+
+    #[test]
+    fn test_skip_rest() {
+        let mut prompt = batch_confirm(None);
+
+        assert_eq!(decide(&mut prompt, 'y'), Ok(Validation::Continue));
+        assert_eq!(decide(&mut prompt, 'y'), Ok(Validation::Continue));
+        assert_eq!(decide(&mut prompt, 's'), Ok(Validation::Finish));
+        assert_eq!(prompt.finish(), [true, true, false, false]);
+    }
+
+    #[test]
+    fn test_default() {
+        let mut prompt = batch_confirm(None);
+        assert_eq!(prompt.validate(), Err("Please enter y, n, a or s"));
+        assert!(!prompt.handle_key(KeyCode::Char('x').into()));
+        assert_eq!(prompt.validate(), Err("Please enter y, n, a or s"));
+
+        let mut prompt = batch_confirm(Some(false));
+        assert_eq!(prompt.validate(), Ok(Validation::Continue));
+        assert_eq!(decide(&mut prompt, 'y'), Ok(Validation::Continue));
+        assert_eq!(prompt.validate(), Ok(Validation::Continue));
+        assert_eq!(prompt.validate(), Ok(Validation::Finish));
+        assert_eq!(prompt.finish(), [false, true, false, false]);
+    }
+
+    #[test]
+    fn test_render() {
+        let size = (50, 20).into();
+        let base_layout = Layout::new(5, size);
+        let mut prompt = batch_confirm(None);
+        let mut backend = TestBackend::new_with_layout(size, base_layout);
+
+        for &c in ['y', 'n', 'y'].iter() {
+            let mut layout = base_layout;
+            assert!(prompt.render(&mut layout, &mut backend).is_ok());
+            ui::assert_backend_snapshot!(backend);
+
+            let mut height_layout = base_layout;
+            assert_eq!(prompt.height(&mut height_layout), 2);
+            assert_eq!(height_layout, layout);
+            assert_eq!(prompt.cursor_pos(base_layout), (25, 0));
+
+            backend.reset_with_layout(base_layout);
+            assert!(decide(&mut prompt, c).is_ok());
+        }
+    }
+}
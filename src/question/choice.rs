@@ -1,9 +1,15 @@
 use std::{
+    borrow::Cow,
     io,
     ops::{Index, IndexMut},
 };
 
-use ui::{style::Color, widgets::List, Widget};
+use ui::{
+    events::{KeyCode, KeyEvent, KeyModifiers},
+    style::Color,
+    widgets::{List, Text},
+    Widget,
+};
 
 use crate::ExpandItem;
 
@@ -104,14 +110,16 @@ impl<T: Widget> List for SelectList<T> {
         mut layout: ui::layout::Layout,
         b: &mut B,
     ) -> io::Result<()> {
+        let theme = ui::theme::current();
+
         if hovered {
-            b.set_fg(Color::Cyan)?;
+            b.set_fg(theme.cursor)?;
             write!(b, "{} ", ui::symbols::current().pointer)?;
         } else {
             b.write_all(b"  ")?;
 
             if !self.is_selectable(index) {
-                b.set_fg(Color::DarkGrey)?;
+                b.set_fg(theme.disabled)?;
             }
         }
 
@@ -146,6 +154,142 @@ impl<T: Widget> List for SelectList<T> {
 
 pub(crate) type ChoiceList<T> = SelectList<Choice<T>>;
 
+/// The [`Select`](ui::widgets::Select) widget requires at least one selectable element, so this
+/// is checked before a list is shown to the user.
+pub(crate) fn ensure_selectable<L: List>(list: &L) -> ui::Result<()> {
+    if (0..list.len()).any(|i| list.is_selectable(i)) {
+        Ok(())
+    } else {
+        Err(ui::ErrorKind::NoSelectableChoices)
+    }
+}
+
+/// Writes the items separated by commas, which is how the answers of questions with multiple
+/// choices are shown once they are answered.
+pub(crate) fn print_comma_separated<'a, B: ui::backend::Backend>(
+    mut iter: impl Iterator<Item = &'a str>,
+    b: &mut B,
+) -> io::Result<()> {
+    if let Some(first) = iter.next() {
+        b.write_all(first.as_bytes())?;
+    }
+
+    for item in iter {
+        b.write_all(b", ")?;
+        b.write_all(item.as_bytes())?;
+    }
+
+    Ok(())
+}
+
+/// Checks that a non-interactive answer refers to a choice which can be selected.
+pub(crate) fn check_answer_index<T>(choices: &ChoiceList<T>, index: usize) -> Result<(), String> {
+    if index < choices.len() && choices[index].is_choice() {
+        Ok(())
+    } else {
+        Err(format!("there is no choice at index {}", index))
+    }
+}
+
+/// A fuzzy search over the choices of a list, used by the filterable list prompts.
+///
+/// Only the choices matching the query are shown, so the indices used by the [`Select`] widget
+/// must be mapped to the indices of the choices with [`index`](Search::index).
+///
+/// [`Select`]: ui::widgets::Select
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Search {
+    query: String,
+    /// The indices of the visible choices.
+    visible: Vec<usize>,
+}
+
+impl Search {
+    pub(crate) fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// The number of visible choices.
+    pub(crate) fn len(&self) -> usize {
+        self.visible.len()
+    }
+
+    /// The index of the choice which is shown at `index`.
+    pub(crate) fn index(&self, index: usize) -> usize {
+        self.visible[index]
+    }
+
+    /// Recomputes the visible choices for the current query.
+    ///
+    /// All choices, including separators, are shown if the query is empty. Otherwise only the
+    /// choices whose text fuzzy matches the query are shown.
+    pub(crate) fn update(&mut self, choices: &[Choice<Text<String>>]) {
+        let query = &self.query;
+
+        self.visible.clear();
+        self.visible.extend(
+            choices
+                .iter()
+                .enumerate()
+                .filter(|(_, choice)| match choice {
+                    Choice::Choice(choice) => fuzzy_match(query, &choice.text),
+                    _ => query.is_empty(),
+                })
+                .map(|(i, _)| i),
+        );
+    }
+
+    /// Updates the query with the given key.
+    ///
+    /// Returns `None` if the key is not used by the search, otherwise whether the visible choices
+    /// changed. A character which would leave no choices visible is ignored, so there is always
+    /// at least one choice to select.
+    pub(crate) fn handle_key(
+        &mut self,
+        key: KeyEvent,
+        choices: &[Choice<Text<String>>],
+    ) -> Option<bool> {
+        match key.code {
+            KeyCode::Char(c)
+                if !key
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                self.query.push(c);
+
+                let matches = choices.iter().any(|choice| match choice {
+                    Choice::Choice(choice) => fuzzy_match(&self.query, &choice.text),
+                    _ => false,
+                });
+
+                if !matches {
+                    self.query.pop();
+                    return Some(false);
+                }
+            }
+            KeyCode::Backspace => {
+                if self.query.pop().is_none() {
+                    return Some(false);
+                }
+            }
+            _ => return None,
+        }
+
+        self.update(choices);
+        Some(true)
+    }
+}
+
+/// Checks whether all the characters of `query` occur in `text` in the same order, ignoring
+/// case.
+fn fuzzy_match(query: &str, text: &str) -> bool {
+    let mut text = text.chars().flat_map(char::to_lowercase);
+    query
+        .chars()
+        .flat_map(char::to_lowercase)
+        .all(|q| text.any(|t| t == q))
+}
+
 impl<T> std::iter::FromIterator<T> for ChoiceList<T> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         let mut this = Self::new(Choice::is_choice);
@@ -172,7 +316,22 @@ pub enum Choice<T> {
     /// If the text is more than one line, it will be cut-off.
     Separator(String),
     /// A separator which prints a line: "──────────────"
+    ///
+    /// The line is drawn with the [`box_horizontal`] symbol of the current [`SymbolSet`].
+    ///
+    /// [`box_horizontal`]: crate::symbols::SymbolSet::box_horizontal
+    /// [`SymbolSet`]: crate::symbols::SymbolSet
     DefaultSeparator,
+    /// A choice which is shown, but cannot be picked. It is greyed out and skipped over when users
+    /// navigate, like separators.
+    ///
+    /// If a `reason` is given, it is shown in parentheses after the text.
+    Disabled {
+        /// The choice which cannot be picked.
+        text: T,
+        /// Why the choice cannot be picked.
+        reason: Option<String>,
+    },
 }
 
 impl<T> Choice<T> {
@@ -183,6 +342,10 @@ impl<T> Choice<T> {
             Choice::Choice(c) => Choice::Choice(f(c)),
             Choice::Separator(s) => Choice::Separator(s),
             Choice::DefaultSeparator => Choice::DefaultSeparator,
+            Choice::Disabled { text, reason } => Choice::Disabled {
+                text: f(text),
+                reason,
+            },
         }
     }
 
@@ -193,28 +356,63 @@ impl<T> Choice<T> {
 
     /// Returns `true` if the choice is a separator.
     pub fn is_separator(&self) -> bool {
-        !self.is_choice()
+        matches!(self, Choice::Separator(_) | Choice::DefaultSeparator)
+    }
+
+    /// Returns `true` if the choice is a [`Choice::Disabled`].
+    pub fn is_disabled(&self) -> bool {
+        matches!(self, Choice::Disabled { .. })
     }
 
     /// Converts `&Choice<T>` to `Choice<&T>`.
     ///
-    /// This will clone the [`Choice::Separator`] if any.
+    /// This will clone the [`Choice::Separator`] or the reason of the [`Choice::Disabled`] if any.
     pub fn as_ref(&self) -> Choice<&T> {
         match self {
             Choice::Choice(t) => Choice::Choice(t),
             Choice::Separator(s) => Choice::Separator(s.clone()),
             Choice::DefaultSeparator => Choice::DefaultSeparator,
+            Choice::Disabled { text, reason } => Choice::Disabled {
+                text,
+                reason: reason.clone(),
+            },
         }
     }
 
     /// Converts `&mut Choice<T>` to `Choice<&mut T>`.
     ///
-    /// This will clone the [`Choice::Separator`] if any.
+    /// This will clone the [`Choice::Separator`] or the reason of the [`Choice::Disabled`] if any.
     pub fn as_mut(&mut self) -> Choice<&mut T> {
         match self {
             Choice::Choice(t) => Choice::Choice(t),
             Choice::Separator(s) => Choice::Separator(s.clone()),
             Choice::DefaultSeparator => Choice::DefaultSeparator,
+            Choice::Disabled { text, reason } => Choice::Disabled {
+                text,
+                reason: reason.clone(),
+            },
+        }
+    }
+
+    /// Adds the reason of a [`Choice::Disabled`] to the end of its text, so that the reason is
+    /// shown along with the text.
+    pub(crate) fn fold_reason(self, text: impl FnOnce(&mut T) -> &mut String) -> Self {
+        match self {
+            Choice::Disabled {
+                text: mut t,
+                reason: Some(reason),
+            } => {
+                let s = text(&mut t);
+                s.push_str(" (");
+                s.push_str(&reason);
+                s.push(')');
+
+                Choice::Disabled {
+                    text: t,
+                    reason: None,
+                }
+            }
+            choice => choice,
         }
     }
 
@@ -231,12 +429,20 @@ impl<T> Choice<T> {
     }
 }
 
+/// The number of characters in the line of a [`Choice::DefaultSeparator`].
+const DEFAULT_SEPARATOR_LEN: usize = 14;
+
 #[inline]
-pub(crate) fn get_sep_str<T>(separator: &Choice<T>) -> &str {
+pub(crate) fn get_sep_str<T>(separator: &Choice<T>) -> Cow<'_, str> {
     match separator {
-        Choice::Choice(_) => unreachable!(),
-        Choice::Separator(s) => s,
-        Choice::DefaultSeparator => "──────────────",
+        Choice::Choice(_) | Choice::Disabled { .. } => unreachable!(),
+        Choice::Separator(s) => Cow::Borrowed(s),
+        Choice::DefaultSeparator => Cow::Owned(
+            ui::symbols::current()
+                .box_horizontal
+                .to_string()
+                .repeat(DEFAULT_SEPARATOR_LEN),
+        ),
     }
 }
 
@@ -247,14 +453,14 @@ impl<T: ui::Widget> ui::Widget for Choice<T> {
         backend: &mut B,
     ) -> io::Result<()> {
         match self {
-            Choice::Choice(c) => c.render(layout, backend),
+            Choice::Choice(c) | Choice::Disabled { text: c, .. } => c.render(layout, backend),
             sep => get_sep_str(sep).render(layout, backend),
         }
     }
 
     fn height(&mut self, layout: &mut ui::layout::Layout) -> u16 {
         match self {
-            Choice::Choice(c) => c.height(layout),
+            Choice::Choice(c) | Choice::Disabled { text: c, .. } => c.height(layout),
             _ => 1,
         }
     }
@@ -289,3 +495,23 @@ impl<I: Into<String>> From<(I, bool)> for Choice<(String, bool)> {
         Choice::Choice((text.into(), checked))
     }
 }
+
+// Run with `RUSTFLAGS="--cfg nightly" cargo +nightly bench -p requestty`
+#[cfg(all(test, nightly))]
+mod benches {
+    extern crate test;
+
+    use test::Bencher;
+    use ui::backend::{BufferBackend, Size};
+
+    #[bench]
+    fn bench_print_comma_separated(b: &mut Bencher) {
+        let items: Vec<_> = (0..1000).map(|i| format!("Choice {}", i)).collect();
+
+        b.iter(|| {
+            let mut backend = BufferBackend::new(Size::from((100, 100)));
+            super::print_comma_separated(items.iter().map(String::as_str), &mut backend).unwrap();
+            backend
+        });
+    }
+}
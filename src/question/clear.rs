@@ -0,0 +1,122 @@
+use std::{fmt::Display, io};
+
+use ui::{
+    backend::{Backend, ClearType, MoveDirection, Size},
+    style::{Attributes, Color, Styled},
+};
+
+/// A [`Backend`] which hides the answer of a question so that it can be cleared once the question
+/// is finished.
+///
+/// The [`Input`](ui::Input) which renders a question clears it and disables raw mode when it
+/// finishes. Everything written after that is the answer echoed by the question, so it is
+/// dropped while `clear` is set. If the question is shown again, for example after launching an
+/// editor, raw mode is enabled again and the writes are passed through.
+pub(crate) struct ClearAnswered<'a, B> {
+    backend: &'a mut B,
+    clear: bool,
+    finished: bool,
+}
+
+impl<'a, B: Backend> ClearAnswered<'a, B> {
+    pub(crate) fn new(backend: &'a mut B, clear: bool) -> Self {
+        Self {
+            backend,
+            clear,
+            finished: false,
+        }
+    }
+
+    fn hidden(&self) -> bool {
+        self.clear && self.finished
+    }
+
+    /// Clears the `header_height` lines above the cursor, which were printed before the question.
+    ///
+    /// The answer is not written at all, and the question has already cleared itself, so this
+    /// only needs to remove the header.
+    pub(crate) fn clear_header(&mut self, header_height: u16) -> io::Result<()> {
+        if header_height > 0 {
+            self.backend.move_cursor(MoveDirection::PrevLine(header_height))?;
+        }
+        self.backend.clear(ClearType::FromCursorDown)?;
+        self.backend.flush()
+    }
+}
+
+impl<B: Backend> io::Write for ClearAnswered<'_, B> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.hidden() {
+            Ok(buf.len())
+        } else {
+            self.backend.write(buf)
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.backend.flush()
+    }
+}
+
+impl<B: Backend> Backend for ClearAnswered<'_, B> {
+    fn enable_raw_mode(&mut self) -> io::Result<()> {
+        self.finished = false;
+        self.backend.enable_raw_mode()
+    }
+    fn disable_raw_mode(&mut self) -> io::Result<()> {
+        self.finished = true;
+        self.backend.disable_raw_mode()
+    }
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        self.backend.hide_cursor()
+    }
+    fn show_cursor(&mut self) -> io::Result<()> {
+        self.backend.show_cursor()
+    }
+    fn get_cursor_pos(&mut self) -> io::Result<(u16, u16)> {
+        self.backend.get_cursor_pos()
+    }
+    fn move_cursor_to(&mut self, x: u16, y: u16) -> io::Result<()> {
+        self.backend.move_cursor_to(x, y)
+    }
+    fn move_cursor(&mut self, direction: MoveDirection) -> io::Result<()> {
+        self.backend.move_cursor(direction)
+    }
+    fn scroll(&mut self, dist: i16) -> io::Result<()> {
+        self.backend.scroll(dist)
+    }
+    fn set_attributes(&mut self, attributes: Attributes) -> io::Result<()> {
+        if self.hidden() {
+            Ok(())
+        } else {
+            self.backend.set_attributes(attributes)
+        }
+    }
+    fn set_fg(&mut self, color: Color) -> io::Result<()> {
+        if self.hidden() {
+            Ok(())
+        } else {
+            self.backend.set_fg(color)
+        }
+    }
+    fn set_bg(&mut self, color: Color) -> io::Result<()> {
+        if self.hidden() {
+            Ok(())
+        } else {
+            self.backend.set_bg(color)
+        }
+    }
+    fn write_styled(&mut self, styled: &Styled<dyn Display + '_>) -> io::Result<()> {
+        if self.hidden() {
+            Ok(())
+        } else {
+            self.backend.write_styled(styled)
+        }
+    }
+    fn clear(&mut self, clear_type: ClearType) -> io::Result<()> {
+        self.backend.clear(clear_type)
+    }
+    fn size(&self) -> io::Result<Size> {
+        self.backend.size()
+    }
+}
@@ -2,8 +2,8 @@ use std::io;
 
 use ui::{
     backend::Backend,
-    events::{EventIterator, KeyEvent},
-    style::Stylize,
+    events::{EventIterator, KeyCode, KeyEvent},
+    style::{StyledStr, StyledString},
     widgets, Prompt, Validation, Widget,
 };
 
@@ -13,13 +13,119 @@ use crate::{Answer, Answers};
 #[derive(Debug, Default)]
 pub(super) struct Confirm<'a> {
     default: Option<bool>,
+    keys: Option<(char, char)>,
+    labels: Option<(String, String)>,
+    /// Whether the answer is typed as a word, rather than a single key
+    typed: bool,
     transform: Transform<'a, bool>,
 }
 
+impl Confirm<'_> {
+    fn keys(&self) -> (char, char) {
+        self.keys.unwrap_or(('y', 'n'))
+    }
+
+    /// The answer the given character stands for, if any.
+    fn parse(&self, c: char) -> Option<bool> {
+        let (yes, no) = self.keys();
+
+        if eq_ignore_case(c, yes) {
+            Some(true)
+        } else if eq_ignore_case(c, no) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    /// The words which can be typed for yes and no, along with the keys, if the answer is typed.
+    fn words(&self) -> (&str, &str) {
+        match self.labels {
+            Some((ref yes, ref no)) => (yes, no),
+            None => ("yes", "no"),
+        }
+    }
+
+    /// The answer the given word stands for, if any. The word can be either a key or the whole
+    /// word.
+    fn parse_word(&self, word: &str) -> Option<bool> {
+        let mut chars = word.chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => self.parse(c),
+            _ => {
+                let (yes, no) = self.words();
+
+                if word.to_lowercase() == yes.to_lowercase() {
+                    Some(true)
+                } else if word.to_lowercase() == no.to_lowercase() {
+                    Some(false)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+fn eq_ignore_case(a: char, b: char) -> bool {
+    a.to_lowercase().eq(b.to_lowercase())
+}
+
+/// The input for the answer, which is either a single key or a typed word.
+enum ConfirmInput {
+    Key(widgets::CharInput),
+    Typed(widgets::StringInput),
+}
+
+impl Widget for ConfirmInput {
+    fn render<B: Backend>(&mut self, layout: &mut ui::layout::Layout, b: &mut B) -> io::Result<()> {
+        match self {
+            ConfirmInput::Key(input) => input.render(layout, b),
+            ConfirmInput::Typed(input) => input.render(layout, b),
+        }
+    }
+
+    fn height(&mut self, layout: &mut ui::layout::Layout) -> u16 {
+        match self {
+            ConfirmInput::Key(input) => input.height(layout),
+            ConfirmInput::Typed(input) => input.height(layout),
+        }
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        match self {
+            ConfirmInput::Key(input) => input.handle_key(key),
+            ConfirmInput::Typed(input) => input.handle_key(key),
+        }
+    }
+
+    fn cursor_pos(&mut self, layout: ui::layout::Layout) -> (u16, u16) {
+        match self {
+            ConfirmInput::Key(input) => input.cursor_pos(layout),
+            ConfirmInput::Typed(input) => input.cursor_pos(layout),
+        }
+    }
+}
+
 struct ConfirmPrompt<'a> {
-    prompt: widgets::Prompt<&'a str>,
+    prompt: widgets::Prompt<StyledStr<'a>, String>,
     confirm: Confirm<'a>,
-    input: widgets::CharInput,
+    input: ConfirmInput,
+}
+
+impl ConfirmPrompt<'_> {
+    /// The answer that has been entered. It is `Some(None)` if something has been entered which
+    /// is not an answer.
+    fn answer(&self) -> Option<Option<bool>> {
+        match self.input {
+            ConfirmInput::Key(ref input) => input.value().map(|c| self.confirm.parse(c)),
+            ConfirmInput::Typed(ref input) => match input.value().trim() {
+                "" => None,
+                word => Some(self.confirm.parse_word(word)),
+            },
+        }
+    }
 }
 
 impl Widget for ConfirmPrompt<'_> {
@@ -33,7 +139,10 @@ impl Widget for ConfirmPrompt<'_> {
     }
 
     fn handle_key(&mut self, key: KeyEvent) -> bool {
-        self.input.handle_key(key)
+        match key.code {
+            KeyCode::Char(c) if !self.confirm.typed && self.confirm.parse(c).is_none() => false,
+            _ => self.input.handle_key(key),
+        }
     }
 
     fn cursor_pos(&mut self, layout: ui::layout::Layout) -> (u16, u16) {
@@ -42,7 +151,7 @@ impl Widget for ConfirmPrompt<'_> {
     }
 }
 
-fn only_yn(c: char) -> Option<char> {
+pub(super) fn only_yn(c: char) -> Option<char> {
     match c {
         'y' | 'Y' | 'n' | 'N' => Some(c),
         _ => None,
@@ -50,63 +159,101 @@ fn only_yn(c: char) -> Option<char> {
 }
 
 impl Prompt for ConfirmPrompt<'_> {
-    type ValidateErr = &'static str;
+    type ValidateErr = String;
     type Output = bool;
 
     fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
-        if self.input.value().is_some() || self.confirm.default.is_some() {
-            Ok(Validation::Finish)
-        } else {
-            Err("Please enter y or n")
+        match self.answer() {
+            Some(Some(_)) => Ok(Validation::Finish),
+            None if self.confirm.default.is_some() => Ok(Validation::Finish),
+            _ if self.confirm.typed => {
+                let (yes, no) = self.confirm.words();
+                Err(format!("Please enter {} or {}", yes, no))
+            }
+            _ => {
+                let (yes, no) = self.confirm.keys();
+                Err(format!("Please enter {} or {}", yes, no))
+            }
         }
     }
 
     fn finish(self) -> Self::Output {
-        match self.input.value() {
-            Some('y') | Some('Y') => true,
-            Some('n') | Some('N') => false,
-            _ => self
+        match self.answer().flatten() {
+            Some(ans) => ans,
+            None => self
                 .confirm
                 .default
                 .expect("Validation would fail if there was no answer and no default"),
         }
     }
+
+    fn has_default(&self) -> bool {
+        self.confirm.default.is_some()
+    }
 }
 
 impl<'a> Confirm<'a> {
-    fn into_confirm_prompt(self, message: &'a str) -> ConfirmPrompt<'a> {
+    fn into_confirm_prompt(self, message: impl Into<StyledStr<'a>>) -> ConfirmPrompt<'a> {
+        let (yes, no) = self.keys();
+        // The key for the default answer is capitalised
         let hint = match self.default {
-            Some(true) => "Y/n",
-            Some(false) => "y/N",
-            None => "y/n",
+            Some(true) => format!("{}/{}", yes.to_uppercase(), no.to_lowercase()),
+            Some(false) => format!("{}/{}", yes.to_lowercase(), no.to_uppercase()),
+            None => format!("{}/{}", yes.to_lowercase(), no.to_lowercase()),
+        };
+
+        let input = if self.typed {
+            ConfirmInput::Typed(widgets::StringInput::new())
+        } else {
+            ConfirmInput::Key(widgets::CharInput::new())
         };
 
         ConfirmPrompt {
-            prompt: widgets::Prompt::new(message).with_hint(hint),
+            prompt: widgets::Prompt::new(message.into()).with_hint(hint),
             confirm: self,
-            input: widgets::CharInput::with_filter_map(only_yn),
+            input,
         }
     }
 
     pub(crate) fn ask<B: Backend, E: EventIterator>(
         mut self,
-        message: String,
-        on_esc: ui::OnEsc,
+        message: StyledString,
+        input_opts: super::InputOptions<'_>,
         answers: &Answers,
         b: &mut B,
         events: &mut E,
     ) -> ui::Result<Option<Answer>> {
         let transform = self.transform.take();
+        let labels = self.labels.take();
 
         let ans = ui::Input::new(self.into_confirm_prompt(&message), b)
-            .on_esc(on_esc)
+            .on_esc(input_opts.on_esc)
+            .timeout(input_opts.timeout)
+            .on_validation_failed(input_opts.on_validation_failed)
             .run(events)?;
 
         crate::write_final!(transform, message, ans, answers, b, |ans| {
-            let ans = if ans { "Yes" } else { "No" };
-            b.write_styled(&ans.cyan())?;
+            let ans = match (&labels, ans) {
+                (Some((yes, _)), true) => yes.as_str(),
+                (Some((_, no)), false) => no.as_str(),
+                (None, true) => "Yes",
+                (None, false) => "No",
+            };
+            b.write_all(ans.as_bytes())?;
         })
     }
+
+    pub(super) fn answer_non_interactive(
+        self,
+        answer: Option<Answer>,
+    ) -> Result<Answer, super::Rejected> {
+        match (answer, self.default) {
+            (Some(Answer::Bool(ans)), _) => Ok(Answer::Bool(ans)),
+            (Some(_), _) => Err(super::Rejected::Invalid("expected a bool".into())),
+            (None, Some(default)) => Ok(Answer::Bool(default)),
+            (None, None) => Err(super::Rejected::Missing),
+        }
+    }
 }
 
 /// The builder for a [`confirm`] prompt.
@@ -210,6 +357,84 @@ impl<'a> ConfirmBuilder<'a> {
         self
     }
 
+    /// Set the keys used to answer yes and no
+    ///
+    /// The keys are case insensitive, and are shown in the hint with the key for the [`default`]
+    /// capitalised. By default, `y` and `n` are used.
+    ///
+    /// # Panics
+    ///
+    /// It will panic if both keys are the same when ignoring case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let confirm = Question::confirm("anonymous")
+    ///     .keys('o', 'n')
+    ///     .build();
+    /// ```
+    ///
+    /// [`default`]: ConfirmBuilder::default
+    pub fn keys(mut self, yes: char, no: char) -> Self {
+        assert!(
+            !eq_ignore_case(yes, no),
+            "Invalid keys: yes and no are both '{}'",
+            yes
+        );
+
+        self.confirm.keys = Some((yes, no));
+        self
+    }
+
+    /// Set the labels shown for the answer once the question is answered
+    ///
+    /// By default, `Yes` and `No` are used. These are not used if a [`transform`] is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let confirm = Question::confirm("anonymous")
+    ///     .labels("Oui", "Non")
+    ///     .keys('o', 'n')
+    ///     .build();
+    /// ```
+    ///
+    /// [`transform`]: ConfirmBuilder::transform
+    pub fn labels<Y: Into<String>, N: Into<String>>(mut self, yes: Y, no: N) -> Self {
+        self.confirm.labels = Some((yes.into(), no.into()));
+        self
+    }
+
+    /// Whether the answer is typed out as a word and submitted with `Enter`, rather than answered
+    /// with a single key.
+    ///
+    /// The words `yes` and `no`, or the [`labels`] if they are set, are accepted along with the
+    /// [`keys`], ignoring case. Anything else shows an error, and the question is asked again.
+    ///
+    /// If `typed` is not set, it will default to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let confirm = Question::confirm("delete")
+    ///     .message("Delete all the files?")
+    ///     .typed(true)
+    ///     .build();
+    /// ```
+    ///
+    /// [`labels`]: ConfirmBuilder::labels
+    /// [`keys`]: ConfirmBuilder::keys
+    pub fn typed(mut self, typed: bool) -> Self {
+        self.confirm.typed = typed;
+        self
+    }
+
     crate::impl_transform_builder! {
     /// # Examples
     ///
@@ -369,4 +594,88 @@ mod tests {
             assert_eq!(confirm.cursor_pos(layout), (21, offset_y));
         }
     }
+
+    #[test]
+    fn test_custom_keys() {
+        let custom = |default| {
+            Confirm {
+                default,
+                keys: Some(('o', 'n')),
+                ..Default::default()
+            }
+            .into_confirm_prompt("message")
+        };
+
+        assert_eq!(custom(None).prompt.hint().unwrap(), "o/n");
+        assert_eq!(custom(Some(true)).prompt.hint().unwrap(), "O/n");
+        assert_eq!(custom(Some(false)).prompt.hint().unwrap(), "o/N");
+
+        let mut confirm = custom(Some(false));
+        assert!(!confirm.handle_key(KeyCode::Char('y').into()));
+        assert!(confirm.handle_key(KeyCode::Char('O').into()));
+        assert!(confirm.finish());
+
+        let mut confirm = custom(Some(true));
+        assert!(confirm.handle_key(KeyCode::Char('n').into()));
+        assert!(!confirm.finish());
+
+        let mut confirm = custom(None);
+        assert_eq!(confirm.validate().unwrap_err(), "Please enter o or n");
+    }
+
+    #[test]
+    fn test_typed() {
+        let typed = |default, labels: Option<(&str, &str)>| {
+            Confirm {
+                default,
+                labels: labels.map(|(yes, no)| (yes.into(), no.into())),
+                typed: true,
+                ..Default::default()
+            }
+            .into_confirm_prompt("message")
+        };
+        let type_str = |confirm: &mut ConfirmPrompt<'_>, s: &str| {
+            for c in s.chars() {
+                assert!(confirm.handle_key(KeyCode::Char(c).into()));
+            }
+        };
+
+        for &(word, ans) in [("y", true), ("YES", true), (" no ", false), ("N", false)].iter() {
+            let mut confirm = typed(None, None);
+            type_str(&mut confirm, word);
+            assert_eq!(confirm.validate(), Ok(Validation::Finish), "{}", word);
+            assert_eq!(confirm.finish(), ans, "{}", word);
+        }
+
+        // Other words are not accepted, even with a default
+        let mut confirm = typed(Some(true), None);
+        type_str(&mut confirm, "yep");
+        assert_eq!(confirm.validate().unwrap_err(), "Please enter yes or no");
+        for _ in 0..2 {
+            assert!(confirm.handle_key(KeyCode::Backspace.into()));
+        }
+        assert_eq!(confirm.validate(), Ok(Validation::Finish));
+        assert!(confirm.finish());
+
+        let mut confirm = typed(Some(false), None);
+        assert_eq!(confirm.validate(), Ok(Validation::Finish));
+        assert!(!confirm.finish());
+
+        let mut confirm = typed(None, None);
+        assert_eq!(confirm.validate().unwrap_err(), "Please enter yes or no");
+
+        // The labels are the words that can be typed
+        let mut confirm = typed(None, Some(("Oui", "Non")));
+        type_str(&mut confirm, "yes");
+        assert_eq!(confirm.validate().unwrap_err(), "Please enter Oui or Non");
+        let mut confirm = typed(None, Some(("Oui", "Non")));
+        type_str(&mut confirm, "oui");
+        assert!(confirm.finish());
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid keys: yes and no are both 'n'")]
+    fn test_same_keys() {
+        ConfirmBuilder::new("name".into()).keys('n', 'N');
+    }
 }
@@ -11,7 +11,17 @@ use crate::{Answer, Answers};
 /// the rest of the in-built questions.
 ///
 /// You can use the `requestty-ui` crate to build the prompts. You can see the implementations of
-/// the in-built questions for examples on how to use it.
+/// the in-built questions for examples on how to use it. A prompt built as a [`ui::Prompt`] can be
+/// run with [`run_widget`], which uses the same event loop as the in-built questions. See the
+/// `custom` example for a complete prompt using it.
+///
+/// The size of the terminal can be got from [`Backend::size`]. It can change while the prompt is
+/// shown if the terminal is resized, so it should be fetched again before every render. Prompts
+/// run with [`run_widget`] are instead given a fresh [`Layout`] with the current size every render.
+///
+/// [`Layout`]: ui::layout::Layout
+///
+/// [`run_widget`]: crate::prompt::run_widget
 ///
 /// See also [`Question::custom`]
 pub trait Prompt: std::fmt::Debug {
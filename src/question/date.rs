@@ -0,0 +1,766 @@
+use std::{
+    borrow::Cow,
+    io,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use ui::{
+    backend::Backend,
+    events::{EventIterator, KeyEvent, Movement},
+    style::{Color, StyledStr, StyledString, Stylize},
+    widgets, Prompt, Validation, Widget,
+};
+
+use super::{Hint, Options, TransformByVal as Transform, ValidateByVal as Validate};
+use crate::{Answer, Answers, Date};
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const WEEKDAYS: &str = "Mo Tu We Th Fr Sa Su";
+
+/// The width of the calendar grid -- 7 columns of 2 characters each, separated by spaces.
+const GRID_WIDTH: usize = 20;
+
+#[derive(Debug, Default)]
+pub(super) struct DatePicker<'a> {
+    default: Option<Date>,
+    min: Option<Date>,
+    max: Option<Date>,
+    hint: Hint,
+    validate: Validate<'a, Date>,
+    transform: Transform<'a, Date>,
+}
+
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+fn is_valid(date: Date) -> bool {
+    (1..=12).contains(&date.month) && (1..=days_in_month(date.year, date.month)).contains(&date.day)
+}
+
+/// The number of days since 1970-01-01.
+///
+/// See <http://howardhinnant.github.io/date_algorithms.html#days_from_civil>
+fn days_from_civil(date: Date) -> i64 {
+    let month = date.month as i64;
+    let year = date.year as i64 - (month <= 2) as i64;
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + date.day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// The inverse of [`days_from_civil`].
+///
+/// See <http://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+fn civil_from_days(days: i64) -> Date {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let month = (shifted_month + 2) % 12 + 1;
+
+    Date {
+        year: (year_of_era + era * 400 + (month <= 2) as i64) as i32,
+        month: month as u32,
+        day: (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32,
+    }
+}
+
+/// The day of the week, with 0 being Monday and 6 being Sunday.
+fn weekday(date: Date) -> u32 {
+    // 1970-01-01 was a Thursday
+    (days_from_civil(date) + 3).rem_euclid(7) as u32
+}
+
+fn add_days(date: Date, days: i64) -> Date {
+    civil_from_days(days_from_civil(date) + days)
+}
+
+/// Moves the date by the given number of months, clamping the day to the end of the month if
+/// required.
+fn add_months(date: Date, months: i64) -> Date {
+    let months = date.year as i64 * 12 + date.month as i64 - 1 + months;
+    let year = months.div_euclid(12) as i32;
+    let month = months.rem_euclid(12) as u32 + 1;
+
+    Date {
+        year,
+        month,
+        day: date.day.min(days_in_month(year, month)),
+    }
+}
+
+/// The current date in UTC.
+fn today() -> Date {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs() as i64)
+        .unwrap_or(0);
+
+    civil_from_days(secs.div_euclid(86_400))
+}
+
+impl DatePicker<'_> {
+    fn clamp(&self, mut date: Date) -> Date {
+        if let Some(min) = self.min {
+            date = date.max(min);
+        }
+        if let Some(max) = self.max {
+            date = date.min(max);
+        }
+        date
+    }
+
+    fn in_bounds(&self, date: Date) -> bool {
+        self.clamp(date) == date
+    }
+}
+
+struct DatePrompt<'d, 'a> {
+    prompt: widgets::Prompt<StyledStr<'a>, Cow<'static, str>>,
+    date: DatePicker<'d>,
+    at: Date,
+    answers: &'a Answers,
+}
+
+impl DatePrompt<'_, '_> {
+    fn first_of_month(&self) -> Date {
+        Date { day: 1, ..self.at }
+    }
+
+    /// The number of weeks (rows) of the month being shown.
+    fn weeks(&self) -> u16 {
+        let last_day =
+            weekday(self.first_of_month()) + days_in_month(self.at.year, self.at.month) - 1;
+        (last_day / 7 + 1) as u16
+    }
+
+    fn move_to(&mut self, date: Date) -> bool {
+        let date = self.date.clamp(date);
+
+        if date == self.at {
+            false
+        } else {
+            self.at = date;
+            true
+        }
+    }
+
+    fn next_line<B: Backend>(layout: &mut ui::layout::Layout, b: &mut B) -> io::Result<()> {
+        layout.line_offset = 0;
+        layout.offset_y += 1;
+        b.move_cursor_to(layout.offset_x, layout.offset_y)
+    }
+}
+
+impl Widget for DatePrompt<'_, '_> {
+    fn render<B: Backend>(&mut self, layout: &mut ui::layout::Layout, b: &mut B) -> io::Result<()> {
+        self.prompt.render(layout, b)?;
+
+        if layout.line_offset != 0 {
+            Self::next_line(layout, b)?;
+        }
+
+        let title = format!("{} {}", MONTHS[self.at.month as usize - 1], self.at.year);
+        write!(b, "{:^width$}", title, width = GRID_WIDTH)?;
+        Self::next_line(layout, b)?;
+
+        b.write_styled(&WEEKDAYS.dark_grey())?;
+        Self::next_line(layout, b)?;
+
+        let first = self.first_of_month();
+        let offset = weekday(first);
+        b.write_all("   ".repeat(offset as usize).as_bytes())?;
+
+        let theme = ui::theme::current();
+        for day in 1..=days_in_month(self.at.year, self.at.month) {
+            let date = Date { day, ..first };
+            let column = (offset + day - 1) % 7;
+
+            if date == self.at {
+                b.set_bg(theme.cursor)?;
+                b.set_fg(Color::Black)?;
+            } else if !self.date.in_bounds(date) {
+                b.set_fg(theme.disabled)?;
+            }
+
+            write!(b, "{:>2}", day)?;
+
+            b.set_fg(Color::Reset)?;
+            b.set_bg(Color::Reset)?;
+
+            if column == 6 {
+                Self::next_line(layout, b)?;
+            } else {
+                b.write_all(b" ")?;
+            }
+        }
+
+        // The last week has already moved to the next line if it is full
+        if (offset + days_in_month(self.at.year, self.at.month) - 1) % 7 != 6 {
+            Self::next_line(layout, b)?;
+        }
+
+        Ok(())
+    }
+
+    fn height(&mut self, layout: &mut ui::layout::Layout) -> u16 {
+        let prompt_height = self.prompt.height(layout);
+        // The calendar starts on a new line with the month and the weekdays
+        let calendar_height = (layout.line_offset != 0) as u16 + 2 + self.weeks();
+
+        layout.line_offset = 0;
+        layout.offset_y += calendar_height;
+
+        prompt_height + calendar_height - 1
+    }
+
+    fn cursor_pos(&mut self, layout: ui::layout::Layout) -> (u16, u16) {
+        layout.offset_cursor((layout.line_offset, 0))
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        let date = match Movement::try_from_key(key) {
+            Some(Movement::Left) => add_days(self.at, -1),
+            Some(Movement::Right) => add_days(self.at, 1),
+            Some(Movement::Up) => add_days(self.at, -7),
+            Some(Movement::Down) => add_days(self.at, 7),
+            Some(Movement::PageUp) => add_months(self.at, -1),
+            Some(Movement::PageDown) => add_months(self.at, 1),
+            Some(Movement::Home) => self.first_of_month(),
+            Some(Movement::End) => Date {
+                day: days_in_month(self.at.year, self.at.month),
+                ..self.at
+            },
+            _ => return false,
+        };
+
+        self.move_to(date)
+    }
+}
+
+impl Prompt for DatePrompt<'_, '_> {
+    type ValidateErr = widgets::Text<String>;
+    type Output = Date;
+
+    fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
+        if let Validate::Sync(ref mut validate) = self.date.validate {
+            validate(self.at, self.answers)?;
+        }
+
+        Ok(Validation::Finish)
+    }
+
+    fn finish(self) -> Self::Output {
+        self.at
+    }
+}
+
+impl<'d> DatePicker<'d> {
+    fn into_date_prompt<'a>(
+        mut self,
+        message: impl Into<StyledStr<'a>>,
+        answers: &'a Answers,
+    ) -> DatePrompt<'d, 'a> {
+        let at = self.clamp(self.default.unwrap_or_else(today));
+        let hint = std::mem::take(&mut self.hint).resolve(Some(
+            "Use arrow keys to move, <pgup>/<pgdn> to change the month",
+        ));
+
+        DatePrompt {
+            prompt: widgets::Prompt::new(message.into()).with_optional_hint(hint),
+            date: self,
+            at,
+            answers,
+        }
+    }
+
+    pub(crate) fn ask<B: Backend, E: EventIterator>(
+        mut self,
+        message: StyledString,
+        input_opts: super::InputOptions<'_>,
+        answers: &Answers,
+        b: &mut B,
+        events: &mut E,
+    ) -> ui::Result<Option<Answer>> {
+        let transform = self.transform.take();
+
+        let ans = ui::Input::new(self.into_date_prompt(&message, answers), b)
+            .hide_cursor()
+            .on_esc(input_opts.on_esc)
+            .timeout(input_opts.timeout)
+            .on_validation_failed(input_opts.on_validation_failed)
+            .run(events)?;
+
+        crate::write_final!(transform, message, ans, answers, b, |ans| {
+            write!(b, "{}", ans)?;
+        })
+    }
+}
+
+/// The builder for a [`date`] prompt.
+///
+/// The date is picked from a calendar showing a single month. The arrow keys move between days
+/// and weeks, `PageUp` and `PageDown` move between months, and `Home` and `End` move to the
+/// start and end of the month.
+///
+/// The answer is an [`Answer::Date`].
+///
+/// See the various methods for more details on each available option.
+///
+/// # Examples
+///
+/// ```
+/// use requestty::Question;
+///
+/// let date = Question::date("birthday")
+///     .message("When is your birthday?")
+///     .default((2000, 1, 1))
+///     .build();
+/// ```
+///
+/// [`date`]: crate::question::Question::date
+/// [`Answer::Date`]: crate::Answer::Date
+#[derive(Debug)]
+pub struct DateBuilder<'a> {
+    opts: Options<'a>,
+    date: DatePicker<'a>,
+}
+
+impl<'a> DateBuilder<'a> {
+    pub(crate) fn new(name: String) -> Self {
+        DateBuilder {
+            opts: Options::new(name),
+            date: Default::default(),
+        }
+    }
+
+    crate::impl_options_builder! {
+    message
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let date = Question::date("birthday")
+    ///     .message("When is your birthday?")
+    ///     .build();
+    /// ```
+
+    when
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, Answers};
+    ///
+    /// let date = Question::date("birthday")
+    ///     .when(|previous_answers: &Answers| match previous_answers.get("anonymous") {
+    ///         Some(ans) => !ans.as_bool().unwrap(),
+    ///         None => true,
+    ///     })
+    ///     .build();
+    /// ```
+
+    ask_if_answered
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let date = Question::date("birthday")
+    ///     .ask_if_answered(true)
+    ///     .build();
+    /// ```
+
+    on_esc
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, OnEsc};
+    ///
+    /// let date = Question::date("birthday")
+    ///     .on_esc(OnEsc::Terminate)
+    ///     .build();
+    /// ```
+    }
+
+    /// Set the date which is initially selected
+    ///
+    /// If `default` is not set, the current date (in UTC) is used.
+    ///
+    /// # Panics
+    ///
+    /// If `default` is not a valid date, or is outside the [`min`] and [`max`] dates, it will
+    /// cause a panic on [`build`].
+    ///
+    /// [`min`]: DateBuilder::min
+    /// [`max`]: DateBuilder::max
+    /// [`build`]: DateBuilder::build
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let date = Question::date("birthday")
+    ///     .default((2000, 1, 1))
+    ///     .build();
+    /// ```
+    pub fn default<D: Into<Date>>(mut self, default: D) -> Self {
+        self.date.default = Some(default.into());
+        self
+    }
+
+    /// Set the earliest date that can be picked
+    ///
+    /// # Panics
+    ///
+    /// If `min` is not a valid date, or is after the [`max`] date, it will cause a panic on
+    /// [`build`].
+    ///
+    /// [`max`]: DateBuilder::max
+    /// [`build`]: DateBuilder::build
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let date = Question::date("appointment")
+    ///     .min((2024, 1, 1))
+    ///     .build();
+    /// ```
+    pub fn min<D: Into<Date>>(mut self, min: D) -> Self {
+        self.date.min = Some(min.into());
+        self
+    }
+
+    /// Set the latest date that can be picked
+    ///
+    /// # Panics
+    ///
+    /// If `max` is not a valid date, or is before the [`min`] date, it will cause a panic on
+    /// [`build`].
+    ///
+    /// [`min`]: DateBuilder::min
+    /// [`build`]: DateBuilder::build
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let date = Question::date("appointment")
+    ///     .max((2024, 12, 31))
+    ///     .build();
+    /// ```
+    pub fn max<D: Into<Date>>(mut self, max: D) -> Self {
+        self.date.max = Some(max.into());
+        self
+    }
+
+    crate::impl_validate_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let date = Question::date("appointment")
+    ///     .validate(|date, previous_answers| {
+    ///         if date.day != 13 {
+    ///             Ok(())
+    ///         } else {
+    ///             Err("We are closed on the 13th".to_owned())
+    ///         }
+    ///     })
+    ///     .build();
+    /// ```
+    by val Date; date
+    }
+
+    crate::impl_hint_builder! {
+    hint
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let date = Question::date("birthday")
+    ///     .hint("Use the arrow keys to pick a day")
+    ///     .build();
+    /// ```
+
+    no_hint
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let date = Question::date("birthday")
+    ///     .no_hint()
+    ///     .build();
+    /// ```
+    date
+    }
+
+    crate::impl_transform_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let date = Question::date("birthday")
+    ///     .transform(|date, previous_answers, backend| {
+    ///         write!(backend, "{}/{}/{}", date.day, date.month, date.year)
+    ///     })
+    ///     .build();
+    /// ```
+    by val Date; date
+    }
+
+    /// Consumes the builder returning a [`Question`]
+    ///
+    /// [`Question`]: crate::question::Question
+    pub fn build(self) -> super::Question<'a> {
+        let DatePicker {
+            default, min, max, ..
+        } = self.date;
+
+        for &(date, kind) in [(default, "default"), (min, "min"), (max, "max")].iter() {
+            if let Some(date) = date {
+                if !is_valid(date) {
+                    panic!("Invalid {} date {}", kind, date);
+                }
+            }
+        }
+
+        if let (Some(min), Some(max)) = (min, max) {
+            if min > max {
+                panic!("Invalid min date {} is after the max date {}", min, max);
+            }
+        }
+
+        if let Some(default) = default {
+            if !self.date.in_bounds(default) {
+                panic!(
+                    "Invalid default date {} is outside the min and max dates",
+                    default
+                );
+            }
+        }
+
+        super::Question::new(self.opts, super::QuestionKind::Date(self.date))
+    }
+}
+
+impl<'a> From<DateBuilder<'a>> for super::Question<'a> {
+    /// Consumes the builder returning a [`Question`]
+    ///
+    /// [`Question`]: crate::question::Question
+    fn from(builder: DateBuilder<'a>) -> Self {
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ui::{backend::TestBackend, events::KeyCode, layout::Layout};
+
+    fn date(y: i32, m: u32, d: u32) -> Date {
+        Date {
+            year: y,
+            month: m,
+            day: d,
+        }
+    }
+
+    fn date_prompt<'a>(picker: DatePicker<'a>, answers: &'a Answers) -> DatePrompt<'a, 'a> {
+        picker.into_date_prompt("message", answers)
+    }
+
+    fn press(prompt: &mut DatePrompt<'_, '_>, key: KeyCode) -> Date {
+        prompt.handle_key(key.into());
+        prompt.at
+    }
+
+    #[test]
+    fn test_civil_days() {
+        assert_eq!(days_from_civil(date(1970, 1, 1)), 0);
+        assert_eq!(civil_from_days(0), date(1970, 1, 1));
+        assert_eq!(civil_from_days(-1), date(1969, 12, 31));
+        assert_eq!(weekday(date(2024, 2, 29)), 3);
+
+        for days in -800_000..800_000 {
+            assert_eq!(days_from_civil(civil_from_days(days)), days);
+        }
+    }
+
+    #[test]
+    fn test_navigation_month_boundaries() {
+        let answers = Answers::default();
+        let mut prompt = date_prompt(
+            DatePicker {
+                default: Some(date(2024, 1, 31)),
+                ..Default::default()
+            },
+            &answers,
+        );
+
+        assert_eq!(press(&mut prompt, KeyCode::Right), date(2024, 2, 1));
+        assert_eq!(press(&mut prompt, KeyCode::Left), date(2024, 1, 31));
+        assert_eq!(press(&mut prompt, KeyCode::PageDown), date(2024, 2, 29));
+        assert_eq!(press(&mut prompt, KeyCode::Down), date(2024, 3, 7));
+        assert_eq!(press(&mut prompt, KeyCode::Up), date(2024, 2, 29));
+        assert_eq!(press(&mut prompt, KeyCode::PageDown), date(2024, 3, 29));
+        assert_eq!(press(&mut prompt, KeyCode::PageUp), date(2024, 2, 29));
+
+        prompt.at = date(2023, 12, 28);
+        assert_eq!(press(&mut prompt, KeyCode::Down), date(2024, 1, 4));
+        assert_eq!(press(&mut prompt, KeyCode::Up), date(2023, 12, 28));
+        assert_eq!(press(&mut prompt, KeyCode::PageDown), date(2024, 1, 28));
+        assert_eq!(press(&mut prompt, KeyCode::PageUp), date(2023, 12, 28));
+
+        prompt.at = date(2023, 3, 31);
+        assert_eq!(press(&mut prompt, KeyCode::PageUp), date(2023, 2, 28));
+    }
+
+    #[test]
+    fn test_bounds() {
+        let answers = Answers::default();
+        let mut prompt = date_prompt(
+            DatePicker {
+                default: Some(date(2024, 1, 30)),
+                min: Some(date(2024, 1, 28)),
+                max: Some(date(2024, 2, 2)),
+                ..Default::default()
+            },
+            &answers,
+        );
+
+        assert_eq!(press(&mut prompt, KeyCode::Down), date(2024, 2, 2));
+        assert!(!prompt.handle_key(KeyCode::Right.into()));
+        assert_eq!(press(&mut prompt, KeyCode::PageUp), date(2024, 1, 28));
+        assert!(!prompt.handle_key(KeyCode::Up.into()));
+        assert_eq!(press(&mut prompt, KeyCode::Right), date(2024, 1, 29));
+    }
+
+    #[test]
+    fn test_hint() {
+        let size = (80, 20).into();
+        let answers = Answers::default();
+
+        let hint_line = |hint| {
+            let mut prompt = date_prompt(
+                DatePicker {
+                    hint,
+                    ..Default::default()
+                },
+                &answers,
+            );
+            let mut backend = TestBackend::new(size);
+            assert!(prompt
+                .prompt
+                .render(&mut Layout::new(0, size), &mut backend)
+                .is_ok());
+            backend.row_text(0)
+        };
+
+        assert_eq!(
+            hint_line(Hint::Default),
+            "? message (Use arrow keys to move, <pgup>/<pgdn> to change the month)"
+        );
+        assert_eq!(
+            hint_line(Hint::Custom("Pick a day".into())),
+            "? message (Pick a day)"
+        );
+        assert_eq!(hint_line(Hint::Custom(String::new())), "? message ›");
+        assert_eq!(hint_line(Hint::Hidden), "? message ›");
+    }
+
+    #[test]
+    fn test_render() {
+        let size = (50, 20).into();
+        let base_layout = Layout::new(5, size);
+        let answers = Answers::default();
+
+        let mut prompt = date_prompt(
+            DatePicker {
+                default: Some(date(2024, 1, 30)),
+                min: Some(date(2024, 1, 10)),
+                ..Default::default()
+            },
+            &answers,
+        );
+
+        let mut backend = TestBackend::new_with_layout(size, base_layout);
+
+        for &key in [KeyCode::Right, KeyCode::Down, KeyCode::Right].iter() {
+            let mut layout = base_layout;
+            assert!(prompt.render(&mut layout, &mut backend).is_ok());
+            ui::assert_backend_snapshot!(backend);
+
+            let mut height_layout = base_layout;
+            assert_eq!(prompt.height(&mut height_layout), layout.offset_y);
+            assert_eq!(height_layout, layout);
+
+            backend.reset_with_layout(base_layout);
+            prompt.handle_key(key.into());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid default date 2023-02-29")]
+    fn test_panic_invalid_default() {
+        DateBuilder::new("name".into())
+            .default((2023, 2, 29))
+            .build();
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid min date 2024-02-01 is after the max date 2024-01-01")]
+    fn test_panic_min_after_max() {
+        DateBuilder::new("name".into())
+            .min((2024, 2, 1))
+            .max((2024, 1, 1))
+            .build();
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid default date 2023-12-31 is outside the min and max dates")]
+    fn test_panic_default_out_of_bounds() {
+        DateBuilder::new("name".into())
+            .default((2023, 12, 31))
+            .min((2024, 1, 1))
+            .build();
+    }
+}
@@ -0,0 +1,173 @@
+use serde::{de::Error, Deserialize, Deserializer};
+
+use super::{Choice, Question};
+
+/// The options shared by all the question kinds.
+#[derive(Deserialize)]
+struct OptionsConfig {
+    name: String,
+    message: Option<String>,
+    #[serde(default)]
+    ask_if_answered: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ChoiceConfig {
+    Choice(String),
+    /// A separator with the given text, or the default separator if it is `null`.
+    Separator {
+        separator: Option<String>,
+    },
+}
+
+impl From<ChoiceConfig> for Choice<String> {
+    fn from(choice: ChoiceConfig) -> Self {
+        match choice {
+            ChoiceConfig::Choice(text) => Choice::Choice(text),
+            ChoiceConfig::Separator {
+                separator: Some(text),
+            } => Choice::Separator(text),
+            ChoiceConfig::Separator { separator: None } => Choice::DefaultSeparator,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum QuestionConfig {
+    Input {
+        #[serde(flatten)]
+        opts: OptionsConfig,
+        default: Option<String>,
+    },
+    Confirm {
+        #[serde(flatten)]
+        opts: OptionsConfig,
+        default: Option<bool>,
+    },
+    Int {
+        #[serde(flatten)]
+        opts: OptionsConfig,
+        default: Option<i64>,
+    },
+    Float {
+        #[serde(flatten)]
+        opts: OptionsConfig,
+        default: Option<f64>,
+    },
+    Select {
+        #[serde(flatten)]
+        opts: OptionsConfig,
+        choices: Vec<ChoiceConfig>,
+        default: Option<usize>,
+    },
+    MultiSelect {
+        #[serde(flatten)]
+        opts: OptionsConfig,
+        choices: Vec<ChoiceConfig>,
+    },
+}
+
+macro_rules! build_with_opts {
+    ($builder:expr, $opts:expr $(, $default:expr)?) => {{
+        let opts = $opts;
+        let mut builder = $builder(opts.name).ask_if_answered(opts.ask_if_answered);
+
+        if let Some(message) = opts.message {
+            builder = builder.message(message);
+        }
+
+        $(if let Some(default) = $default {
+            builder = builder.default(default);
+        })?
+
+        builder.build()
+    }};
+}
+
+/// A [`Question`] can be deserialized from a structured config, such as JSON or YAML.
+///
+/// The `kind` field decides the type of the question, and can be one of `input`, `confirm`,
+/// `int`, `float`, `select` and `multi_select`. All the kinds take a `name`, and optionally a
+/// `message` and `ask_if_answered`. Apart from `multi_select`, they can also take a `default`.
+/// Questions of the `select` and `multi_select` kinds take a list of `choices`, where each choice
+/// is either a string, or `{ separator: <text> }` for a separator. A `null` text gives the
+/// [default separator](Choice::DefaultSeparator).
+///
+/// Functions such as `validate`, `filter` and `transform` cannot be deserialized, and have to be
+/// set in code.
+///
+/// # Examples
+///
+/// ```
+/// use requestty::Question;
+///
+/// let question: Question = serde_yaml::from_str(
+///     "
+/// kind: select
+/// name: size
+/// message: What size do you need?
+/// choices:
+///   - Small
+///   - Medium
+///   - separator: ~
+///   - Large
+/// default: 1
+/// ",
+/// )
+/// .unwrap();
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de, 'a> Deserialize<'de> for Question<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let question = match QuestionConfig::deserialize(deserializer)? {
+            QuestionConfig::Input { opts, default } => {
+                build_with_opts!(Question::input, opts, default)
+            }
+            QuestionConfig::Confirm { opts, default } => {
+                build_with_opts!(Question::confirm, opts, default)
+            }
+            QuestionConfig::Int { opts, default } => build_with_opts!(Question::int, opts, default),
+            QuestionConfig::Float { opts, default } => {
+                build_with_opts!(Question::float, opts, default)
+            }
+            QuestionConfig::Select {
+                opts,
+                choices,
+                default,
+            } => {
+                // The builder panics on an invalid default, so it is checked here instead
+                if let Some(default) = default {
+                    match choices.get(default) {
+                        Some(ChoiceConfig::Choice(_)) => {}
+                        Some(ChoiceConfig::Separator { .. }) => {
+                            return Err(D::Error::custom(format_args!(
+                                "invalid default {}, it is a separator",
+                                default
+                            )))
+                        }
+                        None => {
+                            return Err(D::Error::custom(format_args!(
+                                "invalid default {}, there are only {} choices",
+                                default,
+                                choices.len()
+                            )))
+                        }
+                    }
+                }
+
+                build_with_opts!(
+                    |name| Question::select(name).choices(choices),
+                    opts,
+                    default
+                )
+            }
+            QuestionConfig::MultiSelect { opts, choices } => {
+                build_with_opts!(|name| Question::multi_select(name).choices(choices), opts)
+            }
+        };
+
+        Ok(question)
+    }
+}
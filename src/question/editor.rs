@@ -1,13 +1,20 @@
 use std::{
+    borrow::Cow,
     env,
+    ffi::OsStr,
     fs::File,
     io::{self, Read, Seek, SeekFrom, Write},
     process::Command,
 };
 
-use ui::{backend::Backend, events::EventIterator, style::Stylize, widgets, Validation, Widget};
+use ui::{
+    backend::Backend,
+    events::EventIterator,
+    style::{StyledStr, StyledString, Stylize},
+    widgets, Validation, Widget,
+};
 
-use super::{Filter, Options, Transform, Validate};
+use super::{Filter, Hint, Options, Transform, Validate};
 use crate::{Answer, Answers, Question};
 
 #[derive(Debug)]
@@ -15,6 +22,8 @@ pub(super) struct Editor<'a> {
     extension: Option<String>,
     default: Option<String>,
     editor: Command,
+    reopen_on_invalid: bool,
+    hint: Hint,
     filter: Filter<'a, String>,
     validate: Validate<'a, str>,
     transform: Transform<'a, str>,
@@ -24,8 +33,10 @@ impl<'a> Default for Editor<'a> {
     fn default() -> Self {
         Self {
             editor: get_editor(),
+            reopen_on_invalid: false,
             extension: None,
             default: None,
+            hint: Hint::Default,
             filter: Filter::None,
             validate: Validate::None,
             transform: Transform::None,
@@ -34,21 +45,73 @@ impl<'a> Default for Editor<'a> {
 }
 
 fn get_editor() -> Command {
-    Command::new(
-        env::var_os("VISUAL")
-            .or_else(|| env::var_os("EDITOR"))
-            .unwrap_or_else(|| {
-                if cfg!(windows) {
-                    "notepad".into()
-                } else {
-                    "vim".into()
+    let editor = env::var_os("VISUAL")
+        .or_else(|| env::var_os("EDITOR"))
+        .unwrap_or_else(|| {
+            if cfg!(windows) {
+                "notepad".into()
+            } else {
+                "vim".into()
+            }
+        });
+
+    // The variables may contain arguments along with the program, e.g. `code --wait`
+    match editor.to_str().map(split_command) {
+        Some(mut words) if !words.is_empty() => {
+            let mut command = Command::new(words.remove(0));
+            command.args(words);
+            command
+        }
+        _ => Command::new(editor),
+    }
+}
+
+/// Splits a command into words on whitespace. Single and double quotes can be used to include
+/// whitespace in a word, and outside single quotes, a backslash escapes the next character.
+fn split_command(s: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    // Whether a word has been started. Needed for empty quoted words like `''`
+    let mut in_word = false;
+    let mut quote = None;
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            (Some(q), _) if c == q => quote = None,
+            (Some('\''), _) => word.push(c),
+            (None, '\'' | '"') => {
+                quote = Some(c);
+                in_word = true;
+            }
+            // Backslashes are path separators on windows
+            (_, '\\') if !cfg!(windows) => {
+                word.extend(chars.next());
+                in_word = true;
+            }
+            (Some(_), _) => word.push(c),
+            (None, _) if c.is_whitespace() => {
+                if in_word {
+                    words.push(std::mem::take(&mut word));
+                    in_word = false;
                 }
-            }),
-    )
+            }
+            (None, _) => {
+                word.push(c);
+                in_word = true;
+            }
+        }
+    }
+
+    if in_word {
+        words.push(word);
+    }
+
+    words
 }
 
 struct EditorPrompt<'a, 'e> {
-    prompt: widgets::Prompt<&'a str>,
+    prompt: widgets::Prompt<StyledStr<'a>, Cow<'static, str>>,
     file: File,
     ans: String,
     editor: Editor<'e>,
@@ -92,23 +155,32 @@ impl ui::Prompt for EditorPrompt<'_, '_> {
     type Output = String;
 
     fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
-        if !self.editor.editor.status().map_err(map_err)?.success() {
-            return Err(map_err(io::Error::new(
-                io::ErrorKind::Other,
-                "Could not open editor",
-            )));
-        }
-
-        self.ans.clear();
-        self.file.read_to_string(&mut self.ans).map_err(map_err)?;
-        self.file.seek(SeekFrom::Start(0)).map_err(map_err)?;
+        loop {
+            if !self.editor.editor.status().map_err(map_err)?.success() {
+                return Err(map_err(io::Error::new(
+                    io::ErrorKind::Other,
+                    "Could not open editor",
+                )));
+            }
+
+            self.ans.clear();
+            self.file.read_to_string(&mut self.ans).map_err(map_err)?;
+            self.file.seek(SeekFrom::Start(0)).map_err(map_err)?;
+
+            if let Validate::Sync(ref mut validate) = self.editor.validate {
+                match validate(&self.ans, self.answers) {
+                    Ok(()) => {}
+                    // The file still has the user's edits, so the editor can be opened again
+                    // straight away
+                    Err(_) if self.editor.reopen_on_invalid => continue,
+                    Err(err) => {
+                        return Err(map_err(io::Error::new(io::ErrorKind::InvalidInput, err)))
+                    }
+                }
+            }
 
-        if let Validate::Sync(ref mut validate) = self.editor.validate {
-            validate(&self.ans, self.answers)
-                .map_err(|err| map_err(io::Error::new(io::ErrorKind::InvalidInput, err)))?;
+            return Ok(Validation::Finish);
         }
-
-        Ok(Validation::Finish)
     }
 
     fn finish(self) -> Self::Output {
@@ -122,8 +194,8 @@ impl ui::Prompt for EditorPrompt<'_, '_> {
 impl Editor<'_> {
     pub(crate) fn ask<B: Backend, E: EventIterator>(
         mut self,
-        message: String,
-        on_esc: ui::OnEsc,
+        message: StyledString,
+        input_opts: super::InputOptions<'_>,
         answers: &Answers,
         b: &mut B,
         events: &mut E,
@@ -143,6 +215,8 @@ impl Editor<'_> {
         }
 
         let transform = self.transform.take();
+        let hint = std::mem::take(&mut self.hint)
+            .resolve(Some("Press <enter> to launch your preferred editor."));
 
         let (file, path) = file.into_parts();
 
@@ -152,8 +226,8 @@ impl Editor<'_> {
 
         let ans = ui::Input::new(
             EditorPrompt {
-                prompt: widgets::Prompt::new(&*message)
-                    .with_hint("Press <enter> to launch your preferred editor.")
+                prompt: widgets::Prompt::new(message.as_styled_str())
+                    .with_optional_hint(hint)
                     .with_delim(widgets::Delimiter::None),
                 editor: self,
                 file,
@@ -162,7 +236,9 @@ impl Editor<'_> {
             },
             b,
         )
-        .on_esc(on_esc)
+        .on_esc(input_opts.on_esc)
+        .timeout(input_opts.timeout)
+        .on_validation_failed(input_opts.on_validation_failed)
         .run(events)?;
 
         crate::write_final!(transform, message, ans [ref], answers, b, |_ans| b
@@ -302,7 +378,8 @@ impl<'a> EditorBuilder<'a> {
     ///
     /// If unspecified, the editor is determined by the `$VISUAL` or `$EDITOR` environment
     /// variables. If neither of those are present, `vim` (for unix) or `notepad` (for windows) is
-    /// used.
+    /// used. The environment variables can contain arguments along with the program, like
+    /// `code --wait`. Quotes can be used for arguments which contain spaces.
     ///
     /// # Examples
     ///
@@ -321,6 +398,58 @@ impl<'a> EditorBuilder<'a> {
         self
     }
 
+    /// Add arguments to pass to the editor
+    ///
+    /// The arguments are added to those already present in the editor, whether it was given
+    /// through [`editor`] or taken from the environment variables. The path of the temporary file
+    /// is always passed after all the arguments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::process::Command;
+    /// use requestty::Question;
+    ///
+    /// let editor = Question::editor("description")
+    ///     .editor(Command::new("code"))
+    ///     .editor_args(["--wait"])
+    ///     .build();
+    /// ```
+    ///
+    /// [`editor`]: EditorBuilder::editor
+    pub fn editor_args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>,
+    {
+        self.editor.editor.args(args);
+        self
+    }
+
+    /// Re-open the editor as soon as the file fails validation
+    ///
+    /// By default, when the file fails [`validate`], the prompt stays with the error printed below
+    /// it, and the user has to press `Enter` again to re-open the editor. If this is set to `true`,
+    /// the editor is instead re-opened immediately, without the error being shown.
+    ///
+    /// In both cases, the file keeps the contents the user last wrote.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let editor = Question::editor("description")
+    ///     .reopen_on_invalid(true)
+    ///     .build();
+    /// ```
+    ///
+    /// [`validate`]: EditorBuilder::validate
+    pub fn reopen_on_invalid(mut self, reopen_on_invalid: bool) -> Self {
+        self.editor.reopen_on_invalid = reopen_on_invalid;
+        self
+    }
+
     crate::impl_filter_builder! {
     /// # Examples
     ///
@@ -352,6 +481,31 @@ impl<'a> EditorBuilder<'a> {
     str; editor
     }
 
+    crate::impl_hint_builder! {
+    hint
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let editor = Question::editor("description")
+    ///     .hint("Press <enter> to write your description")
+    ///     .build();
+    /// ```
+
+    no_hint
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let editor = Question::editor("description")
+    ///     .no_hint()
+    ///     .build();
+    /// ```
+    editor
+    }
+
     crate::impl_transform_builder! {
     /// # Examples
     ///
@@ -384,4 +538,31 @@ impl<'a> From<EditorBuilder<'a>> for Question<'a> {
     }
 }
 
-// TODO: figure out a way to write tests for this
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_command() {
+        assert_eq!(split_command("vim"), ["vim"]);
+        assert_eq!(split_command("  code   --wait "), ["code", "--wait"]);
+        assert_eq!(
+            split_command("vim -c 'startinsert'"),
+            ["vim", "-c", "startinsert"]
+        );
+        assert_eq!(
+            split_command(r#""my editor" -a "b c"d '' 'e \f'"#),
+            ["my editor", "-a", "b cd", "", "e \\f"]
+        );
+        assert!(split_command(" ").is_empty());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_split_command_escape() {
+        assert_eq!(
+            split_command(r#"my\ editor "a \" b""#),
+            ["my editor", "a \" b"]
+        );
+    }
+}
@@ -300,21 +300,23 @@ impl<'a> ExpandBuilder<'a> {
         } = self;
 
         expand.choices.choices.extend(choices.into_iter().map(|c| {
-            c.into().map(|ExpandItem { text, mut key }| {
-                key = key.to_ascii_lowercase();
-                if key == 'h' {
-                    panic!("Reserved key 'h'");
-                }
-                if keys.contains(&key) {
-                    panic!("Duplicate key '{}'", key);
-                }
-                keys.insert(key);
+            c.into()
+                .fold_reason(|item| &mut item.text)
+                .map(|ExpandItem { text, mut key }| {
+                    key = key.to_ascii_lowercase();
+                    if key == 'h' {
+                        panic!("Reserved key 'h'");
+                    }
+                    if keys.contains(&key) {
+                        panic!("Duplicate key '{}'", key);
+                    }
+                    keys.insert(key);
 
-                ExpandText {
-                    text: Text::new(text),
-                    key,
-                }
-            })
+                    ExpandText {
+                        text: Text::new(text),
+                        key,
+                    }
+                })
         }));
 
         self
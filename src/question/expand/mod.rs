@@ -3,7 +3,7 @@ use std::io;
 use ui::{
     backend::{Backend, MoveDirection},
     events::{EventIterator, KeyEvent},
-    style::{Color, Stylize},
+    style::{Color, StyledStr, StyledString},
     widgets::{self, Text},
     Prompt, Validation, Widget,
 };
@@ -65,7 +65,7 @@ impl<'a> Default for Expand<'a> {
 }
 
 struct ExpandPrompt<'a, F> {
-    prompt: widgets::Prompt<&'a str, &'a str>,
+    prompt: widgets::Prompt<StyledStr<'a>, &'a str>,
     select: widgets::Select<Expand<'a>>,
     input: widgets::CharInput<F>,
     expanded: bool,
@@ -128,6 +128,11 @@ impl<F: Fn(char) -> Option<char>> Prompt for ExpandPrompt<'_, F> {
         let c = self.input.value().unwrap_or(self.select.list.default);
         self.finish_with(c)
     }
+
+    fn has_default(&self) -> bool {
+        // `h` expands the choices instead of answering the question
+        self.select.list.default != 'h'
+    }
 }
 
 const ANSWER_PROMPT: &[u8] = b"  Answer: ";
@@ -145,7 +150,9 @@ impl<F: Fn(char) -> Option<char>> ui::Widget for ExpandPrompt<'_, F> {
 
             if self.input.value().is_some() {
                 b.move_cursor(MoveDirection::NextLine(1))?;
-                b.write_styled(&ui::symbols::current().arrow.cyan())?;
+                b.set_fg(ui::theme::current().cursor)?;
+                write!(b, "{}", ui::symbols::current().arrow)?;
+                b.set_fg(Color::Reset)?;
                 b.write_all(b" ")?;
 
                 layout.offset_y += 1;
@@ -230,7 +237,7 @@ impl widgets::List for Expand<'_> {
         &mut self,
         index: usize,
         _: bool,
-        layout: ui::layout::Layout,
+        mut layout: ui::layout::Layout,
         b: &mut B,
     ) -> io::Result<()> {
         if index == self.choices.len() {
@@ -239,8 +246,15 @@ impl widgets::List for Expand<'_> {
 
         match &mut self.choices[index] {
             Choice::Choice(_) => self.render_choice(Some(index), layout, b),
+            Choice::Disabled { text, .. } => {
+                b.set_fg(ui::theme::current().disabled)?;
+                write!(b, "  {}) ", text.key)?;
+                layout.offset_x += 5;
+                text.render(&mut layout, b)?;
+                b.set_fg(Color::Reset)
+            }
             separator => {
-                b.set_fg(Color::DarkGrey)?;
+                b.set_fg(ui::theme::current().disabled)?;
                 b.write_all(b"   ")?;
                 super::get_sep_str(separator).render(&mut layout.with_line_offset(3), b)?;
                 b.set_fg(Color::Reset)
@@ -297,7 +311,7 @@ impl Expand<'_> {
         let hovered = self.selected.map(|c| c == key).unwrap_or(false);
 
         if hovered {
-            b.set_fg(Color::Cyan)?;
+            b.set_fg(ui::theme::current().cursor)?;
         }
 
         write!(b, "  {}) ", key)?;
@@ -321,8 +335,8 @@ impl Expand<'_> {
 
     pub(crate) fn ask<B: Backend, E: EventIterator>(
         mut self,
-        message: String,
-        on_esc: ui::OnEsc,
+        message: StyledString,
+        input_opts: super::InputOptions<'_>,
         answers: &Answers,
         b: &mut B,
         events: &mut E,
@@ -347,7 +361,7 @@ impl Expand<'_> {
 
         let ans = ui::Input::new(
             ExpandPrompt {
-                prompt: widgets::Prompt::new(&*message).with_hint(&hint),
+                prompt: widgets::Prompt::new(message.as_styled_str()).with_hint(&hint),
                 input: widgets::CharInput::with_filter_map(|c| {
                     let c = c.to_ascii_lowercase();
                     hint.chars()
@@ -359,15 +373,17 @@ impl Expand<'_> {
             },
             b,
         )
-        .on_esc(on_esc)
+        .on_esc(input_opts.on_esc)
+        .timeout(input_opts.timeout)
+        .on_validation_failed(input_opts.on_validation_failed)
         .run(events)?;
 
-        crate::write_final!(transform, message, ans [ref], answers, b, |ans| b.write_styled(
-            &ans.text
+        crate::write_final!(transform, message, ans [ref], answers, b, |ans| b.write_all(
+            ans.text
                 .lines()
                 .next()
                 .expect("There must be at least one line in a `str`")
-                .cyan()
+                .as_bytes()
         )?)
     }
 }
@@ -64,7 +64,7 @@ macro_rules! expand {
             };
 
         let mut $expand = ExpandPrompt {
-            prompt: widgets::Prompt::new($message).with_hint(&hint),
+            prompt: widgets::Prompt::new($message.into()).with_hint(&hint),
             input: widgets::CharInput::with_filter_map(|c| {
                 let c = c.to_ascii_lowercase();
                 hint.chars()
@@ -0,0 +1,626 @@
+use std::{borrow::Cow, collections::BTreeMap, io};
+
+use ui::{
+    backend::Backend,
+    events::{EventIterator, KeyCode, KeyEvent},
+    layout::Layout,
+    style::{Color, StyledStr, StyledString, Stylize},
+    widgets, Prompt, Validation, Widget,
+};
+
+use super::{Hint, Options, Transform, Validate};
+use crate::{Answer, Answers};
+
+#[derive(Debug)]
+enum FieldInput {
+    Text(widgets::StringInput),
+    Char(widgets::CharInput),
+}
+
+impl FieldInput {
+    fn value(&self) -> String {
+        match self {
+            FieldInput::Text(input) => input.value().to_owned(),
+            FieldInput::Char(input) => input.value().map(String::from).unwrap_or_default(),
+        }
+    }
+}
+
+impl Widget for FieldInput {
+    fn render<B: Backend>(&mut self, layout: &mut Layout, b: &mut B) -> io::Result<()> {
+        match self {
+            FieldInput::Text(input) => input.render(layout, b),
+            FieldInput::Char(input) => input.render(layout, b),
+        }
+    }
+
+    fn height(&mut self, layout: &mut Layout) -> u16 {
+        match self {
+            FieldInput::Text(input) => input.height(layout),
+            FieldInput::Char(input) => input.height(layout),
+        }
+    }
+
+    fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+        match self {
+            FieldInput::Text(input) => input.cursor_pos(layout),
+            FieldInput::Char(input) => input.cursor_pos(layout),
+        }
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        match self {
+            FieldInput::Text(input) => input.handle_key(key),
+            FieldInput::Char(input) => input.handle_key(key),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Field<'a> {
+    name: String,
+    label: String,
+    input: FieldInput,
+    validate: Validate<'a, str>,
+}
+
+impl Field<'_> {
+    /// The width of the pointer and the label before the input.
+    fn prefix_width(&self) -> u16 {
+        // The pointer and the space after it, the label, and the ': ' after it
+        2 + self.label.chars().count() as u16 + 2
+    }
+}
+
+#[derive(Debug, Default)]
+pub(super) struct Form<'a> {
+    fields: Vec<Field<'a>>,
+    hint: Hint,
+    validate: Validate<'a, BTreeMap<String, String>>,
+    transform: Transform<'a, BTreeMap<String, String>>,
+}
+
+impl<'a> Form<'a> {
+    fn add_field(&mut self, name: String, label: String, input: FieldInput) {
+        if self.fields.iter().any(|field| field.name == name) {
+            panic!("Duplicate form field '{}'", name);
+        }
+
+        self.fields.push(Field {
+            name,
+            label,
+            input,
+            validate: Validate::None,
+        });
+    }
+}
+
+struct FormPrompt<'f, 'a> {
+    prompt: widgets::Prompt<StyledStr<'a>, Cow<'static, str>>,
+    form: Form<'f>,
+    focused: usize,
+    answers: &'a Answers,
+}
+
+impl FormPrompt<'_, '_> {
+    fn values(&self) -> BTreeMap<String, String> {
+        self.form
+            .fields
+            .iter()
+            .map(|field| (field.name.clone(), field.input.value()))
+            .collect()
+    }
+
+    fn focus_next(&mut self) {
+        self.focused = (self.focused + 1) % self.form.fields.len();
+    }
+
+    fn focus_prev(&mut self) {
+        self.focused = self
+            .focused
+            .checked_sub(1)
+            .unwrap_or(self.form.fields.len() - 1);
+    }
+}
+
+impl Widget for FormPrompt<'_, '_> {
+    fn render<B: Backend>(&mut self, layout: &mut Layout, b: &mut B) -> io::Result<()> {
+        self.prompt.render(layout, b)?;
+
+        for (i, field) in self.form.fields.iter_mut().enumerate() {
+            layout.line_offset = 0;
+            layout.offset_y += 1;
+            b.move_cursor_to(layout.offset_x, layout.offset_y)?;
+
+            if i == self.focused {
+                b.set_fg(ui::theme::current().cursor)?;
+                write!(b, "{} ", ui::symbols::current().pointer)?;
+                b.set_fg(Color::Reset)?;
+            } else {
+                b.write_all(b"  ")?;
+            }
+
+            b.write_styled(&field.label.as_str().bold())?;
+            b.write_all(b": ")?;
+
+            layout.line_offset = field.prefix_width();
+            field.input.render(layout, b)?;
+        }
+
+        Ok(())
+    }
+
+    fn height(&mut self, layout: &mut Layout) -> u16 {
+        let mut height = self.prompt.height(layout);
+
+        for field in self.form.fields.iter_mut() {
+            layout.line_offset = field.prefix_width();
+            layout.offset_y += 1;
+            height += field.input.height(layout);
+        }
+
+        height
+    }
+
+    fn cursor_pos(&mut self, mut layout: Layout) -> (u16, u16) {
+        self.prompt.height(&mut layout);
+
+        for field in self.form.fields[..self.focused].iter_mut() {
+            layout.line_offset = field.prefix_width();
+            layout.offset_y += 1;
+            field.input.height(&mut layout);
+        }
+
+        let field = &mut self.form.fields[self.focused];
+        layout.line_offset = field.prefix_width();
+        layout.offset_y += 1;
+        field.input.cursor_pos(layout)
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Tab | KeyCode::Down => self.focus_next(),
+            KeyCode::BackTab | KeyCode::Up => self.focus_prev(),
+            _ => return self.form.fields[self.focused].input.handle_key(key),
+        }
+
+        true
+    }
+}
+
+impl Prompt for FormPrompt<'_, '_> {
+    type ValidateErr = widgets::Text<String>;
+    type Output = BTreeMap<String, String>;
+
+    fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
+        for (i, field) in self.form.fields.iter_mut().enumerate() {
+            if let Validate::Sync(ref mut validate) = field.validate {
+                if let Err(e) = validate(&field.input.value(), self.answers) {
+                    // Move to the invalid field so it can be fixed
+                    self.focused = i;
+                    return Err(e.into());
+                }
+            }
+        }
+
+        let values = self.values();
+        if let Validate::Sync(ref mut validate) = self.form.validate {
+            validate(&values, self.answers)?;
+        }
+
+        Ok(Validation::Finish)
+    }
+
+    fn finish(self) -> Self::Output {
+        self.values()
+    }
+}
+
+impl<'f> Form<'f> {
+    fn into_form_prompt<'a>(
+        mut self,
+        message: impl Into<StyledStr<'a>>,
+        answers: &'a Answers,
+    ) -> FormPrompt<'f, 'a> {
+        let hint = std::mem::take(&mut self.hint).resolve(Some("Use <tab> to move between fields"));
+
+        FormPrompt {
+            prompt: widgets::Prompt::new(message.into()).with_optional_hint(hint),
+            form: self,
+            focused: 0,
+            answers,
+        }
+    }
+
+    pub(crate) fn ask<B: Backend, E: EventIterator>(
+        mut self,
+        message: StyledString,
+        input_opts: super::InputOptions<'_>,
+        answers: &Answers,
+        b: &mut B,
+        events: &mut E,
+    ) -> ui::Result<Option<Answer>> {
+        // There are no fields to fill in, so there is no need to show the prompt
+        if self.fields.is_empty() {
+            return Ok(Some(Answer::Map(BTreeMap::new())));
+        }
+
+        let transform = self.transform.take();
+        let names: Vec<_> = self.fields.iter().map(|field| field.name.clone()).collect();
+
+        let ans = ui::Input::new(self.into_form_prompt(&message, answers), b)
+            .on_esc(input_opts.on_esc)
+            .timeout(input_opts.timeout)
+            .on_validation_failed(input_opts.on_validation_failed)
+            .run(events)?;
+
+        crate::write_final!(transform, message, ans [ref], answers, b, |ans| {
+            let values: Vec<_> = names.iter().map(|name| ans[name].as_str()).collect();
+            b.write_all(values.join(", ").as_bytes())?;
+        })
+    }
+}
+
+/// The builder for a [`form`] prompt.
+///
+/// Every field is shown on its own line below the message. The user can move between the fields
+/// with `Tab` and `Shift+Tab` (or the up and down arrow keys), and all the fields are submitted
+/// together with `Enter`.
+///
+/// The answer is an [`Answer::Map`] from the name of each field to its value.
+///
+/// See the various methods for more details on each available option.
+///
+/// # Examples
+///
+/// ```
+/// use requestty::Question;
+///
+/// let form = Question::form("account")
+///     .message("Create an account")
+///     .field("username", "Username")
+///     .field("email", "Email")
+///     .char_field("newsletter", "Subscribe to the newsletter? (y/n)")
+///     .build();
+/// ```
+///
+/// [`form`]: crate::question::Question::form
+/// [`Answer::Map`]: crate::Answer::Map
+#[derive(Debug)]
+pub struct FormBuilder<'a> {
+    opts: Options<'a>,
+    form: Form<'a>,
+}
+
+impl<'a> FormBuilder<'a> {
+    pub(crate) fn new(name: String) -> Self {
+        FormBuilder {
+            opts: Options::new(name),
+            form: Default::default(),
+        }
+    }
+
+    crate::impl_options_builder! {
+    message
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let form = Question::form("account")
+    ///     .message("Create an account")
+    ///     .build();
+    /// ```
+
+    when
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, Answers};
+    ///
+    /// let form = Question::form("account")
+    ///     .when(|previous_answers: &Answers| match previous_answers.get("sign_up") {
+    ///         Some(ans) => ans.as_bool().unwrap(),
+    ///         None => true,
+    ///     })
+    ///     .build();
+    /// ```
+
+    ask_if_answered
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let form = Question::form("account")
+    ///     .ask_if_answered(true)
+    ///     .build();
+    /// ```
+
+    on_esc
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, OnEsc};
+    ///
+    /// let form = Question::form("account")
+    ///     .on_esc(OnEsc::Terminate)
+    ///     .build();
+    /// ```
+    }
+
+    /// Adds a field which accepts a line of text.
+    ///
+    /// The `name` is the key of the field in the answer, and the `label` is shown to the user.
+    ///
+    /// # Panics
+    ///
+    /// It will panic if a field with the same `name` has already been added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let form = Question::form("account")
+    ///     .field("username", "Username")
+    ///     .build();
+    /// ```
+    pub fn field<N: Into<String>, L: Into<String>>(mut self, name: N, label: L) -> Self {
+        self.form.add_field(
+            name.into(),
+            label.into(),
+            FieldInput::Text(widgets::StringInput::default()),
+        );
+        self
+    }
+
+    /// Adds a field which accepts a single character.
+    ///
+    /// The `name` is the key of the field in the answer, and the `label` is shown to the user. If
+    /// no character is entered, the value of the field is an empty string.
+    ///
+    /// # Panics
+    ///
+    /// It will panic if a field with the same `name` has already been added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let form = Question::form("account")
+    ///     .char_field("newsletter", "Subscribe to the newsletter? (y/n)")
+    ///     .build();
+    /// ```
+    pub fn char_field<N: Into<String>, L: Into<String>>(mut self, name: N, label: L) -> Self {
+        self.form.add_field(
+            name.into(),
+            label.into(),
+            FieldInput::Char(widgets::CharInput::default()),
+        );
+        self
+    }
+
+    /// Function to validate the value of the last added field.
+    ///
+    /// It is a [`FnMut`] that is given the value of the field and the previous [`Answers`], and
+    /// should return `Ok(())` if the given value is valid. If it is invalid, it should return an
+    /// [`Err`] with the error message to display to the user.
+    ///
+    /// This will be called when the user presses the `Enter` key, and the first invalid field is
+    /// focused.
+    ///
+    /// [`Answers`]: crate::Answers
+    ///
+    /// # Panics
+    ///
+    /// It will panic if no field has been added yet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let form = Question::form("account")
+    ///     .field("email", "Email")
+    ///     .validate_field(|email, previous_answers| {
+    ///         if email.contains('@') {
+    ///             Ok(())
+    ///         } else {
+    ///             Err("Please enter a valid email".to_owned())
+    ///         }
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn validate_field<F>(mut self, validate_field: F) -> Self
+    where
+        F: FnMut(&str, &Answers) -> Result<(), String> + 'a,
+    {
+        let field = self
+            .form
+            .fields
+            .last_mut()
+            .expect("A field must be added before it can be validated");
+
+        field.validate = Validate::Sync(Box::new(validate_field));
+        self
+    }
+
+    crate::impl_validate_builder! {
+    /// This is called after every field has been validated with
+    /// [`validate_field`](Self::validate_field), and can be used to validate fields which depend
+    /// on each other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let form = Question::form("account")
+    ///     .field("password", "Password")
+    ///     .field("confirm", "Confirm password")
+    ///     .validate(|fields, previous_answers| {
+    ///         if fields["password"] == fields["confirm"] {
+    ///             Ok(())
+    ///         } else {
+    ///             Err("The passwords do not match".to_owned())
+    ///         }
+    ///     })
+    ///     .build();
+    /// ```
+    BTreeMap<String, String>; form
+    }
+
+    crate::impl_hint_builder! {
+    hint
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let form = Question::form("account")
+    ///     .hint("All fields are required")
+    ///     .build();
+    /// ```
+
+    no_hint
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let form = Question::form("account")
+    ///     .no_hint()
+    ///     .build();
+    /// ```
+    form
+    }
+
+    crate::impl_transform_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let form = Question::form("account")
+    ///     .field("username", "Username")
+    ///     .transform(|fields, previous_answers, backend| {
+    ///         write!(backend, "Welcome {}", fields["username"])
+    ///     })
+    ///     .build();
+    /// ```
+    BTreeMap<String, String>; form
+    }
+
+    /// Consumes the builder returning a [`Question`]
+    ///
+    /// [`Question`]: crate::question::Question
+    pub fn build(self) -> super::Question<'a> {
+        super::Question::new(self.opts, super::QuestionKind::Form(self.form))
+    }
+}
+
+impl<'a> From<FormBuilder<'a>> for super::Question<'a> {
+    /// Consumes the builder returning a [`Question`]
+    ///
+    /// [`Question`]: crate::question::Question
+    fn from(builder: FormBuilder<'a>) -> Self {
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ui::backend::TestBackend;
+
+    fn form() -> Form<'static> {
+        FormBuilder::new("name".into())
+            .field("name", "Name")
+            .validate_field(|name, _| {
+                if name.is_empty() {
+                    Err("Please enter a name".into())
+                } else {
+                    Ok(())
+                }
+            })
+            .char_field("confirm", "Confirm")
+            .form
+    }
+
+    fn type_str(prompt: &mut FormPrompt<'_, '_>, s: &str) {
+        for c in s.chars() {
+            prompt.handle_key(KeyCode::Char(c).into());
+        }
+    }
+
+    #[test]
+    fn test_focus() {
+        let answers = Answers::default();
+        let mut prompt = form().into_form_prompt("", &answers);
+
+        type_str(&mut prompt, "john");
+        assert!(prompt.handle_key(KeyCode::Tab.into()));
+        assert_eq!(prompt.focused, 1);
+        type_str(&mut prompt, "y");
+
+        assert!(prompt.handle_key(KeyCode::Tab.into()));
+        assert_eq!(prompt.focused, 0);
+        assert!(prompt.handle_key(KeyCode::BackTab.into()));
+        assert_eq!(prompt.focused, 1);
+
+        let mut expected = BTreeMap::new();
+        expected.insert("name".to_owned(), "john".to_owned());
+        expected.insert("confirm".to_owned(), "y".to_owned());
+        assert_eq!(prompt.finish(), expected);
+    }
+
+    #[test]
+    fn test_validate_field() {
+        let answers = Answers::default();
+        let mut prompt = form().into_form_prompt("", &answers);
+
+        prompt.handle_key(KeyCode::Tab.into());
+        assert!(prompt.validate().is_err());
+        assert_eq!(prompt.focused, 0);
+
+        type_str(&mut prompt, "john");
+        assert_eq!(prompt.validate(), Ok(Validation::Finish));
+    }
+
+    #[test]
+    #[should_panic(expected = "Duplicate form field 'name'")]
+    fn test_duplicate_field() {
+        FormBuilder::new("name".into())
+            .field("name", "Name")
+            .char_field("name", "Name");
+    }
+
+    #[test]
+    fn test_render() {
+        let size = (50, 20).into();
+        let base_layout = Layout::new(5, size);
+        let answers = Answers::default();
+        let mut prompt = form().into_form_prompt("message", &answers);
+        let mut backend = TestBackend::new_with_layout(size, base_layout);
+
+        let mut layout = base_layout;
+        assert!(prompt.render(&mut layout, &mut backend).is_ok());
+        ui::assert_backend_snapshot!(backend);
+
+        let mut height_layout = base_layout;
+        assert_eq!(prompt.height(&mut height_layout), 3);
+        assert_eq!(height_layout, layout);
+        assert_eq!(prompt.cursor_pos(base_layout), (8, 1));
+
+        type_str(&mut prompt, "john");
+        prompt.handle_key(KeyCode::Tab.into());
+        backend.reset_with_layout(base_layout);
+
+        let mut layout = base_layout;
+        assert!(prompt.render(&mut layout, &mut backend).is_ok());
+        ui::assert_backend_snapshot!(backend);
+        assert_eq!(prompt.cursor_pos(base_layout), (11, 2));
+    }
+}
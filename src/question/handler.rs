@@ -1,6 +1,6 @@
 use std::fmt;
 
-use ui::backend::Backend;
+use ui::{backend::Backend, widgets};
 
 use super::Completions;
 use crate::Answers;
@@ -68,12 +68,108 @@ macro_rules! handler {
 
 handler!(Filter, FnOnce(T, &Answers) -> T);
 handler!(AutoComplete, FnMut(T, &Answers) -> Completions<T>);
+handler!(Suggestions, ?Sized FnMut(&T, &Answers) -> Completions<String>);
 handler!(Validate, ?Sized FnMut(&T, &Answers) -> Result<(), String>);
 handler!(ValidateByVal, FnMut(T, &Answers) -> Result<(), String>);
+handler!(ValidateSuggest, ?Sized FnMut(&T, &Answers) -> Result<(), ValidationError>);
+handler!(ValidateSuggestByVal, FnMut(T, &Answers) -> Result<(), ValidationError>);
 handler!(ValidateOnKey, ?Sized FnMut(&T, &Answers) -> bool);
 handler!(ValidateOnKeyByVal, FnMut(T, &Answers) -> bool);
+handler!(Strength, ?Sized FnMut(&T) -> (u8, &str));
+handler!(ConfirmOn, ?Sized FnMut(&T) -> bool);
+handler!(ChoicesFn, FnOnce(&Answers) -> Vec<T>);
 handler!(Transform, ?Sized FnOnce(&T, &Answers, &mut dyn Backend) -> std::io::Result<()>);
 handler!(
     TransformByVal,
     FnOnce(T, &Answers, &mut dyn Backend) -> std::io::Result<()>
 );
+
+impl<'a, T: ?Sized + 'a> ValidateSuggest<'a, T> {
+    /// Drops the suggestions, for when there is no user to accept them.
+    pub(super) fn without_suggestion(self) -> Validate<'a, T> {
+        match self {
+            Self::Sync(mut validate) => Validate::Sync(Box::new(move |t, answers| {
+                validate(t, answers).map_err(|e| e.message)
+            })),
+            Self::None => Validate::None,
+        }
+    }
+}
+
+/// The error returned by the `validate_with_suggestion` function of [`input`], [`int`] and
+/// [`float`] questions.
+///
+/// Along with the message to display to the user, it can carry a suggested answer. If it does,
+/// the user is told that they can press `Tab` to replace what they typed with the suggestion.
+///
+/// A [`String`] or [`&str`] can be converted into a `ValidationError` without a suggestion, so
+/// the errors of a `validate` function can be returned as is.
+///
+/// # Examples
+///
+/// ```
+/// use requestty::{question::ValidationError, Question};
+///
+/// let email = Question::input("email")
+///     .validate_with_suggestion(|email, previous_answers| {
+///         if email.contains(char::is_uppercase) {
+///             Err(ValidationError::new("Emails should be lowercase")
+///                 .with_suggestion(email.to_lowercase()))
+///         } else {
+///             Ok(())
+///         }
+///     })
+///     .build();
+/// ```
+///
+/// [`input`]: crate::Question::input
+/// [`int`]: crate::Question::int
+/// [`float`]: crate::Question::float
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    /// The error message to display to the user.
+    pub message: String,
+    /// The answer the user can use instead by pressing `Tab`.
+    pub suggestion: Option<String>,
+}
+
+impl ValidationError {
+    /// Creates a `ValidationError` with the given message and no suggestion.
+    pub fn new<M: Into<String>>(message: M) -> Self {
+        Self {
+            message: message.into(),
+            suggestion: None,
+        }
+    }
+
+    /// Suggests an answer which the user can use instead by pressing `Tab`.
+    pub fn with_suggestion<S: Into<String>>(mut self, suggestion: S) -> Self {
+        self.suggestion = Some(suggestion.into());
+        self
+    }
+
+    /// Splits the error into the text to show to the user and the suggestion.
+    pub(super) fn into_parts(self) -> (widgets::Text<String>, Option<String>) {
+        let text = match self.suggestion {
+            Some(ref suggestion) => format!(
+                "{}\nPress Tab to use suggestion: {}",
+                self.message, suggestion
+            ),
+            None => self.message,
+        };
+
+        (widgets::Text::new(text), self.suggestion)
+    }
+}
+
+impl From<String> for ValidationError {
+    fn from(message: String) -> Self {
+        Self::new(message)
+    }
+}
+
+impl From<&str> for ValidationError {
+    fn from(message: &str) -> Self {
+        Self::new(message)
+    }
+}
@@ -0,0 +1,32 @@
+use std::borrow::Cow;
+
+/// The hint shown next to the message of a prompt, which can be changed on the builders of some
+/// prompts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Hint {
+    /// The hint chosen by the prompt.
+    Default,
+    /// A hint given by the user, which is not shown if it is empty.
+    Custom(String),
+    /// No hint is shown.
+    Hidden,
+}
+
+// `#[default]` cannot be used on enum variants with the minimum supported rust version
+#[allow(clippy::derivable_impls)]
+impl Default for Hint {
+    fn default() -> Self {
+        Hint::Default
+    }
+}
+
+impl Hint {
+    /// The hint to show, which is `default` unless it was changed on the builder.
+    pub(crate) fn resolve(self, default: Option<&'static str>) -> Option<Cow<'static, str>> {
+        match self {
+            Hint::Default => default.map(Cow::Borrowed),
+            Hint::Custom(hint) if !hint.is_empty() => Some(Cow::Owned(hint)),
+            Hint::Custom(_) | Hint::Hidden => None,
+        }
+    }
+}
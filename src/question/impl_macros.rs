@@ -43,8 +43,9 @@ macro_rules! impl_auto_complete_builder {
         ///
         /// If 1 completion is returned, then the state of the answer becomes that completion.
         ///
-        /// If 2 or more completions are returned, a list of completions is displayed from which the
-        /// user can pick one completion.
+        /// If 2 or more completions are returned which all start with a common prefix longer than the
+        /// answer, the answer is extended to that prefix. Otherwise, a list of completions is
+        /// displayed from which the user can pick one completion.
         ///
         /// [`Answers`]: crate::Answers
         ///
@@ -76,6 +77,14 @@ macro_rules! impl_validate_builder {
         crate::impl_validate_builder!($(#[$meta])* impl $t; $inner ValidateByVal);
     };
 
+    ($(#[$meta:meta])+ with suggestion $t:ty; $inner:ident) => {
+        crate::impl_validate_builder!($(#[$meta])* suggest &$t; $inner ValidateSuggest);
+    };
+
+    ($(#[$meta:meta])+ with suggestion by val $t:ty; $inner:ident) => {
+        crate::impl_validate_builder!($(#[$meta])* suggest $t; $inner ValidateSuggestByVal);
+    };
+
     // NOTE: the 2 extra lines at the end of each doc comment is intentional -- it makes sure that
     // other docs that come from the macro invocation have appropriate spacing
     ($(#[$meta:meta])+ impl $t:ty; $inner:ident $handler:ident) => {
@@ -99,6 +108,57 @@ macro_rules! impl_validate_builder {
             self
         }
     };
+
+    // NOTE: the 2 extra lines at the end of each doc comment is intentional -- it makes sure that
+    // other docs that come from the macro invocation have appropriate spacing
+    ($(#[$meta:meta])+ suggest $t:ty; $inner:ident $handler:ident) => {
+        /// Function to validate the submitted value before it's returned.
+        ///
+        /// It is a [`FnMut`] that is given the answer and the previous [`Answers`], and should
+        /// return `Ok(())` if the given answer is valid. If it is invalid, it should return an
+        /// [`Err`] with the error message to display to the user.
+        ///
+        /// This will be called when the user presses the `Enter` key.
+        ///
+        /// To also suggest an answer to the user, use [`validate_with_suggestion`] instead.
+        ///
+        /// [`Answers`]: crate::Answers
+        /// [`validate_with_suggestion`]: Self::validate_with_suggestion
+        ///
+        ///
+        $(#[$meta])*
+        pub fn validate<F>(mut self, mut validate: F) -> Self
+        where
+            F: FnMut($t, &crate::Answers) -> Result<(), String> + 'a,
+        {
+            self.$inner.validate = crate::question::$handler::Sync(Box::new(move |t, answers| {
+                validate(t, answers).map_err(crate::question::ValidationError::from)
+            }));
+            self
+        }
+
+        /// Function to validate the submitted value before it's returned, which can suggest an
+        /// answer to use instead.
+        ///
+        /// It is like [`validate`], except that it returns a [`ValidationError`] if the answer
+        /// is invalid. If the error has a suggestion, the user can press `Tab` to replace what
+        /// they typed with it.
+        ///
+        /// Only one of `validate` and `validate_with_suggestion` is used, whichever is called
+        /// last.
+        ///
+        /// See [`ValidationError`] for an example.
+        ///
+        /// [`validate`]: Self::validate
+        /// [`ValidationError`]: crate::question::ValidationError
+        pub fn validate_with_suggestion<F>(mut self, validate: F) -> Self
+        where
+            F: FnMut($t, &crate::Answers) -> Result<(), crate::question::ValidationError> + 'a,
+        {
+            self.$inner.validate = crate::question::$handler::Sync(Box::new(validate));
+            self
+        }
+    };
 }
 
 #[doc(hidden)]
@@ -160,10 +220,12 @@ macro_rules! impl_transform_builder {
         /// It is a [`FnOnce`] that is given the answer, previous [`Answers`] and the [`Backend`] to
         /// display the answer on. After the `transform` is called, a new line is also added.
         ///
-        /// It will only be called once the user finishes answering the question.
+        /// It will only be called once the user finishes answering the question. If there is no
+        /// `transform`, the answer is shown in the [`answer`] style of the current theme.
         ///
         /// [`Answers`]: crate::Answers
         /// [`Backend`]: crate::prompt::Backend
+        /// [`answer`]: crate::theme::Theme::answer
         ///
         ///
         $(#[$meta])*
@@ -177,6 +239,41 @@ macro_rules! impl_transform_builder {
     };
 }
 
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_hint_builder {
+    // NOTE: the 2 extra lines at the end of each doc comment is intentional -- it makes sure that
+    // other docs that come from the macro invocation have appropriate spacing
+    (hint $(#[$hint_meta:meta])+ no_hint $(#[$no_hint_meta:meta])+ $inner:ident) => {
+        /// The hint shown next to the message, instead of the default hint of the prompt.
+        ///
+        /// An empty hint is not shown, the same as [`no_hint`]. If it is not given, the default
+        /// hint of the prompt is shown.
+        ///
+        /// [`no_hint`]: Self::no_hint
+        ///
+        ///
+        $(#[$hint_meta])+
+        pub fn hint<H: Into<String>>(mut self, hint: H) -> Self {
+            self.$inner.hint = crate::question::Hint::Custom(hint.into());
+            self
+        }
+
+        /// Do not show a hint next to the message.
+        ///
+        /// This overrides any previous call to [`hint`], and vice versa.
+        ///
+        /// [`hint`]: Self::hint
+        ///
+        ///
+        $(#[$no_hint_meta])+
+        pub fn no_hint(mut self) -> Self {
+            self.$inner.hint = crate::question::Hint::Hidden;
+            self
+        }
+    };
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! write_final {
@@ -187,7 +284,21 @@ macro_rules! write_final {
         // $tt is not there
         match (&$ans, $transform) {
             (&Some($($tt)? ans), Transform::Sync(transform)) => transform(ans, $answers, $backend)?,
-            (&Some($($tt)? $ident), _) => $custom,
+            (&Some($($tt)? $ident), _) => {
+                // Without a transform, the answer is written in the answer style of the theme
+                let theme = ui::theme::current();
+                $backend.set_fg(theme.answer)?;
+                if !theme.answer_attributes.is_empty() {
+                    $backend.set_attributes(theme.answer_attributes)?;
+                }
+
+                $custom;
+
+                $backend.set_fg(ui::style::Color::Reset)?;
+                if !theme.answer_attributes.is_empty() {
+                    $backend.set_attributes(ui::style::Attributes::empty())?;
+                }
+            }
             (None, _) => {
                 $backend.write_styled(&ui::style::Stylize::dark_grey("Skipped"))?;
             }
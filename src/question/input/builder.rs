@@ -1,7 +1,9 @@
+use std::time::Duration;
+
 use ui::backend::Backend;
 
 use super::Input;
-use crate::question::{Completions, Options};
+use crate::question::{options::Getter, Completions, Options};
 
 /// The builder for an [`input`] prompt.
 ///
@@ -110,6 +112,88 @@ impl<'a> InputBuilder<'a> {
         let default = default.into();
         let len = default.chars().count();
         self.input.default = Some((default, len));
+        self.input.default_fn = None;
+        self
+    }
+
+    /// Set a default value for the input, which is computed from the previous answers
+    ///
+    /// It is a [`FnOnce`] that is given the previous [`Answers`], and will be called right before
+    /// the question is prompted to the user. The value it returns is used like one given to
+    /// [`default`].
+    ///
+    /// This overrides any previous call to [`default`], and vice versa.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let input = Question::input("path")
+    ///     .default_fn(|previous_answers| {
+    ///         let name = previous_answers
+    ///             .get("name")
+    ///             .and_then(|name| name.as_string())
+    ///             .unwrap_or("project");
+    ///         format!("./{}", name)
+    ///     })
+    ///     .build();
+    /// ```
+    ///
+    /// [`Answers`]: crate::Answers
+    /// [`default`]: InputBuilder::default
+    pub fn default_fn<F>(mut self, default_fn: F) -> Self
+    where
+        F: FnOnce(&crate::Answers) -> String + 'a,
+    {
+        self.input.default = None;
+        self.input.default_fn = Some(Getter::Function(Box::new(default_fn)));
+        self
+    }
+
+    /// Set the text the input starts with
+    ///
+    /// Unlike [`default`], the text is put in the input where the user can edit it, with the
+    /// cursor at its end. The answer is whatever the input contains when the user presses `Enter`,
+    /// and it is validated like any other typed text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let input = Question::input("name")
+    ///     .initial("John Doe")
+    ///     .build();
+    /// ```
+    ///
+    /// [`default`]: InputBuilder::default
+    pub fn initial<I: Into<String>>(mut self, initial: I) -> Self {
+        self.input.initial = Some(initial.into());
+        self
+    }
+
+    /// Set previous entries which the user can recall
+    ///
+    /// The entries should be ordered from oldest to newest. Pressing `Up` replaces the input with
+    /// the previous entry, and `Down` with the next one. Moving down past the newest entry restores
+    /// the text that was being typed before.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let input = Question::input("command")
+    ///     .history(vec!["ls".to_owned(), "cd src".to_owned()])
+    ///     .build();
+    /// ```
+    pub fn history<I>(mut self, history: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.input.history = history.into_iter().map(Into::into).collect();
         self
     }
 
@@ -182,6 +266,130 @@ impl<'a> InputBuilder<'a> {
         self
     }
 
+    /// The maximum length of the answer.
+    ///
+    /// Once the answer is this long, typing more is ignored. The length is counted in graphemes,
+    /// which are the characters as they are seen by the user. For example, an `e` followed by a
+    /// combining accent is a single grapheme, even though it is made of 2 [`char`]s.
+    ///
+    /// Neither the [`default`] nor the [`initial`] value is limited.
+    ///
+    /// [`default`]: InputBuilder::default
+    /// [`initial`]: InputBuilder::initial
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let input = Question::input("status")
+    ///     .max_length(280)
+    ///     .build();
+    /// ```
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.input.max_length = Some(max_length);
+        self
+    }
+
+    /// Whether to show the length of the answer in the hint as it is typed.
+    ///
+    /// The hint is `42 chars`, or `42/280 chars` if there is a [`max_length`]. Like the
+    /// `max_length`, the length is counted in graphemes.
+    ///
+    /// If `show_count` is not set, it will default to `false`.
+    ///
+    /// [`max_length`]: InputBuilder::max_length
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let input = Question::input("status")
+    ///     .max_length(280)
+    ///     .show_count(true)
+    ///     .build();
+    /// ```
+    pub fn show_count(mut self, show_count: bool) -> Self {
+        self.input.show_count = show_count;
+        self
+    }
+
+    /// Whether the user can undo their edits with `Ctrl+Z`, and redo them with `Ctrl+Y`.
+    ///
+    /// A run of typed characters is undone at once, while every other edit, such as deleting a
+    /// word or pasting, is undone on its own. The [`initial`] value cannot be undone.
+    ///
+    /// If `undo` is not set, it will default to `false`.
+    ///
+    /// [`initial`]: InputBuilder::initial
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let input = Question::input("name")
+    ///     .undo(true)
+    ///     .build();
+    /// ```
+    pub fn undo(mut self, undo: bool) -> Self {
+        self.input.undo = undo;
+        self
+    }
+
+    /// Whether to remove whitespace from the start and end of the answer.
+    ///
+    /// The answer is trimmed before [`collapse_whitespace`], and both happen before the [`filter`]
+    /// is called and the answer is shown with [`transform`]. If the trimmed answer is empty, the
+    /// [`default`] is used. Validation is given the input as it was typed.
+    ///
+    /// If `trim` is not set, it will default to `false`.
+    ///
+    /// [`collapse_whitespace`]: InputBuilder::collapse_whitespace
+    /// [`filter`]: InputBuilder::filter
+    /// [`transform`]: InputBuilder::transform
+    /// [`default`]: InputBuilder::default
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let input = Question::input("name")
+    ///     .trim(true)
+    ///     .build();
+    /// ```
+    pub fn trim(mut self, trim: bool) -> Self {
+        self.input.trim = trim;
+        self
+    }
+
+    /// Whether to replace every run of whitespace in the answer with a single space.
+    ///
+    /// Whitespace at the start and end of the answer is also collapsed, so this is usually used
+    /// along with [`trim`]. Like `trim`, this happens before the [`filter`] is called.
+    ///
+    /// If `collapse_whitespace` is not set, it will default to `false`.
+    ///
+    /// [`trim`]: InputBuilder::trim
+    /// [`filter`]: InputBuilder::filter
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let input = Question::input("full_name")
+    ///     .trim(true)
+    ///     .collapse_whitespace(true)
+    ///     .build();
+    /// ```
+    pub fn collapse_whitespace(mut self, collapse_whitespace: bool) -> Self {
+        self.input.collapse_whitespace = collapse_whitespace;
+        self
+    }
+
     crate::impl_filter_builder! {
     /// # Examples
     ///
@@ -209,7 +417,7 @@ impl<'a> InputBuilder<'a> {
     ///     })
     ///     .build();
     /// ```
-    str; input
+    with suggestion str; input
     }
 
     crate::impl_validate_on_key_builder! {
@@ -235,6 +443,44 @@ impl<'a> InputBuilder<'a> {
     str; input
     }
 
+    /// Wait for the user to stop typing before calling [`validate_on_key`].
+    ///
+    /// The validation on key is only called once no key has been pressed for `debounce`, which
+    /// helps when it is expensive, for example if it makes a network request. Until then, the
+    /// input keeps the colour from the last validation.
+    ///
+    /// Pressing `Enter` is not debounced. The final validation given to [`validate`] is always
+    /// called immediately, and the answer is submitted if it passes, even if the validation on key
+    /// has not run yet.
+    ///
+    /// Waiting requires an [`EventIterator`] which supports [`next_event_timeout`]. Otherwise, the
+    /// validation on key is called once all the keys which are already available have been
+    /// handled, which is usually after every key.
+    ///
+    /// If `validate_debounce` is not set, the validation on key is called after every key.
+    ///
+    /// [`validate_on_key`]: Self::validate_on_key
+    /// [`validate`]: Self::validate
+    /// [`EventIterator`]: crate::prompt::EventIterator
+    /// [`next_event_timeout`]: crate::prompt::EventIterator::next_event_timeout
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use requestty::Question;
+    ///
+    /// # fn is_available(name: &str) -> bool { true }
+    /// let input = Question::input("username")
+    ///     .validate_on_key(|name, previous_answers| is_available(name))
+    ///     .validate_debounce(Duration::from_millis(300))
+    ///     .build();
+    /// ```
+    pub fn validate_debounce(mut self, debounce: Duration) -> Self {
+        self.input.validate_debounce = Some(debounce);
+        self
+    }
+
     crate::impl_transform_builder! {
     /// # Examples
     ///
@@ -1,18 +1,25 @@
-use std::io;
+use std::{
+    io,
+    time::{Duration, Instant},
+};
 
 use ui::{
     backend::Backend,
     events::{EventIterator, KeyCode, KeyEvent},
-    style::Stylize,
+    style::{StyledStr, StyledString},
     widgets, Prompt, Validation, Widget,
 };
 
-use super::{AutoComplete, ChoiceList, Filter, Transform, Validate, ValidateOnKey};
+use super::{
+    options::Getter, AutoComplete, ChoiceList, Filter, Transform, ValidateOnKey, ValidateSuggest,
+};
 use crate::{Answer, Answers};
 
 pub use builder::InputBuilder;
+pub use path::PathBuilder;
 
 mod builder;
+mod path;
 
 #[cfg(test)]
 mod tests;
@@ -20,26 +27,46 @@ mod tests;
 #[derive(Debug)]
 pub(super) struct Input<'a> {
     default: Option<(String, usize)>,
+    /// Computes the `default` from the previous answers right before the question is asked.
+    default_fn: Option<Getter<'a, String>>,
+    initial: Option<String>,
+    history: Vec<String>,
     filter: Filter<'a, String>,
-    validate: Validate<'a, str>,
+    validate: ValidateSuggest<'a, str>,
     validate_on_key: ValidateOnKey<'a, str>,
+    /// How long the user must stop typing for before `validate_on_key` is called.
+    validate_debounce: Option<Duration>,
     transform: Transform<'a, str>,
     auto_complete: AutoComplete<'a, String>,
     page_size: usize,
     should_loop: bool,
+    max_length: Option<usize>,
+    show_count: bool,
+    undo: bool,
+    trim: bool,
+    collapse_whitespace: bool,
 }
 
 impl<'a> Default for Input<'a> {
     fn default() -> Self {
         Self {
             default: None,
+            default_fn: None,
+            initial: None,
+            history: Vec::new(),
             filter: Filter::None,
-            validate: Validate::None,
+            validate: ValidateSuggest::None,
             validate_on_key: ValidateOnKey::None,
+            validate_debounce: None,
             transform: Transform::None,
             auto_complete: AutoComplete::None,
             page_size: 15,
             should_loop: true,
+            max_length: None,
+            show_count: false,
+            undo: false,
+            trim: false,
+            collapse_whitespace: false,
         }
     }
 }
@@ -47,14 +74,20 @@ impl<'a> Default for Input<'a> {
 type CompletionSelector = widgets::Select<ChoiceList<widgets::Text<String>>>;
 
 struct InputPrompt<'i, 'a> {
-    prompt: widgets::Prompt<&'a str, String>,
+    prompt: widgets::Prompt<StyledStr<'a>, String>,
     input_opts: Input<'i>,
     input: widgets::StringInput,
     /// When the select is Some, then currently the user is selecting from the
     /// auto complete options. The select must not be used directly, and instead by used
     /// through `select`. See `select_op`s documentation for more.
     select: Option<CompletionSelector>,
+    /// The answer suggested by the last failed validation, which is used if the user presses
+    /// `Tab`. It is forgotten once the input is changed.
+    suggestion: Option<String>,
     is_valid: bool,
+    /// When the input was last changed, if the validation on key is waiting for the user to stop
+    /// typing.
+    changed_at: Option<Instant>,
     answers: &'a Answers,
 }
 
@@ -96,6 +129,38 @@ impl InputPrompt<'_, '_> {
         None
     }
 
+    /// Shows the length of the input in the hint, if [`show_count`](InputBuilder::show_count) is
+    /// set.
+    fn update_count(&mut self) {
+        if !self.input_opts.show_count {
+            return;
+        }
+
+        let len = self.input.grapheme_len();
+        let count = match self.input_opts.max_length {
+            Some(max_length) => format!("{}/{} chars", len, max_length),
+            None => format!("{} chars", len),
+        };
+
+        self.prompt = widgets::Prompt::new(*self.prompt.message()).with_hint(count);
+    }
+
+    /// Calls the validation on key for the changed input, unless it is debounced, in which case it
+    /// is called once the user stops typing.
+    fn input_changed(&mut self) {
+        if self.input_opts.validate_debounce.is_some() {
+            self.changed_at = Some(Instant::now());
+        } else {
+            self.validate_on_key();
+        }
+    }
+
+    fn validate_on_key(&mut self) {
+        if let ValidateOnKey::Sync(ref mut validate) = self.input_opts.validate_on_key {
+            self.is_valid = validate(self.input.value(), self.answers);
+        }
+    }
+
     fn check_complete_default(&mut self) -> bool {
         if self.get_remaining_default().is_some() {
             let (default, default_len) = self.input_opts.default.as_ref().unwrap();
@@ -108,6 +173,117 @@ impl InputPrompt<'_, '_> {
             false
         }
     }
+
+    /// Handles a key, without updating the count. Returns whether the key was handled.
+    fn handle_input_key(&mut self, mut key: KeyEvent) -> bool {
+        if key.code == KeyCode::Tab {
+            if let Some(suggestion) = self.suggestion.take() {
+                let len = suggestion.chars().count();
+                self.input.set_value(suggestion);
+                self.input.set_at(len);
+                self.input_changed();
+
+                return true;
+            } else if let AutoComplete::Sync(ref mut ac) = self.input_opts.auto_complete {
+                if self.select.is_some() {
+                    key.code = KeyCode::Down;
+                } else {
+                    let page_size = self.input_opts.page_size;
+                    let should_loop = self.input_opts.should_loop;
+
+                    let Self {
+                        input,
+                        answers,
+                        select,
+                        ..
+                    } = self;
+
+                    input.replace_with(|s| {
+                        let mut completions = ac(s.clone(), answers);
+                        assert!(!completions.is_empty());
+                        if completions.len() == 1 {
+                            return completions.pop().unwrap();
+                        }
+
+                        let prefix = common_prefix(&completions);
+                        if prefix.len() > s.len() && prefix.starts_with(&s) {
+                            // Extend the answer as far as all the completions agree, and only show
+                            // them once there is nothing more to complete
+                            prefix.to_owned()
+                        } else {
+                            let res = std::mem::take(&mut completions[0]);
+
+                            let mut choices: ChoiceList<_> =
+                                completions.into_iter().map(widgets::Text::new).collect();
+                            choices.set_page_size(page_size);
+                            choices.set_should_loop(should_loop);
+
+                            *select = Some(widgets::Select::new(choices));
+
+                            res
+                        }
+                    });
+                    return true;
+                }
+            } else if self.check_complete_default() {
+                return true;
+            }
+        }
+
+        // While picking a completion, `Up` and `Down` move through the completions instead of the
+        // history
+        let picking = self.select.is_some() && matches!(key.code, KeyCode::Up | KeyCode::Down);
+
+        if !picking && self.input.handle_key(key) {
+            self.input_changed();
+
+            self.select = None;
+            self.suggestion = None;
+            return true;
+        } else if key.code == KeyCode::Right && self.check_complete_default() {
+            return true;
+        }
+
+        self.maybe_select_op(|select| select.handle_key(key))
+            .unwrap_or(false)
+    }
+}
+
+/// Replaces every run of whitespace with a single space.
+fn collapse_whitespace(s: &str) -> String {
+    let mut collapsed = String::with_capacity(s.len());
+    let mut in_whitespace = false;
+
+    for c in s.chars() {
+        if !c.is_whitespace() {
+            collapsed.push(c);
+            in_whitespace = false;
+        } else if !in_whitespace {
+            collapsed.push(' ');
+            in_whitespace = true;
+        }
+    }
+
+    collapsed
+}
+
+/// The longest prefix shared by all the completions. Characters which only differ in case are
+/// considered different, so that the case of what the user typed is never changed.
+fn common_prefix(completions: &[String]) -> &str {
+    let first = &completions[0];
+
+    let len = completions[1..]
+        .iter()
+        .fold(first.len(), |len, completion| {
+            first[..len]
+                .char_indices()
+                .zip(completion.chars())
+                .find(|&((_, a), b)| a != b)
+                .map(|((i, _), _)| i)
+                .unwrap_or_else(|| len.min(completion.len()))
+        });
+
+    &first[..len]
 }
 
 impl Widget for InputPrompt<'_, '_> {
@@ -160,7 +336,7 @@ impl Widget for InputPrompt<'_, '_> {
                 height += 1;
             }
         } else {
-            height = self.input.height(layout);
+            height += self.input.height(layout);
         }
 
         if let Some(picker_height) = self.maybe_select_op(|select| select.height(layout)) {
@@ -169,60 +345,12 @@ impl Widget for InputPrompt<'_, '_> {
         height
     }
 
-    fn handle_key(&mut self, mut key: KeyEvent) -> bool {
-        if key.code == KeyCode::Tab {
-            if let AutoComplete::Sync(ref mut ac) = self.input_opts.auto_complete {
-                if self.select.is_some() {
-                    key.code = KeyCode::Down;
-                } else {
-                    let page_size = self.input_opts.page_size;
-                    let should_loop = self.input_opts.should_loop;
-
-                    let Self {
-                        input,
-                        answers,
-                        select,
-                        ..
-                    } = self;
-
-                    input.replace_with(|s| {
-                        let mut completions = ac(s, answers);
-                        assert!(!completions.is_empty());
-                        if completions.len() == 1 {
-                            completions.pop().unwrap()
-                        } else {
-                            let res = std::mem::take(&mut completions[0]);
-
-                            let mut choices: ChoiceList<_> =
-                                completions.into_iter().map(widgets::Text::new).collect();
-                            choices.set_page_size(page_size);
-                            choices.set_should_loop(should_loop);
-
-                            *select = Some(widgets::Select::new(choices));
-
-                            res
-                        }
-                    });
-                    return true;
-                }
-            } else if self.check_complete_default() {
-                return true;
-            }
-        }
-
-        if self.input.handle_key(key) {
-            if let ValidateOnKey::Sync(ref mut validate) = self.input_opts.validate_on_key {
-                self.is_valid = validate(self.input.value(), self.answers);
-            }
-
-            self.select = None;
-            return true;
-        } else if key.code == KeyCode::Right && self.check_complete_default() {
-            return true;
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        let handled = self.handle_input_key(key);
+        if handled {
+            self.update_count();
         }
-
-        self.maybe_select_op(|select| select.handle_key(key))
-            .unwrap_or(false)
+        handled
     }
 
     fn cursor_pos(&mut self, layout: ui::layout::Layout) -> (u16, u16) {
@@ -238,6 +366,14 @@ impl Prompt for InputPrompt<'_, '_> {
     fn finish(self) -> Self::Output {
         let mut ans = self.input.finish();
 
+        if self.input_opts.trim {
+            ans = ans.trim().to_owned();
+        }
+
+        if self.input_opts.collapse_whitespace {
+            ans = collapse_whitespace(&ans);
+        }
+
         if ans.is_empty() {
             if let Some((default, _)) = self.input_opts.default {
                 ans = default;
@@ -261,30 +397,92 @@ impl Prompt for InputPrompt<'_, '_> {
             return Ok(Validation::Finish);
         }
 
-        if let Validate::Sync(ref mut validate) = self.input_opts.validate {
-            validate(self.input.value(), self.answers)?;
+        if let ValidateSuggest::Sync(ref mut validate) = self.input_opts.validate {
+            if let Err(e) = validate(self.input.value(), self.answers) {
+                let (text, suggestion) = e.into_parts();
+                self.suggestion = suggestion;
+                return Err(text);
+            }
         }
 
         Ok(Validation::Finish)
     }
+
+    fn timeout(&self) -> Option<Duration> {
+        let debounce = self.input_opts.validate_debounce?;
+        let changed_at = self.changed_at?;
+        Some(debounce.saturating_sub(changed_at.elapsed()))
+    }
+
+    fn handle_timeout(&mut self) -> bool {
+        if self.changed_at.take().is_some() {
+            self.validate_on_key();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn has_default(&self) -> bool {
+        self.input_opts.default.is_some()
+    }
 }
 
 impl<'i> Input<'i> {
-    fn into_input_prompt<'a>(self, message: &'a str, answers: &'a Answers) -> InputPrompt<'i, 'a> {
-        InputPrompt {
-            prompt: widgets::Prompt::new(message),
+    /// Replaces the `default` with the one computed by `default_fn`, if there is one.
+    fn compute_default(&mut self, answers: &Answers) {
+        if let Some(default_fn) = self.default_fn.take() {
+            let default = default_fn.get(answers);
+            let len = default.chars().count();
+            self.default = Some((default, len));
+        }
+    }
+
+    fn into_input_prompt<'a>(
+        mut self,
+        message: impl Into<StyledStr<'a>>,
+        answers: &'a Answers,
+    ) -> InputPrompt<'i, 'a> {
+        self.compute_default(answers);
+
+        let mut input =
+            widgets::StringInput::default().with_history(std::mem::take(&mut self.history));
+        if let Some(max_length) = self.max_length {
+            input = input.max_length(max_length);
+        }
+        if self.undo {
+            input = input.with_undo();
+        }
+        let mut is_valid = true;
+
+        if let Some(initial) = self.initial.take() {
+            if let ValidateOnKey::Sync(ref mut validate) = self.validate_on_key {
+                is_valid = validate(&initial, answers);
+            }
+
+            let len = initial.chars().count();
+            input.set_value(initial);
+            input.set_at(len);
+        }
+
+        let mut prompt = InputPrompt {
+            prompt: widgets::Prompt::new(message.into()),
             input_opts: self,
-            input: widgets::StringInput::default(),
+            input,
             select: None,
-            is_valid: true,
+            suggestion: None,
+            is_valid,
+            changed_at: None,
             answers,
-        }
+        };
+        prompt.update_count();
+        prompt
     }
 
     pub(crate) fn ask<B: Backend, E: EventIterator>(
         mut self,
-        message: String,
-        on_esc: ui::OnEsc,
+        message: StyledString,
+        input_opts: super::InputOptions<'_>,
         answers: &Answers,
         b: &mut B,
         events: &mut E,
@@ -292,10 +490,28 @@ impl<'i> Input<'i> {
         let transform = self.transform.take();
 
         let ans = ui::Input::new(self.into_input_prompt(&message, answers), b)
-            .on_esc(on_esc)
+            .on_esc(input_opts.on_esc)
+            .timeout(input_opts.timeout)
+            .on_validation_failed(input_opts.on_validation_failed)
             .run(events)?;
 
         crate::write_final!(transform, message, ans [ref], answers, b, |ans| b
-            .write_styled(&ans.as_str().cyan())?)
+            .write_all(ans.as_bytes())?)
+    }
+
+    pub(super) fn answer_non_interactive(
+        mut self,
+        answer: Option<Answer>,
+        answers: &Answers,
+    ) -> Result<Answer, super::Rejected> {
+        self.compute_default(answers);
+
+        super::answer_string(
+            answer,
+            self.default.map(|(default, _)| default),
+            self.validate.without_suggestion(),
+            self.filter,
+            answers,
+        )
     }
 }
@@ -0,0 +1,429 @@
+use std::path::{Path, MAIN_SEPARATOR};
+
+use ui::backend::Backend;
+
+use super::Input;
+use crate::question::{AutoComplete, Completions, Options, ValidateSuggest};
+
+/// The builder for a [`path`] prompt.
+///
+/// It is an [`input`] prompt which completes the names of files and directories when `Tab` is
+/// pressed. The answer is returned as a [`String`].
+///
+/// See the various methods for more details on each available option.
+///
+/// # Examples
+///
+/// ```
+/// use requestty::Question;
+///
+/// let path = Question::path("config")
+///     .message("Where is the config file?")
+///     .must_exist(true)
+///     .build();
+/// ```
+///
+/// [`path`]: crate::question::Question::path
+/// [`input`]: crate::question::Question::input
+#[derive(Debug)]
+pub struct PathBuilder<'a> {
+    opts: Options<'a>,
+    input: Input<'a>,
+    must_exist: bool,
+    directory_only: bool,
+}
+
+impl<'a> PathBuilder<'a> {
+    pub(crate) fn new(name: String) -> Self {
+        PathBuilder {
+            opts: Options::new(name),
+            input: Default::default(),
+            must_exist: false,
+            directory_only: false,
+        }
+    }
+
+    crate::impl_options_builder! {
+    message
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let path = Question::path("config")
+    ///     .message("Where is the config file?")
+    ///     .build();
+    /// ```
+
+    when
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, Answers};
+    ///
+    /// let path = Question::path("config")
+    ///     .when(|previous_answers: &Answers| match previous_answers.get("use-default-config") {
+    ///         Some(ans) => !ans.as_bool().unwrap(),
+    ///         None => true,
+    ///     })
+    ///     .build();
+    /// ```
+
+    ask_if_answered
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, Answers};
+    ///
+    /// let path = Question::path("config")
+    ///     .ask_if_answered(true)
+    ///     .build();
+    /// ```
+
+    on_esc
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, Answers, OnEsc};
+    ///
+    /// let path = Question::path("config")
+    ///     .on_esc(OnEsc::Terminate)
+    ///     .build();
+    /// ```
+    }
+
+    /// Set a default value for the path
+    ///
+    /// If set and the user presses `Enter` without typing any text, the `default` is taken as the
+    /// answer.
+    ///
+    /// If `default` is used, validation is skipped, but `filter` is still called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let path = Question::path("config")
+    ///     .default("config.toml")
+    ///     .build();
+    /// ```
+    pub fn default<I: Into<String>>(mut self, default: I) -> Self {
+        let default = default.into();
+        let len = default.chars().count();
+        self.input.default = Some((default, len));
+        self
+    }
+
+    /// Whether the path must exist for the answer to be accepted.
+    ///
+    /// If `must_exist` is not set, it will default to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let path = Question::path("config")
+    ///     .must_exist(true)
+    ///     .build();
+    /// ```
+    pub fn must_exist(mut self, must_exist: bool) -> Self {
+        self.must_exist = must_exist;
+        self
+    }
+
+    /// Whether only directories can be picked.
+    ///
+    /// If `directory_only` is `true`, files are not completed, and a path which exists but is not a
+    /// directory is not accepted.
+    ///
+    /// If `directory_only` is not set, it will default to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let path = Question::path("out-dir")
+    ///     .directory_only(true)
+    ///     .build();
+    /// ```
+    pub fn directory_only(mut self, directory_only: bool) -> Self {
+        self.directory_only = directory_only;
+        self
+    }
+
+    /// The maximum height that can be taken by the list of completions
+    ///
+    /// If the total height exceeds the page size, the list will be scrollable.
+    ///
+    /// The `page_size` must be a minimum of 5. If `page_size` is not set, it will default to 15.
+    ///
+    /// # Panics
+    ///
+    /// It will panic if the `page_size` is less than 5.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let path = Question::path("config")
+    ///     .page_size(10)
+    ///     .build();
+    /// ```
+    pub fn page_size(mut self, page_size: usize) -> Self {
+        assert!(page_size >= 5, "page size can be a minimum of 5");
+
+        self.input.page_size = page_size;
+        self
+    }
+
+    /// Whether to wrap around when user gets to the last completion.
+    ///
+    /// If `should_loop` is not set, it will default to `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let path = Question::path("config")
+    ///     .should_loop(false)
+    ///     .build();
+    /// ```
+    pub fn should_loop(mut self, should_loop: bool) -> Self {
+        self.input.should_loop = should_loop;
+        self
+    }
+
+    crate::impl_filter_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let path = Question::path("config")
+    ///     .filter(|path, previous_answers| path.trim_end_matches('/').to_owned())
+    ///     .build();
+    /// ```
+    String; input
+    }
+
+    crate::impl_validate_builder! {
+    /// This is called after checking [`must_exist`] and [`directory_only`].
+    ///
+    /// [`must_exist`]: PathBuilder::must_exist
+    /// [`directory_only`]: PathBuilder::directory_only
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let path = Question::path("config")
+    ///     .validate(|path, previous_answers| if path.ends_with(".toml") {
+    ///         Ok(())
+    ///     } else {
+    ///         Err("The config must be a toml file".to_owned())
+    ///     })
+    ///     .build();
+    /// ```
+    with suggestion str; input
+    }
+
+    crate::impl_transform_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let path = Question::path("config")
+    ///     .transform(|path, previous_answers, backend| {
+    ///         write!(backend, "Using {}", path)
+    ///     })
+    ///     .build();
+    /// ```
+    str; input
+    }
+
+    /// Consumes the builder returning a [`Question`]
+    ///
+    /// [`Question`]: crate::question::Question
+    pub fn build(mut self) -> crate::question::Question<'a> {
+        let must_exist = self.must_exist;
+        let directory_only = self.directory_only;
+
+        self.input.auto_complete =
+            AutoComplete::Sync(Box::new(move |path, _| complete(path, directory_only)));
+
+        let mut validate = self.input.validate.take();
+        self.input.validate = ValidateSuggest::Sync(Box::new(move |path, answers| {
+            check_path(path, must_exist, directory_only)?;
+
+            match validate {
+                ValidateSuggest::Sync(ref mut validate) => validate(path, answers),
+                ValidateSuggest::None => Ok(()),
+            }
+        }));
+
+        crate::question::Question::new(self.opts, crate::question::QuestionKind::Input(self.input))
+    }
+}
+
+impl<'a> From<PathBuilder<'a>> for crate::question::Question<'a> {
+    /// Consumes the builder returning a [`Question`]
+    ///
+    /// [`Question`]: crate::question::Question
+    fn from(builder: PathBuilder<'a>) -> Self {
+        builder.build()
+    }
+}
+
+fn is_separator(c: char) -> bool {
+    c == '/' || c == MAIN_SEPARATOR
+}
+
+fn check_path(path: &str, must_exist: bool, directory_only: bool) -> Result<(), String> {
+    let p = Path::new(path);
+
+    if !p.exists() {
+        if must_exist {
+            return Err(format!("`{}` does not exist", path));
+        }
+    } else if directory_only && !p.is_dir() {
+        return Err(format!("`{}` is not a directory", path));
+    }
+
+    Ok(())
+}
+
+/// Completes the last component of the path with the entries of its directory. Directories are
+/// completed with a trailing separator, so that their entries can be completed next.
+///
+/// Hidden entries are only completed if the last component starts with a `.`. If there is nothing
+/// to complete, the path is returned as is.
+fn complete(path: String, directory_only: bool) -> Completions<String> {
+    let split = path.rfind(is_separator).map(|i| i + 1).unwrap_or(0);
+    let (dir, prefix) = path.split_at(split);
+
+    let entries = match Path::new(if dir.is_empty() { "." } else { dir }).read_dir() {
+        Ok(entries) => entries,
+        Err(_) => return crate::question::completions![path],
+    };
+
+    let mut completions: Completions<String> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+            if !name.starts_with(prefix) || (name.starts_with('.') && !prefix.starts_with('.')) {
+                return None;
+            }
+
+            // Follow symlinks, so that links to directories are completed like directories
+            let is_dir = entry.path().is_dir();
+            if directory_only && !is_dir {
+                return None;
+            }
+
+            let mut completion = format!("{}{}", dir, name);
+            if is_dir {
+                completion.push(MAIN_SEPARATOR);
+            }
+            Some(completion)
+        })
+        .collect();
+
+    if completions.is_empty() {
+        return crate::question::completions![path];
+    }
+
+    completions.sort();
+    completions
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    fn dir() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("src")).unwrap();
+        fs::create_dir(dir.path().join("scripts")).unwrap();
+        fs::write(dir.path().join("setup.sh"), "").unwrap();
+        fs::write(dir.path().join("Cargo.toml"), "").unwrap();
+        fs::write(dir.path().join(".secret"), "").unwrap();
+        dir
+    }
+
+    fn complete_in(dir: &Path, path: &str, directory_only: bool) -> Vec<String> {
+        let root = format!("{}{}", dir.display(), MAIN_SEPARATOR);
+        complete(format!("{}{}", root, path), directory_only)
+            .into_iter()
+            .map(|completion| completion[root.len()..].to_owned())
+            .collect()
+    }
+
+    #[test]
+    fn test_complete() {
+        let dir = dir();
+        let dir = dir.path();
+        let sep = MAIN_SEPARATOR;
+
+        assert_eq!(
+            complete_in(dir, "s", false),
+            [
+                format!("scripts{}", sep),
+                "setup.sh".into(),
+                format!("src{}", sep)
+            ]
+        );
+        assert_eq!(complete_in(dir, "Car", false), ["Cargo.toml"]);
+        assert_eq!(complete_in(dir, ".", false), [".secret"]);
+        assert_eq!(complete_in(dir, "", false).len(), 4);
+
+        // Nothing to complete
+        assert_eq!(complete_in(dir, "x", false), ["x"]);
+        assert_eq!(complete_in(dir, "missing/x", false), ["missing/x"]);
+    }
+
+    #[test]
+    fn test_complete_directory_only() {
+        let dir = dir();
+        let dir = dir.path();
+        let sep = MAIN_SEPARATOR;
+
+        assert_eq!(
+            complete_in(dir, "s", true),
+            [format!("scripts{}", sep), format!("src{}", sep)]
+        );
+        assert_eq!(complete_in(dir, "Car", true), ["Car"]);
+    }
+
+    #[test]
+    fn test_check_path() {
+        let dir = dir();
+        let file = dir.path().join("setup.sh");
+        let file = file.to_str().unwrap();
+        let missing = dir.path().join("missing");
+        let missing = missing.to_str().unwrap();
+        let dir = dir.path().to_str().unwrap();
+
+        assert!(check_path(missing, false, false).is_ok());
+        assert!(check_path(missing, false, true).is_ok());
+        assert!(check_path(file, true, false).is_ok());
+        assert!(check_path(dir, true, true).is_ok());
+
+        assert_eq!(
+            check_path(missing, true, false),
+            Err(format!("`{}` does not exist", missing))
+        );
+        assert_eq!(
+            check_path(file, false, true),
+            Err(format!("`{}` is not a directory", file))
+        );
+    }
+}
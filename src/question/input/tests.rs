@@ -1,7 +1,7 @@
-use ui::{backend::TestBackend, layout::Layout};
+use ui::{backend::TestBackend, events::KeyModifiers, layout::Layout};
 
 use super::*;
-use crate::question::Completions;
+use crate::question::{Completions, ValidationError};
 
 const NINPUTS: usize = 3;
 static INPUT_IDS: [&str; NINPUTS] = ["no_default", "default", "auto_complete"];
@@ -173,3 +173,378 @@ fn test_cursor_pos() {
 
     assert_eq!(prompt.cursor_pos(layout), (line_offset + 6, 0));
 }
+
+#[test]
+fn test_line_end() {
+    let size = (50, 20).into();
+    let base_layout = Layout::new(5, size);
+    let answers = Answers::default();
+
+    for (prompt, _) in inputs(&answers).iter_mut() {
+        // The value exactly fills the rest of the line
+        prompt.input.set_value("a".repeat(33));
+        prompt.input.set_at(32);
+        assert_eq!(prompt.cursor_pos(base_layout), (49, 0));
+
+        // The cursor moves to the next line, which is included in the height
+        prompt.input.set_at(33);
+        assert_eq!(prompt.cursor_pos(base_layout), (0, 1));
+
+        let mut layout = base_layout;
+        assert_eq!(prompt.height(&mut layout), 2);
+        assert_eq!(layout, base_layout.with_offset(0, 1).with_line_offset(0));
+    }
+
+    // The message wraps onto a second line
+    let message = "message ".repeat(6);
+    let mut prompt = Input::default().into_input_prompt(&message, &answers);
+    prompt.input.set_value("input".into());
+    prompt.input.set_at(5);
+
+    let mut layout = base_layout;
+    assert_eq!(prompt.height(&mut layout), 2);
+    assert_eq!(layout, base_layout.with_offset(0, 1).with_line_offset(13));
+    assert_eq!(prompt.cursor_pos(base_layout), (13, 1));
+}
+
+#[test]
+fn test_initial() {
+    let size = (50, 20).into();
+    let base_layout = Layout::new(5, size);
+    let answers = Answers::default();
+
+    let mut prompt = Input {
+        initial: Some("John Doe".into()),
+        validate: ValidateSuggest::Sync(Box::new(|name, _| {
+            if name.contains(' ') {
+                Ok(())
+            } else {
+                Err("Please enter your full name".into())
+            }
+        })),
+        validate_on_key: ValidateOnKey::Sync(Box::new(|name, _| name.contains(' '))),
+        ..Input::default()
+    }
+    .into_input_prompt("message", &answers);
+
+    assert_eq!(prompt.input.value(), "John Doe");
+    assert!(prompt.is_valid);
+    assert_eq!(prompt.cursor_pos(base_layout), (25, 0));
+
+    for _ in 0..4 {
+        assert!(prompt.handle_key(KeyCode::Backspace.into()));
+    }
+    assert!(!prompt.is_valid);
+    assert!(prompt.validate().is_err());
+
+    for c in " Roe".chars() {
+        assert!(prompt.handle_key(KeyCode::Char(c).into()));
+    }
+    assert!(prompt.is_valid);
+    assert!(matches!(prompt.validate(), Ok(Validation::Finish)));
+    assert_eq!(prompt.finish(), "John Roe");
+}
+
+#[test]
+fn test_history() {
+    let answers = Answers::default();
+
+    let mut prompt = Input {
+        history: vec!["first".into(), "second".into()],
+        auto_complete: AutoComplete::Sync(Box::new(|s, _| {
+            let mut completions = Completions::new();
+            completions.push(s.clone() + "a");
+            completions.push(s + "b");
+            completions
+        })),
+        ..Input::default()
+    }
+    .into_input_prompt("message", &answers);
+
+    assert!(prompt.handle_key(KeyCode::Up.into()));
+    assert_eq!(prompt.input.value(), "second");
+
+    // While picking a completion, `Up` and `Down` move through the completions
+    assert!(prompt.handle_key(KeyCode::Tab.into()));
+    assert_eq!(prompt.input.value(), "seconda");
+    assert!(prompt.handle_key(KeyCode::Down.into()));
+    assert_eq!(prompt.input.value(), "secondb");
+
+    assert!(prompt.handle_key(KeyCode::Char('c').into()));
+    assert!(prompt.handle_key(KeyCode::Up.into()));
+    assert_eq!(prompt.input.value(), "first");
+}
+
+#[test]
+fn test_auto_complete_common_prefix() {
+    let answers = Answers::default();
+
+    let mut prompt = Input {
+        auto_complete: AutoComplete::Sync(Box::new(|s, _| {
+            let completions: Completions<_> = ["apple", "apply", "applesauce", "Apple"]
+                .iter()
+                .filter(|c| c.starts_with(&s))
+                .map(|&c| c.to_owned())
+                .collect();
+
+            if completions.is_empty() {
+                Completions::from(vec![s])
+            } else {
+                completions
+            }
+        })),
+        ..Input::default()
+    }
+    .into_input_prompt("message", &answers);
+
+    prompt.input.set_value("a".into());
+    prompt.input.set_at(1);
+
+    // Extends to the common prefix without showing the completions
+    assert!(prompt.handle_key(KeyCode::Tab.into()));
+    assert_eq!(prompt.input.value(), "appl");
+    assert!(prompt.select.is_none());
+
+    // The prefix is fully typed, so the completions are shown
+    assert!(prompt.handle_key(KeyCode::Tab.into()));
+    assert_eq!(prompt.input.value(), "apple");
+    assert!(prompt.select.is_some());
+
+    // Completions which only differ in case do not share a prefix
+    let mut prompt = Input {
+        auto_complete: AutoComplete::Sync(Box::new(|_, _| {
+            Completions::from(vec!["Apple".to_owned(), "apple".to_owned()])
+        })),
+        ..Input::default()
+    }
+    .into_input_prompt("message", &answers);
+
+    assert!(prompt.handle_key(KeyCode::Tab.into()));
+    assert_eq!(prompt.input.value(), "Apple");
+    assert!(prompt.select.is_some());
+}
+
+#[test]
+fn test_common_prefix() {
+    let completions = |c: &[&str]| c.iter().map(|&c| c.to_owned()).collect::<Vec<_>>();
+
+    assert_eq!(
+        common_prefix(&completions(&["apple", "apply", "applesauce"])),
+        "appl"
+    );
+    assert_eq!(common_prefix(&completions(&["apple", "app"])), "app");
+    assert_eq!(common_prefix(&completions(&["Apple", "apple"])), "");
+    assert_eq!(common_prefix(&completions(&["漢字", "漢a"])), "漢");
+}
+
+#[test]
+fn test_suggestion() {
+    let answers = Answers::default();
+
+    let mut prompt = Input {
+        validate: ValidateSuggest::Sync(Box::new(|email, _| {
+            if email.contains(char::is_uppercase) {
+                Err(ValidationError::new("Emails should be lowercase")
+                    .with_suggestion(email.to_lowercase()))
+            } else {
+                Ok(())
+            }
+        })),
+        ..Input::default()
+    }
+    .into_input_prompt("message", &answers);
+
+    prompt.input.set_value("Ferris@Example.com".into());
+    prompt.input.set_at(18);
+
+    let err = prompt.validate().expect_err("uppercase is invalid");
+    assert_eq!(
+        err.text,
+        "Emails should be lowercase\nPress Tab to use suggestion: ferris@example.com"
+    );
+
+    assert!(prompt.handle_key(KeyCode::Tab.into()));
+    assert_eq!(prompt.input.value(), "ferris@example.com");
+    assert!(matches!(prompt.validate(), Ok(Validation::Finish)));
+
+    // Changing the input forgets the suggestion, so `Tab` does nothing
+    prompt.input.set_value("Ferris".into());
+    prompt.input.set_at(6);
+    assert!(prompt.validate().is_err());
+    assert!(prompt.handle_key(KeyCode::Char('!').into()));
+    assert!(!prompt.handle_key(KeyCode::Tab.into()));
+    assert_eq!(prompt.input.value(), "Ferris!");
+
+    // Errors without a suggestion are shown as is
+    let mut prompt = Input {
+        validate: ValidateSuggest::Sync(Box::new(|_, _| Err("invalid".into()))),
+        ..Input::default()
+    }
+    .into_input_prompt("message", &answers);
+
+    assert_eq!(
+        prompt.validate().expect_err("always invalid").text,
+        "invalid"
+    );
+    assert!(!prompt.handle_key(KeyCode::Tab.into()));
+}
+
+#[test]
+fn test_count() {
+    let size = (50, 20).into();
+    let base_layout = Layout::new(5, size);
+    let answers = Answers::default();
+
+    let render = |prompt: &mut InputPrompt<'_, '_>| {
+        let mut backend = TestBackend::new_with_layout(size, base_layout);
+        prompt
+            .render(&mut base_layout.clone(), &mut backend)
+            .unwrap();
+        backend.row_text(0)
+    };
+
+    let mut prompt = Input {
+        initial: Some("ab".into()),
+        max_length: Some(3),
+        show_count: true,
+        ..Input::default()
+    }
+    .into_input_prompt("message", &answers);
+
+    assert!(render(&mut prompt).contains("message (2/3 chars)"));
+
+    assert!(prompt.handle_key(KeyCode::Char('c').into()));
+    assert!(render(&mut prompt).contains("message (3/3 chars)"));
+
+    // Typing past the maximum length is ignored
+    assert!(!prompt.handle_key(KeyCode::Char('d').into()));
+    assert_eq!(prompt.input.value(), "abc");
+
+    // The count is of graphemes, so the combining accent is not counted separately
+    assert!(prompt.handle_key(KeyCode::Char('\u{301}').into()));
+    assert_eq!(prompt.input.value().chars().count(), 4);
+    assert!(render(&mut prompt).contains("message (3/3 chars)"));
+
+    // Without a maximum length, only the count is shown
+    let mut prompt = Input {
+        show_count: true,
+        ..Input::default()
+    }
+    .into_input_prompt("message", &answers);
+
+    assert!(render(&mut prompt).contains("message (0 chars)"));
+    assert!(prompt.handle_key(KeyCode::Char('a').into()));
+    assert!(render(&mut prompt).contains("message (1 chars)"));
+
+    // The hint is not shown unless asked for
+    let mut prompt = Input {
+        max_length: Some(3),
+        ..Input::default()
+    }
+    .into_input_prompt("message", &answers);
+
+    assert!(!render(&mut prompt).contains("chars"));
+}
+
+#[test]
+fn test_validate_debounce() {
+    let answers = Answers::default();
+
+    let mut prompt = Input {
+        validate_on_key: ValidateOnKey::Sync(Box::new(|s, _| s.len() < 3)),
+        validate_debounce: Some(Duration::from_secs(60)),
+        ..Input::default()
+    }
+    .into_input_prompt("message", &answers);
+
+    assert_eq!(prompt.timeout(), None);
+
+    for c in "abc".chars() {
+        assert!(prompt.handle_key(KeyCode::Char(c).into()));
+    }
+
+    // The validation on key waits until the user stops typing
+    assert!(prompt.is_valid);
+    let timeout = prompt.timeout().expect("input changed");
+    assert!(timeout <= Duration::from_secs(60));
+
+    assert!(prompt.handle_timeout());
+    assert!(!prompt.is_valid);
+    assert_eq!(prompt.timeout(), None);
+    assert!(!prompt.handle_timeout());
+
+    // Without a debounce, it is called on every key
+    let mut prompt = Input {
+        validate_on_key: ValidateOnKey::Sync(Box::new(|s, _| s.len() < 3)),
+        ..Input::default()
+    }
+    .into_input_prompt("message", &answers);
+
+    for c in "abc".chars() {
+        assert!(prompt.handle_key(KeyCode::Char(c).into()));
+    }
+    assert!(!prompt.is_valid);
+    assert_eq!(prompt.timeout(), None);
+}
+
+#[test]
+fn test_undo() {
+    let answers = Answers::default();
+    let undo = KeyEvent::new(KeyCode::Char('z'), KeyModifiers::CONTROL);
+
+    let mut prompt = Input {
+        initial: Some("hello".into()),
+        undo: true,
+        ..Input::default()
+    }
+    .into_input_prompt("message", &answers);
+
+    for c in " world".chars() {
+        assert!(prompt.handle_key(KeyCode::Char(c).into()));
+    }
+    assert!(prompt.handle_key(KeyEvent::new(KeyCode::Backspace, KeyModifiers::ALT)));
+    assert_eq!(prompt.input.value(), "hello ");
+
+    assert!(prompt.handle_key(undo));
+    assert_eq!(prompt.input.value(), "hello world");
+    assert!(prompt.handle_key(undo));
+    assert_eq!(prompt.input.value(), "hello");
+
+    // The initial value cannot be undone
+    assert!(!prompt.handle_key(undo));
+    assert_eq!(prompt.input.value(), "hello");
+}
+
+#[test]
+fn test_whitespace() {
+    let answers = Answers::default();
+
+    let finish = |trim, collapse_whitespace| {
+        Input {
+            initial: Some("  a   b  ".into()),
+            trim,
+            collapse_whitespace,
+            filter: Filter::Sync(Box::new(|s, _| format!("[{}]", s))),
+            ..Input::default()
+        }
+        .into_input_prompt("message", &answers)
+        .finish()
+    };
+
+    assert_eq!(finish(false, false), "[  a   b  ]");
+    assert_eq!(finish(true, false), "[a   b]");
+    assert_eq!(finish(false, true), "[ a b ]");
+    assert_eq!(finish(true, true), "[a b]");
+
+    assert_eq!(collapse_whitespace("a\t\n b"), "a b");
+
+    // The default is used if nothing is left after trimming
+    let prompt = Input {
+        initial: Some(" \t ".into()),
+        default: Some(("default".into(), 7)),
+        trim: true,
+        ..Input::default()
+    }
+    .into_input_prompt("message", &answers);
+    assert_eq!(prompt.finish(), "default");
+}
@@ -0,0 +1,525 @@
+use std::io;
+
+use ui::{
+    backend::Backend,
+    events::{EventIterator, KeyCode, KeyEvent, Movement},
+    layout::Layout,
+    style::{Color, StyledStr, StyledString},
+    widgets, Prompt, Validation, Widget,
+};
+
+use super::{Options, Transform, Validate};
+use crate::{Answer, Answers};
+
+/// The character in a template which is replaced by a digit.
+const BLANK: char = '_';
+
+#[derive(Debug)]
+pub(super) struct Masked<'a> {
+    template: String,
+    formatted: bool,
+    validate: Validate<'a, str>,
+    transform: Transform<'a, str>,
+}
+
+impl<'a> Masked<'a> {
+    fn new(template: String) -> Self {
+        Self {
+            template,
+            formatted: false,
+            validate: Validate::None,
+            transform: Transform::None,
+        }
+    }
+
+    fn blanks(&self) -> usize {
+        self.template.chars().filter(|&c| c == BLANK).count()
+    }
+}
+
+struct MaskedPrompt<'m, 'a> {
+    prompt: widgets::Prompt<StyledStr<'a>>,
+    masked: Masked<'m>,
+    blanks: usize,
+    digits: Vec<char>,
+    /// The index of the blank the cursor is on.
+    at: usize,
+    answers: &'a Answers,
+}
+
+impl MaskedPrompt<'_, '_> {
+    /// The template with the blanks filled in by the entered digits.
+    fn formatted(&self) -> String {
+        let mut digits = self.digits.iter();
+
+        self.masked
+            .template
+            .chars()
+            .map(|c| match c {
+                BLANK => *digits.next().unwrap_or(&BLANK),
+                c => c,
+            })
+            .collect()
+    }
+
+    fn answer(&self) -> String {
+        if self.masked.formatted {
+            self.formatted()
+        } else {
+            self.digits.iter().collect()
+        }
+    }
+
+    /// The position in the template of the blank at the given index, or the end of the template
+    /// if there is no such blank.
+    fn template_pos(&self, blank: usize) -> usize {
+        self.masked
+            .template
+            .chars()
+            .enumerate()
+            .filter(|&(_, c)| c == BLANK)
+            .nth(blank)
+            .map(|(i, _)| i)
+            .unwrap_or_else(|| self.masked.template.chars().count())
+    }
+
+    fn move_to(&mut self, at: usize) -> bool {
+        let at = at.min(self.digits.len());
+
+        if at == self.at {
+            false
+        } else {
+            self.at = at;
+            true
+        }
+    }
+
+    fn width(&self) -> u16 {
+        self.masked.template.chars().count() as u16
+    }
+}
+
+/// Moves the layout past `width` characters rendered after it, returning the number of lines
+/// that were used.
+fn advance(mut width: u16, layout: &mut Layout) -> u16 {
+    if width > layout.line_width() {
+        width -= layout.line_width();
+
+        layout.line_offset = width % layout.width;
+        layout.offset_y += 1 + width / layout.width;
+
+        2 + width / layout.width
+    } else {
+        layout.line_offset += width;
+        1
+    }
+}
+
+impl Widget for MaskedPrompt<'_, '_> {
+    fn render<B: Backend>(&mut self, layout: &mut Layout, b: &mut B) -> io::Result<()> {
+        self.prompt.render(layout, b)?;
+
+        // Everything after the first empty blank has not been entered yet
+        let filled_until = self.template_pos(self.digits.len());
+
+        for (i, c) in self.formatted().chars().enumerate() {
+            if i == filled_until {
+                b.set_fg(Color::DarkGrey)?;
+            }
+            write!(b, "{}", c)?;
+        }
+        b.set_fg(Color::Reset)?;
+
+        advance(self.width(), layout);
+        Ok(())
+    }
+
+    fn height(&mut self, layout: &mut Layout) -> u16 {
+        self.prompt.height(layout) + advance(self.width(), layout) - 1
+    }
+
+    fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+        let mut layout = layout.with_cursor_pos(self.prompt.cursor_pos(layout));
+        advance(self.template_pos(self.at) as u16, &mut layout);
+        (layout.offset_x + layout.line_offset, layout.offset_y)
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Char(c) if c.is_ascii_digit() => {
+                if self.digits.len() == self.blanks {
+                    return false;
+                }
+
+                self.digits.insert(self.at, c);
+                self.at += 1;
+                true
+            }
+            KeyCode::Backspace if self.at > 0 => {
+                self.at -= 1;
+                self.digits.remove(self.at);
+                true
+            }
+            KeyCode::Delete if self.at < self.digits.len() => {
+                self.digits.remove(self.at);
+                true
+            }
+            _ => match Movement::try_from_key(key) {
+                Some(Movement::Left) if self.at > 0 => self.move_to(self.at - 1),
+                Some(Movement::Right) => self.move_to(self.at + 1),
+                Some(Movement::Home) => self.move_to(0),
+                Some(Movement::End) => self.move_to(self.digits.len()),
+                _ => false,
+            },
+        }
+    }
+}
+
+impl Prompt for MaskedPrompt<'_, '_> {
+    type ValidateErr = widgets::Text<String>;
+    type Output = String;
+
+    fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
+        if self.digits.len() != self.blanks {
+            return Err("Please fill in all the blanks".to_owned().into());
+        }
+
+        let ans = self.answer();
+        if let Validate::Sync(ref mut validate) = self.masked.validate {
+            validate(&ans, self.answers)?;
+        }
+
+        Ok(Validation::Finish)
+    }
+
+    fn finish(self) -> Self::Output {
+        self.answer()
+    }
+}
+
+impl<'m> Masked<'m> {
+    fn into_masked_prompt<'a>(
+        self,
+        message: impl Into<StyledStr<'a>>,
+        answers: &'a Answers,
+    ) -> MaskedPrompt<'m, 'a> {
+        MaskedPrompt {
+            prompt: widgets::Prompt::new(message.into()),
+            blanks: self.blanks(),
+            digits: Vec::new(),
+            at: 0,
+            masked: self,
+            answers,
+        }
+    }
+
+    pub(crate) fn ask<B: Backend, E: EventIterator>(
+        mut self,
+        message: StyledString,
+        input_opts: super::InputOptions<'_>,
+        answers: &Answers,
+        b: &mut B,
+        events: &mut E,
+    ) -> ui::Result<Option<Answer>> {
+        let transform = self.transform.take();
+
+        let ans = ui::Input::new(self.into_masked_prompt(&message, answers), b)
+            .on_esc(input_opts.on_esc)
+            .timeout(input_opts.timeout)
+            .on_validation_failed(input_opts.on_validation_failed)
+            .run(events)?;
+
+        crate::write_final!(transform, message, ans [ref], answers, b, |ans| {
+            b.write_all(ans.as_bytes())?;
+        })
+    }
+}
+
+/// The builder for a [`masked`] prompt.
+///
+/// The template is shown to the user, and every `_` in it is a blank which can be filled in with a
+/// digit. All the other characters are literals, which are inserted automatically and skipped over
+/// by the cursor. All the blanks must be filled in before the answer can be submitted.
+///
+/// The answer is an [`Answer::String`] with only the entered digits, unless [`formatted`] is set.
+///
+/// See the various methods for more details on each available option.
+///
+/// # Examples
+///
+/// ```
+/// use requestty::Question;
+///
+/// let masked = Question::masked("phone", "(___) ___-____")
+///     .message("What is your phone number?")
+///     .build();
+/// ```
+///
+/// [`masked`]: crate::question::Question::masked
+/// [`formatted`]: MaskedBuilder::formatted
+/// [`Answer::String`]: crate::Answer::String
+#[derive(Debug)]
+pub struct MaskedBuilder<'a> {
+    opts: Options<'a>,
+    masked: Masked<'a>,
+}
+
+impl<'a> MaskedBuilder<'a> {
+    pub(crate) fn new(name: String, template: String) -> Self {
+        MaskedBuilder {
+            opts: Options::new(name),
+            masked: Masked::new(template),
+        }
+    }
+
+    crate::impl_options_builder! {
+    message
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let masked = Question::masked("phone", "(___) ___-____")
+    ///     .message("What is your phone number?")
+    ///     .build();
+    /// ```
+
+    when
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, Answers};
+    ///
+    /// let masked = Question::masked("phone", "(___) ___-____")
+    ///     .when(|previous_answers: &Answers| match previous_answers.get("contact") {
+    ///         Some(ans) => ans.as_bool().unwrap(),
+    ///         None => true,
+    ///     })
+    ///     .build();
+    /// ```
+
+    ask_if_answered
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let masked = Question::masked("phone", "(___) ___-____")
+    ///     .ask_if_answered(true)
+    ///     .build();
+    /// ```
+
+    on_esc
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, OnEsc};
+    ///
+    /// let masked = Question::masked("phone", "(___) ___-____")
+    ///     .on_esc(OnEsc::Terminate)
+    ///     .build();
+    /// ```
+    }
+
+    /// Whether to return the template with the blanks filled in, instead of only the digits.
+    ///
+    /// If `formatted` is not set, it will default to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let masked = Question::masked("phone", "(___) ___-____")
+    ///     .formatted(true)
+    ///     .build();
+    /// ```
+    pub fn formatted(mut self, formatted: bool) -> Self {
+        self.masked.formatted = formatted;
+        self
+    }
+
+    crate::impl_validate_builder! {
+    /// The answer given to the validation is the same as the final answer, so it depends on
+    /// [`formatted`](Self::formatted).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let masked = Question::masked("card", "____ ____ ____ ____")
+    ///     .validate(|card, previous_answers| {
+    ///         if card.starts_with('4') || card.starts_with('5') {
+    ///             Ok(())
+    ///         } else {
+    ///             Err("Only Visa and Mastercard are accepted".to_owned())
+    ///         }
+    ///     })
+    ///     .build();
+    /// ```
+    str; masked
+    }
+
+    crate::impl_transform_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let masked = Question::masked("card", "____ ____ ____ ____")
+    ///     .transform(|card, previous_answers, backend| {
+    ///         write!(backend, "ending in {}", &card[card.len() - 4..])
+    ///     })
+    ///     .build();
+    /// ```
+    str; masked
+    }
+
+    /// Consumes the builder returning a [`Question`]
+    ///
+    /// # Panics
+    ///
+    /// It will panic if the template has no blanks.
+    ///
+    /// [`Question`]: crate::question::Question
+    pub fn build(self) -> super::Question<'a> {
+        if self.masked.blanks() == 0 {
+            panic!("Invalid template '{}' has no blanks", self.masked.template);
+        }
+
+        super::Question::new(self.opts, super::QuestionKind::Masked(self.masked))
+    }
+}
+
+impl<'a> From<MaskedBuilder<'a>> for super::Question<'a> {
+    /// Consumes the builder returning a [`Question`]
+    ///
+    /// [`Question`]: crate::question::Question
+    fn from(builder: MaskedBuilder<'a>) -> Self {
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ui::backend::TestBackend;
+
+    fn type_str(prompt: &mut MaskedPrompt<'_, '_>, s: &str) {
+        for c in s.chars() {
+            prompt.handle_key(KeyCode::Char(c).into());
+        }
+    }
+
+    #[test]
+    fn test_typing() {
+        let answers = Answers::default();
+        let mut prompt = Masked::new("(___) ___-____".into()).into_masked_prompt("", &answers);
+
+        type_str(&mut prompt, "55a5-12");
+        assert_eq!(prompt.formatted(), "(555) 12_-____");
+        assert!(prompt.validate().is_err());
+
+        type_str(&mut prompt, "345678999");
+        assert_eq!(prompt.formatted(), "(555) 123-4567");
+        assert_eq!(prompt.validate(), Ok(Validation::Finish));
+        assert_eq!(prompt.answer(), "5551234567");
+
+        prompt.masked.formatted = true;
+        assert_eq!(prompt.finish(), "(555) 123-4567");
+    }
+
+    #[test]
+    fn test_editing() {
+        let answers = Answers::default();
+        let mut prompt = Masked::new("__/__".into()).into_masked_prompt("", &answers);
+
+        type_str(&mut prompt, "124");
+        assert!(prompt.handle_key(KeyCode::Left.into()));
+        type_str(&mut prompt, "3");
+        assert_eq!(prompt.formatted(), "12/34");
+
+        // All the blanks are filled in
+        assert!(!prompt.handle_key(KeyCode::Char('5').into()));
+
+        assert!(prompt.handle_key(KeyCode::Home.into()));
+        assert!(!prompt.handle_key(KeyCode::Backspace.into()));
+        assert!(prompt.handle_key(KeyCode::Delete.into()));
+        assert_eq!(prompt.formatted(), "23/4_");
+
+        assert!(prompt.handle_key(KeyCode::End.into()));
+        assert!(!prompt.handle_key(KeyCode::Right.into()));
+        assert!(prompt.handle_key(KeyCode::Backspace.into()));
+        assert_eq!(prompt.formatted(), "23/__");
+    }
+
+    #[test]
+    fn test_backspace_skips_literals() {
+        let answers = Answers::default();
+        let mut prompt = Masked::new("____-__-__".into()).into_masked_prompt("", &answers);
+
+        type_str(&mut prompt, "20240");
+        assert_eq!(prompt.formatted(), "2024-0_-__");
+
+        // The '-' is not part of the answer, so backspace removes the digit before it
+        assert!(prompt.handle_key(KeyCode::Backspace.into()));
+        assert!(prompt.handle_key(KeyCode::Backspace.into()));
+        assert_eq!(prompt.formatted(), "202_-__-__");
+
+        type_str(&mut prompt, "40229");
+        assert_eq!(prompt.answer(), "20240229");
+
+        prompt.masked.formatted = true;
+        assert_eq!(prompt.finish(), "2024-02-29");
+    }
+
+    #[test]
+    fn test_cursor_skips_literals() {
+        let answers = Answers::default();
+        let mut prompt = Masked::new("(___) ___".into()).into_masked_prompt("", &answers);
+        let layout = Layout::new(0, (50, 20).into());
+        let start = prompt.cursor_pos(layout).0;
+
+        // The cursor starts on the first blank, after the '('
+        type_str(&mut prompt, "123");
+        assert_eq!(prompt.cursor_pos(layout).0, start + 5);
+        type_str(&mut prompt, "4");
+        assert_eq!(prompt.cursor_pos(layout).0, start + 6);
+
+        prompt.handle_key(KeyCode::Left.into());
+        assert_eq!(prompt.cursor_pos(layout).0, start + 5);
+        prompt.handle_key(KeyCode::Left.into());
+        assert_eq!(prompt.cursor_pos(layout).0, start + 2);
+    }
+
+    #[test]
+    fn test_render() {
+        let size = (50, 20).into();
+        let base_layout = Layout::new(5, size);
+        let answers = Answers::default();
+        let mut prompt =
+            Masked::new("(___) ___-____".into()).into_masked_prompt("message", &answers);
+        let mut backend = TestBackend::new_with_layout(size, base_layout);
+
+        for &digits in ["", "5551", "234567"].iter() {
+            type_str(&mut prompt, digits);
+
+            let mut layout = base_layout;
+            assert!(prompt.render(&mut layout, &mut backend).is_ok());
+            ui::assert_backend_snapshot!(backend);
+
+            let mut height_layout = base_layout;
+            assert_eq!(prompt.height(&mut height_layout), 1);
+            assert_eq!(height_layout, layout);
+
+            backend.reset_with_layout(base_layout);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid template '(...)' has no blanks")]
+    fn test_panic_no_blanks() {
+        MaskedBuilder::new("name".into(), "(...)".into()).build();
+    }
+}
@@ -1,13 +1,22 @@
 //! A module that contains things related to [`Question`]s.
 
+mod autocomplete;
+mod batch_confirm;
 mod choice;
+mod clear;
 mod confirm;
+mod date;
+#[cfg(feature = "serde")]
+mod deserialize;
 mod editor;
 mod expand;
+mod form;
 mod handler;
+mod hint;
 #[macro_use]
 mod impl_macros;
 mod input;
+mod masked;
 mod multi_select;
 mod number;
 mod order_select;
@@ -17,38 +26,65 @@ mod custom_prompt;
 mod password;
 mod raw_select;
 mod select;
+mod select_lazy;
+mod slider;
+mod tags;
+mod text;
+mod time;
+mod tree;
 
+pub use autocomplete::AutocompleteBuilder;
+pub use batch_confirm::BatchConfirmBuilder;
 pub use choice::Choice;
 pub use confirm::ConfirmBuilder;
 pub use custom_prompt::{CustomPromptBuilder, Prompt};
+pub use date::DateBuilder;
 pub use editor::EditorBuilder;
 pub use expand::ExpandBuilder;
-pub use input::InputBuilder;
+pub use form::FormBuilder;
+pub use handler::ValidationError;
+pub use input::{InputBuilder, PathBuilder};
+pub use masked::MaskedBuilder;
 pub use multi_select::MultiSelectBuilder;
 pub use number::{FloatBuilder, IntBuilder};
 pub use order_select::{OrderSelectBuilder, OrderSelectItem};
 pub use password::PasswordBuilder;
 pub use raw_select::RawSelectBuilder;
 pub use select::SelectBuilder;
+pub use select_lazy::SelectLazyBuilder;
+pub use slider::SliderBuilder;
+pub use tags::TagsBuilder;
+pub use text::TextBuilder;
+pub use time::TimeBuilder;
+pub use tree::{TreeBuilder, TreeNode};
+
+use std::cell::RefCell;
 
 use ui::{backend::Backend, events::EventIterator};
 
-use crate::{Answer, Answers};
+use crate::{
+    observer::{ObservedEvents, Observer, PromptEvent},
+    Answer, Answers,
+};
 use choice::{get_sep_str, ChoiceList};
+use clear::ClearAnswered;
 use custom_prompt::CustomPromptInteral;
 use handler::{
-    AutoComplete, Filter, Transform, TransformByVal, Validate, ValidateByVal, ValidateOnKey,
-    ValidateOnKeyByVal,
+    AutoComplete, ChoicesFn, ConfirmOn, Filter, Strength, Suggestions, Transform, TransformByVal,
+    Validate, ValidateByVal, ValidateOnKey, ValidateOnKeyByVal, ValidateSuggest,
+    ValidateSuggestByVal,
 };
+use hint::Hint;
 use options::Options;
 
 /// A `Question` that can be asked.
 ///
-/// There are 11 variants.
+/// There are 22 variants.
 ///
 /// - [`input`](Question::input)
 /// - [`password`](Question::password)
 /// - [`editor`](Question::editor)
+/// - [`text`](Question::text)
 /// - [`confirm`](Question::confirm)
 /// - [`int`](Question::int)
 /// - [`float`](Question::float)
@@ -57,6 +93,16 @@ use options::Options;
 /// - [`raw_select`](Question::raw_select)
 /// - [`multi_select`](Question::multi_select)
 /// - [`order_select`](Question::order_select)
+/// - [`date`](Question::date)
+/// - [`time`](Question::time)
+/// - [`autocomplete`](Question::autocomplete)
+/// - [`slider`](Question::slider)
+/// - [`tree`](Question::tree)
+/// - [`tags`](Question::tags)
+/// - [`batch_confirm`](Question::batch_confirm)
+/// - [`masked`](Question::masked)
+/// - [`form`](Question::form)
+/// - [`select_lazy`](Question::select_lazy)
 /// - [`custom`](Question::custom)
 ///
 /// Every [`Question`] has 4 common options.
@@ -136,6 +182,31 @@ impl Question<'static> {
         InputBuilder::new(name.into())
     }
 
+    /// Prompt that takes a path to a file or directory, completing it with `Tab`.
+    ///
+    /// The names of the entries in the directory of the path typed so far are completed, and a
+    /// directory is completed with a trailing separator so that its entries can be completed next.
+    /// The answer is returned as a [`String`].
+    ///
+    /// See the various methods on the [`builder`] for more details on each available option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let path = Question::path("out-dir")
+    ///     .message("Where should the output go?")
+    ///     .directory_only(true)
+    ///     .must_exist(true)
+    ///     .build();
+    /// ```
+    ///
+    /// [`builder`]: PathBuilder
+    pub fn path<N: Into<String>>(name: N) -> PathBuilder<'static> {
+        PathBuilder::new(name.into())
+    }
+
     /// Prompt that takes user input and hides it.
     ///
     /// How it looks if you set a mask:
@@ -201,6 +272,29 @@ impl Question<'static> {
         EditorBuilder::new(name.into())
     }
 
+    /// Prompt that takes multiple lines of text.
+    ///
+    /// Unlike [`editor`](Question::editor), the text is entered directly in the terminal, and is
+    /// word wrapped as it is typed. `Enter` starts a new line, and pressing `Enter` on an empty
+    /// line at the end submits the text. The answer is returned as a [`String`].
+    ///
+    /// See the various methods on the [`builder`] for more details on each available option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let text = Question::text("bio")
+    ///     .message("Tell us a bit about yourself")
+    ///     .build();
+    /// ```
+    ///
+    /// [`builder`]: TextBuilder
+    pub fn text<N: Into<String>>(name: N) -> TextBuilder<'static> {
+        TextBuilder::new(name.into())
+    }
+
     /// Prompt that returns `true` or `false`.
     ///
     /// <img
@@ -466,6 +560,262 @@ impl Question<'static> {
         OrderSelectBuilder::new(name.into())
     }
 
+    /// Prompt that allows the user to pick a date from a calendar.
+    ///
+    /// The answer is returned as a [`Date`](crate::Date).
+    ///
+    /// See the various methods on the [`builder`] for more details on each available option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let date = Question::date("appointment")
+    ///     .message("When do you want to book the appointment?")
+    ///     .min((2024, 1, 1))
+    ///     .max((2024, 12, 31))
+    ///     .default((2024, 6, 1))
+    ///     .build();
+    /// ```
+    ///
+    /// [`builder`]: DateBuilder
+    pub fn date<N: Into<String>>(name: N) -> DateBuilder<'static> {
+        DateBuilder::new(name.into())
+    }
+
+    /// Prompt that allows the user to pick a time of the day.
+    ///
+    /// The answer is returned as a [`Time`](crate::Time).
+    ///
+    /// See the various methods on the [`builder`] for more details on each available option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let time = Question::time("alarm")
+    ///     .message("When should the alarm ring?")
+    ///     .twelve_hour()
+    ///     .default((7, 30, 0))
+    ///     .build();
+    /// ```
+    ///
+    /// [`builder`]: TimeBuilder
+    pub fn time<N: Into<String>>(name: N) -> TimeBuilder<'static> {
+        TimeBuilder::new(name.into())
+    }
+
+    /// Prompt that allows the user to type to filter a list of suggestions, and pick one of them
+    ///
+    /// The suggestions are computed from the typed text by a function, so this is well suited for
+    /// large sets of options.
+    ///
+    /// See the various methods on the [`builder`] for more details on each available option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let autocomplete = Question::autocomplete("language")
+    ///     .message("What is your favourite programming language?")
+    ///     .suggestions(|input, previous_answers| {
+    ///         ["C", "Go", "Haskell", "Python", "Rust"]
+    ///             .iter()
+    ///             .filter(|language| language.to_lowercase().contains(&input.to_lowercase()))
+    ///             .map(|language| language.to_string())
+    ///             .collect()
+    ///     })
+    ///     .build();
+    /// ```
+    ///
+    /// [`builder`]: AutocompleteBuilder
+    pub fn autocomplete<N: Into<String>>(name: N) -> AutocompleteBuilder<'static> {
+        AutocompleteBuilder::new(name.into())
+    }
+
+    /// Prompt that allows the user to pick a number in a range by moving a slider.
+    ///
+    /// The answer is returned as an [`i64`].
+    ///
+    /// See the various methods on the [`builder`] for more details on each available option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let slider = Question::slider("volume")
+    ///     .message("How loud should the music be?")
+    ///     .max(100)
+    ///     .step(5)
+    ///     .default(40)
+    ///     .build();
+    /// ```
+    ///
+    /// [`builder`]: SliderBuilder
+    pub fn slider<N: Into<String>>(name: N) -> SliderBuilder<'static> {
+        SliderBuilder::new(name.into())
+    }
+
+    /// Prompt that allows the user to select a leaf from a tree of choices.
+    ///
+    /// The nodes are represented with [`TreeNode`]s. The right and left arrow keys expand and
+    /// collapse the hovered node. The answer is the path from the root to the selected leaf.
+    ///
+    /// See the various methods on the [`builder`] for more details on each available option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{question::TreeNode, Question};
+    ///
+    /// let tree = Question::tree("food")
+    ///     .message("What would you like to eat?")
+    ///     .choices(vec![
+    ///         TreeNode::new("Fruits").children(vec!["Apple", "Banana"]),
+    ///         TreeNode::new("Vegetables").children(vec!["Carrot", "Potato"]),
+    ///     ])
+    ///     .build();
+    /// ```
+    ///
+    /// [`builder`]: TreeBuilder
+    pub fn tree<N: Into<String>>(name: N) -> TreeBuilder<'static> {
+        TreeBuilder::new(name.into())
+    }
+
+    /// Prompt that allows the user to enter multiple free-form tags.
+    ///
+    /// Pressing `Enter` or `,` adds the current input as a tag, and `Backspace` on an empty input
+    /// removes the last tag. The answer is returned as a [`Vec<String>`].
+    ///
+    /// See the various methods on the [`builder`] for more details on each available option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let tags = Question::tags("topics")
+    ///     .message("What topics is your post about?")
+    ///     .build();
+    /// ```
+    ///
+    /// [`builder`]: TagsBuilder
+    pub fn tags<N: Into<String>>(name: N) -> TagsBuilder<'static> {
+        TagsBuilder::new(name.into())
+    }
+
+    /// Prompt that asks the user to confirm each item in a list, one at a time.
+    ///
+    /// For every item, the user can answer yes or no, or apply a decision to the current and all
+    /// the remaining items at once. The answer is returned as a [`Vec<bool>`] with a decision for
+    /// every item.
+    ///
+    /// See the various methods on the [`builder`] for more details on each available option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let batch_confirm = Question::batch_confirm("overwrite")
+    ///     .message("Overwrite the existing file?")
+    ///     .items(vec!["Cargo.toml", "src/main.rs", "README.md"])
+    ///     .build();
+    /// ```
+    ///
+    /// [`builder`]: BatchConfirmBuilder
+    pub fn batch_confirm<N: Into<String>>(name: N) -> BatchConfirmBuilder<'static> {
+        BatchConfirmBuilder::new(name.into())
+    }
+
+    /// Prompt that allows the user to fill in the blanks of a template with digits.
+    ///
+    /// Every `_` in the `template` is a blank, and all the other characters are inserted
+    /// automatically. The answer is returned as a [`String`].
+    ///
+    /// See the various methods on the [`builder`] for more details on each available option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let masked = Question::masked("phone", "(___) ___-____")
+    ///     .message("What is your phone number?")
+    ///     .build();
+    ///
+    /// let date = Question::masked("birthday", "____-__-__")
+    ///     .message("When were you born?")
+    ///     .formatted(true)
+    ///     .build();
+    /// ```
+    ///
+    /// [`builder`]: MaskedBuilder
+    pub fn masked<N: Into<String>, T: Into<String>>(
+        name: N,
+        template: T,
+    ) -> MaskedBuilder<'static> {
+        MaskedBuilder::new(name.into(), template.into())
+    }
+
+    /// Prompt that allows the user to fill in several fields on a single screen.
+    ///
+    /// The user can move between the fields with `Tab` and `Shift+Tab`, and submits all of them at
+    /// once. The answer is returned as a [`BTreeMap`] from the name of each field to its value.
+    ///
+    /// See the various methods on the [`builder`] for more details on each available option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let form = Question::form("account")
+    ///     .message("Create an account")
+    ///     .field("username", "Username")
+    ///     .field("email", "Email")
+    ///     .build();
+    /// ```
+    ///
+    /// [`builder`]: FormBuilder
+    /// [`BTreeMap`]: std::collections::BTreeMap
+    pub fn form<N: Into<String>>(name: N) -> FormBuilder<'static> {
+        FormBuilder::new(name.into())
+    }
+
+    /// Prompt that allows the user to select from a list of options, where the choices are created
+    /// on demand by the `provider`.
+    ///
+    /// The `provider` is given the index of a choice, which is less than `len`, and should return
+    /// the choice at that index. Only the choices which are shown are created, so this can be used
+    /// for a very large number of choices. The selected option is returned as a
+    /// [`ListItem`](crate::ListItem).
+    ///
+    /// See the various methods on the [`builder`] for more details on each available option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select_lazy("number", 1_000_000, |i| format!("Number {}", i).into())
+    ///     .message("Pick a number")
+    ///     .build();
+    /// ```
+    ///
+    /// [`builder`]: SelectLazyBuilder
+    pub fn select_lazy<'a, N, F>(name: N, len: usize, provider: F) -> SelectLazyBuilder<'a>
+    where
+        N: Into<String>,
+        F: Fn(usize) -> Choice<String> + 'a,
+    {
+        SelectLazyBuilder::new(name.into(), len, Box::new(provider))
+    }
+
     /// Create a [`Question`] from a custom prompt.
     ///
     /// See [`Prompt`] for more information on writing custom prompts and the various methods on the
@@ -524,26 +874,131 @@ enum QuestionKind<'a> {
     Expand(expand::Expand<'a>),
     MultiSelect(multi_select::MultiSelect<'a>),
     OrderSelect(order_select::OrderSelect<'a>),
+    Date(date::DatePicker<'a>),
+    Time(time::TimePicker<'a>),
+    Autocomplete(autocomplete::Autocomplete<'a>),
+    Slider(slider::Slider<'a>),
+    Tree(tree::Tree<'a>),
+    Tags(tags::Tags<'a>),
+    BatchConfirm(batch_confirm::BatchConfirm<'a>),
+    Masked(masked::Masked<'a>),
+    Form(form::Form<'a>),
+    SelectLazy(select_lazy::SelectLazy<'a>),
     Password(password::Password<'a>),
     Editor(editor::Editor<'a>),
+    Text(text::Text<'a>),
     Custom(Box<dyn CustomPromptInteral + 'a>),
 }
 
+/// Why an answer given to a question non-interactively was not accepted.
+enum Rejected {
+    /// No answer was given, and there is no default to use instead.
+    Missing,
+    /// The answer is of the wrong type, or did not pass validation.
+    Invalid(String),
+}
+
+impl From<String> for Rejected {
+    fn from(message: String) -> Self {
+        Rejected::Invalid(message)
+    }
+}
+
+impl From<ValidationError> for Rejected {
+    fn from(e: ValidationError) -> Self {
+        Rejected::Invalid(e.message)
+    }
+}
+
+/// Answers a question which takes a string non-interactively, following the same steps as when
+/// the answer is typed in by the user.
+fn answer_string(
+    answer: Option<Answer>,
+    default: Option<String>,
+    validate: Validate<'_, str>,
+    filter: Filter<'_, String>,
+    answers: &Answers,
+) -> Result<Answer, Rejected> {
+    let ans = match (answer, default) {
+        (Some(Answer::String(ans)), _) => {
+            if let Validate::Sync(mut validate) = validate {
+                validate(&ans, answers)?;
+            }
+            ans
+        }
+        (Some(_), _) => return Err(Rejected::Invalid("expected a string".into())),
+        // Like an empty input, the default is not validated
+        (None, Some(default)) => default,
+        (None, None) => return Err(Rejected::Missing),
+    };
+
+    match filter {
+        Filter::Sync(filter) => Ok(Answer::String(filter(ans, answers))),
+        Filter::None => Ok(Answer::String(ans)),
+    }
+}
+
+/// The options of a question which are passed on to the [`ui::Input`] it is asked with.
+pub(crate) struct InputOptions<'a> {
+    pub(crate) on_esc: ui::OnEsc,
+    pub(crate) timeout: Option<std::time::Duration>,
+    /// Called every time the answer fails validation.
+    pub(crate) on_validation_failed: &'a mut dyn FnMut(),
+}
+
 impl Question<'_> {
-    pub(crate) fn ask<B: Backend, I: EventIterator>(
+    /// Whether the question should be asked given the previous answers.
+    ///
+    /// This can only be called once, since `when` can be a [`FnOnce`].
+    fn should_ask(&mut self, answers: &Answers) -> bool {
+        // Already asked
+        if !self.opts.ask_if_answered && answers.contains_key(&self.opts.name) {
+            return false;
+        }
+
+        // Shouldn't be asked
+        std::mem::replace(&mut self.opts.when, false.into()).get(answers)
+    }
+
+    /// Asks the question, reporting its lifecycle to the `observer`.
+    pub(crate) fn ask<B: Backend, I: EventIterator, O: Observer>(
         self,
         answers: &Answers,
         b: &mut B,
         events: &mut I,
+        observer: &mut O,
     ) -> ui::Result<Option<(String, Answer)>> {
-        // Already asked
-        if !self.opts.ask_if_answered && answers.contains_key(&self.opts.name) {
+        let res = self.ask_with_header(None, false, answers, b, events, observer)?;
+        Ok(res.and_then(|(name, answer)| Some((name, answer?))))
+    }
+
+    /// Asks the question like [`ask`](Question::ask), but if the question is asked, the `header`
+    /// is first printed on its own line above it.
+    ///
+    /// If `clear_answered` is set, the question and its header are removed from the terminal once
+    /// it is finished, instead of leaving the answer behind. A `silent` question is removed in the
+    /// same way, but its header is kept.
+    ///
+    /// Unlike `ask`, a question which is asked but skipped by the user is returned with no answer,
+    /// so that it can be told apart from a question which is not asked.
+    pub(crate) fn ask_with_header<B: Backend, I: EventIterator, O: Observer>(
+        mut self,
+        header: Option<&str>,
+        clear_answered: bool,
+        answers: &Answers,
+        b: &mut B,
+        events: &mut I,
+        observer: &mut O,
+    ) -> ui::Result<Option<(String, Option<Answer>)>> {
+        if !self.should_ask(answers) {
             return Ok(None);
         }
 
-        // Shouldn't be asked
-        if !self.opts.when.get(answers) {
-            return Ok(None);
+        if let Some(header) = header {
+            b.set_fg(ui::theme::current().hint)?;
+            b.write_all(header.as_bytes())?;
+            b.set_fg(ui::style::Color::Reset)?;
+            b.write_all(b"\n")?;
         }
 
         let name = self.opts.name;
@@ -551,25 +1006,106 @@ impl Question<'_> {
             .opts
             .message
             .map(|message| message.get(answers))
-            .unwrap_or_else(|| name.clone() + ":");
+            .unwrap_or_else(|| (name.clone() + ":").into());
         let on_esc = self.opts.on_esc.get(answers);
+        let timeout = self.opts.timeout;
+        let clear = clear_answered || self.opts.silent;
+
+        observer.observe(PromptEvent::Started { name: &name });
+
+        // Both the keys and the failed validations are reported while the question is asked
+        let observed = RefCell::new(&mut *observer);
+        let input_opts = InputOptions {
+            on_esc,
+            timeout,
+            on_validation_failed: &mut || {
+                observed
+                    .borrow_mut()
+                    .observe(PromptEvent::ValidationFailed { name: &name })
+            },
+        };
+        let events = &mut ObservedEvents {
+            events,
+            observer: &observed,
+            name: &name,
+        };
+        let b = &mut ClearAnswered::new(b, clear);
+
+        let res = match self.kind {
+            QuestionKind::Input(i) => i.ask(message, input_opts, answers, b, events)?,
+            QuestionKind::Int(i) => i.ask(message, input_opts, answers, b, events)?,
+            QuestionKind::Float(f) => f.ask(message, input_opts, answers, b, events)?,
+            QuestionKind::Confirm(c) => c.ask(message, input_opts, answers, b, events)?,
+            QuestionKind::Select(l) => l.ask(message, input_opts, answers, b, events)?,
+            QuestionKind::RawSelect(r) => r.ask(message, input_opts, answers, b, events)?,
+            QuestionKind::Expand(e) => e.ask(message, input_opts, answers, b, events)?,
+            QuestionKind::MultiSelect(c) => c.ask(message, input_opts, answers, b, events)?,
+            QuestionKind::OrderSelect(c) => c.ask(message, input_opts, answers, b, events)?,
+            QuestionKind::Date(d) => d.ask(message, input_opts, answers, b, events)?,
+            QuestionKind::Time(t) => t.ask(message, input_opts, answers, b, events)?,
+            QuestionKind::Autocomplete(a) => a.ask(message, input_opts, answers, b, events)?,
+            QuestionKind::Slider(s) => s.ask(message, input_opts, answers, b, events)?,
+            QuestionKind::Tree(t) => t.ask(message, input_opts, answers, b, events)?,
+            QuestionKind::Tags(t) => t.ask(message, input_opts, answers, b, events)?,
+            QuestionKind::BatchConfirm(c) => c.ask(message, input_opts, answers, b, events)?,
+            QuestionKind::Masked(m) => m.ask(message, input_opts, answers, b, events)?,
+            QuestionKind::Form(f) => f.ask(message, input_opts, answers, b, events)?,
+            QuestionKind::SelectLazy(s) => s.ask(message, input_opts, answers, b, events)?,
+            QuestionKind::Password(p) => p.ask(message, input_opts, answers, b, events)?,
+            QuestionKind::Editor(e) => e.ask(message, input_opts, answers, b, events)?,
+            QuestionKind::Text(t) => t.ask(message, input_opts, answers, b, events)?,
+            QuestionKind::Custom(mut o) => o.ask(message.into_text(), answers, b, events)?,
+        };
+
+        if clear_answered {
+            b.clear_header(header.is_some() as u16)?;
+        }
+
+        match res {
+            Some(ref answer) => observer.observe(PromptEvent::Answered {
+                name: &name,
+                answer,
+            }),
+            None => observer.observe(PromptEvent::Skipped { name: &name }),
+        }
+
+        Ok(Some((name, res)))
+    }
+
+    /// Answers the question with the answer of the same name in `provided` without using the
+    /// terminal.
+    ///
+    /// The answer goes through the same validation and filtering as an answer given by the user.
+    /// For the questions which do not support this, the answer is used as is.
+    pub(crate) fn answer_non_interactive(
+        mut self,
+        answers: &Answers,
+        provided: &mut Answers,
+    ) -> ui::Result<Option<(String, Answer)>> {
+        if !self.should_ask(answers) {
+            return Ok(None);
+        }
+
+        let answer = provided.remove(&self.opts.name);
 
         let res = match self.kind {
-            QuestionKind::Input(i) => i.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::Int(i) => i.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::Float(f) => f.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::Confirm(c) => c.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::Select(l) => l.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::RawSelect(r) => r.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::Expand(e) => e.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::MultiSelect(c) => c.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::OrderSelect(c) => c.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::Password(p) => p.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::Editor(e) => e.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::Custom(mut o) => o.ask(message, answers, b, events)?,
+            QuestionKind::Input(i) => i.answer_non_interactive(answer, answers),
+            QuestionKind::Password(p) => p.answer_non_interactive(answer, answers),
+            QuestionKind::Text(t) => t.answer_non_interactive(answer, answers),
+            QuestionKind::Int(i) => i.answer_non_interactive(answer, answers),
+            QuestionKind::Float(f) => f.answer_non_interactive(answer, answers),
+            QuestionKind::Confirm(c) => c.answer_non_interactive(answer),
+            QuestionKind::Select(s) => s.answer_non_interactive(answer, answers),
+            QuestionKind::MultiSelect(m) => m.answer_non_interactive(answer, answers),
+            _ => answer.ok_or(Rejected::Missing),
         };
 
-        Ok(res.map(|res| (name, res)))
+        let name = self.opts.name;
+        match res {
+            Ok(answer) => Ok(Some((name, answer))),
+            Err(Rejected::Missing) => Err(ui::ErrorKind::MissingAnswer(name)),
+            Err(Rejected::Invalid(message)) => Err(ui::ErrorKind::InvalidAnswer { name, message }),
+        }
     }
 }
 
@@ -590,3 +1126,123 @@ pub use smallvec::smallvec as completions;
 
 #[cfg(not(feature = "smallvec"))]
 pub use std::vec as completions;
+
+/// A builder for the [`Completions`] of an [`auto_complete`] function, which ranks the candidates
+/// by how well they match the current answer.
+///
+/// Ignoring case, candidates which are the same as the answer are ranked first, followed by those
+/// which start with it, those which contain it, and then those which contain its characters in
+/// order. Candidates which are ranked the same are sorted alphabetically. Candidates which do not
+/// match at all, and duplicate candidates, are dropped.
+///
+/// If no candidate matches, the answer itself is the only completion, so that there is always at
+/// least one completion.
+///
+/// # Examples
+///
+/// ```
+/// use requestty::{question::CompletionsBuilder, Question};
+///
+/// const COMMANDS: &[&str] = &["status", "stash", "start", "restart", "stop", "log"];
+///
+/// let command = Question::input("command")
+///     .auto_complete(|command, previous_answers| {
+///         CompletionsBuilder::new(command)
+///             .candidates(COMMANDS.iter().copied())
+///             .build()
+///     })
+///     .build();
+///
+/// let completions = CompletionsBuilder::new("st")
+///     .candidates(COMMANDS.iter().copied())
+///     .build();
+/// assert_eq!(&completions[..], ["start", "stash", "status", "stop", "restart"]);
+/// ```
+///
+/// [`auto_complete`]: InputBuilder::auto_complete
+#[derive(Debug, Clone)]
+pub struct CompletionsBuilder {
+    answer: String,
+    candidates: Vec<String>,
+}
+
+/// How well a candidate matches the answer, with the best match first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Relevance {
+    Exact,
+    Prefix,
+    Contains,
+    Fuzzy,
+}
+
+impl Relevance {
+    /// Both `answer` and `candidate` must already be lowercase.
+    fn of(answer: &str, candidate: &str) -> Option<Relevance> {
+        if candidate == answer {
+            Some(Relevance::Exact)
+        } else if candidate.starts_with(answer) {
+            Some(Relevance::Prefix)
+        } else if candidate.contains(answer) {
+            Some(Relevance::Contains)
+        } else {
+            let mut candidate = candidate.chars();
+            if answer.chars().all(|a| candidate.any(|c| c == a)) {
+                Some(Relevance::Fuzzy)
+            } else {
+                None
+            }
+        }
+    }
+}
+
+impl CompletionsBuilder {
+    /// Creates a new `CompletionsBuilder` for the current state of the answer, which is the
+    /// string given to the [`auto_complete`] function.
+    ///
+    /// [`auto_complete`]: InputBuilder::auto_complete
+    pub fn new<S: Into<String>>(answer: S) -> Self {
+        Self {
+            answer: answer.into(),
+            candidates: Vec::new(),
+        }
+    }
+
+    /// Adds a single candidate.
+    pub fn candidate<S: Into<String>>(mut self, candidate: S) -> Self {
+        self.candidates.push(candidate.into());
+        self
+    }
+
+    /// Adds multiple candidates.
+    pub fn candidates<I>(mut self, candidates: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.candidates
+            .extend(candidates.into_iter().map(Into::into));
+        self
+    }
+
+    /// Ranks the candidates, returning the [`Completions`] in order of relevance.
+    pub fn build(self) -> Completions<String> {
+        let answer = self.answer.to_lowercase();
+
+        let mut ranked: Vec<_> = self
+            .candidates
+            .into_iter()
+            .filter_map(|candidate| {
+                Relevance::of(&answer, &candidate.to_lowercase())
+                    .map(|relevance| (relevance, candidate))
+            })
+            .collect();
+
+        if ranked.is_empty() {
+            return completions![self.answer];
+        }
+
+        ranked.sort_unstable();
+        ranked.dedup_by(|a, b| a.1 == b.1);
+        ranked.into_iter().map(|(_, candidate)| candidate).collect()
+    }
+}
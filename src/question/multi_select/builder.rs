@@ -3,7 +3,7 @@ use ui::{backend::Backend, widgets::Text};
 use super::MultiSelect;
 use crate::{
     question::{Choice, Options},
-    ListItem,
+    Answers, ListItem,
 };
 
 /// The builder for a [`multi_select`] prompt.
@@ -143,6 +143,83 @@ impl<'a> MultiSelectBuilder<'a> {
         self
     }
 
+    /// Whether the choices can be filtered by typing.
+    ///
+    /// If `filterable` is `true`, typing narrows down the choices shown to those that fuzzy match
+    /// the typed query, ignoring case, and `Backspace` removes the last character of the query.
+    /// Separators are only shown when the query is empty. The query is shown as the hint of the
+    /// prompt. Characters which would not match any choice are ignored.
+    ///
+    /// `<space>` still toggles the hovered choice, but all other characters are used for the
+    /// query, so `<a>` and `<i>` no longer toggle or invert all the choices. The
+    /// [`ListItem::index`]es of the answer are always the indices in the full list of choices.
+    ///
+    /// If `filterable` is not set, it will default to `false`.
+    ///
+    /// [`ListItem::index`]: crate::ListItem::index
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multi_select = Question::multi_select("cheese")
+    ///     .filterable(true)
+    ///     .build();
+    /// ```
+    pub fn filterable(mut self, filterable: bool) -> Self {
+        self.multi_select.search = if filterable {
+            Some(Default::default())
+        } else {
+            None
+        };
+        self
+    }
+
+    /// The fewest choices which must be selected before the answer can be submitted.
+    ///
+    /// This is checked before the [`validate`] function is called.
+    ///
+    /// If `min_selected` is not set, it will default to `0`.
+    ///
+    /// [`validate`]: Self::validate
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multi_select = Question::multi_select("cheese")
+    ///     .min_selected(1)
+    ///     .build();
+    /// ```
+    pub fn min_selected(mut self, min_selected: usize) -> Self {
+        self.multi_select.min_selected = min_selected;
+        self
+    }
+
+    /// The most choices which can be selected at once.
+    ///
+    /// Once `max_selected` choices are selected, no other choice can be selected until one is
+    /// unselected. Toggling all or inverting the choices is also ignored if it would select too
+    /// many choices. The hint of the prompt is replaced with the limit when a key is ignored.
+    ///
+    /// If `max_selected` is not set, any number of choices can be selected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multi_select = Question::multi_select("cheese")
+    ///     .max_selected(2)
+    ///     .build();
+    /// ```
+    pub fn max_selected(mut self, max_selected: usize) -> Self {
+        self.multi_select.max_selected = Some(max_selected);
+        self
+    }
+
     /// Inserts a [`Choice`] with given text and its default checked state as `false`.
     ///
     /// If you want to set the default checked state, use [`choice_with_default`].
@@ -270,10 +347,11 @@ impl<'a> MultiSelectBuilder<'a> {
         T: Into<Choice<String>>,
         I: IntoIterator<Item = T>,
     {
-        self.multi_select
-            .choices
-            .choices
-            .extend(choices.into_iter().map(|c| c.into().map(Text::new)));
+        self.multi_select.choices.choices.extend(
+            choices
+                .into_iter()
+                .map(|c| c.into().fold_reason(|text| text).map(Text::new)),
+        );
         self.multi_select
             .selected
             .resize(self.multi_select.choices.len(), false);
@@ -315,7 +393,7 @@ impl<'a> MultiSelectBuilder<'a> {
             .reserve(iter.size_hint().0.saturating_add(1));
 
         for choice in iter {
-            match choice.into() {
+            match choice.into().fold_reason(|(text, _)| text) {
                 Choice::Choice((choice, selected)) => {
                     self.multi_select
                         .choices
@@ -334,14 +412,68 @@ impl<'a> MultiSelectBuilder<'a> {
                         .push(Choice::DefaultSeparator);
                     self.multi_select.selected.push(false);
                 }
+                Choice::Disabled {
+                    text: (text, _),
+                    reason,
+                } => {
+                    self.multi_select.choices.choices.push(Choice::Disabled {
+                        text: Text::new(text),
+                        reason,
+                    });
+                    self.multi_select.selected.push(false);
+                }
             }
         }
         self
     }
 
+    /// Creates more [`Choice`]s when the question is asked.
+    ///
+    /// `choices_fn` is called with the previous answers only if the question is asked, so choices
+    /// which are expensive to compute are skipped along with the question. This includes when
+    /// [`when`] returns `false`, and when the question was already answered. The choices it
+    /// returns are added after all the choices given to the other methods, whichever order they
+    /// were called in, and none of them are selected by default. Like with [`choices`], they can
+    /// include separators and disabled choices.
+    ///
+    /// Since the previous answers are passed to `choices_fn`, the choices can depend on how
+    /// earlier questions were answered.
+    ///
+    /// The [`page_size`] and [`should_loop`] options are set on the builder as usual, and apply to
+    /// all the choices.
+    ///
+    /// Calling `choices_fn` again replaces the previous function.
+    ///
+    /// [`Choice`]: crate::question::Choice
+    /// [`when`]: Self::when
+    /// [`choices`]: Self::choices
+    /// [`page_size`]: Self::page_size
+    /// [`should_loop`]: Self::should_loop
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multi_select = Question::multi_select("files")
+    ///     .message("Which files do you want to commit?")
+    ///     .choices_fn(|previous_answers| {
+    ///         // Expensive to compute, for example by listing the changed files of a repository
+    ///         vec!["README.md".into(), "src/main.rs".into()]
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn choices_fn<F>(mut self, choices_fn: F) -> Self
+    where
+        F: FnOnce(&Answers) -> Vec<Choice<String>> + 'a,
+    {
+        self.multi_select.choices_fn = super::ChoicesFn::Sync(Box::new(choices_fn));
+        self
+    }
+
     crate::impl_filter_builder! {
     /// NOTE: The boolean [`Vec`] contains a boolean value for each index even if it is a separator.
-    /// However it is guaranteed that all the separator indices will be false.
+    /// However it is guaranteed that all the separator and disabled indices will be false.
     ///
     /// # Examples
     ///
@@ -359,7 +491,8 @@ impl<'a> MultiSelectBuilder<'a> {
     }
     crate::impl_validate_builder! {
     /// NOTE: The boolean [`slice`] contains a boolean value for each index even if it is a
-    /// separator. However it is guaranteed that all the separator indices will be false.
+    /// separator. However it is guaranteed that all the separator and disabled indices will be
+    /// false.
     ///
     /// # Examples
     ///
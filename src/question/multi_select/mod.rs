@@ -3,12 +3,12 @@ use std::io;
 use ui::{
     backend::Backend,
     events::{EventIterator, KeyCode, KeyEvent},
-    style::Color,
+    style::{Color, StyledStr, StyledString},
     widgets::{self, Text},
     Prompt, Validation, Widget,
 };
 
-use super::{Choice, Filter, Transform, Validate};
+use super::{choice::Search, Choice, ChoicesFn, Filter, Transform, Validate};
 use crate::{Answer, Answers, ListItem};
 
 pub use builder::MultiSelectBuilder;
@@ -21,22 +21,122 @@ mod tests;
 #[derive(Debug, Default)]
 pub(super) struct MultiSelect<'a> {
     choices: super::ChoiceList<Text<String>>,
+    /// Creates more choices when the question is asked, which are not selected by default.
+    choices_fn: ChoicesFn<'a, Choice<String>>,
     selected: Vec<bool>,
+    /// The search over the choices if the multi select is filterable.
+    search: Option<Search>,
+    /// The fewest choices which must be selected to submit.
+    min_selected: usize,
+    /// The most choices which can be selected at once.
+    max_selected: Option<usize>,
     filter: Filter<'a, Vec<bool>>,
     validate: Validate<'a, [bool]>,
     transform: Transform<'a, [ListItem]>,
 }
 
+impl MultiSelect<'_> {
+    /// Adds the choices created by `choices_fn`.
+    fn load_choices(&mut self, answers: &Answers) {
+        if let ChoicesFn::Sync(choices_fn) = self.choices_fn.take() {
+            self.choices.choices.extend(
+                choices_fn(answers)
+                    .into_iter()
+                    .map(|choice| choice.fold_reason(|text| text).map(Text::new)),
+            );
+            self.selected.resize(self.choices.len(), false);
+        }
+    }
+
+    /// The index of the choice which is shown at `index`.
+    fn choice_index(&self, index: usize) -> usize {
+        match self.search {
+            Some(ref search) => search.index(index),
+            None => index,
+        }
+    }
+
+    /// The number of selected choices, ignoring separators and disabled choices.
+    fn selected_count(&self) -> usize {
+        self.selected
+            .iter()
+            .zip(self.choices.choices.iter())
+            .filter(|&(&s, choice)| s && choice.is_choice())
+            .count()
+    }
+
+    /// Whether `count` choices can be selected at once.
+    fn allows(&self, count: usize) -> bool {
+        self.max_selected.map(|max| count <= max).unwrap_or(true)
+    }
+
+    fn hint(&self) -> String {
+        match self.search {
+            Some(ref search) => search_hint(search),
+            None => "Press <space> to select, <a> to toggle all, <i> to invert selection".into(),
+        }
+    }
+
+    /// Checks that the number of selected choices is within `min_selected` and `max_selected`.
+    fn check_count(&self) -> Result<(), String> {
+        let count = self.selected_count();
+
+        if count < self.min_selected {
+            Err(format!(
+                "Please select at least {}",
+                plural(self.min_selected)
+            ))
+        } else if !self.allows(count) {
+            Err(format!(
+                "Please select at most {}",
+                plural(self.max_selected.unwrap_or_default())
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+fn plural(count: usize) -> String {
+    if count == 1 {
+        "1 choice".into()
+    } else {
+        format!("{} choices", count)
+    }
+}
+
 fn set_seperators_false(selected: &mut [bool], choices: &[Choice<Text<String>>]) {
     for (i, choice) in choices.iter().enumerate() {
-        selected[i] &= !choice.is_separator();
+        selected[i] &= choice.is_choice();
     }
 }
 
 struct MultiSelectPrompt<'a, 'c> {
-    prompt: widgets::Prompt<&'a str>,
+    prompt: widgets::Prompt<StyledStr<'a>, String>,
     select: widgets::Select<MultiSelect<'c>>,
     answers: &'a Answers,
+    /// Whether the hint is showing that a key was ignored since too many choices would be selected.
+    showing_max: bool,
+}
+
+impl MultiSelectPrompt<'_, '_> {
+    fn set_hint(&mut self, hint: String) {
+        self.prompt = widgets::Prompt::new(*self.prompt.message()).with_hint(hint);
+    }
+
+    /// Replaces the hint with why the last key was ignored.
+    fn reject_over_max(&mut self) {
+        let max = self.select.list.max_selected.unwrap_or_default();
+        self.set_hint(format!("You can select at most {}", plural(max)));
+        self.showing_max = true;
+    }
+}
+
+fn search_hint(search: &Search) -> String {
+    match search.query() {
+        "" => "Press <space> to select, type to filter".into(),
+        query => format!("Press <space> to select, filter: {}", query),
+    }
 }
 
 fn create_list_items(
@@ -62,6 +162,8 @@ impl Prompt for MultiSelectPrompt<'_, '_> {
     type Output = Vec<ListItem>;
 
     fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
+        self.select.list.check_count()?;
+
         if let Validate::Sync(ref mut validate) = self.select.list.validate {
             set_seperators_false(
                 &mut self.select.list.selected,
@@ -101,23 +203,102 @@ impl Widget for MultiSelectPrompt<'_, '_> {
     }
 
     fn handle_key(&mut self, key: KeyEvent) -> bool {
+        // The hint about the maximum is only shown until the next key
+        let showed_max = std::mem::replace(&mut self.showing_max, false);
+        if showed_max {
+            let hint = self.select.list.hint();
+            self.set_hint(hint);
+        }
+
         match key.code {
             KeyCode::Char(' ') => {
-                let index = self.select.get_at();
-                self.select.list.selected[index] = !self.select.list.selected[index];
+                let at = self.select.get_at();
+                let list = &mut self.select.list;
+                let index = list.choice_index(at);
+
+                if !list.selected[index] && !list.allows(list.selected_count() + 1) {
+                    self.reject_over_max();
+                } else {
+                    list.selected[index] = !list.selected[index];
+                }
+            }
+            _ if self.select.list.search.is_some() => {
+                let MultiSelect {
+                    ref mut search,
+                    ref choices,
+                    ..
+                } = self.select.list;
+                let search = search.as_mut().unwrap();
+
+                return match search.handle_key(key, &choices.choices) {
+                    Some(true) => {
+                        self.prompt = widgets::Prompt::new(*self.prompt.message())
+                            .with_hint(search_hint(search));
+                        self.select.reset();
+                        true
+                    }
+                    Some(false) => showed_max,
+                    None => self.select.handle_key(key) || showed_max,
+                };
             }
             KeyCode::Char('i') => {
-                self.select.list.selected.iter_mut().for_each(|s| *s = !*s);
+                let list = &self.select.list;
+                let choices = list
+                    .choices
+                    .choices
+                    .iter()
+                    .filter(|c| c.is_choice())
+                    .count();
+                if !list.allows(choices - list.selected_count()) {
+                    self.reject_over_max();
+                    return true;
+                }
+
+                let MultiSelect {
+                    ref mut selected,
+                    ref choices,
+                    ..
+                } = self.select.list;
+
+                selected
+                    .iter_mut()
+                    .zip(choices.choices.iter())
+                    .filter(|(_, choice)| choice.is_choice())
+                    .for_each(|(s, _)| *s = !*s);
             }
             KeyCode::Char('a') => {
-                let select_state = self.select.list.selected.iter().any(|s| !s);
-                self.select
-                    .list
-                    .selected
+                let MultiSelect {
+                    ref mut selected,
+                    ref choices,
+                    ..
+                } = self.select.list;
+
+                // Separators and disabled choices are never selected, so they must not take part
+                // in deciding whether everything is selected
+                let select_state = selected
+                    .iter()
+                    .zip(choices.choices.iter())
+                    .any(|(&s, choice)| !s && choice.is_choice());
+
+                let count = choices.choices.iter().filter(|c| c.is_choice()).count();
+                if select_state && !self.select.list.allows(count) {
+                    self.reject_over_max();
+                    return true;
+                }
+
+                let MultiSelect {
+                    ref mut selected,
+                    ref choices,
+                    ..
+                } = self.select.list;
+
+                selected
                     .iter_mut()
-                    .for_each(|s| *s = select_state);
+                    .zip(choices.choices.iter())
+                    .filter(|(_, choice)| choice.is_choice())
+                    .for_each(|(s, _)| *s = select_state);
             }
-            _ => return self.select.handle_key(key),
+            _ => return self.select.handle_key(key) || showed_max,
         }
 
         true
@@ -136,30 +317,33 @@ impl widgets::List for MultiSelect<'_> {
         mut layout: ui::layout::Layout,
         b: &mut B,
     ) -> io::Result<()> {
+        let index = self.choice_index(index);
         let symbol_set = ui::symbols::current();
+        let theme = ui::theme::current();
+
         if hovered {
-            b.set_fg(Color::Cyan)?;
+            b.set_fg(theme.cursor)?;
             write!(b, "{} ", symbol_set.pointer)?;
         } else {
             b.write_all(b"  ")?;
         }
 
-        if self.is_selectable(index) {
+        if self.choices[index].is_choice() {
             if self.selected[index] {
-                b.set_fg(Color::LightGreen)?;
+                b.set_fg(theme.selected)?;
             } else {
-                b.set_fg(Color::DarkGrey)?;
+                b.set_fg(theme.disabled)?;
             }
 
             write!(b, "{} ", symbol_set.completed)?;
 
             if hovered {
-                b.set_fg(Color::Cyan)?;
+                b.set_fg(theme.cursor)?;
             } else {
                 b.set_fg(Color::Reset)?;
             }
         } else {
-            b.set_fg(Color::DarkGrey)?;
+            b.set_fg(theme.disabled)?;
         }
 
         layout.offset_x += 4;
@@ -170,16 +354,20 @@ impl widgets::List for MultiSelect<'_> {
     }
 
     fn is_selectable(&self, index: usize) -> bool {
-        !self.choices[index].is_separator()
+        self.choices[self.choice_index(index)].is_choice()
     }
 
     fn height_at(&mut self, index: usize, mut layout: ui::layout::Layout) -> u16 {
+        let index = self.choice_index(index);
         layout.offset_x += 4;
         self.choices[index].height(&mut layout)
     }
 
     fn len(&self) -> usize {
-        self.choices.len()
+        match self.search {
+            Some(ref search) => search.len(),
+            None => self.choices.len(),
+        }
     }
 
     fn page_size(&self) -> usize {
@@ -193,61 +381,91 @@ impl widgets::List for MultiSelect<'_> {
 
 impl<'c> MultiSelect<'c> {
     fn into_multi_select_prompt<'a>(
-        self,
-        message: &'a str,
+        mut self,
+        message: impl Into<StyledStr<'a>>,
         answers: &'a Answers,
     ) -> MultiSelectPrompt<'a, 'c> {
+        if let Some(ref mut search) = self.search {
+            search.update(&self.choices.choices);
+        }
+
         MultiSelectPrompt {
-            prompt: widgets::Prompt::new(message)
-                .with_hint("Press <space> to select, <a> to toggle all, <i> to invert selection"),
+            prompt: widgets::Prompt::new(message.into()).with_hint(self.hint()),
             select: widgets::Select::new(self),
             answers,
+            showing_max: false,
         }
     }
 
     pub(crate) fn ask<B: Backend, E: EventIterator>(
         mut self,
-        message: String,
-        on_esc: ui::OnEsc,
+        message: StyledString,
+        input_opts: super::InputOptions<'_>,
         answers: &Answers,
         b: &mut B,
         events: &mut E,
     ) -> ui::Result<Option<Answer>> {
+        self.load_choices(answers);
+        super::choice::ensure_selectable(&self.choices)?;
+
         let transform = self.transform.take();
 
         let ans = ui::Input::new(self.into_multi_select_prompt(&message, answers), b)
             .hide_cursor()
-            .on_esc(on_esc)
+            .on_esc(input_opts.on_esc)
+            .timeout(input_opts.timeout)
+            .on_validation_failed(input_opts.on_validation_failed)
             .run(events)?;
 
         crate::write_final!(transform, message, ans [ref], answers, b, |ans| {
-            b.set_fg(Color::Cyan)?;
-            print_comma_separated(
-                ans.iter().map(|item| {
-                    item.text
-                        .lines()
-                        .next()
-                        .expect("There must be at least one line in a `str`")
-                }),
+            super::choice::print_comma_separated(
+                // An empty choice has no lines
+                ans.iter().map(|item| item.text.lines().next().unwrap_or("")),
                 b,
             )?;
-            b.set_fg(Color::Reset)?;
         })
     }
-}
 
-fn print_comma_separated<'a, B: Backend>(
-    iter: impl Iterator<Item = &'a str>,
-    b: &mut B,
-) -> io::Result<()> {
-    let mut iter = iter.peekable();
+    /// The answer must be a list of [`ListItem`]s with the indices of the selected choices. If
+    /// there is no answer, the choices selected by default are used.
+    pub(super) fn answer_non_interactive(
+        mut self,
+        answer: Option<Answer>,
+        answers: &Answers,
+    ) -> Result<Answer, super::Rejected> {
+        self.load_choices(answers);
+
+        match answer {
+            Some(Answer::ListItems(items)) => {
+                self.selected.iter_mut().for_each(|s| *s = false);
 
-    while let Some(item) = iter.next() {
-        b.write_all(item.as_bytes())?;
-        if iter.peek().is_some() {
-            b.write_all(b", ")?;
+                for item in items {
+                    super::choice::check_answer_index(&self.choices, item.index)?;
+                    self.selected[item.index] = true;
+                }
+            }
+            Some(_) => return Err(super::Rejected::Invalid("expected list items".into())),
+            None => set_seperators_false(&mut self.selected, &self.choices.choices),
         }
-    }
 
-    Ok(())
+        self.check_count()?;
+
+        let MultiSelect {
+            mut selected,
+            choices,
+            filter,
+            validate,
+            ..
+        } = self;
+
+        if let Validate::Sync(mut validate) = validate {
+            validate(&selected, answers)?;
+        }
+
+        if let Filter::Sync(filter) = filter {
+            selected = filter(selected, answers);
+        }
+
+        Ok(Answer::ListItems(create_list_items(selected, choices)))
+    }
 }
@@ -176,3 +176,306 @@ test_multi_select!(with_default {
         KeyCode::Char('a').into(),
     ]
 });
+
+#[test]
+fn test_toggle_all_with_separators() {
+    let answers = Answers::default();
+    let mut multi_select =
+        unwrap_multi_select(MultiSelectBuilder::new("name".into()).choices(vec![
+            Choice::Choice("a".to_owned()),
+            Choice::DefaultSeparator,
+            Choice::Choice("b".to_owned()),
+            Choice::Separator("separator".to_owned()),
+            Choice::Choice("c".to_owned()),
+        ]))
+        .into_multi_select_prompt("message", &answers);
+
+    assert!(multi_select.handle_key(KeyCode::Char('a').into()));
+    assert_eq!(
+        multi_select.select.list.selected,
+        [true, false, true, false, true]
+    );
+
+    assert!(multi_select.handle_key(KeyCode::Char('a').into()));
+    assert_eq!(multi_select.select.list.selected, [false; 5]);
+
+    // Every choice selected by hand, with the separators still unselected
+    multi_select.select.list.selected = vec![true, false, true, false, true];
+    assert!(multi_select.handle_key(KeyCode::Char('a').into()));
+    assert_eq!(multi_select.select.list.selected, [false; 5]);
+
+    multi_select.select.list.selected = vec![false, false, true, false, true];
+    assert!(multi_select.handle_key(KeyCode::Char('a').into()));
+    assert_eq!(
+        multi_select.select.list.selected,
+        [true, false, true, false, true]
+    );
+}
+
+#[test]
+fn test_invert_with_separators() {
+    let answers = Answers::default();
+    let mut multi_select =
+        unwrap_multi_select(MultiSelectBuilder::new("name".into()).choices(vec![
+            Choice::Choice("a".to_owned()),
+            Choice::DefaultSeparator,
+            Choice::Choice("b".to_owned()),
+            Choice::Separator("separator".to_owned()),
+            Choice::Choice("c".to_owned()),
+        ]))
+        .into_multi_select_prompt("message", &answers);
+
+    assert!(multi_select.handle_key(KeyCode::Char('i').into()));
+    assert_eq!(
+        multi_select.select.list.selected,
+        [true, false, true, false, true]
+    );
+
+    assert!(multi_select.handle_key(KeyCode::Char(' ').into()));
+    assert!(multi_select.handle_key(KeyCode::Char('i').into()));
+    assert_eq!(
+        multi_select.select.list.selected,
+        [true, false, false, false, false]
+    );
+}
+
+#[test]
+fn test_many_selected() {
+    let multi_select = MultiSelectBuilder::new("name".into())
+        .message("message")
+        .choice("")
+        .choices((1..1000).map(|i| format!("Choice {}\nsecond line", i)));
+
+    let size = (50, 20).into();
+    let mut backend = TestBackend::new(size);
+    let mut events = ui::events::TestEvents::new(vec![
+        KeyEvent::from(KeyCode::Char('a')),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = crate::prompt_one_with(multi_select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_items()
+        .unwrap();
+
+    assert_eq!(ans.len(), 1000);
+    assert!(ans.iter().enumerate().all(|(i, item)| item.index == i));
+}
+
+#[test]
+fn test_filterable() {
+    let multi_select = MultiSelectBuilder::new("name".into())
+        .message("message")
+        .filterable(true)
+        .choices(vec![
+            Choice::Choice("Cheddar".to_owned()),
+            Choice::DefaultSeparator,
+            Choice::Choice("Mozzarella".to_owned()),
+            Choice::Choice("Parmesan".to_owned()),
+        ]);
+
+    let size = (50, 20).into();
+    let mut backend = TestBackend::new(size);
+    let mut events = ui::events::TestEvents::new(vec![
+        // `a` is typed in the query instead of toggling all the choices
+        KeyEvent::from(KeyCode::Char('a')),
+        // No choice matches "ai", so it is ignored
+        KeyCode::Char('i').into(),
+        KeyCode::Backspace.into(),
+        KeyCode::Char('z').into(),
+        KeyCode::Char(' ').into(),
+        KeyCode::Backspace.into(),
+        // `j` is not used for movement either
+        KeyCode::Char('j').into(),
+        KeyCode::Char('p').into(),
+        KeyCode::Char(' ').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = crate::prompt_one_with(multi_select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_items()
+        .unwrap();
+
+    assert_eq!(
+        ans.iter()
+            .map(|item| (item.index, &*item.text))
+            .collect::<Vec<_>>(),
+        [(2, "Mozzarella"), (3, "Parmesan")]
+    );
+}
+
+#[test]
+fn test_disabled() {
+    let answers = Answers::default();
+    let mut multi_select = unwrap_multi_select(
+        MultiSelectBuilder::new("name".into()).choices_with_default(vec![
+            Choice::Choice(("a".to_owned(), false)),
+            Choice::Disabled {
+                text: ("b".to_owned(), true),
+                reason: Some("unavailable".to_owned()),
+            },
+            Choice::Choice(("c".to_owned(), false)),
+        ]),
+    )
+    .into_multi_select_prompt("message", &answers);
+
+    // The disabled choice cannot be selected by default
+    assert_eq!(multi_select.select.list.selected, [false, false, false]);
+
+    multi_select.height(&mut Layout::new(0, (50, 20).into()));
+
+    // The cursor skips the disabled choice in both directions
+    assert!(multi_select.handle_key(KeyCode::Down.into()));
+    assert_eq!(multi_select.select.get_at(), 2);
+    assert!(multi_select.handle_key(KeyCode::Up.into()));
+    assert_eq!(multi_select.select.get_at(), 0);
+
+    assert!(multi_select.handle_key(KeyCode::Char('a').into()));
+    assert_eq!(multi_select.select.list.selected, [true, false, true]);
+
+    assert!(multi_select.handle_key(KeyCode::Char('i').into()));
+    assert_eq!(multi_select.select.list.selected, [false, false, false]);
+}
+
+#[test]
+fn test_max_selected() {
+    let answers = Answers::default();
+    let mut multi_select = unwrap_multi_select(
+        MultiSelectBuilder::new("name".into())
+            .max_selected(2)
+            .choices(vec!["a", "b", "c"]),
+    )
+    .into_multi_select_prompt("message", &answers);
+
+    // Toggling all would select too many choices
+    assert!(multi_select.handle_key(KeyCode::Char('a').into()));
+    assert_eq!(multi_select.select.list.selected, [false; 3]);
+    assert!(multi_select.showing_max);
+
+    assert!(multi_select.handle_key(KeyCode::Char(' ').into()));
+    assert!(!multi_select.showing_max);
+    assert!(multi_select.handle_key(KeyCode::Down.into()));
+    assert!(multi_select.handle_key(KeyCode::Char(' ').into()));
+    assert!(multi_select.handle_key(KeyCode::Down.into()));
+
+    // The cap is hit, so the last choice cannot be selected
+    assert!(multi_select.handle_key(KeyCode::Char(' ').into()));
+    assert_eq!(multi_select.select.list.selected, [true, true, false]);
+    assert!(multi_select.showing_max);
+
+    let size = (50, 20).into();
+    let mut backend = TestBackend::new(size);
+    let mut layout = Layout::new(0, size);
+    assert!(multi_select.render(&mut layout, &mut backend).is_ok());
+    assert_eq!(
+        backend.row_text(0),
+        "? message (You can select at most 2 choices)"
+    );
+
+    // Selected choices can still be unselected
+    assert!(multi_select.handle_key(KeyCode::Up.into()));
+    assert!(!multi_select.showing_max);
+    assert!(multi_select.handle_key(KeyCode::Char(' ').into()));
+    assert_eq!(multi_select.select.list.selected, [true, false, false]);
+
+    // Inverting would select two choices, which is allowed
+    assert!(multi_select.handle_key(KeyCode::Char('i').into()));
+    assert_eq!(multi_select.select.list.selected, [false, true, true]);
+
+    // Toggling all would select every choice since one is unselected
+    assert!(multi_select.handle_key(KeyCode::Char('a').into()));
+    assert_eq!(multi_select.select.list.selected, [false, true, true]);
+    assert!(multi_select.showing_max);
+
+    // Inverting would select all three choices
+    multi_select.select.list.selected = vec![false; 3];
+    assert!(multi_select.handle_key(KeyCode::Char('i').into()));
+    assert_eq!(multi_select.select.list.selected, [false; 3]);
+    assert!(multi_select.showing_max);
+}
+
+#[test]
+fn test_min_selected() {
+    let multi_select = MultiSelectBuilder::new("name".into())
+        .message("message")
+        .min_selected(2)
+        .choices(vec!["a", "b", "c"]);
+
+    let size = (50, 20).into();
+    let mut backend = TestBackend::new(size);
+    let mut events = ui::events::TestEvents::new(vec![
+        KeyEvent::from(KeyCode::Char(' ')),
+        // Rejected since only one choice is selected
+        KeyCode::Enter.into(),
+        KeyCode::Down.into(),
+        KeyCode::Char(' ').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = crate::prompt_one_with(multi_select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_items()
+        .unwrap();
+
+    assert_eq!(
+        ans.iter().map(|item| item.index).collect::<Vec<_>>(),
+        [0, 1]
+    );
+}
+
+#[test]
+fn test_choices_fn() {
+    let answers = Answers::default();
+    let mut multi_select = unwrap_multi_select(
+        MultiSelectBuilder::new("name".into())
+            .choices_fn(|_| vec!["b".into(), "c".into()])
+            .choices_with_default(vec![("a", true)]),
+    );
+    assert_eq!(multi_select.choices.len(), 1);
+
+    multi_select.load_choices(&answers);
+    assert_eq!(multi_select.choices.len(), 3);
+    assert_eq!(multi_select.selected, vec![true, false, false]);
+}
+
+#[test]
+fn test_selected_count_non_interactive() {
+    let answers = Answers::default();
+    let multi_select = || {
+        unwrap_multi_select(
+            MultiSelectBuilder::new("name".into())
+                .min_selected(1)
+                .max_selected(1)
+                .choices(vec!["a", "b"]),
+        )
+    };
+
+    assert!(multi_select()
+        .answer_non_interactive(None, &answers)
+        .is_err());
+    assert!(multi_select()
+        .answer_non_interactive(
+            Some(Answer::ListItems(vec![
+                ListItem {
+                    index: 0,
+                    text: "a".into()
+                },
+                ListItem {
+                    index: 1,
+                    text: "b".into()
+                },
+            ])),
+            &answers
+        )
+        .is_err());
+    assert!(multi_select()
+        .answer_non_interactive(
+            Some(Answer::ListItems(vec![ListItem {
+                index: 1,
+                text: "b".into()
+            }])),
+            &answers
+        )
+        .is_ok());
+}
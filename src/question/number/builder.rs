@@ -126,12 +126,13 @@ macro_rules! builder {
             ///     })
             ///     .build();
             /// ```
-            by val $inner_ty; inner
+            with suggestion by val $inner_ty; inner
             }
 
             crate::impl_validate_on_key_builder! {
             /// Note, the input will be showed in red if the number cannot be parsed even if this
-            /// function is not supplied.
+            /// function is not supplied. Input that is not a number yet, but could become one as
+            /// more is typed (like `-`), is not showed in red and is not given to the function.
             ///
             /// # Examples
             ///
@@ -3,13 +3,13 @@ use std::{fmt::Write, io};
 use ui::{
     backend::Backend,
     events::{EventIterator, KeyCode, KeyEvent},
-    style::Color,
+    style::{StyledStr, StyledString},
     widgets, Prompt, Validation, Widget,
 };
 
 use super::{
-    Filter, TransformByVal as Transform, ValidateByVal as Validate,
-    ValidateOnKeyByVal as ValidateOnKey,
+    Filter, TransformByVal as Transform, ValidateOnKeyByVal as ValidateOnKey,
+    ValidateSuggestByVal as Validate,
 };
 use crate::{Answer, Answers};
 
@@ -41,10 +41,17 @@ pub(super) struct Int<'a> {
 }
 
 impl Int<'_> {
+    const EXPECTED: &'static str = "an integer";
+
+    fn from_answer(answer: Answer) -> Option<i64> {
+        match answer {
+            Answer::Int(i) => Some(i),
+            _ => None,
+        }
+    }
+
     fn write<B: Backend>(i: i64, b: &mut B) -> io::Result<()> {
-        b.set_fg(Color::Cyan)?;
-        write!(b, "{}", i)?;
-        b.set_fg(Color::Reset)
+        write!(b, "{}", i)
     }
 
     fn delta(i: i64, delta: i64) -> i64 {
@@ -58,17 +65,30 @@ impl Int<'_> {
             None
         }
     }
+
+    /// Whether the input could still become a valid integer as more is typed.
+    fn is_incomplete(s: &str) -> bool {
+        matches!(s, "" | "-" | "+")
+    }
 }
 
 impl Float<'_> {
+    const EXPECTED: &'static str = "a number";
+
+    fn from_answer(answer: Answer) -> Option<f64> {
+        match answer {
+            Answer::Float(f) => Some(f),
+            Answer::Int(i) => Some(i as f64),
+            _ => None,
+        }
+    }
+
     fn write<B: Backend>(f: f64, b: &mut B) -> io::Result<()> {
-        b.set_fg(Color::Cyan)?;
         if f.log10().abs() > 19.0 {
-            write!(b, "{:e}", f)?;
+            write!(b, "{:e}", f)
         } else {
-            write!(b, "{}", f)?;
+            write!(b, "{}", f)
         }
-        b.set_fg(Color::Reset)
     }
 
     fn delta(f: f64, delta: i64) -> f64 {
@@ -82,14 +102,33 @@ impl Float<'_> {
             None
         }
     }
+
+    /// Whether the input could still become a valid number as more is typed, for example `-`,
+    /// `.` or `1e-`.
+    fn is_incomplete(s: &str) -> bool {
+        let s = s.strip_prefix(['-', '+']).unwrap_or(s);
+
+        if s.is_empty() || s == "." || "inf".starts_with(s) {
+            return true;
+        }
+
+        let s = s.strip_suffix(['-', '+']).unwrap_or(s);
+        match s.strip_suffix(['e', 'E']) {
+            Some(mantissa) => !mantissa.contains(['e', 'E']) && mantissa.parse::<f64>().is_ok(),
+            None => false,
+        }
+    }
 }
 
 macro_rules! impl_number_prompt {
     ($prompt_name:ident, $type:ident, $inner_ty:ty) => {
         struct $prompt_name<'n, 'a> {
-            prompt: widgets::Prompt<&'a str, String>,
+            prompt: widgets::Prompt<StyledStr<'a>, String>,
             number: $type<'n>,
             input: widgets::StringInput,
+            /// The answer suggested by the last failed validation, which is used if the user
+            /// presses `Tab`. It is forgotten once the input is changed.
+            suggestion: Option<String>,
             is_valid: bool,
             answers: &'a Answers,
         }
@@ -186,17 +225,35 @@ macro_rules! impl_number_prompt {
                         height += 1;
                     }
                 } else {
-                    height = self.input.height(layout);
+                    height += self.input.height(layout);
                 }
 
                 height
             }
 
             fn handle_key(&mut self, key: KeyEvent) -> bool {
+                if key.code == KeyCode::Tab {
+                    if let Some(suggestion) = self.suggestion.take() {
+                        let len = suggestion.chars().count();
+                        self.input.set_value(suggestion);
+                        self.input.set_at(len);
+
+                        match self.parse() {
+                            Ok(n) => self.validate_on_key(n),
+                            Err(_) => self.is_valid = $type::is_incomplete(self.input.value()),
+                        }
+
+                        return true;
+                    }
+                }
+
                 if self.input.handle_key(key) {
+                    self.suggestion = None;
+
                     match self.parse() {
                         Ok(n) => self.validate_on_key(n),
-                        Err(_) => self.is_valid = false,
+                        // Only show the input as invalid if typing more cannot fix it
+                        Err(_) => self.is_valid = $type::is_incomplete(self.input.value()),
                     }
 
                     return true;
@@ -240,7 +297,11 @@ macro_rules! impl_number_prompt {
                 let n = self.parse()?;
 
                 if let Validate::Sync(ref mut validate) = self.number.validate {
-                    validate(n, self.answers)?;
+                    if let Err(e) = validate(n, self.answers) {
+                        let (text, suggestion) = e.into_parts();
+                        self.suggestion = suggestion;
+                        return Err(text);
+                    }
                 }
 
                 Ok(Validation::Finish)
@@ -259,6 +320,10 @@ macro_rules! impl_number_prompt {
                     _ => n,
                 }
             }
+
+            fn has_default(&self) -> bool {
+                self.number.default.is_some()
+            }
         }
     };
 }
@@ -271,12 +336,13 @@ macro_rules! impl_ask {
         impl<'n> $t<'n> {
             fn into_prompt<'a>(
                 self,
-                message: &'a str,
+                message: impl Into<StyledStr<'a>>,
                 answers: &'a Answers,
             ) -> $prompt_name<'n, 'a> {
                 $prompt_name {
-                    prompt: widgets::Prompt::new(message),
+                    prompt: widgets::Prompt::new(message.into()),
                     input: widgets::StringInput::with_filter_map(Self::filter_map),
+                    suggestion: None,
                     is_valid: true,
                     number: self,
                     answers,
@@ -285,8 +351,8 @@ macro_rules! impl_ask {
 
             pub(crate) fn ask<B: Backend, E: EventIterator>(
                 mut self,
-                message: String,
-                on_esc: ui::OnEsc,
+                message: StyledString,
+                input_opts: super::InputOptions<'_>,
                 answers: &Answers,
                 b: &mut B,
                 events: &mut E,
@@ -294,13 +360,41 @@ macro_rules! impl_ask {
                 let transform = self.transform.take();
 
                 let ans = ui::Input::new(self.into_prompt(&message, answers), b)
-                    .on_esc(on_esc)
+                    .on_esc(input_opts.on_esc)
+                    .timeout(input_opts.timeout)
+                    .on_validation_failed(input_opts.on_validation_failed)
                     .run(events)?;
 
                 crate::write_final!(transform, message, ans, answers, b, |ans| Self::write(
                     ans, b
                 )?)
             }
+
+            pub(in crate::question) fn answer_non_interactive(
+                self,
+                answer: Option<Answer>,
+                answers: &Answers,
+            ) -> Result<Answer, crate::question::Rejected> {
+                let n = match (answer, self.default) {
+                    (Some(answer), _) => {
+                        let n = Self::from_answer(answer)
+                            .ok_or_else(|| format!("expected {}", Self::EXPECTED))?;
+
+                        if let Validate::Sync(mut validate) = self.validate {
+                            validate(n, answers)?;
+                        }
+                        n
+                    }
+                    // Like an empty input, the default is not validated
+                    (None, Some((default, _))) => default,
+                    (None, None) => return Err(crate::question::Rejected::Missing),
+                };
+
+                match self.filter {
+                    Filter::Sync(filter) => Ok(filter(n, answers).into()),
+                    Filter::None => Ok(n.into()),
+                }
+            }
         }
     };
 }
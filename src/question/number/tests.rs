@@ -67,7 +67,20 @@ macro_rules! test_numbers {
                     prompt.input.set_value("3".repeat(50));
                     assert_eq!(prompt.height(&mut layout), 2);
                     assert_eq!(layout, base_layout.with_offset(0, 1).with_line_offset(17));
+                    layout = base_layout;
+
+                    // The value exactly fills the rest of the line
+                    prompt.input.set_value("3".repeat(33));
+                    assert_eq!(prompt.height(&mut layout), 2);
+                    assert_eq!(layout, base_layout.with_offset(0, 1).with_line_offset(0));
                 }
+
+                // The message wraps onto a second line
+                let message = "message ".repeat(6);
+                let mut prompt = $prompt_name::default().into_prompt(&message, &answers);
+                let mut layout = base_layout;
+                assert_eq!(prompt.height(&mut layout), 2);
+                assert_eq!(layout, base_layout.with_offset(0, 1).with_line_offset(8));
             }
 
             #[test]
@@ -90,6 +103,13 @@ macro_rules! test_numbers {
                     prompt.input.set_value("3".repeat(50));
                     prompt.input.set_at(50);
                     assert_eq!(prompt.cursor_pos(layout), (17, 1));
+
+                    // The cursor moves to the next line once the line is filled
+                    prompt.input.set_value("3".repeat(33));
+                    prompt.input.set_at(32);
+                    assert_eq!(prompt.cursor_pos(layout), (49, 0));
+                    prompt.input.set_at(33);
+                    assert_eq!(prompt.cursor_pos(layout), (0, 1));
                 }
             }
         }
@@ -98,3 +118,86 @@ macro_rules! test_numbers {
 
 test_numbers!(mod int { Int, 333 });
 test_numbers!(mod float { Float, 3.3 });
+
+#[test]
+fn test_is_incomplete() {
+    for s in ["", "-", "+"].iter() {
+        assert!(Int::is_incomplete(s), "{:?}", s);
+    }
+    for s in ["1-", "--", "+-1"].iter() {
+        assert!(!Int::is_incomplete(s), "{:?}", s);
+    }
+
+    for s in ["", "-", ".", "-.", "i", "-in", "1e", "1.5E-", "-2e+"].iter() {
+        assert!(Float::is_incomplete(s), "{:?}", s);
+    }
+    for s in ["1-", "e", "e-", "1e5e", "inff", "1.2.", "--"].iter() {
+        assert!(!Float::is_incomplete(s), "{:?}", s);
+    }
+}
+
+#[test]
+fn test_incomplete_not_invalid() {
+    use ui::events::KeyCode;
+
+    let answers = Answers::default();
+
+    let mut prompt = Int {
+        validate_on_key: ValidateOnKey::Sync(Box::new(|n, _| n > 0)),
+        ..Default::default()
+    }
+    .into_prompt("message", &answers);
+
+    assert!(prompt.handle_key(KeyCode::Char('-').into()));
+    assert!(prompt.is_valid);
+    assert!(prompt.handle_key(KeyCode::Char('1').into()));
+    assert!(!prompt.is_valid);
+    assert!(prompt.handle_key(KeyCode::Char('-').into()));
+    assert!(!prompt.is_valid);
+
+    let mut prompt = Float::default().into_prompt("message", &answers);
+
+    for &c in ['1', '.', 'e', '-'].iter() {
+        assert!(prompt.handle_key(KeyCode::Char(c).into()));
+        assert!(prompt.is_valid);
+    }
+    assert!(prompt.handle_key(KeyCode::Char('2').into()));
+    assert!(prompt.is_valid);
+    assert!(prompt.handle_key(KeyCode::Char('.').into()));
+    assert!(!prompt.is_valid);
+}
+
+#[test]
+fn test_suggestion() {
+    use ui::events::KeyCode;
+
+    use crate::question::ValidationError;
+
+    let answers = Answers::default();
+
+    let mut prompt = Int {
+        validate: Validate::Sync(Box::new(|n, _| {
+            if n % 2 == 0 {
+                Ok(())
+            } else {
+                Err(ValidationError::new("Please enter an even number")
+                    .with_suggestion((n + 1).to_string()))
+            }
+        })),
+        ..Default::default()
+    }
+    .into_prompt("message", &answers);
+
+    prompt.input.set_value("41".into());
+    prompt.input.set_at(2);
+
+    let err = prompt.validate().expect_err("odd numbers are invalid");
+    assert_eq!(
+        err.text,
+        "Please enter an even number\nPress Tab to use suggestion: 42"
+    );
+
+    assert!(prompt.handle_key(KeyCode::Tab.into()));
+    assert_eq!(prompt.input.value(), "42");
+    assert_eq!(prompt.finish(), 42);
+}
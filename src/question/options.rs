@@ -1,16 +1,18 @@
-use std::fmt;
+use std::{fmt, time::Duration};
 
-use ui::OnEsc;
+use ui::{style::StyledString, OnEsc};
 
 use crate::Answers;
 
 #[derive(Debug)]
 pub(crate) struct Options<'a> {
     pub(crate) name: String,
-    pub(crate) message: Option<Getter<'a, String>>,
+    pub(crate) message: Option<Getter<'a, StyledString>>,
     pub(crate) when: Getter<'a, bool>,
     pub(crate) ask_if_answered: bool,
     pub(crate) on_esc: Getter<'a, OnEsc>,
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) silent: bool,
 }
 
 impl<'a> Options<'a> {
@@ -21,6 +23,8 @@ impl<'a> Options<'a> {
             when: true.into(),
             ask_if_answered: false,
             on_esc: OnEsc::Ignore.into(),
+            timeout: None,
+            silent: false,
         }
     }
 }
@@ -40,15 +44,19 @@ macro_rules! impl_options_builder {
         /// function, it is passed all the previous [`Answers`], and will be called right before the
         /// question is prompted to the user.
         ///
+        /// To highlight parts of the message, a [`StyledString`] can be used instead of a
+        /// [`String`]. Custom prompts are only given the text of the message.
+        ///
         /// If it is not given, the `message` defaults to "\<name\>: ".
         ///
         /// [`Answers`]: crate::Answers
+        /// [`StyledString`]: ui::style::StyledString
         ///
         ///
         $(#[$message_meta])*
         pub fn message<M>(mut self, message: M) -> Self
         where
-            M: Into<crate::question::options::Getter<'a, String>>,
+            M: Into<crate::question::options::Getter<'a, ui::style::StyledString>>,
         {
             self.opts.message = Some(message.into());
             self
@@ -114,6 +122,45 @@ macro_rules! impl_options_builder {
             self.opts.on_esc = on_esc.into();
             self
         }
+
+        /// How long to wait for the user to respond before giving up on them.
+        ///
+        /// If the user does not press any key within the `timeout`, the default answer is
+        /// submitted as if they pressed `Enter`. If the question has no default, it is cancelled
+        /// instead, and an [`ErrorKind::TimedOut`] is returned. Once a key is pressed, the
+        /// `timeout` no longer applies.
+        ///
+        /// If it is not given, it waits for the user forever.
+        ///
+        /// This needs an event source which can wait for a limited time, see
+        /// [`EventIterator::supports_timeout`]. The `termion` backend cannot, so the `timeout` is
+        /// ignored with it.
+        ///
+        /// [`ErrorKind::TimedOut`]: crate::ErrorKind::TimedOut
+        /// [`EventIterator::supports_timeout`]: crate::prompt::events::EventIterator::supports_timeout
+        pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
+            self.opts.timeout = Some(timeout);
+            self
+        }
+
+        /// Whether to leave nothing behind once the question is finished.
+        ///
+        /// By default, a finished question prints its message along with the answer, or the
+        /// result of the `transform`. If `silent` is set, the question is removed from the
+        /// terminal instead, and nothing is printed in its place. The answer is still returned as
+        /// usual.
+        ///
+        /// To do this for every question in a [`PromptModule`], use
+        /// [`PromptModule::clear_answered`].
+        ///
+        /// If it is not given, it defaults to `false`.
+        ///
+        /// [`PromptModule`]: crate::PromptModule
+        /// [`PromptModule::clear_answered`]: crate::PromptModule::clear_answered
+        pub fn silent(mut self, silent: bool) -> Self {
+            self.opts.silent = silent;
+            self
+        }
         )?
     };
 }
@@ -162,19 +209,27 @@ macro_rules! impl_getter_from_val {
     };
 }
 
-impl_getter_from_val!(String, String);
-impl_getter_from_val!(String, &String);
-impl_getter_from_val!(String, &str);
-impl_getter_from_val!(String, &mut str, s => s.to_owned());
-impl_getter_from_val!(String, Box<str>);
-impl_getter_from_val!(String, char, c => c.to_string());
+impl_getter_from_val!(StyledString, StyledString);
+impl_getter_from_val!(StyledString, String);
+impl_getter_from_val!(StyledString, &String, s => s.as_str().into());
+impl_getter_from_val!(StyledString, &str);
+impl_getter_from_val!(StyledString, &mut str, s => (&*s).into());
+impl_getter_from_val!(StyledString, Box<str>, s => String::from(s).into());
+impl_getter_from_val!(StyledString, char, c => c.to_string().into());
+
+impl<'a, T: fmt::Display> From<ui::style::Styled<T>> for Getter<'a, StyledString> {
+    fn from(styled: ui::style::Styled<T>) -> Self {
+        Self::Value(styled.into())
+    }
+}
 
-impl<'a, F> From<F> for Getter<'a, String>
+impl<'a, F, M> From<F> for Getter<'a, StyledString>
 where
-    F: FnOnce(&Answers) -> String + 'a,
+    F: FnOnce(&Answers) -> M + 'a,
+    M: Into<StyledString>,
 {
     fn from(f: F) -> Self {
-        Getter::Function(Box::new(f))
+        Getter::Function(Box::new(|answers| f(answers).into()))
     }
 }
 
@@ -11,6 +11,14 @@ use super::{OrderSelect, OrderSelectItem};
 ///
 /// The choices are [`String`]s and can be multiline.
 ///
+/// The answer is an [`Answer::ListItems`] with the choices in the final order. The
+/// [`index`](crate::ListItem::index) of each item is its index in the original list of choices.
+///
+/// The hovered choice can be moved straight to the top of the list with `Shift+Up` (or `t`), and
+/// to the bottom with `Shift+Down` (or `b`). While a choice is grabbed with `Space`, `Home` (or
+/// `g`) and `End` (or `G`) move it to the top and bottom, and `PageUp` and `PageDown` move it by a
+/// page.
+///
 /// // TODO : add gif
 /// <img
 ///   src="https://raw.githubusercontent.com/lutetium-vanadium/requestty/master/assets/multi-select.gif"
@@ -36,10 +44,12 @@ use super::{OrderSelect, OrderSelectItem};
 ///
 ///
 /// [`order_select`]: crate::question::Question::order_select
+/// [`Answer::ListItems`]: crate::Answer::ListItems
 #[derive(Debug)]
 pub struct OrderSelectBuilder<'a> {
     opts: Options<'a>,
     order_select: OrderSelect<'a>,
+    default_order: Option<Vec<usize>>,
 }
 
 impl<'a> OrderSelectBuilder<'a> {
@@ -47,6 +57,7 @@ impl<'a> OrderSelectBuilder<'a> {
         Self {
             opts: Options::new(name),
             order_select: Default::default(),
+            default_order: None,
         }
     }
 
@@ -150,6 +161,117 @@ impl<'a> OrderSelectBuilder<'a> {
         self
     }
 
+    /// Set the order in which the choices are initially shown.
+    ///
+    /// The `i`th element of `default_order` is the index of the choice (in the order they were
+    /// added) that is shown at position `i`. If `default_order` is unspecified, the choices are shown
+    /// in the order they were added.
+    ///
+    /// # Panics
+    ///
+    /// If `default_order` is not a permutation of the indices of the choices, or if it moves a
+    /// [locked] choice, it will cause a panic on [`build`].
+    ///
+    /// [locked]: OrderSelectBuilder::locked_choice
+    /// [`build`]: Self::build
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let order_select = Question::order_select("home_tasks")
+    ///     .choices(vec![
+    ///         "Make the bed",
+    ///         "Clean the dishes",
+    ///         "Mow the lawn",
+    ///     ])
+    ///     // Mow the lawn, Make the bed, Clean the dishes
+    ///     .default_order(vec![2, 0, 1])
+    ///     .build();
+    /// ```
+    pub fn default_order(mut self, default_order: Vec<usize>) -> Self {
+        self.default_order = Some(default_order);
+        self
+    }
+
+    /// Use grab and drop to move the choices.
+    ///
+    /// In grab mode, pressing `Space` grabs the hovered choice, which is then moved with the arrow
+    /// keys. Pressing `Enter` (or `Space` again) drops it in place. `Enter` only submits the answer
+    /// when no choice is grabbed.
+    ///
+    /// If `grab_mode` is not called, `Enter` will submit the answer even if a choice is being
+    /// moved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let order_select = Question::order_select("home_tasks")
+    ///     //...
+    ///     .grab_mode()
+    ///     //...
+    ///     .build();
+    /// ```
+    pub fn grab_mode(mut self) -> Self {
+        self.order_select.grab_mode = true;
+        self
+    }
+
+    /// Allow the user to sort the list by pressing `s` (ascending) or `S` (descending).
+    ///
+    /// Choices which are numbers are sorted by their value, and are placed before the rest which
+    /// are sorted alphabetically. [Locked] choices keep their position. The user can continue to
+    /// reorder the list after sorting it.
+    ///
+    /// If `allow_sort` is not called, sorting is disabled.
+    ///
+    /// [Locked]: OrderSelectBuilder::locked_choice
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let order_select = Question::order_select("home_tasks")
+    ///     //...
+    ///     .allow_sort()
+    ///     //...
+    ///     .build();
+    /// ```
+    pub fn allow_sort(mut self) -> Self {
+        self.order_select.allow_sort = true;
+        self
+    }
+
+    /// Ask the user to confirm the order before submitting it.
+    ///
+    /// When the user presses `Enter` (and the order passes [validation]), the question asks
+    /// whether the order is correct. Answering `y` submits the answer, while answering `n` lets
+    /// the user continue reordering the choices.
+    ///
+    /// If `confirm_before_finish` is not called, the answer is submitted without confirmation.
+    ///
+    /// [validation]: OrderSelectBuilder::validate
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let order_select = Question::order_select("home_tasks")
+    ///     //...
+    ///     .confirm_before_finish()
+    ///     //...
+    ///     .build();
+    /// ```
+    pub fn confirm_before_finish(mut self) -> Self {
+        self.order_select.confirm_before_finish = true;
+        self
+    }
+
     /// Extends the given iterator of [`Choice`]s
     ///
     /// The choices are [`String`]s and can be multiline.
@@ -185,13 +307,48 @@ impl<'a> OrderSelectBuilder<'a> {
                 .map(|(i, c)|
                     OrderSelectItem { 
                         initial_index:len + i, 
-                        text: Text::new(c.into())
+                        text: Text::new(c.into()),
+                        locked: false,
                     }
                 ),
         );
         self
     }
 
+    /// Adds a choice which is locked in place.
+    ///
+    /// A locked choice keeps its position in the list -- it cannot be hovered or moved, and other
+    /// choices are moved around it. It is rendered greyed out with a `·` beside it.
+    ///
+    /// There must be at least one choice which is not locked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let order_select = Question::order_select("home_tasks")
+    ///     //...
+    ///     .locked_choice("Wake up")
+    ///     .choices(vec![
+    ///         "Make the bed",
+    ///         "Clean the dishes",
+    ///     ])
+    ///     .locked_choice("Go to sleep")
+    ///     //...
+    ///     .build();
+    /// ```
+    pub fn locked_choice<T: Into<String>>(mut self, choice: T) -> Self {
+        let initial_index = self.order_select.choices.choices.len();
+
+        self.order_select.choices.choices.push(OrderSelectItem {
+            initial_index,
+            text: Text::new(choice.into()),
+            locked: true,
+        });
+        self
+    }
+
     crate::impl_filter_builder! {
         /// # Examples
         ///
@@ -231,6 +388,31 @@ impl<'a> OrderSelectBuilder<'a> {
         [OrderSelectItem]; order_select
     }
 
+    crate::impl_hint_builder! {
+        hint
+        /// # Examples
+        ///
+        /// ```
+        /// use requestty::Question;
+        ///
+        /// let order_select = Question::order_select("home_tasks")
+        ///     .hint("Most important first")
+        ///     .build();
+        /// ```
+
+        no_hint
+        /// # Examples
+        ///
+        /// ```
+        /// use requestty::Question;
+        ///
+        /// let order_select = Question::order_select("home_tasks")
+        ///     .no_hint()
+        ///     .build();
+        /// ```
+        order_select
+    }
+
     crate::impl_transform_builder! {
         /// # Examples
         ///
@@ -255,6 +437,34 @@ impl<'a> OrderSelectBuilder<'a> {
     ///
     /// [`Question`]: crate::question::Question
     pub fn build(mut self) -> crate::question::Question<'a> {
+        if let Some(default_order) = self.default_order {
+            let choices = &mut self.order_select.choices.choices;
+            let mut positions = vec![usize::MAX; choices.len()];
+
+            for (position, &index) in default_order.iter().enumerate() {
+                if index >= choices.len() || positions[index] != usize::MAX {
+                    break;
+                }
+                positions[index] = position;
+            }
+
+            if default_order.len() != choices.len() || positions.contains(&usize::MAX) {
+                panic!(
+                    "Invalid default order {:?} is not a permutation of the choices",
+                    default_order
+                );
+            }
+
+            if let Some(item) = choices
+                .iter()
+                .find(|item| item.locked && positions[item.initial_index] != item.initial_index)
+            {
+                panic!("Invalid default order moves locked choice '{}'", item.text());
+            }
+
+            choices.sort_unstable_by_key(|item| positions[item.initial_index]);
+        }
+
         self.order_select.max_index_width = (self.order_select.choices.len() as f64 + 1.0).log10() as usize + 1;
 
         crate::question::Question::new(
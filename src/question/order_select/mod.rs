@@ -1,11 +1,11 @@
 mod builder;
 
-use std::io;
+use std::{borrow::Cow, cmp::Ordering, io};
 
 use ui::{
     backend::Backend,
-    events::{EventIterator, KeyEvent},
-    style::Color,
+    events::{EventIterator, KeyCode, KeyEvent, KeyModifiers},
+    style::{Color, StyledStr, StyledString},
     widgets::{self, Text},
     Prompt, Widget,
 };
@@ -13,7 +13,9 @@ use ui::{
 use crate::{Answer, Answers, ListItem};
 
 use super::{
-    handler::{Filter, Transform, Validate}, choice::SelectList,
+    choice::SelectList,
+    handler::{Filter, Transform, Validate},
+    Hint,
 };
 
 pub use builder::OrderSelectBuilder;
@@ -30,6 +32,10 @@ pub(super) struct OrderSelect<'a> {
     choices: SelectList<OrderSelectItem>,
     max_index_width: usize,
     moving: bool,
+    grab_mode: bool,
+    allow_sort: bool,
+    confirm_before_finish: bool,
+    hint: Hint,
 
     transform: Transform<'a, [OrderSelectItem]>,
     validate: Validate<'a, [OrderSelectItem]>,
@@ -38,7 +44,7 @@ pub(super) struct OrderSelect<'a> {
 
 impl<'a> Default for OrderSelect<'a> {
     fn default() -> Self {
-        Self { 
+        Self {
             choices: SelectList::new(|_| true),
 
             // can't put
@@ -46,12 +52,16 @@ impl<'a> Default for OrderSelect<'a> {
             // because of recursion
             max_index_width: Default::default(),
             moving: Default::default(),
+            grab_mode: Default::default(),
+            allow_sort: Default::default(),
+            confirm_before_finish: Default::default(),
+            hint: Default::default(),
             transform: Default::default(),
             validate: Default::default(),
             filter: Default::default(),
         }
     }
-} 
+}
 
 impl widgets::List for OrderSelect<'_> {
     fn render_item<B: ui::backend::Backend>(
@@ -62,16 +72,20 @@ impl widgets::List for OrderSelect<'_> {
         b: &mut B,
     ) -> std::io::Result<()> {
         let symbol_set = ui::symbols::current();
+        let theme = ui::theme::current();
 
         if hovered {
             if self.moving {
-                b.set_bg(Color::Cyan)?;
+                b.set_bg(theme.cursor)?;
                 b.set_fg(Color::Black)?;
             } else {
-                b.set_fg(Color::Cyan)?;
+                b.set_fg(theme.cursor)?;
             }
 
             write!(b, "{} ", symbol_set.pointer)?;
+        } else if self.choices[index].locked {
+            b.set_fg(theme.disabled)?;
+            write!(b, "{} ", symbol_set.middle_dot)?;
         } else {
             b.write_all(b"  ")?;
         }
@@ -91,8 +105,8 @@ impl widgets::List for OrderSelect<'_> {
         b.set_bg(Color::Reset)
     }
 
-    fn is_selectable(&self, _index: usize) -> bool {
-        true
+    fn is_selectable(&self, index: usize) -> bool {
+        !self.choices[index].locked
     }
 
     fn page_size(&self) -> usize {
@@ -114,62 +128,133 @@ impl widgets::List for OrderSelect<'_> {
 }
 
 impl<'c> OrderSelect<'c> {
+    /// Moves the item at `from` to `to`, shifting all the unlocked items in between by one.
+    ///
+    /// Locked items are never moved, so `from` and `to` must not be locked.
+    fn move_item(&mut self, from: usize, to: usize) {
+        let choices = &mut self.choices.choices;
+        let mut prev = from;
+
+        let mut swap_unlocked = |i: usize| {
+            if !choices[i].locked {
+                choices.swap(prev, i);
+                prev = i;
+            }
+        };
+
+        if from < to {
+            (from + 1..=to).for_each(&mut swap_unlocked);
+        } else {
+            (to..from).rev().for_each(&mut swap_unlocked);
+        }
+    }
+
+    /// Sorts the unlocked items by their text, leaving the locked items in place.
+    fn sort(&mut self, descending: bool) {
+        let len = self.choices.choices.len();
+
+        let (locked, mut unlocked): (Vec<_>, Vec<_>) = std::mem::take(&mut self.choices.choices)
+            .into_iter()
+            .enumerate()
+            .partition(|(_, item)| item.locked);
+
+        unlocked.sort_by(|(_, a), (_, b)| {
+            let ordering = compare_text(a.text(), b.text());
+            if descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+
+        let mut locked = locked.into_iter().peekable();
+        let mut unlocked = unlocked.into_iter();
+
+        self.choices.choices = (0..len)
+            .map(|i| match locked.peek() {
+                Some(&(index, _)) if index == i => locked.next().unwrap().1,
+                _ => unlocked.next().unwrap().1,
+            })
+            .collect();
+    }
+
+    fn first_unlocked(&self) -> usize {
+        self.choices
+            .choices
+            .iter()
+            .position(|item| !item.locked)
+            .unwrap_or(0)
+    }
+
+    fn last_unlocked(&self) -> usize {
+        self.choices
+            .choices
+            .iter()
+            .rposition(|item| !item.locked)
+            .unwrap_or(0)
+    }
+
     fn into_order_select_prompt<'a>(
-        self,
-        message: &'a str,
+        mut self,
+        message: impl Into<StyledStr<'a>>,
         answers: &'a Answers,
     ) -> OrderSelectPrompt<'a, 'c> {
+        let hint = std::mem::take(&mut self.hint).resolve(Some(if self.grab_mode {
+            "Press <space> to grab an option and <enter> to drop it"
+        } else {
+            "Press <space> to take and place an option"
+        }));
+
         OrderSelectPrompt {
-            prompt: widgets::Prompt::new(message).with_hint(
-                "Press <space> to take and place an option",
-            ),
+            prompt: widgets::Prompt::new(message.into()).with_optional_hint(hint),
             select: widgets::Select::new(self),
+            confirm: None,
             answers,
         }
     }
 
     pub(crate) fn ask<B: Backend, E: EventIterator>(
         mut self,
-        message: String,
-        on_esc: ui::OnEsc,
+        message: StyledString,
+        input_opts: super::InputOptions<'_>,
         answers: &Answers,
         b: &mut B,
         events: &mut E,
     ) -> ui::Result<Option<Answer>> {
+        super::choice::ensure_selectable(&self)?;
+
         let transform = self.transform.take();
 
         let ans = ui::Input::new(self.into_order_select_prompt(&message, answers), b)
             .hide_cursor()
-            .on_esc(on_esc)
+            .on_esc(input_opts.on_esc)
+            .timeout(input_opts.timeout)
+            .on_validation_failed(input_opts.on_validation_failed)
             .run(events)?;
 
         crate::write_final!(transform, message, ans [ref], answers, b, |ans| {
-            b.set_fg(Color::Cyan)?;
-            print_comma_separated(
+            super::choice::print_comma_separated(
                 ans.iter().map(|item| {
                     item.text()
                 }),
                 b,
             )?;
-            b.set_fg(Color::Reset)?;
         })
     }
 }
 
-fn print_comma_separated<'a, B: Backend>(
-    iter: impl Iterator<Item = &'a str>,
-    b: &mut B,
-) -> io::Result<()> {
-    let mut iter = iter.peekable();
-
-    while let Some(item) = iter.next() {
-        b.write_all(item.as_bytes())?;
-        if iter.peek().is_some() {
-            b.write_all(b", ")?;
-        }
+/// Compares numbers by their value, and everything else alphabetically. Numbers are always
+/// placed before text.
+fn compare_text(a: &str, b: &str) -> Ordering {
+    match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+        // NaN cannot be compared, so it is placed after the other numbers
+        (Ok(a), Ok(b)) => a
+            .partial_cmp(&b)
+            .unwrap_or_else(|| a.is_nan().cmp(&b.is_nan())),
+        (Ok(_), Err(_)) => Ordering::Less,
+        (Err(_), Ok(_)) => Ordering::Greater,
+        (Err(_), Err(_)) => a.cmp(b),
     }
-
-    Ok(())
 }
 
 // =============================================================================
@@ -177,20 +262,22 @@ fn print_comma_separated<'a, B: Backend>(
 // =============================================================================
 
 struct OrderSelectPrompt<'a, 'c> {
-    prompt: widgets::Prompt<&'a str>,
+    prompt: widgets::Prompt<StyledStr<'a>, Cow<'static, str>>,
     select: widgets::Select<OrderSelect<'c>>,
+    /// The y/n input shown once the order is submitted, if `confirm_before_finish` was set.
+    confirm: Option<widgets::CharInput>,
     answers: &'a Answers,
 }
 
+const CONFIRM_MESSAGE: &str = "Is this order correct?";
+
 impl Prompt for OrderSelectPrompt<'_, '_> {
     type ValidateErr = widgets::Text<String>;
     type Output = Vec<OrderSelectItem>;
 
     fn finish(self) -> Self::Output {
         let OrderSelect {
-            choices,
-            filter,
-            ..
+            choices, filter, ..
         } = self.select.into_inner();
 
         let mut c = choices.choices;
@@ -203,9 +290,34 @@ impl Prompt for OrderSelectPrompt<'_, '_> {
     }
 
     fn validate(&mut self) -> Result<ui::Validation, Self::ValidateErr> {
+        if let Some(ref confirm) = self.confirm {
+            return match confirm.value() {
+                Some('y') | Some('Y') => Ok(ui::Validation::Finish),
+                Some(_) => {
+                    // The user rejected the order, so let them continue editing it
+                    self.confirm = None;
+                    Ok(ui::Validation::Continue)
+                }
+                None => Err("Please enter y or n".to_owned().into()),
+            };
+        }
+
+        if self.select.list.grab_mode && self.select.list.moving {
+            // In grab mode, enter drops the grabbed item instead of submitting
+            self.select.list.moving = false;
+            return Ok(ui::Validation::Continue);
+        }
+
         if let Validate::Sync(ref mut validate) = self.select.list.validate {
             validate(&self.select.list.choices.choices, self.answers)?;
         }
+
+        if self.select.list.confirm_before_finish {
+            self.select.list.moving = false;
+            self.confirm = Some(widgets::CharInput::with_filter_map(super::confirm::only_yn));
+            return Ok(ui::Validation::Continue);
+        }
+
         Ok(ui::Validation::Finish)
     }
 }
@@ -217,11 +329,24 @@ impl Widget for OrderSelectPrompt<'_, '_> {
         backend: &mut B,
     ) -> io::Result<()> {
         self.prompt.render(layout, backend)?;
-        self.select.render(layout, backend)
+        self.select.render(layout, backend)?;
+
+        if let Some(ref mut confirm) = self.confirm {
+            confirm_prompt().render(layout, backend)?;
+            confirm.render(layout, backend)?;
+        }
+
+        Ok(())
     }
 
     fn height(&mut self, layout: &mut ui::layout::Layout) -> u16 {
-        self.prompt.height(layout) + self.select.height(layout) - 1
+        let mut height = self.prompt.height(layout) + self.select.height(layout) - 1;
+
+        if let Some(ref mut confirm) = self.confirm {
+            height += confirm_prompt().height(layout) + confirm.height(layout) - 1;
+        }
+
+        height
     }
 
     fn cursor_pos(&mut self, layout: ui::layout::Layout) -> (u16, u16) {
@@ -229,30 +354,60 @@ impl Widget for OrderSelectPrompt<'_, '_> {
     }
 
     fn handle_key(&mut self, key: ui::events::KeyEvent) -> bool {
+        if let Some(ref mut confirm) = self.confirm {
+            return confirm.handle_key(key);
+        }
+
         let prev_at = self.select.get_at();
-    
-        if let ui::events::KeyCode::Char(' ') = key.code {
-            self.select.list.moving = !self.select.list.moving;
-        } else if self.select.handle_key(key) {
-            if self.select.list.moving {
-                let new_at = self.select.get_at();
-    
-                if prev_at < new_at {
-                    self.select.list.choices.choices[prev_at..=new_at].rotate_left(1);
-                } else {
-                    self.select.list.choices.choices[new_at..=prev_at].rotate_right(1);
+
+        match key.code {
+            KeyCode::Char(' ') => {
+                self.select.list.moving = !self.select.list.moving;
+            }
+            KeyCode::Up if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                self.move_hovered_to(self.select.list.first_unlocked())
+            }
+            KeyCode::Char('t') => self.move_hovered_to(self.select.list.first_unlocked()),
+            KeyCode::Down if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                self.move_hovered_to(self.select.list.last_unlocked())
+            }
+            KeyCode::Char('b') => self.move_hovered_to(self.select.list.last_unlocked()),
+            KeyCode::Char(c @ 's') | KeyCode::Char(c @ 'S') if self.select.list.allow_sort => {
+                self.select.list.moving = false;
+                self.select.list.sort(c == 'S');
+                self.select.refresh_heights();
+            }
+            _ if self.select.handle_key(key) => {
+                if self.select.list.moving {
+                    let new_at = self.select.get_at();
+                    self.select.list.move_item(prev_at, new_at);
+                    self.select.refresh_heights();
                 }
             }
-        } else {
-            return false;
+            _ => return false,
         }
-    
+
         true
     }
 }
 
+fn confirm_prompt() -> widgets::Prompt<&'static str> {
+    widgets::Prompt::new(CONFIRM_MESSAGE).with_hint("y/n")
+}
+
+impl OrderSelectPrompt<'_, '_> {
+    /// Moves the hovered item to `new_at`, and keeps hovering over it.
+    fn move_hovered_to(&mut self, new_at: usize) {
+        let prev_at = self.select.get_at();
+
+        self.select.list.move_item(prev_at, new_at);
+        self.select.refresh_heights();
+        self.select.set_at(new_at);
+    }
+}
+
 // =============================================================================
-// 
+//
 // =============================================================================
 
 /// The representation of each choice in an [`OrderSelect`].
@@ -262,6 +417,7 @@ impl Widget for OrderSelectPrompt<'_, '_> {
 pub struct OrderSelectItem {
     initial_index: usize,
     text: Text<String>,
+    locked: bool,
 }
 
 impl OrderSelectItem {
@@ -275,6 +431,11 @@ impl OrderSelectItem {
     pub fn text(&self) -> &str {
         &self.text.text
     }
+
+    /// Whether the choice is locked in place, and so cannot be moved by the user.
+    pub fn is_locked(&self) -> bool {
+        self.locked
+    }
 }
 
 impl Widget for OrderSelectItem {
@@ -1,4 +1,4 @@
-use ui::{backend::TestBackend, layout::Layout, events::{KeyCode, KeyEvent}};
+use ui::{backend::TestBackend, layout::Layout, events::{KeyCode, KeyEvent, KeyModifiers}};
 
 use crate::question::{Question, QuestionKind};
 
@@ -125,3 +125,315 @@ test_order_select!(pagination {
         );
     height = 17;
 });
+
+test_order_select!(locked {
+    order_select = unwrap_order_select(
+            OrderSelectBuilder::new("name".into())
+                .locked_choice("locked")
+                .choices(choices(8))
+                .locked_choice("also locked"),
+        );
+    height = 12;
+    events = [
+        KeyEvent::from(KeyCode::Char(' ')),
+        KeyCode::Up.into(),
+        KeyCode::Down.into(),
+        KeyCode::Char(' ').into(),
+    ];
+});
+
+test_order_select!(default_order {
+    order_select = unwrap_order_select(
+            OrderSelectBuilder::new("name".into())
+                .choices(choices(10))
+                .default_order(vec![3, 1, 4, 0, 5, 9, 2, 6, 8, 7]),
+        );
+    height = 12;
+});
+
+#[test]
+#[should_panic(expected = "Invalid default order [0, 0] is not a permutation of the choices")]
+fn test_panic_default_order_duplicate() {
+    OrderSelectBuilder::new("name".into())
+        .choices(choices(2))
+        .default_order(vec![0, 0])
+        .build();
+}
+
+#[test]
+#[should_panic(expected = "Invalid default order [0, 1] is not a permutation of the choices")]
+fn test_panic_default_order_length() {
+    OrderSelectBuilder::new("name".into())
+        .choices(choices(3))
+        .default_order(vec![0, 1])
+        .build();
+}
+
+#[test]
+#[should_panic(expected = "Invalid default order moves locked choice 'locked'")]
+fn test_panic_default_order_locked() {
+    OrderSelectBuilder::new("name".into())
+        .locked_choice("locked")
+        .choices(choices(2))
+        .default_order(vec![1, 0, 2])
+        .build();
+}
+
+fn multiline_choices(len: usize) -> impl Iterator<Item = String> {
+    (0..len).map(|choice| {
+        if choice % 3 == 0 {
+            format!("{}\n{} continued", choice, choice)
+        } else {
+            choice.to_string()
+        }
+    })
+}
+
+test_order_select!(multiline {
+    order_select = unwrap_order_select(
+            OrderSelectBuilder::new("name".into()).choices(multiline_choices(6)),
+        );
+    height = 10;
+    events = [
+        KeyEvent::from(KeyCode::Char(' ')),
+        KeyCode::Down.into(),
+        KeyCode::Down.into(),
+        KeyCode::Up.into(),
+        KeyCode::Char(' ').into(),
+    ];
+});
+
+test_order_select!(multiline_pagination {
+    order_select = unwrap_order_select(
+            OrderSelectBuilder::new("name".into())
+                .choices(multiline_choices(20))
+                .page_size(10),
+        );
+    height = 12;
+    events = [
+        KeyEvent::from(KeyCode::Down),
+        KeyCode::Down.into(),
+        KeyCode::Char(' ').into(),
+        KeyCode::Down.into(),
+        KeyCode::Down.into(),
+        KeyCode::Down.into(),
+        KeyCode::Down.into(),
+        KeyCode::Down.into(),
+        KeyCode::Up.into(),
+        KeyCode::Char(' ').into(),
+        KeyCode::Up.into(),
+        KeyCode::Char(' ').into(),
+        KeyCode::Up.into(),
+        KeyCode::Up.into(),
+        KeyCode::Up.into(),
+        KeyCode::Up.into(),
+    ];
+});
+
+fn initial_indices(order_select: &OrderSelectPrompt<'_, '_>) -> Vec<usize> {
+    order_select
+        .select
+        .list
+        .choices
+        .choices
+        .iter()
+        .map(OrderSelectItem::initial_index)
+        .collect()
+}
+
+#[test]
+fn test_move_to_top_and_bottom() {
+    let answers = Answers::default();
+    let mut order_select = unwrap_order_select(
+        OrderSelectBuilder::new("name".into()).choices(choices(5)),
+    )
+    .into_order_select_prompt("message", &answers);
+
+    let mut layout = Layout::new(5, (50, 20).into());
+    order_select.height(&mut layout);
+
+    assert!(order_select.handle_key(KeyCode::Down.into()));
+    assert!(order_select.handle_key(KeyCode::Down.into()));
+    assert!(order_select.handle_key(KeyEvent::new(KeyCode::Up, KeyModifiers::SHIFT)));
+    assert_eq!(initial_indices(&order_select), [2, 0, 1, 3, 4]);
+    assert_eq!(order_select.select.get_at(), 0);
+
+    assert!(order_select.handle_key(KeyEvent::new(KeyCode::Down, KeyModifiers::SHIFT)));
+    assert_eq!(initial_indices(&order_select), [0, 1, 3, 4, 2]);
+    assert_eq!(order_select.select.get_at(), 4);
+
+    assert!(order_select.handle_key(KeyCode::Up.into()));
+    assert!(order_select.handle_key(KeyCode::Char('t').into()));
+    assert_eq!(initial_indices(&order_select), [4, 0, 1, 3, 2]);
+    assert_eq!(order_select.select.get_at(), 0);
+
+    assert!(order_select.handle_key(KeyCode::Char('b').into()));
+    assert_eq!(initial_indices(&order_select), [0, 1, 3, 2, 4]);
+    assert_eq!(order_select.select.get_at(), 4);
+}
+
+#[test]
+fn test_locked_keep_index() {
+    let answers = Answers::default();
+    let mut order_select = unwrap_order_select(
+        OrderSelectBuilder::new("name".into())
+            .choices(choices(2))
+            .locked_choice("locked")
+            .choices(choices(2)),
+    )
+    .into_order_select_prompt("message", &answers);
+
+    let mut layout = Layout::new(5, (50, 20).into());
+    order_select.height(&mut layout);
+
+    // the locked item is skipped over while moving
+    assert!(order_select.handle_key(KeyCode::Down.into()));
+    assert!(order_select.handle_key(KeyCode::Char(' ').into()));
+    assert!(order_select.handle_key(KeyCode::Down.into()));
+    assert_eq!(initial_indices(&order_select), [0, 3, 2, 1, 4]);
+    assert_eq!(order_select.select.get_at(), 3);
+
+    assert!(order_select.handle_key(KeyCode::Up.into()));
+    assert!(order_select.handle_key(KeyCode::Up.into()));
+    assert_eq!(initial_indices(&order_select), [1, 0, 2, 3, 4]);
+    assert_eq!(order_select.select.get_at(), 0);
+    assert!(order_select.handle_key(KeyCode::Char(' ').into()));
+
+    assert!(order_select.handle_key(KeyCode::Char('b').into()));
+    assert_eq!(initial_indices(&order_select), [0, 3, 2, 4, 1]);
+
+    assert!(order_select.handle_key(KeyCode::Char('t').into()));
+    assert_eq!(initial_indices(&order_select), [1, 0, 2, 3, 4]);
+}
+
+#[test]
+fn test_sort() {
+    let answers = Answers::default();
+    let mut order_select = unwrap_order_select(
+        OrderSelectBuilder::new("name".into())
+            .choices(vec!["b", "10", "a"])
+            .locked_choice("locked")
+            .choices(vec!["9"])
+            .allow_sort(),
+    )
+    .into_order_select_prompt("message", &answers);
+
+    assert!(order_select.handle_key(KeyCode::Char('s').into()));
+    assert_eq!(initial_indices(&order_select), [4, 1, 2, 3, 0]);
+
+    assert!(order_select.handle_key(KeyCode::Char('S').into()));
+    assert_eq!(initial_indices(&order_select), [0, 2, 1, 3, 4]);
+
+    // the sorted list can still be reordered
+    assert!(order_select.handle_key(KeyCode::Char('b').into()));
+    assert_eq!(initial_indices(&order_select), [2, 1, 4, 3, 0]);
+
+    let mut order_select =
+        unwrap_order_select(OrderSelectBuilder::new("name".into()).choices(vec!["b", "a"]))
+            .into_order_select_prompt("message", &answers);
+
+    assert!(!order_select.handle_key(KeyCode::Char('s').into()));
+    assert_eq!(initial_indices(&order_select), [0, 1]);
+}
+
+#[test]
+fn test_finish_keeps_initial_index() {
+    let answers = Answers::default();
+    let mut order_select = unwrap_order_select(
+        OrderSelectBuilder::new("name".into()).choices(vec!["a", "b", "c"]),
+    )
+    .into_order_select_prompt("message", &answers);
+
+    let mut layout = Layout::new(5, (50, 20).into());
+    order_select.height(&mut layout);
+
+    // move "a" to the bottom, then "c" to the top
+    assert!(order_select.handle_key(KeyCode::Char('b').into()));
+    assert!(order_select.handle_key(KeyCode::Up.into()));
+    assert!(order_select.handle_key(KeyCode::Char('t').into()));
+
+    let ans: Answer = order_select.finish().into();
+    assert_eq!(
+        ans,
+        Answer::ListItems(vec![
+            ListItem::from((2, "c")),
+            ListItem::from((1, "b")),
+            ListItem::from((0, "a")),
+        ])
+    );
+}
+
+#[test]
+fn test_move_grabbed_to_extremes() {
+    let answers = Answers::default();
+    let mut order_select = unwrap_order_select(
+        OrderSelectBuilder::new("name".into()).choices(choices(5)),
+    )
+    .into_order_select_prompt("message", &answers);
+
+    let mut layout = Layout::new(5, (50, 20).into());
+    order_select.height(&mut layout);
+
+    assert!(order_select.handle_key(KeyCode::Down.into()));
+    assert!(order_select.handle_key(KeyCode::Down.into()));
+    assert!(order_select.handle_key(KeyCode::Char(' ').into()));
+
+    assert!(order_select.handle_key(KeyCode::End.into()));
+    assert_eq!(initial_indices(&order_select), [0, 1, 3, 4, 2]);
+    assert_eq!(order_select.select.get_at(), 4);
+
+    assert!(order_select.handle_key(KeyCode::Home.into()));
+    assert_eq!(initial_indices(&order_select), [2, 0, 1, 3, 4]);
+    assert_eq!(order_select.select.get_at(), 0);
+
+    assert!(order_select.handle_key(KeyCode::Char('G').into()));
+    assert_eq!(initial_indices(&order_select), [0, 1, 3, 4, 2]);
+
+    assert!(order_select.handle_key(KeyCode::Char('g').into()));
+    assert_eq!(initial_indices(&order_select), [2, 0, 1, 3, 4]);
+
+    // the single step moves still work
+    assert!(order_select.handle_key(KeyCode::Down.into()));
+    assert_eq!(initial_indices(&order_select), [0, 2, 1, 3, 4]);
+    assert_eq!(order_select.select.get_at(), 1);
+}
+
+#[test]
+fn test_move_grabbed_by_page() {
+    let answers = Answers::default();
+    let mut order_select = unwrap_order_select(
+        OrderSelectBuilder::new("name".into())
+            .choices(choices(20))
+            .page_size(10)
+            .should_loop(false),
+    )
+    .into_order_select_prompt("message", &answers);
+
+    // the page is only set up on the first render
+    let mut backend = TestBackend::new((50, 20).into());
+    let mut layout = Layout::new(5, (50, 20).into());
+    order_select.render(&mut layout, &mut backend).unwrap();
+
+    assert!(order_select.handle_key(KeyCode::Char(' ').into()));
+
+    for &at in &[7, 14, 19, 12, 5, 0] {
+        let key = if at > order_select.select.get_at() {
+            KeyCode::PageDown
+        } else {
+            KeyCode::PageUp
+        };
+
+        assert!(order_select.handle_key(key.into()));
+        assert_eq!(order_select.select.get_at(), at);
+        assert_eq!(initial_indices(&order_select)[at], 0);
+    }
+
+    assert_eq!(initial_indices(&order_select), (0..20).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_compare_text_nan() {
+    let mut texts = vec!["b", "NaN", "2", "a", "-1"];
+    texts.sort_by(|a, b| compare_text(a, b));
+    assert_eq!(texts, ["-1", "2", "NaN", "a", "b"]);
+}
@@ -2,31 +2,93 @@ use std::io;
 
 use ui::{
     backend::Backend,
-    events::{EventIterator, KeyEvent},
-    style::Stylize,
+    events::{EventIterator, KeyCode, KeyEvent, KeyModifiers},
+    style::{Color, StyledStr, StyledString, Stylize},
     widgets, Validation, Widget,
 };
 
-use super::{Filter, Options, Transform, Validate, ValidateOnKey};
+use super::{Filter, Options, Strength, Transform, Validate, ValidateOnKey};
 use crate::{Answer, Answers};
 
 #[derive(Debug, Default)]
 pub(super) struct Password<'a> {
     mask: Option<char>,
+    /// The key which toggles revealing the password along with `Ctrl`, or `r` if it is not set
+    reveal_key: Option<char>,
+    max_length: Option<usize>,
+    undo: bool,
     filter: Filter<'a, String>,
     validate: Validate<'a, str>,
     validate_on_key: ValidateOnKey<'a, str>,
+    strength: Strength<'a, str>,
     transform: Transform<'a, str>,
 }
 
+/// The number of segments in the strength meter, which is also the maximum score.
+const METER_SEGMENTS: u8 = 4;
+/// The width of a single segment of the strength meter.
+const SEGMENT_WIDTH: u16 = 3;
+/// The width of the bar of the strength meter, with a space between the segments.
+const METER_WIDTH: u16 = METER_SEGMENTS as u16 * (SEGMENT_WIDTH + 1) - 1;
+
 struct PasswordPrompt<'a, 'p> {
-    prompt: widgets::Prompt<&'a str>,
+    prompt: widgets::Prompt<StyledStr<'a>>,
     password: Password<'p>,
     input: widgets::StringInput,
     is_valid: bool,
     answers: &'a Answers,
 }
 
+impl PasswordPrompt<'_, '_> {
+    /// The meter is only shown if the password is masked, since otherwise even the length of the
+    /// password is hidden.
+    fn has_meter(&self) -> bool {
+        self.password.mask.is_some() && matches!(self.password.strength, Strength::Sync(_))
+    }
+
+    fn render_meter<B: Backend>(
+        &mut self,
+        layout: &mut ui::layout::Layout,
+        b: &mut B,
+    ) -> io::Result<()> {
+        let (score, label) = match self.password.strength {
+            Strength::Sync(ref mut strength) => strength(self.input.value()),
+            Strength::None => return Ok(()),
+        };
+        let score = score.min(METER_SEGMENTS);
+
+        if layout.line_offset != 0 {
+            layout.line_offset = 0;
+            layout.offset_y += 1;
+            b.move_cursor_to(layout.offset_x, layout.offset_y)?;
+        }
+
+        let segment = ui::symbols::current()
+            .box_horizontal
+            .to_string()
+            .repeat(SEGMENT_WIDTH as usize);
+
+        b.set_fg(match score {
+            0 | 1 => Color::Red,
+            2 => Color::Yellow,
+            _ => Color::Green,
+        })?;
+        for i in 0..METER_SEGMENTS {
+            if i == score {
+                b.set_fg(Color::DarkGrey)?;
+            }
+            if i != 0 {
+                b.write_all(b" ")?;
+            }
+            b.write_all(segment.as_bytes())?;
+        }
+        b.set_fg(Color::Reset)?;
+
+        layout.line_offset += METER_WIDTH;
+        format!(" {}", label).render(layout, b)
+    }
+}
+
 impl ui::Prompt for PasswordPrompt<'_, '_> {
     type ValidateErr = widgets::Text<String>;
     type Output = String;
@@ -64,14 +126,39 @@ impl Widget for PasswordPrompt<'_, '_> {
             b.set_fg(ui::style::Color::Reset)?;
         }
 
+        if self.has_meter() {
+            self.render_meter(layout, b)?;
+        }
+
         Ok(())
     }
 
     fn height(&mut self, layout: &mut ui::layout::Layout) -> u16 {
-        self.prompt.height(layout) + self.input.height(layout) - 1
+        let mut height = self.prompt.height(layout) + self.input.height(layout) - 1;
+
+        if self.has_meter() {
+            // The meter is on the line after the input, unless the input exactly filled its last
+            // line, in which case we are already at the start of a new line
+            if layout.line_offset != 0 {
+                height += 1;
+                layout.offset_y += 1;
+            }
+
+            layout.line_offset = 0;
+            layout.offset_y += 1;
+        }
+
+        height
     }
 
     fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if key.code == KeyCode::Char(self.password.reveal_key.unwrap_or('r'))
+            && key.modifiers.contains(KeyModifiers::CONTROL)
+        {
+            self.input.toggle_reveal();
+            return true;
+        }
+
         let handled = self.input.handle_key(key);
 
         match self.password.validate_on_key {
@@ -91,16 +178,31 @@ impl Widget for PasswordPrompt<'_, '_> {
 }
 
 impl<'p> Password<'p> {
-    fn into_prompt<'a>(self, message: &'a str, answers: &'a Answers) -> PasswordPrompt<'a, 'p> {
+    fn string_input(&self) -> widgets::StringInput {
+        let mut input = widgets::StringInput::default().password(self.mask);
+        if let Some(max_length) = self.max_length {
+            input = input.max_length(max_length);
+        }
+        if self.undo {
+            input = input.with_undo();
+        }
+        input
+    }
+
+    fn into_prompt<'a>(
+        self,
+        message: impl Into<StyledStr<'a>>,
+        answers: &'a Answers,
+    ) -> PasswordPrompt<'a, 'p> {
         PasswordPrompt {
-            prompt: widgets::Prompt::new(message)
+            prompt: widgets::Prompt::new(message.into())
                 .with_delim(widgets::Delimiter::SquareBracket)
                 .with_optional_hint(if self.mask.is_none() {
                     Some("input is hidden")
                 } else {
                     None
                 }),
-            input: widgets::StringInput::default().password(self.mask),
+            input: self.string_input(),
             is_valid: true,
             password: self,
             answers,
@@ -109,8 +211,8 @@ impl<'p> Password<'p> {
 
     pub(crate) fn ask<B: Backend, E: EventIterator>(
         mut self,
-        message: String,
-        on_esc: ui::OnEsc,
+        message: StyledString,
+        input_opts: super::InputOptions<'_>,
         answers: &Answers,
         b: &mut B,
         events: &mut E,
@@ -118,7 +220,9 @@ impl<'p> Password<'p> {
         let transform = self.transform.take();
 
         let ans = ui::Input::new(self.into_prompt(&message, answers), b)
-            .on_esc(on_esc)
+            .on_esc(input_opts.on_esc)
+            .timeout(input_opts.timeout)
+            .on_validation_failed(input_opts.on_validation_failed)
             .run(events)?;
 
         crate::write_final!(
@@ -130,6 +234,14 @@ impl<'p> Password<'p> {
             |_ans| b.write_styled(&"[hidden]".dark_grey())?
         )
     }
+
+    pub(super) fn answer_non_interactive(
+        self,
+        answer: Option<Answer>,
+        answers: &Answers,
+    ) -> Result<Answer, super::Rejected> {
+        super::answer_string(answer, None, self.validate, self.filter, answers)
+    }
 }
 
 /// The builder for an [`password`] prompt.
@@ -244,6 +356,77 @@ impl<'a> PasswordBuilder<'a> {
         self
     }
 
+    /// The key which, when pressed with `Ctrl`, toggles between showing the password as it is
+    /// typed and masking or hiding it.
+    ///
+    /// The answer is still hidden once the prompt is finished.
+    ///
+    /// If this is not given, it defaults to `r`, so `Ctrl+R` toggles the password.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// // Ctrl+S shows the password
+    /// let password = Question::password("password")
+    ///     .mask('*')
+    ///     .reveal_key('s')
+    ///     .build();
+    /// ```
+    pub fn reveal_key(mut self, key: char) -> Self {
+        self.password.reveal_key = Some(key);
+        self
+    }
+
+    /// The maximum length of the password.
+    ///
+    /// Once the password is this long, typing more is ignored, while deleting and moving the
+    /// cursor still work. Pasted text is cut off to fit. Like the [`max_length`] of `input`, the
+    /// length is counted in graphemes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let password = Question::password("password")
+    ///     .mask('*')
+    ///     .max_length(64)
+    ///     .build();
+    /// ```
+    ///
+    /// [`max_length`]: crate::question::InputBuilder::max_length
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.password.max_length = Some(max_length);
+        self
+    }
+
+    /// Whether the user can undo their edits with `Ctrl+Z`, and redo them with `Ctrl+Y`.
+    ///
+    /// This works the same as the [`undo`] of `input`. If a [`reveal_key`] of `z` or `y` is given,
+    /// it takes precedence.
+    ///
+    /// If `undo` is not set, it will default to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let password = Question::password("password")
+    ///     .mask('*')
+    ///     .undo(true)
+    ///     .build();
+    /// ```
+    ///
+    /// [`undo`]: crate::question::InputBuilder::undo
+    /// [`reveal_key`]: Self::reveal_key
+    pub fn undo(mut self, undo: bool) -> Self {
+        self.password.undo = undo;
+        self
+    }
+
     crate::impl_filter_builder! {
     /// # Examples
     ///
@@ -296,6 +479,38 @@ impl<'a> PasswordBuilder<'a> {
     str; password
     }
 
+    /// Show a strength meter below the password as it is typed
+    ///
+    /// The function is given the password, and returns a score from 0 to 4 along with a label to
+    /// describe it. Scores larger than 4 are treated as 4. The score is shown as a coloured bar,
+    /// followed by the label.
+    ///
+    /// Note, the meter is only shown if a [`mask`] is set, since otherwise the input is hidden.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let password = Question::password("password")
+    ///     .mask('*')
+    ///     .strength_meter(|password| match password.chars().count() {
+    ///         0..=5 => (0, "too short"),
+    ///         6..=9 => (2, "ok"),
+    ///         _ => (4, "strong"),
+    ///     })
+    ///     .build();
+    /// ```
+    ///
+    /// [`mask`]: PasswordBuilder::mask
+    pub fn strength_meter<F>(mut self, strength: F) -> Self
+    where
+        F: FnMut(&str) -> (u8, &str) + 'a,
+    {
+        self.password.strength = Strength::Sync(Box::new(strength));
+        self
+    }
+
     crate::impl_transform_builder! {
     /// # Examples
     ///
@@ -435,4 +650,174 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_reveal() {
+        let size = (50, 20).into();
+        let base_layout = Layout::new(5, size);
+        let answers = Answers::default();
+        let ctrl = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL);
+
+        let render = |prompt: &mut PasswordPrompt<'_, '_>| {
+            let mut backend = TestBackend::new_with_layout(size, base_layout);
+            prompt
+                .render(&mut base_layout.clone(), &mut backend)
+                .unwrap();
+            backend.row_text(0)
+        };
+
+        let mut prompt = Password {
+            mask: Some('*'),
+            ..Default::default()
+        }
+        .into_prompt("message", &answers);
+        prompt.input.set_value("hunter2".into());
+        prompt.input.set_at(7);
+
+        assert!(render(&mut prompt).ends_with("message › *******"));
+
+        assert!(prompt.handle_key(ctrl('r')));
+        assert!(render(&mut prompt).ends_with("message › hunter2"));
+        assert_eq!(prompt.input.value(), "hunter2");
+
+        assert!(prompt.handle_key(ctrl('r')));
+        assert!(render(&mut prompt).ends_with("message › *******"));
+
+        // Hidden passwords are shown as well, with a configured key
+        let mut prompt = Password {
+            reveal_key: Some('s'),
+            ..Default::default()
+        }
+        .into_prompt("message", &answers);
+        prompt.input.set_value("hunter2".into());
+        prompt.input.set_at(7);
+
+        assert!(!render(&mut prompt).contains("hunter2"));
+        assert!(prompt.handle_key(ctrl('s')));
+        assert!(render(&mut prompt).ends_with("hunter2"));
+        assert_eq!(prompt.cursor_pos(base_layout), (40, 0));
+
+        // `Ctrl+R` is not handled by the input, so it does nothing
+        assert!(!prompt.handle_key(ctrl('r')));
+        assert!(prompt.input.is_revealed());
+    }
+
+    #[test]
+    fn test_max_length() {
+        let answers = Answers::default();
+
+        let mut prompt = Password {
+            mask: Some('*'),
+            max_length: Some(3),
+            ..Default::default()
+        }
+        .into_prompt("message", &answers);
+
+        for c in "abc".chars() {
+            assert!(prompt.handle_key(KeyCode::Char(c).into()));
+        }
+        assert!(!prompt.handle_key(KeyCode::Char('d').into()));
+        assert_eq!(prompt.input.value(), "abc");
+
+        // Deleting and moving still work once the password is full
+        assert!(prompt.handle_key(KeyCode::Left.into()));
+        assert!(prompt.handle_key(KeyCode::Backspace.into()));
+        assert_eq!(prompt.input.value(), "ac");
+        assert!(prompt.handle_key(KeyCode::Char('d').into()));
+        assert_eq!(prompt.input.value(), "adc");
+    }
+
+    #[test]
+    fn test_undo() {
+        let answers = Answers::default();
+        let ctrl = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL);
+
+        let mut prompt = Password {
+            mask: Some('*'),
+            undo: true,
+            ..Default::default()
+        }
+        .into_prompt("message", &answers);
+
+        for c in "hunter2".chars() {
+            assert!(prompt.handle_key(KeyCode::Char(c).into()));
+        }
+        assert!(prompt.handle_key(ctrl('w')));
+        assert_eq!(prompt.input.value(), "hunter");
+        assert!(prompt.handle_key(ctrl('z')));
+        assert_eq!(prompt.input.value(), "hunter2");
+
+        // The reveal key takes precedence
+        let mut prompt = Password {
+            mask: Some('*'),
+            reveal_key: Some('z'),
+            undo: true,
+            ..Default::default()
+        }
+        .into_prompt("message", &answers);
+
+        assert!(prompt.handle_key(KeyCode::Char('a').into()));
+        assert!(prompt.handle_key(ctrl('z')));
+        assert!(prompt.input.is_revealed());
+        assert_eq!(prompt.input.value(), "a");
+    }
+
+    #[test]
+    fn test_strength_meter() {
+        let size = (50, 20).into();
+        let base_layout = Layout::new(5, size);
+        let answers = Answers::default();
+
+        let mut backend = TestBackend::new_with_layout(size, base_layout);
+
+        let mut prompt = Password {
+            mask: Some('*'),
+            strength: Strength::Sync(Box::new(|password| (password.len() as u8 / 10, "label"))),
+            ..Default::default()
+        }
+        .into_prompt("message", &answers);
+
+        let mut layout = base_layout;
+        assert_eq!(prompt.height(&mut layout), 2);
+        assert_eq!(layout, base_layout.with_offset(0, 2).with_line_offset(0));
+
+        let mut layout = base_layout;
+        backend.reset_with_layout(layout);
+        assert!(prompt.render(&mut layout, &mut backend).is_ok());
+        assert_eq!(layout, base_layout.with_offset(0, 2).with_line_offset(0));
+        ui::assert_backend_snapshot!("meter-1", backend);
+
+        prompt.input.set_value("3".repeat(25));
+
+        let mut layout = base_layout;
+        assert_eq!(prompt.height(&mut layout), 2);
+        let mut layout = base_layout;
+        backend.reset_with_layout(layout);
+        assert!(prompt.render(&mut layout, &mut backend).is_ok());
+        assert_eq!(layout, base_layout.with_offset(0, 2).with_line_offset(0));
+        ui::assert_backend_snapshot!("meter-2", backend);
+
+        // The input exactly fills the line, so the meter is on the line the cursor is already on
+        prompt.input.set_value("3".repeat(33));
+
+        let mut layout = base_layout;
+        assert_eq!(prompt.height(&mut layout), 2);
+        assert_eq!(layout, base_layout.with_offset(0, 2).with_line_offset(0));
+
+        // The cursor stays at the end of the input
+        prompt.input.set_at(25);
+        assert_eq!(prompt.cursor_pos(base_layout), (42, 0));
+
+        // Hidden passwords do not show the meter
+        let mut prompt = Password {
+            strength: Strength::Sync(Box::new(|_| (4, "label"))),
+            ..Default::default()
+        }
+        .into_prompt("message", &answers);
+        prompt.input.set_value("3".repeat(50));
+
+        let mut layout = base_layout;
+        assert_eq!(prompt.height(&mut layout), 1);
+        assert_eq!(layout, base_layout.with_line_offset(33));
+    }
 }
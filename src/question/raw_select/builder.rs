@@ -42,6 +42,7 @@ pub struct RawSelectBuilder<'a> {
     opts: Options<'a>,
     raw_select: RawSelect<'a>,
     choice_count: usize,
+    key_labels: Option<fn(usize) -> String>,
 }
 
 impl<'a> RawSelectBuilder<'a> {
@@ -51,6 +52,7 @@ impl<'a> RawSelectBuilder<'a> {
             raw_select: Default::default(),
             // It is one indexed for the user
             choice_count: 1,
+            key_labels: None,
         }
     }
 
@@ -182,6 +184,29 @@ impl<'a> RawSelectBuilder<'a> {
         self
     }
 
+    /// Label the choices with something other than their numbers.
+    ///
+    /// It is given the position of each choice which can be picked, starting from 0, and should
+    /// return the label for it. The user picks a choice by typing its label, so the labels should
+    /// be unique. Disabled choices and separators are not labelled.
+    ///
+    /// If `key_labels` is not set, the choices are numbered starting from 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let raw_select = Question::raw_select("theme")
+    ///     .choices(vec!["Order a pizza", "Make a reservation"])
+    ///     .key_labels(|i| ((b'a' + i as u8) as char).to_string())
+    ///     .build();
+    /// ```
+    pub fn key_labels(mut self, key_labels: fn(usize) -> String) -> Self {
+        self.key_labels = Some(key_labels);
+        self
+    }
+
     /// Inserts a [`Choice`] with the given text.
     ///
     /// See [`raw_select`] for more information.
@@ -288,11 +313,15 @@ impl<'a> RawSelectBuilder<'a> {
             .choices
             .choices
             .extend(choices.into_iter().map(|choice| {
-                choice.into().map(|c| {
-                    let choice = (*choice_count, Text::new(c));
-                    *choice_count += 1;
-                    choice
-                })
+                match choice.into().fold_reason(|text| text) {
+                    Choice::Choice(c) => {
+                        let choice = Choice::Choice((*choice_count, Text::new(c)));
+                        *choice_count += 1;
+                        choice
+                    }
+                    // Disabled choices cannot be picked, so they are not numbered
+                    choice => choice.map(|c| (0, Text::new(c))),
+                }
             }));
         self
     }
@@ -328,7 +357,15 @@ impl<'a> RawSelectBuilder<'a> {
             })
             .unwrap_or(0);
 
-        self.raw_select.max_index_width = (num_choices as f64).log10() as u16 + 1;
+        self.raw_select.max_index_width = match self.key_labels {
+            Some(key_labels) => {
+                let labels: Vec<_> = (0..num_choices).map(key_labels).collect();
+                let max_width = labels.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+                self.raw_select.labels = Some(labels);
+                max_width as u16
+            }
+            None => (num_choices as f64).log10() as u16 + 1,
+        };
 
         crate::question::Question::new(
             self.opts,
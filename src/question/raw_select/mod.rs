@@ -3,7 +3,7 @@ use std::io;
 use ui::{
     backend::Backend,
     events::{EventIterator, KeyEvent},
-    style::{Color, Stylize},
+    style::{Color, StyledStr, StyledString},
     widgets::{self, List, Text},
     Prompt, Validation, Widget,
 };
@@ -23,11 +23,14 @@ mod tests;
 pub(super) struct RawSelect<'a> {
     choices: super::ChoiceList<(usize, Text<String>)>,
     transform: Transform<'a, ListItem>,
+    /// The key labels of the numbered choices, in order. If they are not set, the choices are
+    /// labelled with their numbers.
+    labels: Option<Vec<String>>,
     max_index_width: u16,
 }
 
 struct RawSelectPrompt<'a> {
-    prompt: widgets::Prompt<&'a str>,
+    prompt: widgets::Prompt<StyledStr<'a>>,
     select: widgets::Select<RawSelect<'a>>,
     input: widgets::StringInput,
 }
@@ -65,6 +68,10 @@ impl Prompt for RawSelectPrompt<'_> {
         let index = self.select.get_at();
         self.finish_index(index)
     }
+
+    fn has_default(&self) -> bool {
+        self.select.list.choices.default().is_some()
+    }
 }
 
 const ANSWER_PROMPT: &[u8] = b"  Answer: ";
@@ -87,7 +94,15 @@ impl Widget for RawSelectPrompt<'_> {
 
     fn handle_key(&mut self, key: KeyEvent) -> bool {
         if self.input.handle_key(key) {
-            if let Ok(n) = self.input.value().parse::<usize>() {
+            let n = match self.select.list.labels {
+                Some(ref labels) => labels
+                    .iter()
+                    .position(|label| label == self.input.value())
+                    .map(|i| i + 1),
+                None => self.input.value().parse::<usize>().ok(),
+            };
+
+            if let Some(n) = n {
                 if n <= self.select.list.len() && n > 0 {
                     let pos = self.select.list.choices.choices[(n - 1)..]
                         .iter()
@@ -105,7 +120,8 @@ impl Widget for RawSelectPrompt<'_> {
         } else if self.select.handle_key(key) {
             let at = self.select.get_at();
             let index = self.select.list.choices[at].as_ref().unwrap_choice().0;
-            self.input.set_value(index.to_string());
+            self.input
+                .set_value(key_label(self.select.list.labels.as_deref(), index));
             true
         } else {
             false
@@ -131,16 +147,18 @@ impl widgets::List for RawSelect<'_> {
         mut layout: ui::layout::Layout,
         b: &mut B,
     ) -> io::Result<()> {
+        let theme = ui::theme::current();
+
         match &mut self.choices[index] {
             &mut Choice::Choice((index, ref mut text)) => {
                 if hovered {
-                    b.set_fg(Color::Cyan)?;
+                    b.set_fg(theme.cursor)?;
                 }
 
                 write!(
                     b,
                     "  {:>width$}. ",
-                    index,
+                    key_label(self.labels.as_deref(), index),
                     width = self.max_index_width as usize
                 )?;
 
@@ -151,8 +169,25 @@ impl widgets::List for RawSelect<'_> {
                     b.set_fg(Color::Reset)?;
                 }
             }
+            Choice::Disabled {
+                text: (_, ref mut text),
+                ..
+            } => {
+                b.set_fg(theme.disabled)?;
+                // Line up with the text of the numbered choices
+                write!(
+                    b,
+                    "{:width$}",
+                    "",
+                    width = self.max_index_width as usize + 4
+                )?;
+
+                layout.offset_x += self.max_index_width + 4;
+                text.render(&mut layout, b)?;
+                b.set_fg(Color::Reset)?;
+            }
             separator => {
-                b.set_fg(Color::DarkGrey)?;
+                b.set_fg(theme.disabled)?;
                 b.write_all(b"  ")?;
                 super::get_sep_str(separator).render(&mut layout.with_line_offset(2), b)?;
                 b.set_fg(Color::Reset)?;
@@ -163,12 +198,16 @@ impl widgets::List for RawSelect<'_> {
     }
 
     fn is_selectable(&self, index: usize) -> bool {
-        !self.choices[index].is_separator()
+        self.choices[index].is_choice()
     }
 
     fn height_at(&mut self, index: usize, mut layout: ui::layout::Layout) -> u16 {
         match self.choices[index] {
-            Choice::Choice((_, ref mut c)) => {
+            Choice::Choice((_, ref mut c))
+            | Choice::Disabled {
+                text: (_, ref mut c),
+                ..
+            } => {
                 layout.offset_x += self.max_index_width + 4;
                 c.height(&mut layout)
             }
@@ -189,47 +228,60 @@ impl widgets::List for RawSelect<'_> {
     }
 }
 
+/// The key label of the choice numbered `n`.
+fn key_label(labels: Option<&[String]>, n: usize) -> String {
+    match labels {
+        Some(labels) => labels[n - 1].clone(),
+        None => n.to_string(),
+    }
+}
+
 impl<'a> RawSelect<'a> {
-    fn into_prompt(self, message: &'a str) -> RawSelectPrompt<'a> {
+    fn into_prompt(self, message: impl Into<StyledStr<'a>>) -> RawSelectPrompt<'a> {
         let mut select = widgets::Select::new(self);
 
         if let Some(default) = select.list.choices.default() {
             select.set_at(default);
         }
 
+        let filter_map: widgets::FilterMapChar = if select.list.labels.is_some() {
+            // Custom labels can use any characters
+            Some
+        } else {
+            |c| if c.is_ascii_digit() { Some(c) } else { None }
+        };
+
         RawSelectPrompt {
-            input: widgets::StringInput::with_filter_map(|c| {
-                if c.is_digit(10) {
-                    Some(c)
-                } else {
-                    None
-                }
-            }),
+            input: widgets::StringInput::with_filter_map(filter_map),
             select,
-            prompt: widgets::Prompt::new(message),
+            prompt: widgets::Prompt::new(message.into()),
         }
     }
 
     pub(crate) fn ask<B: Backend, E: EventIterator>(
         mut self,
-        message: String,
-        on_esc: ui::OnEsc,
+        message: StyledString,
+        input_opts: super::InputOptions<'_>,
         answers: &Answers,
         b: &mut B,
         events: &mut E,
     ) -> ui::Result<Option<Answer>> {
+        super::choice::ensure_selectable(&self)?;
+
         let transform = self.transform.take();
 
         let ans = ui::Input::new(self.into_prompt(&message), b)
-            .on_esc(on_esc)
+            .on_esc(input_opts.on_esc)
+            .timeout(input_opts.timeout)
+            .on_validation_failed(input_opts.on_validation_failed)
             .run(events)?;
 
-        crate::write_final!(transform, message, ans [ref], answers, b, |ans| b.write_styled(
-            &ans.text
+        crate::write_final!(transform, message, ans [ref], answers, b, |ans| b.write_all(
+            ans.text
                 .lines()
                 .next()
                 .expect("There must be at least one line in a `str`")
-                .cyan()
+                .as_bytes()
         )?)
     }
 }
@@ -3,7 +3,7 @@ use ui::{backend::Backend, widgets::Text};
 use super::Select;
 use crate::{
     question::{Choice, Options},
-    ListItem,
+    Answers, ListItem,
 };
 
 /// The builder for a [`select`] prompt.
@@ -108,10 +108,13 @@ impl<'a> SelectBuilder<'a> {
     ///
     /// # Panics
     ///
-    /// If the default given is not a [`Choice`], it will cause a panic on [`build`]
+    /// If the default given is out of range or is not a [`Choice`], it will cause a panic on
+    /// [`build`]. If [`choices_fn`] is used, the default is only checked once the question is
+    /// asked, so it panics then instead.
     ///
     /// [`Choice`]: crate::question::Choice
     /// [`build`]: Self::build
+    /// [`choices_fn`]: Self::choices_fn
     ///
     /// # Examples
     ///
@@ -132,6 +135,44 @@ impl<'a> SelectBuilder<'a> {
     /// ```
     pub fn default(mut self, default: usize) -> Self {
         self.select.choices.set_default(default);
+        self.select.default_name = None;
+        self
+    }
+
+    /// Set the default for the select by the text of a choice
+    ///
+    /// The first [`Choice`] whose text is exactly `name` will be hovered in the beginning. This
+    /// overrides any previous call to [`default`], and vice versa.
+    ///
+    /// # Panics
+    ///
+    /// If no [`Choice`] has the given text, it will cause a panic on [`build`]. If [`choices_fn`]
+    /// is used, the name is only looked up once the question is asked, so it panics then instead.
+    ///
+    /// [`Choice`]: crate::question::Choice
+    /// [`default`]: Self::default
+    /// [`build`]: Self::build
+    /// [`choices_fn`]: Self::choices_fn
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, DefaultSeparator};
+    ///
+    /// let select = Question::select("theme")
+    ///     .choices(vec![
+    ///         "Order a pizza".into(),
+    ///         "Make a reservation".into(),
+    ///         DefaultSeparator,
+    ///         "Ask for opening hours".into(),
+    ///         "Contact support".into(),
+    ///         "Talk to the receptionist".into(),
+    ///     ])
+    ///     .default_by_name("Contact support")
+    ///     .build();
+    /// ```
+    pub fn default_by_name<I: Into<String>>(mut self, name: I) -> Self {
+        self.select.default_name = Some(name.into());
         self
     }
 
@@ -179,6 +220,124 @@ impl<'a> SelectBuilder<'a> {
         self
     }
 
+    /// Whether the choices can be filtered by typing.
+    ///
+    /// If `filterable` is `true`, typing narrows down the choices shown to those that fuzzy match
+    /// the typed query, ignoring case, and `Backspace` removes the last character of the query.
+    /// Separators are only shown when the query is empty. The query is shown as the hint of the
+    /// prompt. Characters which would not match any choice are ignored.
+    ///
+    /// Since all characters are used for the query, movement keys like `j` and `k` cannot be used
+    /// while filterable. The [`ListItem::index`] of the answer is always the index in the full
+    /// list of choices.
+    ///
+    /// If `filterable` is not set, it will default to `false`.
+    ///
+    /// [`ListItem::index`]: crate::ListItem::index
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("theme")
+    ///     .filterable(true)
+    ///     .build();
+    /// ```
+    pub fn filterable(mut self, filterable: bool) -> Self {
+        self.select.search = if filterable {
+            Some(Default::default())
+        } else {
+            None
+        };
+        self
+    }
+
+    /// Ask the user to confirm some choices before submitting them.
+    ///
+    /// When a choice is submitted, `confirm_on` is called with it. If it returns `true`, the user
+    /// is asked whether they are sure, and must enter `y` or `n`. Answering `n` or pressing `Esc`
+    /// returns to the list, so another choice can be picked.
+    ///
+    /// If `confirm_on` is not set, choices are submitted without confirmation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("action")
+    ///     .choices(vec!["Save", "Discard changes"])
+    ///     .confirm_on(|choice| choice.text == "Discard changes")
+    ///     .build();
+    /// ```
+    pub fn confirm_on<F>(mut self, confirm_on: F) -> Self
+    where
+        F: FnMut(&ListItem) -> bool + 'a,
+    {
+        self.select.confirm_on = super::ConfirmOn::Sync(Box::new(confirm_on));
+        self
+    }
+
+    /// Whether the digit keys `1` to `9` hover the choice with that number.
+    ///
+    /// Only the choices which can be selected are numbered, so `1` hovers the first choice which is
+    /// not a separator or disabled. Digits without a matching choice are ignored. The choice is
+    /// only hovered, and `Enter` still needs to be pressed to submit it.
+    ///
+    /// If the select is also [`filterable`], digits are used for the query instead.
+    ///
+    /// If `number_shortcuts` is not set, it will default to `false`.
+    ///
+    /// [`filterable`]: Self::filterable
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("theme")
+    ///     .number_shortcuts(true)
+    ///     .build();
+    /// ```
+    pub fn number_shortcuts(mut self, number_shortcuts: bool) -> Self {
+        self.select.number_shortcuts = number_shortcuts;
+        self
+    }
+
+    /// Lay out the choices in a grid with the given number of columns.
+    ///
+    /// The choices fill the grid row by row, and each column takes an equal share of the width.
+    /// `Left` and `Right` move across the columns of a row, and `Up` and `Down` move across the
+    /// rows, skipping separators and disabled choices. The [`page_size`] is the number of rows
+    /// shown at once. Descriptions of the choices are not shown when there is more than one
+    /// column.
+    ///
+    /// If `columns` is not set, it will default to 1.
+    ///
+    /// [`page_size`]: Self::page_size
+    ///
+    /// # Panics
+    ///
+    /// It will panic if `columns` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("size")
+    ///     .choices(vec!["XS", "S", "M", "L", "XL", "XXL"])
+    ///     .columns(3)
+    ///     .build();
+    /// ```
+    pub fn columns(mut self, columns: usize) -> Self {
+        assert!(columns > 0, "there must be at least one column");
+
+        self.select.columns = columns;
+        self
+    }
+
     /// Inserts a [`Choice`] with the given text.
     ///
     /// See [`select`] for more information.
@@ -203,6 +362,36 @@ impl<'a> SelectBuilder<'a> {
         self
     }
 
+    /// Inserts a [`Choice`] with the given text, and a description shown below it.
+    ///
+    /// The description is a single dimmed line, indented under the text of the choice. If it is
+    /// too long to fit, it is cut-off. Only the text of the choice is part of the answer.
+    ///
+    /// See [`select`] for more information.
+    ///
+    /// [`Choice`]: crate::question::Choice::Choice
+    /// [`select`]: crate::question::Question::select
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("manager")
+    ///     .choice_with_description("npm", "The default package manager for node")
+    ///     .choice_with_description("yarn", "Fast, reliable and secure dependency management")
+    ///     .build();
+    /// ```
+    pub fn choice_with_description<I: Into<String>, D: Into<String>>(
+        mut self,
+        text: I,
+        description: D,
+    ) -> Self {
+        let index = self.select.choices.len();
+        self.select.descriptions.insert(index, description.into());
+        self.choice(text)
+    }
+
     /// Inserts a [`Separator`] with the given text
     ///
     /// See [`select`] for more information.
@@ -279,11 +468,74 @@ impl<'a> SelectBuilder<'a> {
         self.select.choices.choices.extend(
             choices
                 .into_iter()
-                .map(|choice| choice.into().map(Text::new)),
+                .map(|choice| choice.into().fold_reason(|text| text).map(Text::new)),
         );
         self
     }
 
+    /// Creates more [`Choice`]s when the question is asked.
+    ///
+    /// `choices_fn` is called with the previous answers only if the question is asked, so choices
+    /// which are expensive to compute are skipped along with the question. This includes when
+    /// [`when`] returns `false`, and when the question was already answered. The choices it
+    /// returns are added after all the choices given to the other methods, whichever order they
+    /// were called in. Like with [`choices`], they can include separators and disabled choices.
+    ///
+    /// Since the previous answers are passed to `choices_fn`, the choices can depend on how
+    /// earlier questions were answered.
+    ///
+    /// Since the choices are not known until then, [`default`] and [`default_by_name`] are only
+    /// checked when the question is asked. Indices given to [`default`] count the choices created
+    /// by `choices_fn` after the other choices. The [`page_size`] and [`should_loop`] options are
+    /// set on the builder as usual, and apply to all the choices.
+    ///
+    /// Calling `choices_fn` again replaces the previous function.
+    ///
+    /// [`Choice`]: crate::question::Choice
+    /// [`when`]: Self::when
+    /// [`choices`]: Self::choices
+    /// [`default`]: Self::default
+    /// [`default_by_name`]: Self::default_by_name
+    /// [`page_size`]: Self::page_size
+    /// [`should_loop`]: Self::should_loop
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, DefaultSeparator};
+    ///
+    /// let select = Question::select("branch")
+    ///     .message("Which branch do you want to check out?")
+    ///     .choices_fn(|previous_answers| {
+    ///         // Expensive to compute, for example by listing the branches of a repository
+    ///         vec!["main".into(), "develop".into()]
+    ///     })
+    ///     .default_by_name("main")
+    ///     .build();
+    ///
+    /// let dish = Question::select("dish")
+    ///     .message("What would you like to order?")
+    ///     .choices_fn(|previous_answers| {
+    ///         match previous_answers["course"].as_list_item().unwrap().text.as_str() {
+    ///             "Starter" => vec!["Soup".into(), "Salad".into()],
+    ///             _ => vec![
+    ///                 "Pasta".into(),
+    ///                 "Pizza".into(),
+    ///                 DefaultSeparator,
+    ///                 "Chef's special".into(),
+    ///             ],
+    ///         }
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn choices_fn<F>(mut self, choices_fn: F) -> Self
+    where
+        F: FnOnce(&Answers) -> Vec<Choice<String>> + 'a,
+    {
+        self.select.choices_fn = super::ChoicesFn::Sync(Box::new(choices_fn));
+        self
+    }
+
     crate::impl_transform_builder! {
     /// # Examples
     ///
@@ -302,11 +554,10 @@ impl<'a> SelectBuilder<'a> {
     /// Consumes the builder returning a [`Question`]
     ///
     /// [`Question`]: crate::question::Question
-    pub fn build(self) -> crate::question::Question<'a> {
-        if let Some(default) = self.select.choices.default() {
-            if self.select.choices[default].is_separator() {
-                panic!("Invalid default '{}' is not a `Choice`", default);
-            }
+    pub fn build(mut self) -> crate::question::Question<'a> {
+        // The default is checked once the choices are created instead
+        if let super::ChoicesFn::None = self.select.choices_fn {
+            self.select.resolve_default();
         }
 
         crate::question::Question::new(
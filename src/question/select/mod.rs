@@ -1,14 +1,15 @@
-use std::io;
+use std::{collections::HashMap, io};
 
 use ui::{
     backend::Backend,
-    events::{EventIterator, KeyEvent},
-    style::Stylize,
-    widgets::{self, Text},
-    Prompt, Widget,
+    events::{EventIterator, KeyCode, KeyEvent, Movement},
+    layout::{Layout, RenderRegion},
+    style::{Color, StyledStr, StyledString},
+    widgets::{self, List, Text},
+    Prompt, Validation, Widget,
 };
 
-use super::Transform;
+use super::{choice::Search, Choice, ChoicesFn, ConfirmOn, Transform};
 use crate::{Answer, Answers, ListItem};
 
 pub use builder::SelectBuilder;
@@ -21,12 +22,189 @@ mod tests;
 #[derive(Debug, Default)]
 pub(super) struct Select<'a> {
     choices: super::ChoiceList<Text<String>>,
+    /// Creates more choices when the question is asked.
+    choices_fn: ChoicesFn<'a, Choice<String>>,
+    /// The text of the default choice, which is resolved once all the choices are known.
+    default_name: Option<String>,
+    /// The search over the choices if the select is filterable.
+    search: Option<Search>,
+    /// The descriptions shown below the choices, by the index of the choice.
+    descriptions: HashMap<usize, String>,
+    /// Whether the chosen item must be confirmed before it is submitted.
+    confirm_on: ConfirmOn<'a, ListItem>,
+    /// Whether digit keys hover the choice with that number.
+    number_shortcuts: bool,
+    /// The number of columns the choices are laid out in. If there is more than one column, each
+    /// item of the list is a row of choices.
+    columns: usize,
+    /// The column of the hovered choice in its row, if there is more than one column.
+    column: usize,
     transform: Transform<'a, ListItem>,
 }
 
+impl Select<'_> {
+    /// Adds the choices created by `choices_fn`, and then checks the default.
+    fn load_choices(&mut self, answers: &Answers) {
+        if let ChoicesFn::Sync(choices_fn) = self.choices_fn.take() {
+            self.choices.choices.extend(
+                choices_fn(answers)
+                    .into_iter()
+                    .map(|choice| choice.fold_reason(|text| text).map(Text::new)),
+            );
+        }
+
+        self.resolve_default();
+    }
+
+    /// Sets the default from `default_name`, and checks that the default can be hovered.
+    ///
+    /// # Panics
+    ///
+    /// It will panic if the default does not refer to a [`Choice::Choice`].
+    fn resolve_default(&mut self) {
+        if let Some(name) = self.default_name.take() {
+            let default = self
+                .choices
+                .choices
+                .iter()
+                .position(|choice| matches!(choice, Choice::Choice(c) if c.text == name))
+                .unwrap_or_else(|| {
+                    panic!("Invalid default '{}' does not match any `Choice`", name)
+                });
+
+            self.choices.set_default(default);
+        }
+
+        if let Some(default) = self.choices.default() {
+            let len = self.choices.len();
+            if default >= len {
+                panic!(
+                    "Invalid default '{}' is out of range for {} choices",
+                    default, len
+                );
+            }
+
+            if !self.choices[default].is_choice() {
+                panic!("Invalid default '{}' is not a `Choice`", default);
+            }
+        }
+    }
+
+    /// The index of the choice which is shown at `index`.
+    fn choice_index(&self, index: usize) -> usize {
+        match self.search {
+            Some(ref search) => search.index(index),
+            None => index,
+        }
+    }
+
+    /// The number of choices which are shown.
+    fn visible_len(&self) -> usize {
+        match self.search {
+            Some(ref search) => search.len(),
+            None => self.choices.len(),
+        }
+    }
+
+    /// The index at which the `n`th selectable choice is shown, counting from 0.
+    fn nth_selectable(&self, n: usize) -> Option<usize> {
+        (0..self.visible_len())
+            .filter(|&index| self.choices.is_selectable(self.choice_index(index)))
+            .nth(n)
+    }
+
+    fn is_grid(&self) -> bool {
+        self.columns > 1
+    }
+
+    /// The index of the choice hovered when the list is at `at`.
+    fn hovered_index(&self, at: usize) -> usize {
+        if self.is_grid() {
+            self.choice_index(at * self.columns + self.column)
+        } else {
+            self.choice_index(at)
+        }
+    }
+
+    /// The index of the choice shown in the given cell, if the cell is not past the last choice.
+    fn cell_index(&self, row: usize, column: usize) -> Option<usize> {
+        let index = row * self.columns + column;
+
+        if index < self.visible_len() {
+            Some(self.choice_index(index))
+        } else {
+            None
+        }
+    }
+
+    fn is_cell_selectable(&self, row: usize, column: usize) -> bool {
+        match self.cell_index(row, column) {
+            Some(index) => self.choices.is_selectable(index),
+            None => false,
+        }
+    }
+
+    /// Moves the hovered column to the next selectable cell in the row, returning whether it moved.
+    fn move_column(&mut self, row: usize, movement: Movement) -> bool {
+        let column = match movement {
+            Movement::Left => (0..self.column)
+                .rev()
+                .find(|&column| self.is_cell_selectable(row, column)),
+            _ => {
+                (self.column + 1..self.columns).find(|&column| self.is_cell_selectable(row, column))
+            }
+        };
+
+        match column {
+            Some(column) => {
+                self.column = column;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Moves the hovered column to the nearest selectable cell in the row, since the row may be
+    /// shorter or have a separator in the hovered column.
+    fn fix_column(&mut self, row: usize) {
+        let nearest = (0..self.columns)
+            .filter(|&column| self.is_cell_selectable(row, column))
+            .min_by_key(|&column| (column as isize - self.column as isize).abs());
+
+        if let Some(column) = nearest {
+            self.column = column;
+        }
+    }
+
+    /// The layout of the cell in the given column, which takes an equal share of the width.
+    fn cell_layout(&self, layout: Layout, column: usize) -> Layout {
+        let width = layout.available_width() / self.columns as u16;
+        let mut layout =
+            layout.with_offset(layout.offset_x + column as u16 * width, layout.offset_y);
+        layout.width = layout.offset_x + width;
+        layout.line_offset = 0;
+        layout
+    }
+}
+
 struct SelectPrompt<'a> {
-    prompt: widgets::Prompt<&'a str>,
+    prompt: widgets::Prompt<StyledStr<'a>, String>,
     select: widgets::Select<Select<'a>>,
+    /// The y/n input shown once a choice matching `confirm_on` is submitted.
+    confirm: Option<widgets::CharInput>,
+}
+
+const CONFIRM_MESSAGE: &str = "Are you sure?";
+
+fn confirm_prompt() -> widgets::Prompt<&'static str> {
+    widgets::Prompt::new(CONFIRM_MESSAGE).with_hint("y/n")
+}
+
+fn search_hint(search: &Search) -> String {
+    match search.query() {
+        "" => "Type to filter".into(),
+        query => format!("Filter: {}", query),
+    }
 }
 
 impl SelectPrompt<'_> {
@@ -45,24 +223,97 @@ impl SelectPrompt<'_> {
     }
 }
 
+impl SelectPrompt<'_> {
+    /// The index of the choice which is hovered.
+    fn hovered_index(&self) -> usize {
+        self.select.list.hovered_index(self.select.get_at())
+    }
+
+    /// Hovers the choice shown at `index`.
+    fn hover(&mut self, index: usize) {
+        if self.select.list.is_grid() {
+            self.select.list.column = index % self.select.list.columns;
+            self.select.set_at(index / self.select.list.columns);
+        } else {
+            self.select.set_at(index);
+        }
+    }
+}
+
 impl Prompt for SelectPrompt<'_> {
     type ValidateErr = &'static str;
     type Output = ListItem;
 
+    fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
+        if let Some(ref confirm) = self.confirm {
+            return match confirm.value() {
+                Some('y') | Some('Y') => Ok(Validation::Finish),
+                Some(_) => {
+                    // The user rejected the choice, so let them pick another one
+                    self.confirm = None;
+                    Ok(Validation::Continue)
+                }
+                None => Err("Please enter y or n"),
+            };
+        }
+
+        let index = self.hovered_index();
+        let Select {
+            ref mut confirm_on,
+            ref choices,
+            ..
+        } = self.select.list;
+
+        if let ConfirmOn::Sync(confirm_on) = confirm_on {
+            let item = ListItem {
+                index,
+                text: choices[index].as_ref().unwrap_choice().text.clone(),
+            };
+
+            if confirm_on(&item) {
+                self.confirm = Some(widgets::CharInput::with_filter_map(super::confirm::only_yn));
+                return Ok(Validation::Continue);
+            }
+        }
+
+        Ok(Validation::Finish)
+    }
+
     fn finish(self) -> Self::Output {
-        let index = self.select.get_at();
+        let index = self.hovered_index();
         self.finish_index(index)
     }
+
+    fn handles_esc(&self) -> bool {
+        // `Esc` returns to the list instead of leaving the prompt
+        self.confirm.is_some()
+    }
+    fn has_default(&self) -> bool {
+        self.select.list.choices.default().is_some()
+    }
 }
 
 impl Widget for SelectPrompt<'_> {
     fn render<B: Backend>(&mut self, layout: &mut ui::layout::Layout, b: &mut B) -> io::Result<()> {
         self.prompt.render(layout, b)?;
-        self.select.render(layout, b)
+        self.select.render(layout, b)?;
+
+        if let Some(ref mut confirm) = self.confirm {
+            confirm_prompt().render(layout, b)?;
+            confirm.render(layout, b)?;
+        }
+
+        Ok(())
     }
 
     fn height(&mut self, layout: &mut ui::layout::Layout) -> u16 {
-        self.prompt.height(layout) + self.select.height(layout) - 1
+        let mut height = self.prompt.height(layout) + self.select.height(layout) - 1;
+
+        if let Some(ref mut confirm) = self.confirm {
+            height += confirm_prompt().height(layout) + confirm.height(layout) - 1;
+        }
+
+        height
     }
 
     fn cursor_pos(&mut self, layout: ui::layout::Layout) -> (u16, u16) {
@@ -70,7 +321,85 @@ impl Widget for SelectPrompt<'_> {
     }
 
     fn handle_key(&mut self, key: KeyEvent) -> bool {
-        self.select.handle_key(key)
+        if let Some(ref mut confirm) = self.confirm {
+            if key.code == KeyCode::Esc {
+                self.confirm = None;
+                return true;
+            }
+
+            return confirm.handle_key(key);
+        }
+
+        let Select {
+            ref mut search,
+            ref choices,
+            ..
+        } = self.select.list;
+
+        if let Some(search) = search {
+            match search.handle_key(key, &choices.choices) {
+                Some(true) => {
+                    self.prompt =
+                        widgets::Prompt::new(*self.prompt.message()).with_hint(search_hint(search));
+                    self.select.reset();
+                    self.select.list.column = 0;
+                    self.select.list.fix_column(self.select.get_at());
+                    return true;
+                }
+                Some(false) => return false,
+                None => {}
+            }
+        }
+
+        match key.code {
+            // Digits are used for the query if the select is filterable
+            KeyCode::Char(c @ '1'..='9')
+                if self.select.list.number_shortcuts && self.select.list.search.is_none() =>
+            {
+                let n = c as usize - '1' as usize;
+
+                return match self.select.list.nth_selectable(n) {
+                    Some(index) => {
+                        self.hover(index);
+                        true
+                    }
+                    None => false,
+                };
+            }
+            _ => {}
+        }
+
+        if !self.select.list.is_grid() {
+            return self.select.handle_key(key);
+        }
+
+        let at = self.select.get_at();
+        match Movement::try_from_key(key) {
+            Some(movement @ Movement::Left) | Some(movement @ Movement::Right) => {
+                self.select.list.move_column(at, movement)
+            }
+            _ if self.select.handle_key(key) => {
+                self.select.list.fix_column(self.select.get_at());
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn render_changes<B: Backend>(
+        &mut self,
+        layout: &mut ui::layout::Layout,
+        b: &mut B,
+    ) -> io::Result<bool> {
+        // The confirmation is below the list, so the list cannot be rendered on its own. In a grid,
+        // the hovered choice can change without the hovered row changing.
+        if self.confirm.is_some() || self.select.list.is_grid() {
+            return Ok(false);
+        }
+
+        // The message does not change once rendered, so only the list may need to be rendered
+        self.prompt.height(layout);
+        self.select.render_changes(layout, b)
     }
 }
 
@@ -82,19 +411,104 @@ impl widgets::List for Select<'_> {
         layout: ui::layout::Layout,
         backend: &mut B,
     ) -> io::Result<()> {
-        self.choices.render_item(index, hovered, layout, backend)
+        if self.is_grid() {
+            let row = index;
+
+            for column in 0..self.columns {
+                let index = match self.cell_index(row, column) {
+                    Some(index) => index,
+                    None => break,
+                };
+
+                let cell_layout = self.cell_layout(layout, column);
+                backend.move_cursor_to(cell_layout.offset_x, cell_layout.offset_y)?;
+                self.choices.render_item(
+                    index,
+                    hovered && column == self.column,
+                    cell_layout,
+                    backend,
+                )?;
+            }
+
+            return Ok(());
+        }
+
+        let index = self.choice_index(index);
+
+        let description = match self.descriptions.get(&index) {
+            Some(description) => description,
+            None => return self.choices.render_item(index, hovered, layout, backend),
+        };
+
+        let label_height = self
+            .choices
+            .height_at(index, layout.with_max_height(u16::MAX));
+
+        // The description takes the last line of the item, so it is only rendered if the whole
+        // item fits, or the bottom of the item is being rendered
+        if layout.max_height <= label_height && layout.render_region != RenderRegion::Bottom {
+            return self.choices.render_item(index, hovered, layout, backend);
+        }
+
+        let label_lines = label_height.min(layout.max_height.saturating_sub(1));
+        if label_lines > 0 {
+            let label_layout = layout.with_max_height(label_lines);
+            self.choices
+                .render_item(index, hovered, label_layout, backend)?;
+        }
+
+        let mut description_layout =
+            layout.with_offset(layout.offset_x + 4, layout.offset_y + label_lines);
+        description_layout.line_offset = 0;
+        backend.move_cursor_to(description_layout.offset_x, description_layout.offset_y)?;
+        backend.set_fg(ui::theme::current().hint)?;
+        description
+            .as_str()
+            .render(&mut description_layout, backend)?;
+        backend.set_fg(Color::Reset)
     }
 
     fn is_selectable(&self, index: usize) -> bool {
-        self.choices.is_selectable(index)
+        if self.is_grid() {
+            (0..self.columns).any(|column| self.is_cell_selectable(index, column))
+        } else {
+            self.choices.is_selectable(self.choice_index(index))
+        }
     }
 
     fn height_at(&mut self, index: usize, layout: ui::layout::Layout) -> u16 {
-        self.choices.height_at(index, layout)
+        if self.is_grid() {
+            let row = index;
+
+            return (0..self.columns)
+                .filter_map(|column| {
+                    let index = self.cell_index(row, column)?;
+                    let cell_layout = self.cell_layout(layout, column);
+                    Some(self.choices.height_at(index, cell_layout))
+                })
+                .max()
+                .unwrap_or(1);
+        }
+
+        let index = self.choice_index(index);
+        let height = self.choices.height_at(index, layout);
+
+        if self.descriptions.contains_key(&index) {
+            height + 1
+        } else {
+            height
+        }
     }
 
     fn len(&self) -> usize {
-        self.choices.len()
+        let len = self.visible_len();
+
+        if self.is_grid() && len > 0 {
+            // The last row may not be full
+            (len - 1) / self.columns + 1
+        } else {
+            len
+        }
     }
 
     fn page_size(&self) -> usize {
@@ -107,38 +521,86 @@ impl widgets::List for Select<'_> {
 }
 
 impl<'a> Select<'a> {
-    fn into_prompt(self, message: &'a str) -> SelectPrompt<'a> {
-        let mut select = widgets::Select::new(self);
-        if let Some(default) = select.list.choices.default() {
-            select.set_at(default);
-        }
+    fn into_prompt(mut self, message: impl Into<StyledStr<'a>>) -> SelectPrompt<'a> {
+        let choices = &self.choices.choices;
+        let hint = self.search.as_mut().map(|search| {
+            search.update(choices);
+            search_hint(search)
+        });
 
-        SelectPrompt {
-            prompt: widgets::Prompt::new(message),
-            select,
+        // The query is empty, so all the choices are visible and the default needs no mapping
+        let mut prompt = SelectPrompt {
+            prompt: widgets::Prompt::new(message.into()).with_optional_hint(hint),
+            select: widgets::Select::new(self),
+            confirm: None,
+        };
+
+        if let Some(default) = prompt.select.list.choices.default() {
+            prompt.hover(default);
+        } else if prompt.select.list.is_grid() {
+            prompt.select.list.fix_column(prompt.select.get_at());
         }
+
+        prompt
     }
 
     pub(crate) fn ask<B: Backend, E: EventIterator>(
         mut self,
-        message: String,
-        on_esc: ui::OnEsc,
+        message: StyledString,
+        input_opts: super::InputOptions<'_>,
         answers: &Answers,
         b: &mut B,
         events: &mut E,
     ) -> ui::Result<Option<Answer>> {
+        self.load_choices(answers);
+        super::choice::ensure_selectable(&self.choices)?;
+
         let transform = self.transform.take();
         let ans = ui::Input::new(self.into_prompt(&message), b)
             .hide_cursor()
-            .on_esc(on_esc)
+            .on_esc(input_opts.on_esc)
+            .timeout(input_opts.timeout)
+            .on_validation_failed(input_opts.on_validation_failed)
             .run(events)?;
 
-        crate::write_final!(transform, message, ans [ref], answers, b, |ans| b.write_styled(
-            &ans.text
+        crate::write_final!(transform, message, ans [ref], answers, b, |ans| b.write_all(
+            ans.text
                 .lines()
                 .next()
                 .expect("There must be at least one line in a `str`")
-                .cyan()
+                .as_bytes()
         )?)
     }
+
+    /// The answer can either be a [`ListItem`] with the index of the choice, or a string with the
+    /// text of the choice.
+    pub(super) fn answer_non_interactive(
+        mut self,
+        answer: Option<Answer>,
+        answers: &Answers,
+    ) -> Result<Answer, super::Rejected> {
+        self.load_choices(answers);
+
+        let index = match answer {
+            Some(Answer::ListItem(item)) => item.index,
+            Some(Answer::String(text)) => self
+                .choices
+                .choices
+                .iter()
+                .position(|choice| match choice {
+                    Choice::Choice(choice) => choice.text == text,
+                    _ => false,
+                })
+                .ok_or_else(|| format!("`{}` is not one of the choices", text))?,
+            Some(_) => return Err(super::Rejected::Invalid("expected a list item".into())),
+            None => self.choices.default().ok_or(super::Rejected::Missing)?,
+        };
+
+        super::choice::check_answer_index(&self.choices, index)?;
+
+        Ok(Answer::ListItem(ListItem {
+            index,
+            text: self.choices.choices.swap_remove(index).unwrap_choice().text,
+        }))
+    }
 }
@@ -128,3 +128,482 @@ test_select!(pagination {
         );
     height = 16;
 });
+
+#[test]
+fn test_multiline_message() {
+    let size = (50, 20).into();
+    let base_layout = Layout::new(5, size);
+    let mut select = unwrap_select(SelectBuilder::new("name".into()).choices(choices(10)))
+        .into_prompt("first line\nsecond line\nthird line");
+
+    let mut layout = base_layout;
+    assert_eq!(select.height(&mut layout), 13);
+    assert_eq!(layout, base_layout.with_offset(0, 13).with_line_offset(0));
+
+    let mut layout = base_layout;
+    let mut backend = TestBackend::new_with_layout(size, layout);
+    assert!(select.render(&mut layout, &mut backend).is_ok());
+    assert_eq!(layout, base_layout.with_offset(0, 13).with_line_offset(0));
+    ui::assert_backend_snapshot!(backend);
+}
+
+#[test]
+fn test_default_by_name() {
+    let select = unwrap_select(
+        SelectBuilder::new("name".into())
+            .default_by_name("Choice 3")
+            .choices(choices(10)),
+    );
+    assert_eq!(select.choices.default(), Some(3));
+
+    // the last of `default` and `default_by_name` wins
+    let select = unwrap_select(
+        SelectBuilder::new("name".into())
+            .choices(choices(10))
+            .default_by_name("Choice 3")
+            .default(4),
+    );
+    assert_eq!(select.choices.default(), Some(4));
+}
+
+#[test]
+#[should_panic(expected = "Invalid default 'Choice 2' does not match any `Choice`")]
+fn test_default_by_name_missing() {
+    SelectBuilder::new("name".into())
+        .choices(choices(10))
+        .default_by_name("Choice 2")
+        .build();
+}
+
+#[test]
+#[should_panic(expected = "Invalid default '2' is not a `Choice`")]
+fn test_default_separator() {
+    SelectBuilder::new("name".into())
+        .choices(choices(10))
+        .default(2)
+        .build();
+}
+
+#[test]
+#[should_panic(expected = "Invalid default '10' is out of range for 10 choices")]
+fn test_default_out_of_range() {
+    SelectBuilder::new("name".into())
+        .choices(choices(10))
+        .default(10)
+        .build();
+}
+
+#[test]
+fn test_choices_fn() {
+    let answers = Answers::default();
+    let mut select = unwrap_select(
+        SelectBuilder::new("name".into())
+            .choices_fn(|_| choices(10).skip(5).collect())
+            .choices(choices(5))
+            .default_by_name("Choice 8"),
+    );
+
+    // The choices and default are only known once the question is asked
+    assert_eq!(select.choices.len(), 5);
+    assert_eq!(select.choices.default(), None);
+
+    select.load_choices(&answers);
+    assert_eq!(select.choices.len(), 10);
+    assert_eq!(select.choices.default(), Some(8));
+
+    let select = unwrap_select(
+        SelectBuilder::new("name".into())
+            .choices_fn(|_| choices(10).collect())
+            .page_size(5)
+            .default(8),
+    );
+    match select.answer_non_interactive(None, &answers) {
+        Ok(Answer::ListItem(item)) => assert_eq!(item.index, 8),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+#[should_panic(expected = "Invalid default 'Choice 2' does not match any `Choice`")]
+fn test_choices_fn_default_by_name_missing() {
+    unwrap_select(
+        SelectBuilder::new("name".into())
+            .choices_fn(|_| choices(10).collect())
+            .default_by_name("Choice 2"),
+    )
+    .load_choices(&Answers::default());
+}
+
+#[test]
+fn test_filterable() {
+    let size = (50, 20).into();
+    let base_layout = Layout::new(5, size);
+    let mut select = unwrap_select(SelectBuilder::new("name".into()).filterable(true).choices(
+        vec![
+            Choice::Choice("Apple".to_owned()),
+            Choice::DefaultSeparator,
+            Choice::Choice("Banana".to_owned()),
+            Choice::Choice("Blueberry".to_owned()),
+            Choice::Choice("Cherry".to_owned()),
+        ],
+    ))
+    .into_prompt("message");
+
+    let visible = |select: &SelectPrompt<'_>| {
+        let list = &select.select.list;
+        (0..widgets::List::len(list))
+            .map(|i| list.choice_index(i))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(visible(&select), [0, 1, 2, 3, 4]);
+
+    assert!(select.handle_key(KeyCode::Char('R').into()));
+    assert_eq!(visible(&select), [3, 4]);
+    assert_eq!(select.select.get_at(), 0);
+
+    // Characters which match nothing are ignored, and letters are not used for movement
+    assert!(!select.handle_key(KeyCode::Char('z').into()));
+    assert!(select.handle_key(KeyCode::Char('y').into()));
+    assert_eq!(visible(&select), [3, 4]);
+    assert_eq!(select.select.get_at(), 0);
+
+    assert!(select.handle_key(KeyCode::Down.into()));
+    assert_eq!(select.select.get_at(), 1);
+
+    let mut layout = base_layout;
+    let mut backend = TestBackend::new_with_layout(size, layout);
+    assert!(select.render(&mut layout, &mut backend).is_ok());
+    ui::assert_backend_snapshot!(backend);
+
+    assert!(select.handle_key(KeyCode::Backspace.into()));
+    assert!(select.handle_key(KeyCode::Backspace.into()));
+    assert!(!select.handle_key(KeyCode::Backspace.into()));
+    assert_eq!(visible(&select), [0, 1, 2, 3, 4]);
+
+    assert!(select.handle_key(KeyCode::Char('b').into()));
+    assert!(select.handle_key(KeyCode::Down.into()));
+    assert_eq!(visible(&select), [2, 3]);
+
+    let ans = select.finish();
+    assert_eq!(ans.index, 3);
+    assert_eq!(ans.text, "Blueberry");
+}
+
+#[test]
+fn test_disabled() {
+    let size = (50, 20).into();
+    let base_layout = Layout::new(5, size);
+    let mut select = unwrap_select(SelectBuilder::new("name".into()).choices(vec![
+        Choice::Choice("Free".to_owned()),
+        Choice::Disabled {
+            text: "Pro".to_owned(),
+            reason: Some("requires an upgrade".to_owned()),
+        },
+        Choice::Disabled {
+            text: "Enterprise".to_owned(),
+            reason: None,
+        },
+        Choice::Choice("Trial".to_owned()),
+    ]))
+    .into_prompt("message");
+
+    let mut layout = base_layout;
+    let mut backend = TestBackend::new_with_layout(size, layout);
+    assert!(select.render(&mut layout, &mut backend).is_ok());
+    ui::assert_backend_snapshot!(backend);
+
+    // The cursor skips the disabled choices in both directions
+    assert!(select.handle_key(KeyCode::Down.into()));
+    assert_eq!(select.select.get_at(), 3);
+    assert!(select.handle_key(KeyCode::Up.into()));
+    assert_eq!(select.select.get_at(), 0);
+    assert!(select.handle_key(KeyCode::End.into()));
+    assert_eq!(select.select.get_at(), 3);
+
+    assert_eq!(select.finish().index, 3);
+}
+
+#[test]
+#[should_panic(expected = "Invalid default '1' is not a `Choice`")]
+fn test_default_disabled() {
+    SelectBuilder::new("name".into())
+        .choice("Free")
+        .choices(vec![Choice::Disabled {
+            text: "Pro".to_owned(),
+            reason: None,
+        }])
+        .default(1)
+        .build();
+}
+
+#[test]
+fn test_descriptions() {
+    let size = (50, 20).into();
+    let base_layout = Layout::new(5, size);
+    let mut select = unwrap_select(
+        SelectBuilder::new("name".into())
+            .choice_with_description("npm", "The default package manager")
+            .default_separator()
+            .choice("pnpm")
+            .choice_with_description(
+                "yarn",
+                "A description which is far too long to fit on the line",
+            ),
+    )
+    .into_prompt("message");
+
+    let mut layout = base_layout;
+    assert_eq!(select.height(&mut layout), 7);
+
+    let mut layout = base_layout;
+    let mut backend = TestBackend::new_with_layout(size, layout);
+    assert!(select.render(&mut layout, &mut backend).is_ok());
+    assert_eq!(layout, base_layout.with_offset(0, 7).with_line_offset(0));
+
+    let pointer = ui::symbols::current().pointer;
+    assert_eq!(backend.row_text(1), format!("{} npm", pointer));
+    assert_eq!(backend.row_text(2), "    The default package manager");
+    assert_eq!(backend.row_text(4), "  pnpm");
+    assert_eq!(backend.row_text(5), "  yarn");
+    assert_eq!(
+        backend.row_text(6),
+        "    A description which is far too long to fit on…"
+    );
+
+    assert!(select.handle_key(KeyCode::Down.into()));
+    assert!(select.handle_key(KeyCode::Down.into()));
+
+    let ans = select.finish();
+    assert_eq!(ans.index, 3);
+    assert_eq!(ans.text, "yarn");
+}
+
+#[test]
+fn test_confirm_on() {
+    let select = SelectBuilder::new("name".into())
+        .message("message")
+        .on_esc(crate::OnEsc::Terminate)
+        .choices(vec!["Save", "Discard"])
+        .confirm_on(|choice| choice.text == "Discard");
+
+    let size = (50, 20).into();
+    let mut backend = TestBackend::new(size);
+    let mut events = ui::events::TestEvents::new(vec![
+        KeyCode::Down.into(),
+        KeyCode::Enter.into(),
+        // `Esc` returns to the list instead of terminating
+        KeyCode::Esc.into(),
+        KeyCode::Enter.into(),
+        // Answering no also returns to the list
+        KeyCode::Char('n').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('y').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = crate::prompt_one_with(select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_item()
+        .unwrap();
+
+    assert_eq!(ans.index, 1);
+    assert_eq!(ans.text, "Discard");
+
+    // Choices which do not need to be confirmed are submitted straight away
+    let select = SelectBuilder::new("name".into())
+        .message("message")
+        .choices(vec!["Save", "Discard"])
+        .confirm_on(|choice| choice.text == "Discard");
+
+    let mut events = ui::events::TestEvents::new(vec![KeyCode::Enter.into()]);
+    let ans = crate::prompt_one_with(select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_item()
+        .unwrap();
+
+    assert_eq!(ans.index, 0);
+}
+
+#[test]
+fn test_confirm_on_render() {
+    let size = (50, 20).into();
+    let base_layout = Layout::new(0, size);
+    let mut select = unwrap_select(
+        SelectBuilder::new("name".into())
+            .choices(vec!["Save", "Discard"])
+            .confirm_on(|_| true),
+    )
+    .into_prompt("message");
+
+    assert_eq!(select.validate(), Ok(Validation::Continue));
+    assert!(select.handles_esc());
+    assert!(select.handle_key(KeyCode::Char('y').into()));
+
+    let mut layout = base_layout;
+    assert_eq!(select.height(&mut layout), 4);
+
+    let mut layout = base_layout;
+    let mut backend = TestBackend::new_with_layout(size, layout);
+    assert!(select.render(&mut layout, &mut backend).is_ok());
+    assert_eq!(backend.row_text(3), "? Are you sure? (y/n) y");
+
+    assert!(select.handle_key(KeyCode::Esc.into()));
+    assert!(!select.handles_esc());
+
+    let mut layout = base_layout;
+    assert_eq!(select.height(&mut layout), 3);
+}
+
+#[test]
+fn test_columns() {
+    let size = (30, 20).into();
+    let base_layout = Layout::new(0, size);
+    let mut select = unwrap_select(SelectBuilder::new("name".into()).columns(3).choices(vec![
+        Choice::Choice("a".to_owned()),
+        Choice::Choice("b".to_owned()),
+        Choice::Choice("c".to_owned()),
+        Choice::Separator("--".to_owned()),
+        Choice::Choice("d".to_owned()),
+        Choice::Choice("e".to_owned()),
+        Choice::Choice("f".to_owned()),
+    ]))
+    .into_prompt("message");
+
+    let mut layout = base_layout;
+    assert_eq!(select.height(&mut layout), 4);
+
+    let mut layout = base_layout;
+    let mut backend = TestBackend::new_with_layout(size, layout);
+    assert!(select.render(&mut layout, &mut backend).is_ok());
+
+    let pointer = ui::symbols::current().pointer;
+    assert_eq!(
+        backend.row_text(1),
+        format!("{} a         b         c", pointer)
+    );
+    assert_eq!(backend.row_text(2), "  --        d         e");
+    assert_eq!(backend.row_text(3), "  f");
+
+    assert!(select.handle_key(KeyCode::Right.into()));
+    assert!(select.handle_key(KeyCode::Right.into()));
+    assert_eq!(select.hovered_index(), 2);
+    // There is no column to the right of the last one
+    assert!(!select.handle_key(KeyCode::Right.into()));
+
+    assert!(select.handle_key(KeyCode::Down.into()));
+    assert_eq!(select.hovered_index(), 5);
+    assert!(select.handle_key(KeyCode::Left.into()));
+    assert_eq!(select.hovered_index(), 4);
+    // The separator cannot be hovered
+    assert!(!select.handle_key(KeyCode::Left.into()));
+
+    // The last row only has one choice
+    assert!(select.handle_key(KeyCode::Down.into()));
+    assert_eq!(select.hovered_index(), 6);
+
+    // The nearest choice to the separator is hovered instead
+    assert!(select.handle_key(KeyCode::Up.into()));
+    assert_eq!(select.hovered_index(), 4);
+
+    let ans = select.finish();
+    assert_eq!(ans.index, 4);
+    assert_eq!(ans.text, "d");
+}
+
+#[test]
+fn test_columns_page_size() {
+    let size = (50, 20).into();
+    let height = |select: SelectBuilder<'_>| {
+        let mut select = unwrap_select(select.page_size(5)).into_prompt("message");
+        select.height(&mut Layout::new(0, size))
+    };
+
+    // The page size is the number of rows shown
+    assert_eq!(
+        height(
+            SelectBuilder::new("name".into())
+                .columns(3)
+                .choices((0..30).map(|i| format!("Choice {}", i)))
+        ),
+        height(SelectBuilder::new("name".into()).choices((0..10).map(|i| format!("Choice {}", i))))
+    );
+}
+
+#[test]
+fn test_columns_default() {
+    let select = SelectBuilder::new("name".into())
+        .message("message")
+        .columns(2)
+        .choices(vec!["a", "b", "c", "d"])
+        .default(3);
+
+    let size = (50, 20).into();
+    let mut backend = TestBackend::new(size);
+    let mut events = ui::events::TestEvents::new(vec![
+        KeyCode::Left.into(),
+        KeyCode::Up.into(),
+        KeyCode::Right.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = crate::prompt_one_with(select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_item()
+        .unwrap();
+
+    assert_eq!(ans.index, 1);
+}
+
+#[test]
+fn test_number_shortcuts() {
+    let select = || {
+        SelectBuilder::new("name".into())
+            .message("message")
+            .number_shortcuts(true)
+            .choices(vec![
+                Choice::Choice("a".to_owned()),
+                Choice::DefaultSeparator,
+                Choice::Choice("b".to_owned()),
+                Choice::Disabled {
+                    text: "c".to_owned(),
+                    reason: None,
+                },
+                Choice::Choice("d".to_owned()),
+            ])
+    };
+
+    let mut prompt = unwrap_select(select()).into_prompt("message");
+
+    // Only the selectable choices are numbered
+    assert!(prompt.handle_key(KeyCode::Char('3').into()));
+    assert_eq!(prompt.hovered_index(), 4);
+    assert!(prompt.handle_key(KeyCode::Char('2').into()));
+    assert_eq!(prompt.hovered_index(), 2);
+
+    // Digits without a choice are ignored
+    assert!(!prompt.handle_key(KeyCode::Char('4').into()));
+    assert!(!prompt.handle_key(KeyCode::Char('0').into()));
+    assert_eq!(prompt.hovered_index(), 2);
+
+    // The choice is hovered without being submitted
+    let size = (50, 20).into();
+    let mut backend = TestBackend::new(size);
+    let mut events = ui::events::TestEvents::new(vec![
+        KeyCode::Char('3').into(),
+        KeyCode::Up.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = crate::prompt_one_with(select(), &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_item()
+        .unwrap();
+    assert_eq!(ans.index, 2);
+
+    // The query takes precedence when filterable
+    let mut prompt = unwrap_select(select().filterable(true)).into_prompt("message");
+    assert!(!prompt.handle_key(KeyCode::Char('2').into()));
+    assert_eq!(prompt.hovered_index(), 0);
+}
@@ -0,0 +1,506 @@
+use std::{fmt, io};
+
+use ui::{
+    backend::Backend,
+    events::{EventIterator, KeyEvent},
+    layout::Layout,
+    style::{Color, StyledStr, StyledString},
+    widgets::{self, List},
+    Prompt, Widget,
+};
+
+use super::{choice::get_sep_str, Choice, Options, Transform};
+use crate::{Answer, Answers, ListItem};
+
+pub(super) struct SelectLazy<'a> {
+    len: usize,
+    provider: Box<dyn Fn(usize) -> Choice<String> + 'a>,
+    default: Option<usize>,
+    page_size: usize,
+    should_loop: bool,
+    transform: Transform<'a, ListItem>,
+}
+
+impl fmt::Debug for SelectLazy<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SelectLazy")
+            .field("len", &self.len)
+            .field("provider", &"<function>")
+            .field("default", &self.default)
+            .field("page_size", &self.page_size)
+            .field("should_loop", &self.should_loop)
+            .field("transform", &self.transform)
+            .finish()
+    }
+}
+
+impl<'a> SelectLazy<'a> {
+    fn new(len: usize, provider: Box<dyn Fn(usize) -> Choice<String> + 'a>) -> Self {
+        Self {
+            len,
+            provider,
+            default: None,
+            page_size: 15,
+            should_loop: true,
+            transform: Transform::None,
+        }
+    }
+}
+
+impl List for SelectLazy<'_> {
+    fn render_item<B: Backend>(
+        &mut self,
+        index: usize,
+        hovered: bool,
+        mut layout: Layout,
+        b: &mut B,
+    ) -> io::Result<()> {
+        let choice = (self.provider)(index);
+
+        let theme = ui::theme::current();
+
+        if hovered {
+            b.set_fg(theme.cursor)?;
+            write!(b, "{} ", ui::symbols::current().pointer)?;
+        } else {
+            b.write_all(b"  ")?;
+
+            if !choice.is_choice() {
+                b.set_fg(theme.disabled)?;
+            }
+        }
+
+        layout.offset_x += 2;
+        match choice {
+            Choice::Choice(text) => text.as_str().render(&mut layout, b)?,
+            Choice::Disabled { text, reason: None } => text.as_str().render(&mut layout, b)?,
+            Choice::Disabled {
+                text,
+                reason: Some(reason),
+            } => format!("{} ({})", text, reason)
+                .as_str()
+                .render(&mut layout, b)?,
+            sep => get_sep_str(&sep).render(&mut layout, b)?,
+        }
+
+        b.set_fg(Color::Reset)
+    }
+
+    fn is_selectable(&self, index: usize) -> bool {
+        (self.provider)(index).is_choice()
+    }
+
+    fn page_size(&self) -> usize {
+        self.page_size
+    }
+
+    fn should_loop(&self) -> bool {
+        self.should_loop
+    }
+
+    fn height_at(&mut self, _: usize, _: Layout) -> u16 {
+        1
+    }
+
+    /// Every choice takes a single line, so the choices do not need to be created to know their
+    /// height, and it need not be stored for each of them
+    fn fixed_height(&self) -> Option<u16> {
+        Some(1)
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+struct SelectLazyPrompt<'a> {
+    prompt: widgets::Prompt<StyledStr<'a>>,
+    select: widgets::Select<SelectLazy<'a>>,
+}
+
+impl Prompt for SelectLazyPrompt<'_> {
+    type ValidateErr = &'static str;
+    type Output = ListItem;
+
+    fn finish(self) -> Self::Output {
+        let index = self.select.get_at();
+
+        ListItem {
+            index,
+            text: (self.select.list.provider)(index).unwrap_choice(),
+        }
+    }
+}
+
+impl Widget for SelectLazyPrompt<'_> {
+    fn render<B: Backend>(&mut self, layout: &mut Layout, b: &mut B) -> io::Result<()> {
+        self.prompt.render(layout, b)?;
+        self.select.render(layout, b)
+    }
+
+    fn height(&mut self, layout: &mut Layout) -> u16 {
+        self.prompt.height(layout) + self.select.height(layout) - 1
+    }
+
+    fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+        self.select.cursor_pos(layout)
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        self.select.handle_key(key)
+    }
+
+    fn render_changes<B: Backend>(&mut self, layout: &mut Layout, b: &mut B) -> io::Result<bool> {
+        // The message does not change once rendered, so only the list may need to be rendered
+        self.prompt.height(layout);
+        self.select.render_changes(layout, b)
+    }
+}
+
+impl<'a> SelectLazy<'a> {
+    fn into_prompt(self, message: impl Into<StyledStr<'a>>) -> SelectLazyPrompt<'a> {
+        let default = self.default;
+        let mut select = widgets::Select::new(self);
+        if let Some(default) = default {
+            select.set_at(default);
+        }
+
+        SelectLazyPrompt {
+            prompt: widgets::Prompt::new(message.into()),
+            select,
+        }
+    }
+
+    pub(crate) fn ask<B: Backend, E: EventIterator>(
+        mut self,
+        message: StyledString,
+        input_opts: super::InputOptions<'_>,
+        answers: &Answers,
+        b: &mut B,
+        events: &mut E,
+    ) -> ui::Result<Option<Answer>> {
+        super::choice::ensure_selectable(&self)?;
+
+        let transform = self.transform.take();
+        let ans = ui::Input::new(self.into_prompt(&message), b)
+            .hide_cursor()
+            .on_esc(input_opts.on_esc)
+            .timeout(input_opts.timeout)
+            .on_validation_failed(input_opts.on_validation_failed)
+            .run(events)?;
+
+        crate::write_final!(transform, message, ans [ref], answers, b, |ans| b.write_all(
+            ans.text
+                .lines()
+                .next()
+                .expect("There must be at least one line in a `str`")
+                .as_bytes()
+        )?)
+    }
+}
+
+/// The builder for a [`select_lazy`] prompt.
+///
+/// Unlike [`select`], the choices are not stored. Instead, they are created by the provider
+/// whenever they are needed, so only the choices on the current page are created while rendering.
+/// This makes it suitable for a very large number of choices, for example ones coming from a
+/// database.
+///
+/// Since the choices are created on demand, every choice can only take a single line. Longer
+/// choices are cut-off.
+///
+/// See the various methods for more details on each available option.
+///
+/// # Examples
+///
+/// ```
+/// use requestty::Question;
+///
+/// let select = Question::select_lazy("number", 1_000_000, |i| format!("Number {}", i).into())
+///     .message("Pick a number")
+///     .build();
+/// ```
+///
+/// [`select_lazy`]: crate::question::Question::select_lazy
+/// [`select`]: crate::question::Question::select
+#[derive(Debug)]
+pub struct SelectLazyBuilder<'a> {
+    opts: Options<'a>,
+    select: SelectLazy<'a>,
+}
+
+impl<'a> SelectLazyBuilder<'a> {
+    pub(crate) fn new(
+        name: String,
+        len: usize,
+        provider: Box<dyn Fn(usize) -> Choice<String> + 'a>,
+    ) -> Self {
+        SelectLazyBuilder {
+            opts: Options::new(name),
+            select: SelectLazy::new(len, provider),
+        }
+    }
+
+    crate::impl_options_builder! {
+    message
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select_lazy("number", 100, |i| i.to_string().into())
+    ///     .message("Pick a number")
+    ///     .build();
+    /// ```
+
+    when
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, Answers};
+    ///
+    /// let select = Question::select_lazy("number", 100, |i| i.to_string().into())
+    ///     .when(|previous_answers: &Answers| match previous_answers.get("pick_number") {
+    ///         Some(ans) => ans.as_bool().unwrap(),
+    ///         None => true,
+    ///     })
+    ///     .build();
+    /// ```
+
+    ask_if_answered
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select_lazy("number", 100, |i| i.to_string().into())
+    ///     .ask_if_answered(true)
+    ///     .build();
+    /// ```
+
+    on_esc
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, OnEsc};
+    ///
+    /// let select = Question::select_lazy("number", 100, |i| i.to_string().into())
+    ///     .on_esc(OnEsc::Terminate)
+    ///     .build();
+    /// ```
+    }
+
+    /// Set a default index for the select
+    ///
+    /// The given index will be hovered in the beginning.
+    ///
+    /// If `default` is unspecified, the first [`Choice`] will be hovered.
+    ///
+    /// # Panics
+    ///
+    /// If the default given is out of range or not a [`Choice`], it will cause a panic on
+    /// [`build`]
+    ///
+    /// [`Choice`]: crate::question::Choice
+    /// [`build`]: Self::build
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select_lazy("number", 100, |i| i.to_string().into())
+    ///     .default(42)
+    ///     .build();
+    /// ```
+    pub fn default(mut self, default: usize) -> Self {
+        self.select.default = Some(default);
+        self
+    }
+
+    /// The maximum height that can be taken by the list
+    ///
+    /// If the total height exceeds the page size, the list will be scrollable.
+    ///
+    /// The `page_size` must be a minimum of 5. If `page_size` is not set, it will default to 15.
+    ///
+    /// # Panics
+    ///
+    /// It will panic if the `page_size` is less than 5.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select_lazy("number", 100, |i| i.to_string().into())
+    ///     .page_size(10)
+    ///     .build();
+    /// ```
+    pub fn page_size(mut self, page_size: usize) -> Self {
+        assert!(page_size >= 5, "page size can be a minimum of 5");
+
+        self.select.page_size = page_size;
+        self
+    }
+
+    /// Whether to wrap around when user gets to the last element.
+    ///
+    /// If `should_loop` is not set, it will default to `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select_lazy("number", 100, |i| i.to_string().into())
+    ///     .should_loop(false)
+    ///     .build();
+    /// ```
+    pub fn should_loop(mut self, should_loop: bool) -> Self {
+        self.select.should_loop = should_loop;
+        self
+    }
+
+    crate::impl_transform_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select_lazy("number", 100, |i| i.to_string().into())
+    ///     .transform(|choice, previous_answers, backend| {
+    ///         write!(backend, "({}) {}", choice.index, choice.text)
+    ///     })
+    ///     .build();
+    /// ```
+    ListItem; select
+    }
+
+    /// Consumes the builder returning a [`Question`]
+    ///
+    /// [`Question`]: crate::question::Question
+    pub fn build(self) -> super::Question<'a> {
+        if let Some(default) = self.select.default {
+            if default >= self.select.len {
+                panic!(
+                    "Invalid default '{}' is out of range for {} choices",
+                    default, self.select.len
+                );
+            }
+
+            if !(self.select.provider)(default).is_choice() {
+                panic!("Invalid default '{}' is not a `Choice`", default);
+            }
+        }
+
+        super::Question::new(self.opts, super::QuestionKind::SelectLazy(self.select))
+    }
+}
+
+impl<'a> From<SelectLazyBuilder<'a>> for super::Question<'a> {
+    /// Consumes the builder returning a [`Question`]
+    ///
+    /// [`Question`]: crate::question::Question
+    fn from(builder: SelectLazyBuilder<'a>) -> Self {
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+    use ui::{backend::TestBackend, events::KeyCode};
+
+    fn select(len: usize, created: &Cell<usize>) -> SelectLazy<'_> {
+        SelectLazy::new(
+            len,
+            Box::new(move |i| {
+                created.set(created.get() + 1);
+
+                if i % 10 == 5 {
+                    Choice::DefaultSeparator
+                } else {
+                    format!("choice {}", i).into()
+                }
+            }),
+        )
+    }
+
+    #[test]
+    fn test_only_visible_created() {
+        let created = Cell::new(0);
+        let size = (50, 20).into();
+        let base_layout = Layout::new(5, size);
+        let mut prompt = select(1_000_000, &created).into_prompt("message");
+        let mut backend = TestBackend::new_with_layout(size, base_layout);
+
+        let mut layout = base_layout;
+        prompt.height(&mut layout.clone());
+        assert!(prompt.render(&mut layout, &mut backend).is_ok());
+        ui::assert_backend_snapshot!(backend);
+
+        // The first and last selectable choices are found, and then a page of 14 choices is
+        // rendered
+        assert_eq!(created.get(), 2 + 14);
+
+        created.set(0);
+        prompt.handle_key(KeyCode::PageDown.into());
+        prompt.handle_key(KeyCode::Down.into());
+        assert!(created.get() < 20);
+
+        created.set(0);
+        backend.reset_with_layout(base_layout);
+        assert!(prompt
+            .render(&mut base_layout.clone(), &mut backend)
+            .is_ok());
+        assert_eq!(created.get(), 14);
+    }
+
+    #[test]
+    fn test_finish() {
+        let created = Cell::new(0);
+        let mut prompt = select(100, &created).into_prompt("message");
+
+        let mut layout = Layout::new(0, (50, 20).into());
+        prompt.height(&mut layout);
+
+        prompt.handle_key(KeyCode::Up.into());
+        assert_eq!(
+            prompt.finish(),
+            ListItem {
+                index: 99,
+                text: "choice 99".into()
+            }
+        );
+
+        let created = Cell::new(0);
+        let mut prompt = select(100, &created).into_prompt("message");
+        prompt.height(&mut layout);
+        for _ in 0..5 {
+            prompt.handle_key(KeyCode::Down.into());
+        }
+        // The separator at index 5 is skipped
+        assert_eq!(prompt.finish().index, 6);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid default '5' is not a `Choice`")]
+    fn test_default_separator() {
+        let created = Cell::new(0);
+        SelectLazyBuilder::new("name".into(), 100, select(100, &created).provider)
+            .default(5)
+            .build();
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid default '100' is out of range for 100 choices")]
+    fn test_default_out_of_range() {
+        let created = Cell::new(0);
+        SelectLazyBuilder::new("name".into(), 100, select(100, &created).provider)
+            .default(100)
+            .build();
+    }
+}
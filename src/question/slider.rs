@@ -0,0 +1,741 @@
+use std::{borrow::Cow, io};
+
+use ui::{
+    backend::Backend,
+    events::{EventIterator, KeyCode, KeyEvent, Movement},
+    style::{Color, StyledStr, StyledString},
+    widgets, Prompt, Validation, Widget,
+};
+
+use super::{Hint, Options, TransformByVal as Transform, ValidateByVal as Validate};
+use crate::{Answer, Answers};
+
+/// The number of characters between the brackets of the slider.
+const BAR_WIDTH: i64 = 20;
+
+/// The number of steps moved by `PageUp` and `PageDown`.
+const PAGE_STEPS: i64 = 10;
+
+#[derive(Debug)]
+pub(super) struct Slider<'a> {
+    min: i64,
+    max: i64,
+    step: i64,
+    default: Option<i64>,
+    hint: Hint,
+    validate: Validate<'a, i64>,
+    transform: Transform<'a, i64>,
+}
+
+impl<'a> Default for Slider<'a> {
+    fn default() -> Self {
+        Self {
+            min: 0,
+            max: 100,
+            step: 1,
+            default: None,
+            hint: Hint::Default,
+            validate: Validate::None,
+            transform: Transform::None,
+        }
+    }
+}
+
+struct SliderPrompt<'s, 'a> {
+    prompt: widgets::Prompt<StyledStr<'a>, Cow<'static, str>>,
+    slider: Slider<'s>,
+    value: i64,
+    /// The number typed by the user since the value was last moved with a key.
+    typed: String,
+    answers: &'a Answers,
+}
+
+impl SliderPrompt<'_, '_> {
+    /// Whether there is only one possible value, in which case there is no bar to move along.
+    fn is_static(&self) -> bool {
+        self.slider.min == self.slider.max
+    }
+
+    /// Adds a character to the typed number, moving the value to it clamped to the min and max.
+    fn type_char(&mut self, c: char) -> bool {
+        self.typed.push(c);
+
+        match self.typed.parse() {
+            Ok(value) => {
+                self.move_to(value);
+                true
+            }
+            // A lone minus sign is the start of a negative number
+            Err(_) if self.typed == "-" => true,
+            // The number is too large to fit
+            Err(_) => {
+                self.typed.pop();
+                false
+            }
+        }
+    }
+
+    /// Moves the value by the given number of steps, clamping it to the min and max.
+    fn move_by(&mut self, steps: i64) -> bool {
+        let value = self.value as i128 + steps as i128 * self.slider.step as i128;
+        let value = value.clamp(self.slider.min as i128, self.slider.max as i128);
+        self.move_to(value as i64)
+    }
+
+    fn move_to(&mut self, value: i64) -> bool {
+        let value = value.clamp(self.slider.min, self.slider.max);
+
+        if value == self.value {
+            false
+        } else {
+            self.value = value;
+            true
+        }
+    }
+
+    /// The position of the knob within the bar.
+    fn knob(&self) -> i64 {
+        let range = self.slider.max as i128 - self.slider.min as i128;
+        let offset = self.value as i128 - self.slider.min as i128;
+
+        // Rounded to the nearest position
+        ((offset * (BAR_WIDTH - 1) as i128 * 2 + range) / (range * 2)) as i64
+    }
+
+    fn width(&self) -> u16 {
+        let value_width = self.value.to_string().len() as u16;
+
+        if self.is_static() {
+            value_width
+        } else {
+            // The brackets, the space and the value
+            (BAR_WIDTH + 3) as u16 + value_width
+        }
+    }
+}
+
+impl Widget for SliderPrompt<'_, '_> {
+    fn render<B: Backend>(&mut self, layout: &mut ui::layout::Layout, b: &mut B) -> io::Result<()> {
+        self.prompt.render(layout, b)?;
+
+        if self.is_static() {
+            write!(b, "{}", self.value)?;
+            layout.line_offset += self.width();
+            return Ok(());
+        }
+
+        let knob = self.knob() as usize;
+
+        let theme = ui::theme::current();
+
+        b.write_all(b"[")?;
+        b.set_fg(theme.cursor)?;
+        write!(b, "{}o", "=".repeat(knob))?;
+        b.set_fg(theme.disabled)?;
+        write!(b, "{}", "-".repeat(BAR_WIDTH as usize - knob - 1))?;
+        b.set_fg(Color::Reset)?;
+        write!(b, "] {}", self.value)?;
+
+        layout.line_offset += self.width();
+
+        Ok(())
+    }
+
+    fn height(&mut self, layout: &mut ui::layout::Layout) -> u16 {
+        let height = self.prompt.height(layout);
+        layout.line_offset += self.width();
+        height
+    }
+
+    fn cursor_pos(&mut self, layout: ui::layout::Layout) -> (u16, u16) {
+        self.prompt.cursor_pos(layout)
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if self.is_static() {
+            return false;
+        }
+
+        match key.code {
+            KeyCode::Char(c) if c.is_ascii_digit() => return self.type_char(c),
+            KeyCode::Char('-') if self.typed.is_empty() && self.slider.min < 0 => {
+                return self.type_char('-')
+            }
+            KeyCode::Backspace if !self.typed.is_empty() => {
+                self.typed.pop();
+                if let Ok(value) = self.typed.parse() {
+                    self.move_to(value);
+                }
+                return true;
+            }
+            _ => {}
+        }
+
+        self.typed.clear();
+
+        match Movement::try_from_key(key) {
+            Some(Movement::Left) | Some(Movement::Down) => self.move_by(-1),
+            Some(Movement::Right) | Some(Movement::Up) => self.move_by(1),
+            Some(Movement::PageDown) => self.move_by(-PAGE_STEPS),
+            Some(Movement::PageUp) => self.move_by(PAGE_STEPS),
+            Some(Movement::Home) => self.move_to(self.slider.min),
+            Some(Movement::End) => self.move_to(self.slider.max),
+            _ => false,
+        }
+    }
+}
+
+impl Prompt for SliderPrompt<'_, '_> {
+    type ValidateErr = widgets::Text<String>;
+    type Output = i64;
+
+    fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
+        if let Validate::Sync(ref mut validate) = self.slider.validate {
+            validate(self.value, self.answers)?;
+        }
+
+        Ok(Validation::Finish)
+    }
+
+    fn finish(self) -> Self::Output {
+        self.value
+    }
+}
+
+impl<'s> Slider<'s> {
+    fn into_slider_prompt<'a>(
+        mut self,
+        message: impl Into<StyledStr<'a>>,
+        answers: &'a Answers,
+    ) -> SliderPrompt<'s, 'a> {
+        let default_hint = if self.min == self.max {
+            None
+        } else {
+            Some("Use arrow keys to move, <pgup>/<pgdn> to jump")
+        };
+        let hint = std::mem::take(&mut self.hint).resolve(default_hint);
+
+        SliderPrompt {
+            prompt: widgets::Prompt::new(message.into()).with_optional_hint(hint),
+            value: self.default.unwrap_or(self.min),
+            typed: String::new(),
+            slider: self,
+            answers,
+        }
+    }
+
+    pub(crate) fn ask<B: Backend, E: EventIterator>(
+        mut self,
+        message: StyledString,
+        input_opts: super::InputOptions<'_>,
+        answers: &Answers,
+        b: &mut B,
+        events: &mut E,
+    ) -> ui::Result<Option<Answer>> {
+        let transform = self.transform.take();
+
+        let ans = ui::Input::new(self.into_slider_prompt(&message, answers), b)
+            .hide_cursor()
+            .on_esc(input_opts.on_esc)
+            .timeout(input_opts.timeout)
+            .on_validation_failed(input_opts.on_validation_failed)
+            .run(events)?;
+
+        crate::write_final!(transform, message, ans, answers, b, |ans| {
+            write!(b, "{}", ans)?;
+        })
+    }
+}
+
+/// The builder for a [`slider`] prompt.
+///
+/// The value is picked by moving a knob along a bar with the arrow keys. `PageUp` and `PageDown`
+/// move by 10 steps at a time, and `Home` and `End` move to the ends of the bar. A number can also
+/// be typed directly, in which case the value is clamped between the min and max.
+///
+/// If the min and max are the same, there is only one value, so it is shown without a bar.
+///
+/// The answer is an [`Answer::Int`].
+///
+/// See the various methods for more details on each available option.
+///
+/// # Examples
+///
+/// ```
+/// use requestty::Question;
+///
+/// let slider = Question::slider("volume")
+///     .message("How loud should the music be?")
+///     .min(0)
+///     .max(100)
+///     .step(5)
+///     .default(40)
+///     .build();
+/// ```
+///
+/// [`slider`]: crate::question::Question::slider
+/// [`Answer::Int`]: crate::Answer::Int
+#[derive(Debug)]
+pub struct SliderBuilder<'a> {
+    opts: Options<'a>,
+    slider: Slider<'a>,
+}
+
+impl<'a> SliderBuilder<'a> {
+    pub(crate) fn new(name: String) -> Self {
+        SliderBuilder {
+            opts: Options::new(name),
+            slider: Default::default(),
+        }
+    }
+
+    crate::impl_options_builder! {
+    message
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let slider = Question::slider("volume")
+    ///     .message("How loud should the music be?")
+    ///     .build();
+    /// ```
+
+    when
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, Answers};
+    ///
+    /// let slider = Question::slider("volume")
+    ///     .when(|previous_answers: &Answers| match previous_answers.get("mute") {
+    ///         Some(ans) => !ans.as_bool().unwrap(),
+    ///         None => true,
+    ///     })
+    ///     .build();
+    /// ```
+
+    ask_if_answered
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let slider = Question::slider("volume")
+    ///     .ask_if_answered(true)
+    ///     .build();
+    /// ```
+
+    on_esc
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, OnEsc};
+    ///
+    /// let slider = Question::slider("volume")
+    ///     .on_esc(OnEsc::Terminate)
+    ///     .build();
+    /// ```
+    }
+
+    /// Set the smallest value of the slider
+    ///
+    /// If `min` is not set, it will default to 0.
+    ///
+    /// # Panics
+    ///
+    /// If `min` is greater than the [`max`], it will cause a panic on [`build`].
+    ///
+    /// [`max`]: SliderBuilder::max
+    /// [`build`]: SliderBuilder::build
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let slider = Question::slider("temperature")
+    ///     .min(-10)
+    ///     .build();
+    /// ```
+    pub fn min(mut self, min: i64) -> Self {
+        self.slider.min = min;
+        self
+    }
+
+    /// Set the largest value of the slider
+    ///
+    /// If `max` is not set, it will default to 100.
+    ///
+    /// # Panics
+    ///
+    /// If `max` is less than the [`min`], it will cause a panic on [`build`].
+    ///
+    /// [`min`]: SliderBuilder::min
+    /// [`build`]: SliderBuilder::build
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let slider = Question::slider("temperature")
+    ///     .max(40)
+    ///     .build();
+    /// ```
+    pub fn max(mut self, max: i64) -> Self {
+        self.slider.max = max;
+        self
+    }
+
+    /// Set the amount the value changes by on each key press
+    ///
+    /// The value is always clamped between the [`min`] and [`max`], so the last step may be
+    /// smaller. If `step` is not set, it will default to 1.
+    ///
+    /// [`min`]: SliderBuilder::min
+    /// [`max`]: SliderBuilder::max
+    ///
+    /// # Panics
+    ///
+    /// It will panic if the `step` is not positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let slider = Question::slider("volume")
+    ///     .step(5)
+    ///     .build();
+    /// ```
+    pub fn step(mut self, step: i64) -> Self {
+        assert!(step > 0, "step must be positive");
+
+        self.slider.step = step;
+        self
+    }
+
+    /// Set the initial value of the slider
+    ///
+    /// If `default` is not set, it will default to the [`min`].
+    ///
+    /// [`min`]: SliderBuilder::min
+    ///
+    /// # Panics
+    ///
+    /// If `default` is not between the [`min`] and [`max`], it will cause a panic on [`build`].
+    ///
+    /// [`max`]: SliderBuilder::max
+    /// [`build`]: SliderBuilder::build
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let slider = Question::slider("volume")
+    ///     .default(40)
+    ///     .build();
+    /// ```
+    pub fn default(mut self, default: i64) -> Self {
+        self.slider.default = Some(default);
+        self
+    }
+
+    crate::impl_validate_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let slider = Question::slider("volume")
+    ///     .validate(|volume, previous_answers| {
+    ///         if volume <= 80 {
+    ///             Ok(())
+    ///         } else {
+    ///             Err("That is too loud!".to_owned())
+    ///         }
+    ///     })
+    ///     .build();
+    /// ```
+    by val i64; slider
+    }
+
+    crate::impl_hint_builder! {
+    hint
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let slider = Question::slider("volume")
+    ///     .hint("Use the arrow keys")
+    ///     .build();
+    /// ```
+
+    no_hint
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let slider = Question::slider("volume")
+    ///     .no_hint()
+    ///     .build();
+    /// ```
+    slider
+    }
+
+    crate::impl_transform_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let slider = Question::slider("volume")
+    ///     .transform(|volume, previous_answers, backend| {
+    ///         write!(backend, "{}%", volume)
+    ///     })
+    ///     .build();
+    /// ```
+    by val i64; slider
+    }
+
+    /// Consumes the builder returning a [`Question`]
+    ///
+    /// [`Question`]: crate::question::Question
+    pub fn build(self) -> super::Question<'a> {
+        let Slider {
+            min, max, default, ..
+        } = self.slider;
+
+        if min > max {
+            panic!(
+                "Invalid slider range: min {} is greater than max {}",
+                min, max
+            );
+        }
+
+        if let Some(default) = default {
+            if default < min || default > max {
+                panic!(
+                    "Invalid default {} is not between min {} and max {}",
+                    default, min, max
+                );
+            }
+        }
+
+        super::Question::new(self.opts, super::QuestionKind::Slider(self.slider))
+    }
+}
+
+impl<'a> From<SliderBuilder<'a>> for super::Question<'a> {
+    /// Consumes the builder returning a [`Question`]
+    ///
+    /// [`Question`]: crate::question::Question
+    fn from(builder: SliderBuilder<'a>) -> Self {
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ui::{backend::TestBackend, events::KeyCode, layout::Layout};
+
+    fn slider(min: i64, max: i64, step: i64, default: i64) -> Slider<'static> {
+        Slider {
+            min,
+            max,
+            step,
+            default: Some(default),
+            ..Default::default()
+        }
+    }
+
+    fn press(prompt: &mut SliderPrompt<'_, '_>, key: KeyCode) -> i64 {
+        prompt.handle_key(key.into());
+        prompt.value
+    }
+
+    #[test]
+    fn test_hint() {
+        let size = (80, 20).into();
+        let answers = Answers::default();
+
+        let hint_line = |slider: Slider<'static>| {
+            let mut prompt = slider.into_slider_prompt("message", &answers);
+            let mut backend = TestBackend::new(size);
+            assert!(prompt
+                .prompt
+                .render(&mut Layout::new(0, size), &mut backend)
+                .is_ok());
+            backend.row_text(0)
+        };
+
+        assert_eq!(
+            hint_line(slider(0, 10, 1, 5)),
+            "? message (Use arrow keys to move, <pgup>/<pgdn> to jump)"
+        );
+        // There is nothing to move, so there is no hint by default
+        assert_eq!(hint_line(slider(5, 5, 1, 5)), "? message ›");
+
+        let custom = Slider {
+            hint: Hint::Custom("Pick a volume".into()),
+            ..slider(5, 5, 1, 5)
+        };
+        assert_eq!(hint_line(custom), "? message (Pick a volume)");
+
+        let hidden = Slider {
+            hint: Hint::Hidden,
+            ..slider(0, 10, 1, 5)
+        };
+        assert_eq!(hint_line(hidden), "? message ›");
+    }
+
+    #[test]
+    fn test_step() {
+        let answers = Answers::default();
+        let mut prompt = slider(0, 100, 5, 40).into_slider_prompt("message", &answers);
+
+        assert_eq!(press(&mut prompt, KeyCode::Right), 45);
+        assert_eq!(press(&mut prompt, KeyCode::Left), 40);
+        assert_eq!(press(&mut prompt, KeyCode::Left), 35);
+        assert_eq!(press(&mut prompt, KeyCode::PageUp), 85);
+        assert_eq!(press(&mut prompt, KeyCode::PageDown), 35);
+        assert_eq!(press(&mut prompt, KeyCode::End), 100);
+        assert_eq!(press(&mut prompt, KeyCode::Home), 0);
+    }
+
+    #[test]
+    fn test_clamp() {
+        let answers = Answers::default();
+        let mut prompt = slider(-10, 10, 3, 8).into_slider_prompt("message", &answers);
+
+        assert_eq!(press(&mut prompt, KeyCode::Right), 10);
+        assert!(!prompt.handle_key(KeyCode::Right.into()));
+        assert!(!prompt.handle_key(KeyCode::PageUp.into()));
+        assert_eq!(press(&mut prompt, KeyCode::PageDown), -10);
+        assert!(!prompt.handle_key(KeyCode::Left.into()));
+        assert_eq!(press(&mut prompt, KeyCode::Right), -7);
+
+        let mut prompt =
+            slider(i64::MIN, i64::MAX, i64::MAX, 0).into_slider_prompt("message", &answers);
+        assert_eq!(press(&mut prompt, KeyCode::PageUp), i64::MAX);
+        assert_eq!(press(&mut prompt, KeyCode::PageDown), i64::MIN);
+    }
+
+    #[test]
+    fn test_type_number() {
+        let answers = Answers::default();
+        let mut prompt = slider(10, 100, 5, 40).into_slider_prompt("message", &answers);
+
+        // Each digit moves to the number typed so far, clamped into the range
+        assert_eq!(press(&mut prompt, KeyCode::Char('7')), 10);
+        assert_eq!(press(&mut prompt, KeyCode::Char('3')), 73);
+        assert_eq!(press(&mut prompt, KeyCode::Char('0')), 100);
+        assert_eq!(press(&mut prompt, KeyCode::Backspace), 73);
+
+        // Moving starts a new number
+        assert_eq!(press(&mut prompt, KeyCode::Right), 78);
+        assert_eq!(press(&mut prompt, KeyCode::Char('2')), 10);
+        assert_eq!(press(&mut prompt, KeyCode::Char('5')), 25);
+
+        // A minus sign is only allowed if the range has negative numbers
+        assert!(!prompt.handle_key(KeyCode::Char('-').into()));
+
+        let mut prompt = slider(-50, 50, 1, 0).into_slider_prompt("message", &answers);
+        assert_eq!(press(&mut prompt, KeyCode::Char('-')), 0);
+        assert_eq!(press(&mut prompt, KeyCode::Char('4')), -4);
+        assert_eq!(press(&mut prompt, KeyCode::Char('2')), -42);
+        assert!(!prompt.handle_key(KeyCode::Char('-').into()));
+
+        // Numbers which do not fit are not typed
+        let mut prompt = slider(0, i64::MAX, 1, 0).into_slider_prompt("message", &answers);
+        for _ in 0..18 {
+            prompt.handle_key(KeyCode::Char('9').into());
+        }
+        assert!(!prompt.handle_key(KeyCode::Char('9').into()));
+        assert_eq!(prompt.value, 999_999_999_999_999_999);
+    }
+
+    #[test]
+    fn test_static() {
+        let size = (50, 20).into();
+        let base_layout = Layout::new(5, size);
+        let answers = Answers::default();
+
+        let mut prompt = SliderBuilder::new("name".into())
+            .min(7)
+            .max(7)
+            .slider
+            .into_slider_prompt("message", &answers);
+
+        assert_eq!(prompt.value, 7);
+        assert!(!prompt.handle_key(KeyCode::Right.into()));
+        assert!(!prompt.handle_key(KeyCode::Char('1').into()));
+
+        let mut layout = base_layout;
+        let mut backend = TestBackend::new_with_layout(size, base_layout);
+        assert!(prompt.render(&mut layout, &mut backend).is_ok());
+        ui::assert_backend_snapshot!(backend);
+
+        let mut height_layout = base_layout;
+        assert_eq!(prompt.height(&mut height_layout), layout.offset_y + 1);
+        assert_eq!(height_layout, layout);
+    }
+
+    #[test]
+    fn test_knob() {
+        let answers = Answers::default();
+        let mut prompt = slider(0, 100, 1, 0).into_slider_prompt("message", &answers);
+
+        assert_eq!(prompt.knob(), 0);
+        prompt.value = 50;
+        assert_eq!(prompt.knob(), 10);
+        prompt.value = 100;
+        assert_eq!(prompt.knob(), BAR_WIDTH - 1);
+    }
+
+    #[test]
+    fn test_render() {
+        let size = (50, 20).into();
+        let base_layout = Layout::new(5, size);
+        let answers = Answers::default();
+
+        let mut prompt = slider(0, 100, 10, 40).into_slider_prompt("message", &answers);
+        let mut backend = TestBackend::new_with_layout(size, base_layout);
+
+        for &key in [KeyCode::Home, KeyCode::End, KeyCode::Home].iter() {
+            let mut layout = base_layout;
+            assert!(prompt.render(&mut layout, &mut backend).is_ok());
+            ui::assert_backend_snapshot!(backend);
+
+            let mut height_layout = base_layout;
+            assert_eq!(prompt.height(&mut height_layout), layout.offset_y + 1);
+            assert_eq!(height_layout, layout);
+
+            backend.reset_with_layout(base_layout);
+            prompt.handle_key(key.into());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid slider range: min 11 is greater than max 10")]
+    fn test_panic_range() {
+        SliderBuilder::new("name".into()).min(11).max(10).build();
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid default 101 is not between min 0 and max 100")]
+    fn test_panic_default() {
+        SliderBuilder::new("name".into()).default(101).build();
+    }
+
+    #[test]
+    #[should_panic(expected = "step must be positive")]
+    fn test_panic_step() {
+        SliderBuilder::new("name".into()).step(0);
+    }
+}
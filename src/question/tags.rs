@@ -0,0 +1,493 @@
+use std::{borrow::Cow, io};
+
+use ui::{
+    backend::Backend,
+    events::{EventIterator, KeyCode, KeyEvent},
+    layout::Layout,
+    style::{Color, StyledStr, StyledString},
+    widgets, Prompt, Validation, Widget,
+};
+
+use super::{Hint, Options, Transform, Validate};
+use crate::{Answer, Answers};
+
+#[derive(Debug, Default)]
+pub(super) struct Tags<'a> {
+    hint: Hint,
+    validate_tag: Validate<'a, str>,
+    validate: Validate<'a, [String]>,
+    transform: Transform<'a, [String]>,
+}
+
+struct TagsPrompt<'t, 'a> {
+    prompt: widgets::Prompt<StyledStr<'a>, Cow<'static, str>>,
+    tags_opts: Tags<'t>,
+    tags: Vec<String>,
+    input: widgets::StringInput,
+    answers: &'a Answers,
+}
+
+/// The width of a tag when it is rendered as a chip, not including the space after it.
+fn chip_width(tag: &str) -> u16 {
+    tag.chars().count() as u16 + 2
+}
+
+/// Moves the layout past a chip of the given width, returning whether it had to be moved to the
+/// next line.
+fn place_chip(width: u16, layout: &mut Layout) -> bool {
+    let wrapped = width + 1 > layout.line_width() && layout.line_offset != 0;
+
+    if wrapped {
+        layout.line_offset = 0;
+        layout.offset_y += 1;
+    }
+
+    layout.line_offset = (layout.line_offset + width + 1).min(layout.available_width());
+    wrapped
+}
+
+impl TagsPrompt<'_, '_> {
+    /// Moves the layout past all the chips, returning the number of lines that were added.
+    fn place_chips(&self, layout: &mut Layout) -> u16 {
+        self.tags
+            .iter()
+            .filter(|tag| place_chip(chip_width(tag), layout))
+            .count() as u16
+    }
+
+    /// Checks if the current input can be added as a tag.
+    fn check_tag(&mut self) -> Result<String, String> {
+        let tag = self.input.value().trim();
+
+        if self.tags.iter().any(|t| t == tag) {
+            return Err(format!("'{}' has already been added", tag));
+        }
+
+        if let Validate::Sync(ref mut validate) = self.tags_opts.validate_tag {
+            validate(tag, self.answers)?;
+        }
+
+        Ok(tag.to_owned())
+    }
+
+    /// Adds the current input as a tag, clearing the input.
+    fn add_tag(&mut self) -> Result<(), String> {
+        let tag = self.check_tag()?;
+
+        if !tag.is_empty() {
+            self.tags.push(tag);
+        }
+
+        self.input.set_value(String::new());
+        self.input.set_at(0);
+        Ok(())
+    }
+}
+
+impl Widget for TagsPrompt<'_, '_> {
+    fn render<B: Backend>(&mut self, layout: &mut Layout, b: &mut B) -> io::Result<()> {
+        self.prompt.render(layout, b)?;
+
+        for tag in &self.tags {
+            if place_chip(chip_width(tag), layout) {
+                b.move_cursor_to(layout.offset_x, layout.offset_y)?;
+            }
+
+            b.set_bg(Color::DarkGrey)?;
+            write!(b, " {} ", tag)?;
+            b.set_bg(Color::Reset)?;
+            b.write_all(b" ")?;
+        }
+
+        self.input.render(layout, b)
+    }
+
+    fn height(&mut self, layout: &mut Layout) -> u16 {
+        self.prompt.height(layout) + self.place_chips(layout) + self.input.height(layout) - 1
+    }
+
+    fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+        let mut layout = layout.with_cursor_pos(self.prompt.cursor_pos(layout));
+        self.place_chips(&mut layout);
+        self.input.cursor_pos(layout)
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            // Invalid tags are only reported on enter, so a comma does nothing for them
+            KeyCode::Char(',') => self.add_tag().is_ok(),
+            KeyCode::Backspace if self.input.value().is_empty() => self.tags.pop().is_some(),
+            _ => self.input.handle_key(key),
+        }
+    }
+}
+
+impl Prompt for TagsPrompt<'_, '_> {
+    type ValidateErr = widgets::Text<String>;
+    type Output = Vec<String>;
+
+    fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
+        if !self.input.value().is_empty() {
+            self.add_tag()?;
+            return Ok(Validation::Continue);
+        }
+
+        if let Validate::Sync(ref mut validate) = self.tags_opts.validate {
+            validate(&self.tags, self.answers)?;
+        }
+
+        Ok(Validation::Finish)
+    }
+
+    fn finish(self) -> Self::Output {
+        self.tags
+    }
+}
+
+impl<'t> Tags<'t> {
+    fn into_tags_prompt<'a>(
+        mut self,
+        message: impl Into<StyledStr<'a>>,
+        answers: &'a Answers,
+    ) -> TagsPrompt<'t, 'a> {
+        let hint = std::mem::take(&mut self.hint).resolve(Some(
+            "Press <enter> or <,> to add a tag, <enter> again to finish",
+        ));
+
+        TagsPrompt {
+            prompt: widgets::Prompt::new(message.into()).with_optional_hint(hint),
+            tags_opts: self,
+            tags: Vec::new(),
+            input: widgets::StringInput::default(),
+            answers,
+        }
+    }
+
+    pub(crate) fn ask<B: Backend, E: EventIterator>(
+        mut self,
+        message: StyledString,
+        input_opts: super::InputOptions<'_>,
+        answers: &Answers,
+        b: &mut B,
+        events: &mut E,
+    ) -> ui::Result<Option<Answer>> {
+        let transform = self.transform.take();
+
+        let ans = ui::Input::new(self.into_tags_prompt(&message, answers), b)
+            .on_esc(input_opts.on_esc)
+            .timeout(input_opts.timeout)
+            .on_validation_failed(input_opts.on_validation_failed)
+            .run(events)?;
+
+        crate::write_final!(transform, message, ans [ref], answers, b, |ans| {
+            b.write_all(ans.join(", ").as_bytes())?;
+        })
+    }
+}
+
+/// The builder for a [`tags`] prompt.
+///
+/// Pressing `Enter` or `,` adds the current input as a tag, and `Backspace` on an empty input
+/// removes the last tag. Pressing `Enter` on an empty input submits the tags.
+///
+/// Duplicate tags are always rejected. Whitespace around a tag is trimmed, and empty tags are
+/// ignored.
+///
+/// The answer is an [`Answer::Strings`].
+///
+/// See the various methods for more details on each available option.
+///
+/// # Examples
+///
+/// ```
+/// use requestty::Question;
+///
+/// let tags = Question::tags("topics")
+///     .message("What topics is your post about?")
+///     .validate_tag(|tag, previous_answers| {
+///         if tag.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+///             Ok(())
+///         } else {
+///             Err("Tags can only contain letters, numbers and dashes".to_owned())
+///         }
+///     })
+///     .build();
+/// ```
+///
+/// [`tags`]: crate::question::Question::tags
+/// [`Answer::Strings`]: crate::Answer::Strings
+#[derive(Debug)]
+pub struct TagsBuilder<'a> {
+    opts: Options<'a>,
+    tags: Tags<'a>,
+}
+
+impl<'a> TagsBuilder<'a> {
+    pub(crate) fn new(name: String) -> Self {
+        TagsBuilder {
+            opts: Options::new(name),
+            tags: Default::default(),
+        }
+    }
+
+    crate::impl_options_builder! {
+    message
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let tags = Question::tags("topics")
+    ///     .message("What topics is your post about?")
+    ///     .build();
+    /// ```
+
+    when
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, Answers};
+    ///
+    /// let tags = Question::tags("topics")
+    ///     .when(|previous_answers: &Answers| match previous_answers.get("publish") {
+    ///         Some(ans) => ans.as_bool().unwrap(),
+    ///         None => true,
+    ///     })
+    ///     .build();
+    /// ```
+
+    ask_if_answered
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let tags = Question::tags("topics")
+    ///     .ask_if_answered(true)
+    ///     .build();
+    /// ```
+
+    on_esc
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, OnEsc};
+    ///
+    /// let tags = Question::tags("topics")
+    ///     .on_esc(OnEsc::Terminate)
+    ///     .build();
+    /// ```
+    }
+
+    /// Function to validate each tag before it is added.
+    ///
+    /// It is a [`FnMut`] that is given the tag and the previous [`Answers`], and should return
+    /// `Ok(())` if the given tag is valid. If it is invalid, it should return an [`Err`] with the
+    /// error message to display to the user.
+    ///
+    /// This will be called when the user presses the `Enter` key with a non-empty input. An
+    /// invalid tag entered with `,` is not added, but the error is only shown on `Enter`.
+    ///
+    /// [`Answers`]: crate::Answers
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let tags = Question::tags("topics")
+    ///     .validate_tag(|tag, previous_answers| {
+    ///         if tag.len() <= 20 {
+    ///             Ok(())
+    ///         } else {
+    ///             Err("Tags can be at most 20 characters long".to_owned())
+    ///         }
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn validate_tag<F>(mut self, validate_tag: F) -> Self
+    where
+        F: FnMut(&str, &Answers) -> Result<(), String> + 'a,
+    {
+        self.tags.validate_tag = Validate::Sync(Box::new(validate_tag));
+        self
+    }
+
+    crate::impl_validate_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let tags = Question::tags("topics")
+    ///     .validate(|tags, previous_answers| {
+    ///         if tags.len() <= 5 {
+    ///             Ok(())
+    ///         } else {
+    ///             Err("Please pick at most 5 topics".to_owned())
+    ///         }
+    ///     })
+    ///     .build();
+    /// ```
+    [String]; tags
+    }
+
+    crate::impl_hint_builder! {
+    hint
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let tags = Question::tags("topics")
+    ///     .hint("Separate the topics with commas")
+    ///     .build();
+    /// ```
+
+    no_hint
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let tags = Question::tags("topics")
+    ///     .no_hint()
+    ///     .build();
+    /// ```
+    tags
+    }
+
+    crate::impl_transform_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let tags = Question::tags("topics")
+    ///     .transform(|tags, previous_answers, backend| {
+    ///         for tag in tags {
+    ///             write!(backend, "#{} ", tag)?;
+    ///         }
+    ///         Ok(())
+    ///     })
+    ///     .build();
+    /// ```
+    [String]; tags
+    }
+
+    /// Consumes the builder returning a [`Question`]
+    ///
+    /// [`Question`]: crate::question::Question
+    pub fn build(self) -> super::Question<'a> {
+        super::Question::new(self.opts, super::QuestionKind::Tags(self.tags))
+    }
+}
+
+impl<'a> From<TagsBuilder<'a>> for super::Question<'a> {
+    /// Consumes the builder returning a [`Question`]
+    ///
+    /// [`Question`]: crate::question::Question
+    fn from(builder: TagsBuilder<'a>) -> Self {
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ui::backend::TestBackend;
+
+    fn type_str(prompt: &mut TagsPrompt<'_, '_>, s: &str) {
+        for c in s.chars() {
+            prompt.handle_key(KeyCode::Char(c).into());
+        }
+    }
+
+    #[test]
+    fn test_add_remove() {
+        let answers = Answers::default();
+        let mut prompt = Tags::default().into_tags_prompt("message", &answers);
+
+        type_str(&mut prompt, "rust,");
+        assert_eq!(prompt.tags, ["rust"]);
+        assert_eq!(prompt.input.value(), "");
+
+        type_str(&mut prompt, " cli ");
+        assert_eq!(prompt.validate(), Ok(Validation::Continue));
+        assert_eq!(prompt.tags, ["rust", "cli"]);
+
+        // Empty tags are ignored
+        type_str(&mut prompt, " ,");
+        assert_eq!(prompt.tags, ["rust", "cli"]);
+
+        // Backspace only removes a tag if the input is empty
+        type_str(&mut prompt, "t");
+        assert!(prompt.handle_key(KeyCode::Backspace.into()));
+        assert_eq!(prompt.tags, ["rust", "cli"]);
+        assert!(prompt.handle_key(KeyCode::Backspace.into()));
+        assert_eq!(prompt.tags, ["rust"]);
+        assert!(prompt.handle_key(KeyCode::Backspace.into()));
+        assert!(!prompt.handle_key(KeyCode::Backspace.into()));
+        assert!(prompt.tags.is_empty());
+
+        type_str(&mut prompt, "tui,");
+        assert_eq!(prompt.validate(), Ok(Validation::Finish));
+        assert_eq!(prompt.finish(), ["tui"]);
+    }
+
+    #[test]
+    fn test_reject() {
+        let answers = Answers::default();
+        let tags = Tags {
+            validate_tag: Validate::Sync(Box::new(|tag, _| {
+                if tag.len() <= 4 {
+                    Ok(())
+                } else {
+                    Err("too long".into())
+                }
+            })),
+            ..Default::default()
+        };
+        let mut prompt = tags.into_tags_prompt("message", &answers);
+
+        type_str(&mut prompt, "rust,");
+
+        type_str(&mut prompt, "rust,");
+        assert_eq!(prompt.tags, ["rust"]);
+        assert_eq!(prompt.input.value(), "rust");
+        assert_eq!(
+            prompt.validate(),
+            Err(widgets::Text::new("'rust' has already been added".into()))
+        );
+
+        prompt.input.set_value("serde".into());
+        assert_eq!(
+            prompt.validate(),
+            Err(widgets::Text::new("too long".into()))
+        );
+        assert_eq!(prompt.tags, ["rust"]);
+    }
+
+    #[test]
+    fn test_render() {
+        let size = (50, 20).into();
+        let base_layout = Layout::new(5, size);
+        let answers = Answers::default();
+        let mut prompt = Tags::default().into_tags_prompt("message", &answers);
+        let mut backend = TestBackend::new_with_layout(size, base_layout);
+
+        for &tags in ["", "rust,cli,", "terminal,prompt,interactive,tag"].iter() {
+            type_str(&mut prompt, tags);
+
+            let mut layout = base_layout;
+            assert!(prompt.render(&mut layout, &mut backend).is_ok());
+            ui::assert_backend_snapshot!(backend);
+
+            let mut height_layout = base_layout;
+            assert_eq!(prompt.height(&mut height_layout), layout.offset_y + 1);
+            assert_eq!(height_layout, layout);
+
+            backend.reset_with_layout(base_layout);
+        }
+    }
+}
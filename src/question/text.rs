@@ -0,0 +1,334 @@
+use std::{borrow::Cow, io};
+
+use ui::{
+    backend::Backend,
+    events::{EventIterator, KeyCode, KeyEvent},
+    style::{StyledStr, StyledString, Stylize},
+    widgets, Validation, Widget,
+};
+
+use super::{Filter, Hint, Options, Transform, Validate};
+use crate::{Answer, Answers};
+
+#[derive(Debug, Default)]
+pub(super) struct Text<'a> {
+    default: Option<String>,
+    hint: Hint,
+    filter: Filter<'a, String>,
+    validate: Validate<'a, str>,
+    transform: Transform<'a, str>,
+}
+
+struct TextPrompt<'a, 't> {
+    prompt: widgets::Prompt<StyledStr<'a>, Cow<'static, str>>,
+    text: Text<'t>,
+    input: widgets::TextArea,
+    answers: &'a Answers,
+}
+
+impl TextPrompt<'_, '_> {
+    /// The value without the empty line used to submit it.
+    fn value(&self) -> &str {
+        let value = self.input.value();
+        value.strip_suffix('\n').unwrap_or(value)
+    }
+}
+
+impl ui::Prompt for TextPrompt<'_, '_> {
+    type ValidateErr = widgets::Text<String>;
+    type Output = String;
+
+    fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
+        let value = self.input.value();
+
+        // `Enter` only submits the text on an empty line at the end, otherwise it starts a new line
+        if self.input.get_at() != value.len() || !(value.is_empty() || value.ends_with('\n')) {
+            self.input.handle_key(KeyEvent::from(KeyCode::Enter));
+            return Ok(Validation::Continue);
+        }
+
+        if let Validate::Sync(ref mut validate) = self.text.validate {
+            validate(value.strip_suffix('\n').unwrap_or(value), self.answers)?;
+        }
+
+        Ok(Validation::Finish)
+    }
+
+    fn finish(self) -> Self::Output {
+        let mut ans = self.value().to_owned();
+
+        if let Filter::Sync(filter) = self.text.filter {
+            ans = filter(ans, self.answers);
+        }
+
+        ans
+    }
+}
+
+impl Widget for TextPrompt<'_, '_> {
+    fn render<B: Backend>(&mut self, layout: &mut ui::layout::Layout, b: &mut B) -> io::Result<()> {
+        self.prompt.render(layout, b)?;
+        self.input.render(layout, b)
+    }
+
+    fn height(&mut self, layout: &mut ui::layout::Layout) -> u16 {
+        self.prompt.height(layout) + self.input.height(layout) - 1
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        self.input.handle_key(key)
+    }
+
+    fn cursor_pos(&mut self, layout: ui::layout::Layout) -> (u16, u16) {
+        self.input
+            .cursor_pos(layout.with_cursor_pos(self.prompt.cursor_pos(layout)))
+    }
+}
+
+impl<'t> Text<'t> {
+    fn into_prompt<'a>(
+        mut self,
+        message: impl Into<StyledStr<'a>>,
+        answers: &'a Answers,
+    ) -> TextPrompt<'a, 't> {
+        let mut input = widgets::TextArea::new();
+        if let Some(default) = self.default.take() {
+            input.set_value(default);
+        }
+
+        TextPrompt {
+            prompt: widgets::Prompt::new(message.into())
+                .with_delim(widgets::Delimiter::SquareBracket)
+                .with_optional_hint(
+                    std::mem::take(&mut self.hint)
+                        .resolve(Some("Enter on an empty line to submit")),
+                ),
+            input,
+            text: self,
+            answers,
+        }
+    }
+
+    pub(crate) fn ask<B: Backend, E: EventIterator>(
+        mut self,
+        message: StyledString,
+        input_opts: super::InputOptions<'_>,
+        answers: &Answers,
+        b: &mut B,
+        events: &mut E,
+    ) -> ui::Result<Option<Answer>> {
+        let transform = self.transform.take();
+
+        let ans = ui::Input::new(self.into_prompt(&message, answers), b)
+            .on_esc(input_opts.on_esc)
+            .timeout(input_opts.timeout)
+            .on_validation_failed(input_opts.on_validation_failed)
+            .run(events)?;
+
+        crate::write_final!(transform, message, ans [ref], answers, b, |ans| {
+            let lines = ans.lines().count();
+
+            match ans.lines().next() {
+                Some(first) if lines > 1 => {
+                    b.write_all(first.as_bytes())?;
+                    b.write_styled(&format!(" (+{} more)", lines - 1).dark_grey())?;
+                }
+                first => b.write_all(first.unwrap_or("").as_bytes())?,
+            }
+        })
+    }
+
+    pub(super) fn answer_non_interactive(
+        self,
+        answer: Option<Answer>,
+        answers: &Answers,
+    ) -> Result<Answer, super::Rejected> {
+        super::answer_string(answer, self.default, self.validate, self.filter, answers)
+    }
+}
+
+/// The builder for a [`text`] prompt.
+///
+/// The text is entered directly in the terminal, and is word wrapped as it is typed. `Enter` starts
+/// a new line, and pressing `Enter` on an empty line at the end submits the text.
+///
+/// See the various methods for more details on each available option.
+///
+/// # Examples
+///
+/// ```
+/// use requestty::Question;
+///
+/// let text = Question::text("bio")
+///     .message("Tell us a bit about yourself")
+///     .build();
+/// ```
+///
+/// [`text`]: crate::question::Question::text
+#[derive(Debug)]
+pub struct TextBuilder<'a> {
+    opts: Options<'a>,
+    text: Text<'a>,
+}
+
+impl<'a> TextBuilder<'a> {
+    pub(crate) fn new(name: String) -> Self {
+        TextBuilder {
+            opts: Options::new(name),
+            text: Default::default(),
+        }
+    }
+
+    crate::impl_options_builder! {
+    message
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let text = Question::text("bio")
+    ///     .message("Tell us a bit about yourself")
+    ///     .build();
+    /// ```
+
+    when
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Answers, Question};
+    ///
+    /// let text = Question::text("bio")
+    ///     .when(|previous_answers: &Answers| match previous_answers.get("anonymous") {
+    ///         Some(ans) => !ans.as_bool().unwrap(),
+    ///         None => true,
+    ///     })
+    ///     .build();
+    /// ```
+
+    ask_if_answered
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let text = Question::text("bio")
+    ///     .ask_if_answered(true)
+    ///     .build();
+    /// ```
+
+    on_esc
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, OnEsc};
+    ///
+    /// let text = Question::text("bio")
+    ///     .on_esc(OnEsc::Terminate)
+    ///     .build();
+    /// ```
+    }
+
+    /// Set a default value for the text
+    ///
+    /// If set, the text starts with the `default` value, which the user can then edit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let text = Question::text("bio")
+    ///     .default("My name is ")
+    ///     .build();
+    /// ```
+    pub fn default<I: Into<String>>(mut self, default: I) -> Self {
+        self.text.default = Some(default.into());
+        self
+    }
+
+    crate::impl_filter_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let text = Question::text("bio")
+    ///     .filter(|bio, previous_answers| bio.trim().to_owned())
+    ///     .build();
+    /// ```
+    String; text
+    }
+
+    crate::impl_validate_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let text = Question::text("bio")
+    ///     .validate(|bio, previous_answers| if bio.lines().count() <= 5 {
+    ///         Ok(())
+    ///     } else {
+    ///         Err("Please keep it to at most 5 lines".to_owned())
+    ///     })
+    ///     .build();
+    /// ```
+    str; text
+    }
+
+    crate::impl_hint_builder! {
+    hint
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let text = Question::text("bio")
+    ///     .hint("Press <enter> twice when you are done")
+    ///     .build();
+    /// ```
+
+    no_hint
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let text = Question::text("bio")
+    ///     .no_hint()
+    ///     .build();
+    /// ```
+    text
+    }
+
+    crate::impl_transform_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let text = Question::text("bio")
+    ///     .transform(|bio, previous_answers, backend| {
+    ///         write!(backend, "{} lines", bio.lines().count())
+    ///     })
+    ///     .build();
+    /// ```
+    str; text
+    }
+
+    /// Consumes the builder returning a [`Question`]
+    ///
+    /// [`Question`]: crate::question::Question
+    pub fn build(self) -> super::Question<'a> {
+        super::Question::new(self.opts, super::QuestionKind::Text(self.text))
+    }
+}
+
+impl<'a> From<TextBuilder<'a>> for super::Question<'a> {
+    /// Consumes the builder returning a [`Question`]
+    ///
+    /// [`Question`]: crate::question::Question
+    fn from(builder: TextBuilder<'a>) -> Self {
+        builder.build()
+    }
+}
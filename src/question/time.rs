@@ -0,0 +1,749 @@
+use std::{borrow::Cow, io};
+
+use ui::{
+    backend::Backend,
+    events::{EventIterator, KeyCode, KeyEvent, Movement},
+    style::{Color, StyledStr, StyledString},
+    widgets, Prompt, Validation, Widget,
+};
+
+use super::{Hint, Options, TransformByVal as Transform, ValidateByVal as Validate};
+use crate::{Answer, Answers, Time};
+
+const SECONDS_IN_DAY: i64 = 24 * 60 * 60;
+
+#[derive(Debug, Default)]
+pub(super) struct TimePicker<'a> {
+    default: Option<Time>,
+    min: Option<Time>,
+    max: Option<Time>,
+    show_seconds: bool,
+    twelve_hour: bool,
+    hint: Hint,
+    validate: Validate<'a, Time>,
+    transform: Transform<'a, Time>,
+}
+
+/// A part of the time which can be changed on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Hour,
+    Minute,
+    Second,
+    Meridiem,
+}
+
+impl Field {
+    /// The number of seconds the time changes by when the field is incremented.
+    fn step(self) -> i64 {
+        match self {
+            Field::Hour => 60 * 60,
+            Field::Minute => 60,
+            Field::Second => 1,
+            Field::Meridiem => 12 * 60 * 60,
+        }
+    }
+
+    fn separator(self) -> &'static str {
+        match self {
+            Field::Hour => "",
+            Field::Minute | Field::Second => ":",
+            Field::Meridiem => " ",
+        }
+    }
+
+    fn text(self, time: Time, twelve_hour: bool) -> String {
+        match self {
+            Field::Hour if twelve_hour => format!("{:02}", (time.hour + 11) % 12 + 1),
+            Field::Hour => format!("{:02}", time.hour),
+            Field::Minute => format!("{:02}", time.minute),
+            Field::Second => format!("{:02}", time.second),
+            Field::Meridiem if time.hour < 12 => "AM".into(),
+            Field::Meridiem => "PM".into(),
+        }
+    }
+}
+
+fn is_valid(time: Time) -> bool {
+    time.hour < 24 && time.minute < 60 && time.second < 60
+}
+
+fn to_seconds(time: Time) -> i64 {
+    (time.hour as i64 * 60 + time.minute as i64) * 60 + time.second as i64
+}
+
+fn from_seconds(seconds: i64) -> Time {
+    Time {
+        hour: (seconds / 3600) as u32,
+        minute: (seconds / 60 % 60) as u32,
+        second: (seconds % 60) as u32,
+    }
+}
+
+impl TimePicker<'_> {
+    fn fields(&self) -> &'static [Field] {
+        match (self.show_seconds, self.twelve_hour) {
+            (false, false) => &[Field::Hour, Field::Minute],
+            (true, false) => &[Field::Hour, Field::Minute, Field::Second],
+            (false, true) => &[Field::Hour, Field::Minute, Field::Meridiem],
+            (true, true) => &[Field::Hour, Field::Minute, Field::Second, Field::Meridiem],
+        }
+    }
+
+    fn is_bounded(&self) -> bool {
+        self.min.is_some() || self.max.is_some()
+    }
+
+    fn clamp(&self, mut time: Time) -> Time {
+        if let Some(min) = self.min {
+            time = time.max(min);
+        }
+        if let Some(max) = self.max {
+            time = time.min(max);
+        }
+        time
+    }
+
+    fn format(&self, time: Time) -> String {
+        self.fields()
+            .iter()
+            .map(|field| {
+                format!(
+                    "{}{}",
+                    field.separator(),
+                    field.text(time, self.twelve_hour)
+                )
+            })
+            .collect()
+    }
+}
+
+struct TimePrompt<'t, 'a> {
+    prompt: widgets::Prompt<StyledStr<'a>, Cow<'static, str>>,
+    time: TimePicker<'t>,
+    at: Time,
+    /// The index of the field being changed
+    field: usize,
+    answers: &'a Answers,
+}
+
+impl TimePrompt<'_, '_> {
+    /// Changes the time by `delta` seconds. The time wraps around midnight, unless there is a min
+    /// or max time in which case it is clamped.
+    fn move_by(&mut self, delta: i64) -> bool {
+        let seconds = to_seconds(self.at) + delta;
+
+        let time = if self.time.is_bounded() {
+            self.time
+                .clamp(from_seconds(seconds.clamp(0, SECONDS_IN_DAY - 1)))
+        } else {
+            from_seconds(seconds.rem_euclid(SECONDS_IN_DAY))
+        };
+
+        if time == self.at {
+            false
+        } else {
+            self.at = time;
+            true
+        }
+    }
+}
+
+impl Widget for TimePrompt<'_, '_> {
+    fn render<B: Backend>(&mut self, layout: &mut ui::layout::Layout, b: &mut B) -> io::Result<()> {
+        self.prompt.render(layout, b)?;
+
+        let theme = ui::theme::current();
+        for (i, field) in self.time.fields().iter().enumerate() {
+            let text = field.text(self.at, self.time.twelve_hour);
+
+            b.write_all(field.separator().as_bytes())?;
+
+            if i == self.field {
+                b.set_bg(theme.cursor)?;
+                b.set_fg(Color::Black)?;
+            }
+
+            b.write_all(text.as_bytes())?;
+
+            b.set_fg(Color::Reset)?;
+            b.set_bg(Color::Reset)?;
+
+            layout.line_offset += (field.separator().len() + text.len()) as u16;
+        }
+
+        Ok(())
+    }
+
+    fn height(&mut self, layout: &mut ui::layout::Layout) -> u16 {
+        let height = self.prompt.height(layout);
+        layout.line_offset += self.time.format(self.at).len() as u16;
+        height
+    }
+
+    fn cursor_pos(&mut self, layout: ui::layout::Layout) -> (u16, u16) {
+        self.prompt.cursor_pos(layout)
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        let n_fields = self.time.fields().len();
+
+        match key.code {
+            KeyCode::Tab => {
+                self.field = (self.field + 1) % n_fields;
+                return true;
+            }
+            KeyCode::BackTab => {
+                self.field = (self.field + n_fields - 1) % n_fields;
+                return true;
+            }
+            _ => {}
+        }
+
+        let step = self.time.fields()[self.field].step();
+
+        match Movement::try_from_key(key) {
+            Some(Movement::Up) => self.move_by(step),
+            Some(Movement::Down) => self.move_by(-step),
+            Some(Movement::Left) if self.field > 0 => {
+                self.field -= 1;
+                true
+            }
+            Some(Movement::Right) if self.field + 1 < n_fields => {
+                self.field += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Prompt for TimePrompt<'_, '_> {
+    type ValidateErr = widgets::Text<String>;
+    type Output = Time;
+
+    fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
+        if let Validate::Sync(ref mut validate) = self.time.validate {
+            validate(self.at, self.answers)?;
+        }
+
+        Ok(Validation::Finish)
+    }
+
+    fn finish(self) -> Self::Output {
+        self.at
+    }
+}
+
+impl<'t> TimePicker<'t> {
+    fn into_time_prompt<'a>(
+        mut self,
+        message: impl Into<StyledStr<'a>>,
+        answers: &'a Answers,
+    ) -> TimePrompt<'t, 'a> {
+        let at = self.clamp(self.default.unwrap_or_default());
+        let hint = std::mem::take(&mut self.hint).resolve(Some(
+            "Use arrow keys to change the time, <tab> to move between fields",
+        ));
+
+        TimePrompt {
+            prompt: widgets::Prompt::new(message.into()).with_optional_hint(hint),
+            time: self,
+            at,
+            field: 0,
+            answers,
+        }
+    }
+
+    pub(crate) fn ask<B: Backend, E: EventIterator>(
+        mut self,
+        message: StyledString,
+        input_opts: super::InputOptions<'_>,
+        answers: &Answers,
+        b: &mut B,
+        events: &mut E,
+    ) -> ui::Result<Option<Answer>> {
+        let transform = self.transform.take();
+        let twelve_hour = self.twelve_hour;
+        let show_seconds = self.show_seconds;
+
+        let ans = ui::Input::new(self.into_time_prompt(&message, answers), b)
+            .hide_cursor()
+            .on_esc(input_opts.on_esc)
+            .timeout(input_opts.timeout)
+            .on_validation_failed(input_opts.on_validation_failed)
+            .run(events)?;
+
+        crate::write_final!(transform, message, ans, answers, b, |ans| {
+            let picker = TimePicker {
+                twelve_hour,
+                show_seconds,
+                ..Default::default()
+            };
+            b.write_all(picker.format(ans).as_bytes())?;
+        })
+    }
+}
+
+/// The builder for a [`time`] prompt.
+///
+/// The time is shown as separate fields for the hour and minute (and optionally the second). The
+/// up and down arrow keys change the current field, and the left and right arrow keys (or `Tab`
+/// and `Shift+Tab`) move between the fields. Going past the end of a field carries over to the
+/// next one, so incrementing the minute from `59` increments the hour as well.
+///
+/// The answer is an [`Answer::Time`].
+///
+/// See the various methods for more details on each available option.
+///
+/// # Examples
+///
+/// ```
+/// use requestty::Question;
+///
+/// let time = Question::time("alarm")
+///     .message("When should the alarm ring?")
+///     .default((7, 30, 0))
+///     .build();
+/// ```
+///
+/// [`time`]: crate::question::Question::time
+/// [`Answer::Time`]: crate::Answer::Time
+#[derive(Debug)]
+pub struct TimeBuilder<'a> {
+    opts: Options<'a>,
+    time: TimePicker<'a>,
+}
+
+impl<'a> TimeBuilder<'a> {
+    pub(crate) fn new(name: String) -> Self {
+        TimeBuilder {
+            opts: Options::new(name),
+            time: Default::default(),
+        }
+    }
+
+    crate::impl_options_builder! {
+    message
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let time = Question::time("alarm")
+    ///     .message("When should the alarm ring?")
+    ///     .build();
+    /// ```
+
+    when
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, Answers};
+    ///
+    /// let time = Question::time("alarm")
+    ///     .when(|previous_answers: &Answers| match previous_answers.get("set_alarm") {
+    ///         Some(ans) => ans.as_bool().unwrap(),
+    ///         None => true,
+    ///     })
+    ///     .build();
+    /// ```
+
+    ask_if_answered
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let time = Question::time("alarm")
+    ///     .ask_if_answered(true)
+    ///     .build();
+    /// ```
+
+    on_esc
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, OnEsc};
+    ///
+    /// let time = Question::time("alarm")
+    ///     .on_esc(OnEsc::Terminate)
+    ///     .build();
+    /// ```
+    }
+
+    /// Set the time which is initially shown
+    ///
+    /// If `default` is not set, it will default to midnight (or the [`min`] time if it is later).
+    ///
+    /// # Panics
+    ///
+    /// If `default` is not a valid time, or is outside the [`min`] and [`max`] times, it will
+    /// cause a panic on [`build`].
+    ///
+    /// [`min`]: TimeBuilder::min
+    /// [`max`]: TimeBuilder::max
+    /// [`build`]: TimeBuilder::build
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let time = Question::time("alarm")
+    ///     .default((7, 30, 0))
+    ///     .build();
+    /// ```
+    pub fn default<T: Into<Time>>(mut self, default: T) -> Self {
+        self.time.default = Some(default.into());
+        self
+    }
+
+    /// Set the earliest time that can be picked
+    ///
+    /// If either `min` or [`max`] is set, the time no longer wraps around midnight.
+    ///
+    /// # Panics
+    ///
+    /// If `min` is not a valid time, or is after the [`max`] time, it will cause a panic on
+    /// [`build`].
+    ///
+    /// [`max`]: TimeBuilder::max
+    /// [`build`]: TimeBuilder::build
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let time = Question::time("meeting")
+    ///     .min((9, 0, 0))
+    ///     .build();
+    /// ```
+    pub fn min<T: Into<Time>>(mut self, min: T) -> Self {
+        self.time.min = Some(min.into());
+        self
+    }
+
+    /// Set the latest time that can be picked
+    ///
+    /// If either [`min`] or `max` is set, the time no longer wraps around midnight.
+    ///
+    /// # Panics
+    ///
+    /// If `max` is not a valid time, or is before the [`min`] time, it will cause a panic on
+    /// [`build`].
+    ///
+    /// [`min`]: TimeBuilder::min
+    /// [`build`]: TimeBuilder::build
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let time = Question::time("meeting")
+    ///     .max((17, 0, 0))
+    ///     .build();
+    /// ```
+    pub fn max<T: Into<Time>>(mut self, max: T) -> Self {
+        self.time.max = Some(max.into());
+        self
+    }
+
+    /// Show a field for the seconds
+    ///
+    /// If `show_seconds` is not called, only the hour and minute can be changed, and the seconds
+    /// of the answer are those of the [`default`] time.
+    ///
+    /// [`default`]: TimeBuilder::default
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let time = Question::time("lap")
+    ///     .show_seconds()
+    ///     .build();
+    /// ```
+    pub fn show_seconds(mut self) -> Self {
+        self.time.show_seconds = true;
+        self
+    }
+
+    /// Show the time as a 12 hour clock with an `AM`/`PM` field
+    ///
+    /// This only changes how the time is shown, the answer is always a 24 hour [`Time`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let time = Question::time("alarm")
+    ///     .twelve_hour()
+    ///     .build();
+    /// ```
+    pub fn twelve_hour(mut self) -> Self {
+        self.time.twelve_hour = true;
+        self
+    }
+
+    crate::impl_validate_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let time = Question::time("meeting")
+    ///     .validate(|time, previous_answers| {
+    ///         if time.minute % 15 == 0 {
+    ///             Ok(())
+    ///         } else {
+    ///             Err("Meetings start on the quarter hour".to_owned())
+    ///         }
+    ///     })
+    ///     .build();
+    /// ```
+    by val Time; time
+    }
+
+    crate::impl_hint_builder! {
+    hint
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let time = Question::time("alarm")
+    ///     .hint("Use the arrow keys to pick a time")
+    ///     .build();
+    /// ```
+
+    no_hint
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let time = Question::time("alarm")
+    ///     .no_hint()
+    ///     .build();
+    /// ```
+    time
+    }
+
+    crate::impl_transform_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let time = Question::time("alarm")
+    ///     .transform(|time, previous_answers, backend| {
+    ///         write!(backend, "{}h{}", time.hour, time.minute)
+    ///     })
+    ///     .build();
+    /// ```
+    by val Time; time
+    }
+
+    /// Consumes the builder returning a [`Question`]
+    ///
+    /// [`Question`]: crate::question::Question
+    pub fn build(self) -> super::Question<'a> {
+        let TimePicker {
+            default, min, max, ..
+        } = self.time;
+
+        for &(time, kind) in [(default, "default"), (min, "min"), (max, "max")].iter() {
+            if let Some(time) = time {
+                if !is_valid(time) {
+                    panic!("Invalid {} time {}", kind, time);
+                }
+            }
+        }
+
+        if let (Some(min), Some(max)) = (min, max) {
+            if min > max {
+                panic!("Invalid min time {} is after the max time {}", min, max);
+            }
+        }
+
+        if let Some(default) = default {
+            if self.time.clamp(default) != default {
+                panic!(
+                    "Invalid default time {} is outside the min and max times",
+                    default
+                );
+            }
+        }
+
+        super::Question::new(self.opts, super::QuestionKind::Time(self.time))
+    }
+}
+
+impl<'a> From<TimeBuilder<'a>> for super::Question<'a> {
+    /// Consumes the builder returning a [`Question`]
+    ///
+    /// [`Question`]: crate::question::Question
+    fn from(builder: TimeBuilder<'a>) -> Self {
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ui::{backend::TestBackend, layout::Layout};
+
+    fn time(h: u32, m: u32, s: u32) -> Time {
+        Time {
+            hour: h,
+            minute: m,
+            second: s,
+        }
+    }
+
+    fn time_prompt<'a>(picker: TimePicker<'a>, answers: &'a Answers) -> TimePrompt<'a, 'a> {
+        picker.into_time_prompt("message", answers)
+    }
+
+    fn press(prompt: &mut TimePrompt<'_, '_>, key: KeyCode) -> Time {
+        prompt.handle_key(key.into());
+        prompt.at
+    }
+
+    #[test]
+    fn test_wrap_around() {
+        let answers = Answers::default();
+        let mut prompt = time_prompt(
+            TimePicker {
+                default: Some(time(9, 59, 0)),
+                show_seconds: true,
+                ..Default::default()
+            },
+            &answers,
+        );
+
+        // Hour
+        assert_eq!(press(&mut prompt, KeyCode::Up), time(10, 59, 0));
+        // Minute
+        assert_eq!(press(&mut prompt, KeyCode::Tab), time(10, 59, 0));
+        assert_eq!(press(&mut prompt, KeyCode::Up), time(11, 0, 0));
+        assert_eq!(press(&mut prompt, KeyCode::Down), time(10, 59, 0));
+        // Second
+        assert_eq!(press(&mut prompt, KeyCode::Tab), time(10, 59, 0));
+        assert_eq!(press(&mut prompt, KeyCode::Down), time(10, 58, 59));
+        assert_eq!(press(&mut prompt, KeyCode::Up), time(10, 59, 0));
+
+        prompt.at = time(23, 59, 59);
+        assert_eq!(press(&mut prompt, KeyCode::Up), time(0, 0, 0));
+        assert_eq!(press(&mut prompt, KeyCode::Down), time(23, 59, 59));
+
+        // Tab wraps back to the hour
+        assert_eq!(press(&mut prompt, KeyCode::Tab), time(23, 59, 59));
+        assert_eq!(press(&mut prompt, KeyCode::Up), time(0, 59, 59));
+    }
+
+    #[test]
+    fn test_twelve_hour() {
+        let answers = Answers::default();
+        let mut prompt = time_prompt(
+            TimePicker {
+                default: Some(time(11, 30, 0)),
+                twelve_hour: true,
+                ..Default::default()
+            },
+            &answers,
+        );
+
+        assert_eq!(prompt.time.format(prompt.at), "11:30 AM");
+        assert_eq!(press(&mut prompt, KeyCode::Up), time(12, 30, 0));
+        assert_eq!(prompt.time.format(prompt.at), "12:30 PM");
+
+        assert_eq!(press(&mut prompt, KeyCode::BackTab), time(12, 30, 0));
+        assert_eq!(press(&mut prompt, KeyCode::Up), time(0, 30, 0));
+        assert_eq!(prompt.time.format(prompt.at), "12:30 AM");
+    }
+
+    #[test]
+    fn test_bounds() {
+        let answers = Answers::default();
+        let mut prompt = time_prompt(
+            TimePicker {
+                min: Some(time(9, 0, 0)),
+                max: Some(time(17, 30, 0)),
+                ..Default::default()
+            },
+            &answers,
+        );
+
+        assert_eq!(prompt.at, time(9, 0, 0));
+        assert!(!prompt.handle_key(KeyCode::Down.into()));
+        assert_eq!(press(&mut prompt, KeyCode::Right), time(9, 0, 0));
+        assert_eq!(press(&mut prompt, KeyCode::Up), time(9, 1, 0));
+
+        prompt.at = time(17, 0, 0);
+        assert_eq!(press(&mut prompt, KeyCode::Left), time(17, 0, 0));
+        assert_eq!(press(&mut prompt, KeyCode::Up), time(17, 30, 0));
+        assert!(!prompt.handle_key(KeyCode::Up.into()));
+    }
+
+    #[test]
+    fn test_render() {
+        let size = (50, 20).into();
+        let base_layout = Layout::new(5, size);
+        let answers = Answers::default();
+
+        let mut prompt = time_prompt(
+            TimePicker {
+                default: Some(time(13, 5, 9)),
+                show_seconds: true,
+                twelve_hour: true,
+                ..Default::default()
+            },
+            &answers,
+        );
+
+        let mut backend = TestBackend::new_with_layout(size, base_layout);
+
+        for &key in [KeyCode::Tab, KeyCode::Tab, KeyCode::Tab].iter() {
+            let mut layout = base_layout;
+            assert!(prompt.render(&mut layout, &mut backend).is_ok());
+            ui::assert_backend_snapshot!(backend);
+
+            let mut height_layout = base_layout;
+            assert_eq!(prompt.height(&mut height_layout), layout.offset_y + 1);
+            assert_eq!(height_layout, layout);
+
+            backend.reset_with_layout(base_layout);
+            prompt.handle_key(key.into());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid default time 24:00:00")]
+    fn test_panic_invalid_default() {
+        TimeBuilder::new("name".into()).default((24, 0, 0)).build();
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid min time 12:00:00 is after the max time 11:00:00")]
+    fn test_panic_min_after_max() {
+        TimeBuilder::new("name".into())
+            .min((12, 0, 0))
+            .max((11, 0, 0))
+            .build();
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid default time 08:00:00 is outside the min and max times")]
+    fn test_panic_default_out_of_bounds() {
+        TimeBuilder::new("name".into())
+            .default((8, 0, 0))
+            .min((9, 0, 0))
+            .build();
+    }
+}
@@ -0,0 +1,777 @@
+use std::{borrow::Cow, io};
+
+use ui::{
+    backend::Backend,
+    events::{EventIterator, KeyEvent, Movement},
+    style::{Color, StyledStr, StyledString},
+    widgets::{self, Text},
+    Prompt, Validation, Widget,
+};
+
+use super::{Hint, Options, Transform};
+use crate::{Answer, Answers, ListItem};
+
+/// A node in the choices of a [`tree`] prompt.
+///
+/// A node without any children is a leaf, and only leaves can be selected. Nodes with children
+/// can be expanded and collapsed to show and hide their children.
+///
+/// # Examples
+///
+/// ```
+/// use requestty::question::TreeNode;
+///
+/// let fruits = TreeNode::new("Fruits").children(vec!["Apple", "Banana"]);
+///
+/// assert!(!fruits.is_leaf());
+/// assert!(fruits.children[0].is_leaf());
+/// ```
+///
+/// [`tree`]: crate::question::Question::tree
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TreeNode {
+    /// The text of the node.
+    pub text: String,
+    /// The children of the node.
+    pub children: Vec<TreeNode>,
+}
+
+impl TreeNode {
+    /// Creates a new [`TreeNode`] without any children.
+    pub fn new<S: Into<String>>(text: S) -> Self {
+        Self {
+            text: text.into(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Adds a child to the node.
+    pub fn child<N: Into<TreeNode>>(mut self, child: N) -> Self {
+        self.children.push(child.into());
+        self
+    }
+
+    /// Adds all the children in the iterator to the node.
+    pub fn children<I, N>(mut self, children: I) -> Self
+    where
+        N: Into<TreeNode>,
+        I: IntoIterator<Item = N>,
+    {
+        self.children.extend(children.into_iter().map(Into::into));
+        self
+    }
+
+    /// Returns `true` if the node has no children.
+    pub fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+}
+
+impl From<String> for TreeNode {
+    fn from(text: String) -> Self {
+        Self::new(text)
+    }
+}
+
+impl From<&'_ str> for TreeNode {
+    fn from(text: &str) -> Self {
+        Self::new(text)
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct Tree<'a> {
+    roots: Vec<TreeNode>,
+    page_size: usize,
+    should_loop: bool,
+    hint: Hint,
+    transform: Transform<'a, [ListItem]>,
+}
+
+impl<'a> Default for Tree<'a> {
+    fn default() -> Self {
+        Self {
+            roots: Vec::new(),
+            page_size: 15,
+            should_loop: true,
+            hint: Hint::Default,
+            transform: Transform::None,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Node {
+    text: Text<String>,
+    depth: usize,
+    /// The index of the node among its siblings.
+    index: usize,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    expanded: bool,
+}
+
+/// The flattened tree, of which only the nodes whose ancestors are all expanded are shown.
+#[derive(Debug, Default)]
+struct TreeList {
+    nodes: Vec<Node>,
+    roots: Vec<usize>,
+    /// The indices into `nodes` of the nodes that are currently shown.
+    visible: Vec<usize>,
+    page_size: usize,
+    should_loop: bool,
+}
+
+impl TreeList {
+    fn new(roots: Vec<TreeNode>, page_size: usize, should_loop: bool) -> Self {
+        let mut list = Self {
+            page_size,
+            should_loop,
+            ..Default::default()
+        };
+
+        list.roots = list.flatten(roots, 0, None);
+        list.update_visible();
+        list
+    }
+
+    /// Adds the given nodes and all their descendants, returning the ids of the given nodes.
+    fn flatten(&mut self, nodes: Vec<TreeNode>, depth: usize, parent: Option<usize>) -> Vec<usize> {
+        nodes
+            .into_iter()
+            .enumerate()
+            .map(|(index, node)| {
+                let id = self.nodes.len();
+                self.nodes.push(Node {
+                    text: Text::new(node.text),
+                    depth,
+                    index,
+                    parent,
+                    children: Vec::new(),
+                    expanded: false,
+                });
+
+                self.nodes[id].children = self.flatten(node.children, depth + 1, Some(id));
+                id
+            })
+            .collect()
+    }
+
+    fn update_visible(&mut self) {
+        fn visit(nodes: &[Node], ids: &[usize], visible: &mut Vec<usize>) {
+            for &id in ids {
+                visible.push(id);
+                if nodes[id].expanded {
+                    visit(nodes, &nodes[id].children, visible);
+                }
+            }
+        }
+
+        self.visible.clear();
+        visit(&self.nodes, &self.roots, &mut self.visible);
+    }
+
+    fn node_at(&self, index: usize) -> &Node {
+        &self.nodes[self.visible[index]]
+    }
+
+    /// The path from the root to the node at the given index.
+    fn path(mut self, index: usize) -> Vec<ListItem> {
+        let mut path = Vec::new();
+        let mut id = Some(self.visible[index]);
+
+        while let Some(i) = id {
+            let node = &mut self.nodes[i];
+            path.push(ListItem {
+                index: node.index,
+                text: std::mem::take(&mut node.text.text),
+            });
+            id = node.parent;
+        }
+
+        path.reverse();
+        path
+    }
+}
+
+impl widgets::List for TreeList {
+    fn render_item<B: Backend>(
+        &mut self,
+        index: usize,
+        hovered: bool,
+        mut layout: ui::layout::Layout,
+        b: &mut B,
+    ) -> io::Result<()> {
+        if hovered {
+            b.set_fg(ui::theme::current().cursor)?;
+            write!(b, "{} ", ui::symbols::current().pointer)?;
+        } else {
+            b.write_all(b"  ")?;
+        }
+
+        let node = &mut self.nodes[self.visible[index]];
+
+        for _ in 0..node.depth {
+            b.write_all(b"  ")?;
+        }
+
+        if node.children.is_empty() {
+            b.write_all(b"  ")?;
+        } else if node.expanded {
+            b.write_all(b"- ")?;
+        } else {
+            b.write_all(b"+ ")?;
+        }
+
+        layout.offset_x += 4 + 2 * node.depth as u16;
+        node.text.render(&mut layout, b)?;
+
+        b.set_fg(Color::Reset)
+    }
+
+    fn is_selectable(&self, _: usize) -> bool {
+        true
+    }
+
+    fn page_size(&self) -> usize {
+        self.page_size
+    }
+
+    fn should_loop(&self) -> bool {
+        self.should_loop
+    }
+
+    fn height_at(&mut self, index: usize, mut layout: ui::layout::Layout) -> u16 {
+        let node = &mut self.nodes[self.visible[index]];
+        layout.offset_x += 4 + 2 * node.depth as u16;
+        node.text.height(&mut layout)
+    }
+
+    fn len(&self) -> usize {
+        self.visible.len()
+    }
+}
+
+struct TreePrompt<'a> {
+    prompt: widgets::Prompt<StyledStr<'a>, Cow<'static, str>>,
+    select: widgets::Select<TreeList>,
+}
+
+impl TreePrompt<'_> {
+    /// Expands or collapses the hovered node, returning whether anything changed.
+    fn set_expanded(&mut self, expanded: bool) -> bool {
+        let at = self.select.get_at();
+        let id = self.select.list.visible[at];
+        let node = &mut self.select.list.nodes[id];
+
+        if node.children.is_empty() || node.expanded == expanded {
+            return false;
+        }
+
+        node.expanded = expanded;
+
+        // The number of visible nodes changes, so the select needs to be recreated. Only the
+        // descendants of the hovered node are affected, so it stays at the same index.
+        let mut list = std::mem::take(&mut self.select.list);
+        list.update_visible();
+        self.select = widgets::Select::new(list);
+        self.select.set_at(at);
+
+        true
+    }
+
+    fn expand(&mut self) -> bool {
+        if self.set_expanded(true) {
+            return true;
+        }
+
+        // Already expanded, so move to the first child instead
+        let at = self.select.get_at();
+        if self.select.list.node_at(at).expanded {
+            self.select.set_at(at + 1);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn collapse(&mut self) -> bool {
+        if self.set_expanded(false) {
+            return true;
+        }
+
+        // Already collapsed, so move to the parent instead
+        let list = &self.select.list;
+        let parent = list
+            .node_at(self.select.get_at())
+            .parent
+            .and_then(|parent| list.visible.iter().position(|&id| id == parent));
+
+        match parent {
+            Some(parent) => {
+                self.select.set_at(parent);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl Prompt for TreePrompt<'_> {
+    type ValidateErr = &'static str;
+    type Output = Vec<ListItem>;
+
+    fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
+        let at = self.select.get_at();
+
+        if self.select.list.node_at(at).children.is_empty() {
+            Ok(Validation::Finish)
+        } else {
+            // Only leaves can be selected, so enter toggles the hovered node instead
+            let expanded = self.select.list.node_at(at).expanded;
+            self.set_expanded(!expanded);
+            Ok(Validation::Continue)
+        }
+    }
+
+    fn finish(self) -> Self::Output {
+        let at = self.select.get_at();
+        self.select.into_inner().path(at)
+    }
+}
+
+impl Widget for TreePrompt<'_> {
+    fn render<B: Backend>(&mut self, layout: &mut ui::layout::Layout, b: &mut B) -> io::Result<()> {
+        self.prompt.render(layout, b)?;
+        self.select.render(layout, b)
+    }
+
+    fn height(&mut self, layout: &mut ui::layout::Layout) -> u16 {
+        self.prompt.height(layout) + self.select.height(layout) - 1
+    }
+
+    fn cursor_pos(&mut self, layout: ui::layout::Layout) -> (u16, u16) {
+        self.select.cursor_pos(layout)
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        match Movement::try_from_key(key) {
+            Some(Movement::Right) => self.expand(),
+            Some(Movement::Left) => self.collapse(),
+            _ => self.select.handle_key(key),
+        }
+    }
+}
+
+impl<'a> Tree<'a> {
+    fn into_prompt(self, message: impl Into<StyledStr<'a>>) -> TreePrompt<'a> {
+        let hint = self.hint.resolve(Some(
+            "Use arrow keys to move, <right>/<left> to expand and collapse",
+        ));
+
+        TreePrompt {
+            prompt: widgets::Prompt::new(message.into()).with_optional_hint(hint),
+            select: widgets::Select::new(TreeList::new(
+                self.roots,
+                self.page_size,
+                self.should_loop,
+            )),
+        }
+    }
+
+    pub(crate) fn ask<B: Backend, E: EventIterator>(
+        mut self,
+        message: StyledString,
+        input_opts: super::InputOptions<'_>,
+        answers: &Answers,
+        b: &mut B,
+        events: &mut E,
+    ) -> ui::Result<Option<Answer>> {
+        let transform = self.transform.take();
+
+        let ans = ui::Input::new(self.into_prompt(&message), b)
+            .hide_cursor()
+            .on_esc(input_opts.on_esc)
+            .timeout(input_opts.timeout)
+            .on_validation_failed(input_opts.on_validation_failed)
+            .run(events)?;
+
+        crate::write_final!(transform, message, ans [ref], answers, b, |ans| {
+            for (i, item) in ans.iter().enumerate() {
+                if i != 0 {
+                    write!(b, " {} ", ui::symbols::current().arrow)?;
+                }
+
+                b.write_all(
+                    item.text
+                        .lines()
+                        .next()
+                        .expect("There must be at least one line in a `str`")
+                        .as_bytes(),
+                )?;
+            }
+        })
+    }
+}
+
+/// The builder for a [`tree`] prompt.
+///
+/// The choices are represented with [`TreeNode`]s. The hovered node can be expanded and collapsed
+/// with the right and left arrow keys, and pressing enter on a leaf selects it.
+///
+/// The answer is an [`Answer::ListItems`] containing the path from the root to the selected leaf.
+/// The `index` of each [`ListItem`] is its index among its siblings.
+///
+/// See the various methods for more details on each available option.
+///
+/// # Examples
+///
+/// ```
+/// use requestty::{question::TreeNode, Question};
+///
+/// let tree = Question::tree("food")
+///     .message("What would you like to eat?")
+///     .choices(vec![
+///         TreeNode::new("Fruits").children(vec!["Apple", "Banana"]),
+///         TreeNode::new("Vegetables").children(vec!["Carrot", "Potato"]),
+///         "Nothing".into(),
+///     ])
+///     .build();
+/// ```
+///
+/// [`tree`]: crate::question::Question::tree
+/// [`Answer::ListItems`]: crate::Answer::ListItems
+#[derive(Debug)]
+pub struct TreeBuilder<'a> {
+    opts: Options<'a>,
+    tree: Tree<'a>,
+}
+
+impl<'a> TreeBuilder<'a> {
+    pub(crate) fn new(name: String) -> Self {
+        TreeBuilder {
+            opts: Options::new(name),
+            tree: Default::default(),
+        }
+    }
+
+    crate::impl_options_builder! {
+    message
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let tree = Question::tree("food")
+    ///     .message("What would you like to eat?")
+    ///     .build();
+    /// ```
+
+    when
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, Answers};
+    ///
+    /// let tree = Question::tree("food")
+    ///     .when(|previous_answers: &Answers| match previous_answers.get("hungry") {
+    ///         Some(ans) => ans.as_bool().unwrap(),
+    ///         None => true,
+    ///     })
+    ///     .build();
+    /// ```
+
+    ask_if_answered
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let tree = Question::tree("food")
+    ///     .ask_if_answered(true)
+    ///     .build();
+    /// ```
+
+    on_esc
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, OnEsc};
+    ///
+    /// let tree = Question::tree("food")
+    ///     .on_esc(OnEsc::Terminate)
+    ///     .build();
+    /// ```
+    }
+
+    /// The maximum height that can be taken by the list
+    ///
+    /// If the total height exceeds the page size, the list will be scrollable.
+    ///
+    /// The `page_size` must be a minimum of 5. If `page_size` is not set, it will default to 15.
+    ///
+    /// # Panics
+    ///
+    /// It will panic if the `page_size` is less than 5.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let tree = Question::tree("food")
+    ///     .page_size(10)
+    ///     .build();
+    /// ```
+    pub fn page_size(mut self, page_size: usize) -> Self {
+        assert!(page_size >= 5, "page size can be a minimum of 5");
+
+        self.tree.page_size = page_size;
+        self
+    }
+
+    /// Whether to wrap around when user gets to the last element.
+    ///
+    /// If `should_loop` is not set, it will default to `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let tree = Question::tree("food")
+    ///     .should_loop(false)
+    ///     .build();
+    /// ```
+    pub fn should_loop(mut self, should_loop: bool) -> Self {
+        self.tree.should_loop = should_loop;
+        self
+    }
+
+    /// Inserts a top level [`TreeNode`].
+    ///
+    /// See [`tree`] for more information.
+    ///
+    /// [`tree`]: crate::question::Question::tree
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{question::TreeNode, Question};
+    ///
+    /// let tree = Question::tree("food")
+    ///     .choice(TreeNode::new("Fruits").child("Apple"))
+    ///     .choice("Nothing")
+    ///     .build();
+    /// ```
+    pub fn choice<N: Into<TreeNode>>(mut self, node: N) -> Self {
+        self.tree.roots.push(node.into());
+        self
+    }
+
+    /// Extends the top level nodes with the given iterator of [`TreeNode`]s.
+    ///
+    /// See [`tree`] for more information.
+    ///
+    /// [`tree`]: crate::question::Question::tree
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{question::TreeNode, Question};
+    ///
+    /// let tree = Question::tree("food")
+    ///     .choices(vec![
+    ///         TreeNode::new("Fruits").children(vec!["Apple", "Banana"]),
+    ///         "Nothing".into(),
+    ///     ])
+    ///     .build();
+    /// ```
+    pub fn choices<I, N>(mut self, nodes: I) -> Self
+    where
+        N: Into<TreeNode>,
+        I: IntoIterator<Item = N>,
+    {
+        self.tree.roots.extend(nodes.into_iter().map(Into::into));
+        self
+    }
+
+    crate::impl_hint_builder! {
+    hint
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let tree = Question::tree("food")
+    ///     .hint("Expand a category to see its dishes")
+    ///     .build();
+    /// ```
+
+    no_hint
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let tree = Question::tree("food")
+    ///     .no_hint()
+    ///     .build();
+    /// ```
+    tree
+    }
+
+    crate::impl_transform_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let tree = Question::tree("food")
+    ///     .transform(|path, previous_answers, backend| {
+    ///         write!(backend, "{}", path.last().unwrap().text)
+    ///     })
+    ///     .build();
+    /// ```
+    [ListItem]; tree
+    }
+
+    /// Consumes the builder returning a [`Question`]
+    ///
+    /// [`Question`]: crate::question::Question
+    pub fn build(self) -> super::Question<'a> {
+        super::Question::new(self.opts, super::QuestionKind::Tree(self.tree))
+    }
+}
+
+impl<'a> From<TreeBuilder<'a>> for super::Question<'a> {
+    /// Consumes the builder returning a [`Question`]
+    ///
+    /// [`Question`]: crate::question::Question
+    fn from(builder: TreeBuilder<'a>) -> Self {
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ui::{backend::TestBackend, events::KeyCode, layout::Layout, widgets::List};
+
+    fn tree() -> Tree<'static> {
+        Tree {
+            roots: vec![
+                TreeNode::new("Fruits").children(vec![
+                    TreeNode::new("Apple"),
+                    TreeNode::new("Citrus").children(vec!["Lemon", "Orange"]),
+                ]),
+                TreeNode::new("Vegetables").children(vec!["Carrot", "Potato"]),
+                "Nothing".into(),
+            ],
+            ..Default::default()
+        }
+    }
+
+    fn hovered<'p>(prompt: &'p TreePrompt<'_>) -> &'p str {
+        &prompt.select.list.node_at(prompt.select.get_at()).text.text
+    }
+
+    #[test]
+    fn test_expand_collapse() {
+        let mut prompt = tree().into_prompt("message");
+        assert_eq!(prompt.select.list.len(), 3);
+
+        assert!(prompt.handle_key(KeyCode::Right.into()));
+        assert_eq!(prompt.select.list.len(), 5);
+        assert_eq!(hovered(&prompt), "Fruits");
+
+        // Right on an expanded node moves to its first child
+        assert!(prompt.handle_key(KeyCode::Right.into()));
+        assert_eq!(hovered(&prompt), "Apple");
+
+        // Right on a leaf does nothing
+        assert!(!prompt.handle_key(KeyCode::Right.into()));
+
+        prompt.handle_key(KeyCode::Down.into());
+        prompt.handle_key(KeyCode::Right.into());
+        assert_eq!(prompt.select.list.len(), 7);
+
+        // Left on a collapsed node moves to its parent, and then collapses it
+        prompt.handle_key(KeyCode::Right.into());
+        assert_eq!(hovered(&prompt), "Lemon");
+        assert!(prompt.handle_key(KeyCode::Left.into()));
+        assert_eq!(hovered(&prompt), "Citrus");
+        assert!(prompt.handle_key(KeyCode::Left.into()));
+        assert_eq!(prompt.select.list.len(), 5);
+        assert!(prompt.handle_key(KeyCode::Left.into()));
+        assert_eq!(hovered(&prompt), "Fruits");
+        assert!(prompt.handle_key(KeyCode::Left.into()));
+        assert_eq!(prompt.select.list.len(), 3);
+
+        // Left on a collapsed root does nothing
+        assert!(!prompt.handle_key(KeyCode::Left.into()));
+    }
+
+    #[test]
+    fn test_select_nested_leaf() {
+        let mut prompt = tree().into_prompt("message");
+
+        // Enter on a node toggles it instead of finishing
+        assert_eq!(prompt.validate(), Ok(Validation::Continue));
+        assert_eq!(prompt.select.list.len(), 5);
+
+        prompt.handle_key(KeyCode::Down.into());
+        prompt.handle_key(KeyCode::Down.into());
+        assert_eq!(prompt.validate(), Ok(Validation::Continue));
+        prompt.handle_key(KeyCode::Down.into());
+        prompt.handle_key(KeyCode::Down.into());
+        assert_eq!(hovered(&prompt), "Orange");
+        assert_eq!(prompt.validate(), Ok(Validation::Finish));
+
+        assert_eq!(
+            prompt.finish(),
+            vec![
+                ListItem {
+                    index: 0,
+                    text: "Fruits".into()
+                },
+                ListItem {
+                    index: 1,
+                    text: "Citrus".into()
+                },
+                ListItem {
+                    index: 1,
+                    text: "Orange".into()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let size = (50, 20).into();
+        let base_layout = Layout::new(5, size);
+        let mut prompt = tree().into_prompt("message");
+        let mut backend = TestBackend::new_with_layout(size, base_layout);
+
+        let keys = [
+            KeyCode::Right,
+            KeyCode::Down,
+            KeyCode::Down,
+            KeyCode::Right,
+            KeyCode::Down,
+        ];
+
+        for &key in keys.iter() {
+            let mut layout = base_layout;
+            assert!(prompt.render(&mut layout, &mut backend).is_ok());
+            ui::assert_backend_snapshot!(backend);
+
+            let mut height_layout = base_layout;
+            assert_eq!(prompt.height(&mut height_layout), layout.offset_y);
+
+            backend.reset_with_layout(base_layout);
+            prompt.handle_key(key.into());
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use requestty::{Answer, Question};
+use ui::events::{KeyCode, TestEvents};
+
+mod helpers;
+
+fn languages(input: &str) -> Vec<String> {
+    ["C", "C++", "Go", "Haskell", "Python", "Rust"]
+        .iter()
+        .filter(|language| language.to_lowercase().contains(&input.to_lowercase()))
+        .map(|language| language.to_string())
+        .collect()
+}
+
+#[test]
+fn test_select_suggestion() {
+    let autocomplete = Question::autocomplete("name")
+        .message("message")
+        .suggestions(|input, _| languages(input).into_iter().collect());
+
+    let size = (50, 20).into();
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('x').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Backspace.into(),
+        KeyCode::Char('c').into(),
+        KeyCode::Down.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(autocomplete, &mut backend, &mut events).unwrap();
+
+    assert_eq!(ans, Answer::String("C++".into()));
+}
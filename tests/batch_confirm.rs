@@ -0,0 +1,24 @@
+use requestty::{Answer, Question};
+use ui::events::{KeyCode, TestEvents};
+
+mod helpers;
+
+#[test]
+fn test_yes_to_all() {
+    let batch_confirm = Question::batch_confirm("name")
+        .message("message")
+        .items(vec!["a.txt", "b.txt", "c.txt", "d.txt"]);
+
+    let size = (50, 20).into();
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('n').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('a').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(batch_confirm, &mut backend, &mut events).unwrap();
+
+    assert_eq!(ans, Answer::Bools(vec![false, true, true, true]));
+}
@@ -125,3 +125,33 @@ fn test_on_esc() {
 
     assert!(res.is_empty());
 }
+
+#[test]
+fn test_custom_keys() {
+    let size = (50, 20).into();
+    let confirm = || {
+        Question::confirm("name")
+            .message("message")
+            .labels("Oui", "Non")
+            .keys('o', 'n')
+    };
+
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('y').into(),
+        KeyCode::Char('o').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(confirm(), &mut backend, &mut events).unwrap();
+
+    assert_eq!(ans, Answer::Bool(true));
+
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(vec![KeyCode::Char('n').into(), KeyCode::Enter.into()]);
+
+    let ans =
+        requestty::prompt_one_with(confirm().default(true), &mut backend, &mut events).unwrap();
+
+    assert_eq!(ans, Answer::Bool(false));
+}
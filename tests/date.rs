@@ -0,0 +1,39 @@
+use requestty::{Answer, Date, Question};
+use ui::events::{KeyCode, TestEvents};
+
+mod helpers;
+
+#[test]
+fn test_validate() {
+    let date = Question::date("name")
+        .message("message")
+        .default((2024, 1, 30))
+        .validate(|date, _| {
+            if date.month == 2 {
+                Ok(())
+            } else {
+                Err("Please pick a date in February".to_owned())
+            }
+        });
+
+    let size = (50, 20).into();
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(vec![
+        KeyCode::Enter.into(),
+        KeyCode::Right.into(),
+        KeyCode::Right.into(),
+        KeyCode::Down.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(date, &mut backend, &mut events).unwrap();
+
+    assert_eq!(
+        ans,
+        Answer::Date(Date {
+            year: 2024,
+            month: 2,
+            day: 8
+        })
+    );
+}
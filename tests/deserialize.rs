@@ -0,0 +1,178 @@
+use requestty::{Answer, Question};
+use ui::{
+    backend::TestBackend,
+    events::{KeyCode, KeyEvent, TestEvents},
+};
+
+const QUESTIONS: &str = "
+- kind: input
+  name: name
+  message: What is your name?
+  default: Ferris
+
+- kind: select
+  name: size
+  message: What size do you need?
+  choices:
+    - Small
+    - Medium
+    - separator: ~
+    - Large
+    - separator: Custom
+    - Other
+  default: 1
+";
+
+#[test]
+fn test_deserialize_and_ask() {
+    let questions: Vec<Question> = serde_yaml::from_str(QUESTIONS).unwrap();
+
+    let mut backend = TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyEvent::from(KeyCode::Enter),
+        KeyCode::Down.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let answers = requestty::prompt_with(questions, &mut backend, &mut events).unwrap();
+
+    assert_eq!(answers["name"], Answer::String("Ferris".into()));
+
+    let size = answers["size"].as_list_item().unwrap();
+    assert_eq!(size.index, 3);
+    assert_eq!(size.text, "Large");
+}
+
+#[test]
+fn test_deserialize_kinds() {
+    let questions: Vec<Question> = serde_yaml::from_str(
+        "
+- kind: confirm
+  name: confirm
+  default: true
+- kind: int
+  name: int
+  default: 3
+- kind: float
+  name: float
+  default: 1.5
+- kind: multi_select
+  name: multi_select
+  ask_if_answered: true
+  choices: [a, b, c]
+",
+    )
+    .unwrap();
+
+    let mut backend = TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyEvent::from(KeyCode::Enter),
+        KeyCode::Enter.into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char(' ').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let answers = requestty::prompt_with(questions, &mut backend, &mut events).unwrap();
+
+    assert_eq!(answers["confirm"], Answer::Bool(true));
+    assert_eq!(answers["int"], Answer::Int(3));
+    assert_eq!(answers["float"], Answer::Float(1.5));
+
+    let selected = answers["multi_select"].as_list_items().unwrap();
+    assert_eq!(selected.len(), 1);
+    assert_eq!(selected[0].text, "a");
+}
+
+#[test]
+fn test_deserialize_unknown_kind() {
+    assert!(serde_yaml::from_str::<Question>("kind: unknown\nname: name").is_err());
+    assert!(serde_yaml::from_str::<Question>("kind: input").is_err());
+}
+
+#[test]
+fn test_deserialize_invalid_default() {
+    let err =
+        serde_yaml::from_str::<Question>("kind: select\nname: a\nchoices: [x, y]\ndefault: 5")
+            .unwrap_err();
+    assert!(err.to_string().contains("invalid default 5"));
+
+    let err = serde_yaml::from_str::<Question>(
+        "kind: select\nname: a\nchoices: [x, separator: ~, y]\ndefault: 1",
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("separator"));
+}
+
+#[test]
+fn test_answers_round_trip() {
+    let answers: requestty::Answers = vec![
+        ("string".to_owned(), Answer::String("Ferris".into())),
+        (
+            "list_item".to_owned(),
+            Answer::ListItem((1, "Medium").into()),
+        ),
+        (
+            "expand_item".to_owned(),
+            Answer::ExpandItem(('y', "Yes").into()),
+        ),
+        ("int".to_owned(), Answer::Int(3)),
+        ("float".to_owned(), Answer::Float(1.5)),
+        ("bool".to_owned(), Answer::Bool(true)),
+        (
+            "list_items".to_owned(),
+            Answer::ListItems(vec![(0, "a").into(), (2, "c").into()]),
+        ),
+        ("date".to_owned(), Answer::Date((2021, 6, 4).into())),
+        ("time".to_owned(), Answer::Time((13, 30, 0).into())),
+        (
+            "strings".to_owned(),
+            Answer::Strings(vec!["rust".into(), "cli".into()]),
+        ),
+        ("bools".to_owned(), Answer::Bools(vec![true, false])),
+        (
+            "map".to_owned(),
+            Answer::Map(
+                vec![("first".to_owned(), "Ferris".to_owned())]
+                    .into_iter()
+                    .collect(),
+            ),
+        ),
+    ]
+    .into_iter()
+    .collect();
+
+    let serialized = serde_yaml::to_string(&answers).unwrap();
+    let deserialized: requestty::Answers = serde_yaml::from_str(&serialized).unwrap();
+
+    assert_eq!(deserialized, answers);
+}
+
+#[test]
+fn test_answers_skip_answered() {
+    let answers: requestty::Answers = serde_yaml::from_str(
+        "
+name: Ferris
+size:
+  index: 3
+  text: Large
+",
+    )
+    .unwrap();
+
+    assert_eq!(answers["name"], Answer::String("Ferris".into()));
+    assert_eq!(answers["size"], Answer::ListItem((3, "Large").into()));
+
+    let questions: Vec<Question> = serde_yaml::from_str(QUESTIONS).unwrap();
+
+    let mut backend = TestBackend::new((50, 20).into());
+    let mut events = TestEvents::empty();
+
+    let answers = requestty::PromptModule::new(questions)
+        .with_answers(answers)
+        .prompt_all_with(&mut backend, &mut events)
+        .unwrap();
+
+    assert_eq!(answers.len(), 2);
+    assert_eq!(answers["size"].as_list_item().unwrap().index, 3);
+}
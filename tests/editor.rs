@@ -0,0 +1,66 @@
+#![cfg(unix)]
+
+use std::process::Command;
+
+use requestty::{Answer, Question};
+use ui::{
+    backend::TestBackend,
+    events::{KeyCode, TestEvents},
+};
+
+/// The arguments for an "editor" which appends a line to the file it is given.
+const APPEND_LINE: [&str; 3] = ["-c", r#"echo line >> "$1""#, "sh"];
+
+fn editor(reopen_on_invalid: bool) -> Question<'static> {
+    Question::editor("name")
+        .message("message")
+        .editor(Command::new("sh"))
+        .editor_args(APPEND_LINE)
+        .reopen_on_invalid(reopen_on_invalid)
+        .validate(|ans, _| {
+            if ans.lines().count() >= 3 {
+                Ok(())
+            } else {
+                Err("Please enter at least 3 lines".to_owned())
+            }
+        })
+        .build()
+}
+
+#[test]
+fn test_validate() {
+    let mut backend = TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyCode::Enter.into(),
+        KeyCode::Enter.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(editor(false), &mut backend, &mut events).unwrap();
+
+    assert_eq!(ans, Answer::String("line\nline\nline\n".into()));
+}
+
+#[test]
+fn test_reopen_on_invalid() {
+    let mut backend = TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![KeyCode::Enter.into()]);
+
+    let ans = requestty::prompt_one_with(editor(true), &mut backend, &mut events).unwrap();
+
+    assert_eq!(ans, Answer::String("line\nline\nline\n".into()));
+}
+
+#[test]
+fn test_editor_from_env() {
+    std::env::set_var("VISUAL", r#"sh -c 'echo "a line" >> "$1"' sh"#);
+
+    let editor = Question::editor("name").message("message").build();
+
+    let mut backend = TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![KeyCode::Enter.into()]);
+
+    let ans = requestty::prompt_one_with(editor, &mut backend, &mut events).unwrap();
+
+    assert_eq!(ans, Answer::String("a line\n".into()));
+}
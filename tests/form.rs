@@ -0,0 +1,41 @@
+use requestty::{Answer, Question};
+use ui::events::{KeyCode, TestEvents};
+
+mod helpers;
+
+#[test]
+fn test_tab_and_submit() {
+    let form = Question::form("name")
+        .message("message")
+        .field("username", "Username")
+        .validate_field(|username, _| {
+            if username.is_empty() {
+                Err("Please enter a username".to_owned())
+            } else {
+                Ok(())
+            }
+        })
+        .char_field("newsletter", "Newsletter");
+
+    let size = (50, 20).into();
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(vec![
+        KeyCode::Tab.into(),
+        KeyCode::Char('y').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('r').into(),
+        KeyCode::Char('o').into(),
+        KeyCode::Char('o').into(),
+        KeyCode::Char('t').into(),
+        KeyCode::BackTab.into(),
+        KeyCode::BackTab.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(form, &mut backend, &mut events).unwrap();
+
+    let mut expected = std::collections::BTreeMap::new();
+    expected.insert("username".to_owned(), "root".to_owned());
+    expected.insert("newsletter".to_owned(), "y".to_owned());
+    assert_eq!(ans, Answer::Map(expected));
+}
@@ -1,4 +1,7 @@
-use requestty::{question::Completions, Answer, Question};
+use requestty::{
+    question::{Completions, CompletionsBuilder},
+    Answer, Question,
+};
 use ui::{
     events::{KeyCode, TestEvents},
     style::Color,
@@ -144,6 +147,24 @@ fn test_default() {
     assert_eq!(ans, Answer::String("default".into()));
 }
 
+#[test]
+fn test_default_fn() {
+    let questions = vec![
+        Question::input("name").build(),
+        Question::input("path")
+            .default_fn(|answers| format!("./{}", answers["name"].as_string().unwrap()))
+            .build(),
+    ];
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::from_chars("app")
+        .then(KeyCode::Enter)
+        .then(KeyCode::Enter);
+
+    let answers = requestty::prompt_with(questions, &mut backend, &mut events).unwrap();
+    assert_eq!(answers["path"], Answer::String("./app".into()));
+}
+
 #[test]
 fn test_auto_complete() {
     let prompt = Question::input("name")
@@ -177,6 +198,48 @@ fn test_auto_complete() {
     assert_eq!(ans, Answer::String("string".into()));
 }
 
+#[test]
+fn test_completions_builder() {
+    let rank = |answer: &str, candidates: &[&str]| {
+        CompletionsBuilder::new(answer)
+            .candidates(candidates.iter().copied())
+            .build()
+            .into_iter()
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        rank(
+            "co",
+            &["clone", "commit", "config", "Co", "checkout", "commit"]
+        ),
+        ["Co", "commit", "config", "checkout", "clone"]
+    );
+    assert_eq!(rank("OMM", &["commit", "status"]), ["commit"]);
+    assert_eq!(rank("x", &["commit", "status"]), ["x"]);
+    assert_eq!(rank("", &["b", "a"]), ["a", "b"]);
+
+    let prompt = Question::input("name")
+        .message("message")
+        .auto_complete(|s, _| {
+            CompletionsBuilder::new(s)
+                .candidate("push")
+                .candidate("pull")
+                .build()
+        });
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('p').into(),
+        KeyCode::Char('s').into(),
+        KeyCode::Tab.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::String("push".into()));
+}
+
 #[test]
 fn test_on_esc() {
     let size = (50, 20).into();
@@ -211,3 +274,63 @@ fn test_on_esc() {
 
     assert!(res.is_empty());
 }
+
+#[test]
+fn test_render_once() {
+    let prompt = Question::input("name")
+        .message("message")
+        .default("default");
+
+    let mut backend = ui::backend::TestBackend::new((50, 5).into());
+    requestty::render_once(prompt, &requestty::Answers::default(), &mut backend).unwrap();
+    ui::assert_backend_snapshot!(backend);
+}
+
+/// An event source where the user never presses a key.
+struct NoEvents;
+
+impl ui::events::EventIterator for NoEvents {
+    fn next_event(&mut self) -> std::io::Result<ui::events::KeyEvent> {
+        panic!("the prompt should not wait for an event forever")
+    }
+
+    fn next_event_timeout(
+        &mut self,
+        _: std::time::Duration,
+    ) -> std::io::Result<Option<ui::events::KeyEvent>> {
+        Ok(None)
+    }
+
+    fn supports_timeout(&self) -> bool {
+        true
+    }
+}
+
+#[test]
+fn test_timeout() {
+    let timeout = std::time::Duration::from_secs(1);
+
+    let ans = requestty::prompt_one_with(
+        Question::input("name").default("Bob").timeout(timeout),
+        &mut ui::backend::TestBackend::new((50, 10).into()),
+        &mut NoEvents,
+    )
+    .unwrap();
+    assert_eq!(ans, Answer::String("Bob".into()));
+
+    let res = requestty::prompt_one_with(
+        Question::input("name").timeout(timeout),
+        &mut ui::backend::TestBackend::new((50, 10).into()),
+        &mut NoEvents,
+    );
+    assert!(matches!(res, Err(requestty::ErrorKind::TimedOut)));
+
+    // `TestEvents` cannot wait for a limited time, so the timeout is ignored
+    let ans = requestty::prompt_one_with(
+        Question::input("name").default("Bob").timeout(timeout),
+        &mut ui::backend::TestBackend::new((50, 10).into()),
+        &mut TestEvents::from_chars("a").then(KeyCode::Enter),
+    )
+    .unwrap();
+    assert_eq!(ans, Answer::String("a".into()));
+}
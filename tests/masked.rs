@@ -0,0 +1,37 @@
+use requestty::{Answer, Question};
+use ui::events::{KeyCode, TestEvents};
+
+mod helpers;
+
+#[test]
+fn test_validate() {
+    let masked = Question::masked("name", "__/__")
+        .message("message")
+        .formatted(true)
+        .validate(|date, _| {
+            if date.starts_with('0') || date.starts_with('1') {
+                Ok(())
+            } else {
+                Err("Please enter a valid month".to_owned())
+            }
+        });
+
+    let size = (50, 20).into();
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('3').into(),
+        KeyCode::Char('2').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('1').into(),
+        KeyCode::Char('5').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Home.into(),
+        KeyCode::Delete.into(),
+        KeyCode::Char('1').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(masked, &mut backend, &mut events).unwrap();
+
+    assert_eq!(ans, Answer::String("12/15".into()));
+}
@@ -167,3 +167,20 @@ fn test_on_esc() {
 
     assert!(res.is_empty());
 }
+
+#[test]
+fn test_no_selectable_choices() {
+    let size = (50, 20).into();
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::empty();
+
+    let res = requestty::prompt_one_with(
+        requestty::Question::multi_select("name")
+            .message("message")
+            .choices(vec![Choice::<String>::DefaultSeparator]),
+        &mut backend,
+        &mut events,
+    );
+
+    assert!(matches!(res, Err(requestty::ErrorKind::NoSelectableChoices)));
+}
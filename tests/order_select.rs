@@ -49,6 +49,103 @@ fn test_validate() {
     assert_eq!(indexes_a, indexes_b)
 }
 
+#[test]
+fn test_original_indices() {
+    let order_select = requestty::Question::order_select("name")
+        .message("order select")
+        .choices(choices(5));
+
+    let size = (50, 20).into();
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('b').into(),
+        KeyCode::Up.into(),
+        KeyCode::Char(' ').into(),
+        KeyCode::Up.into(),
+        KeyCode::Up.into(),
+        KeyCode::Char(' ').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let items = requestty::prompt_one_with(order_select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_items()
+        .unwrap();
+
+    assert_eq!(
+        items,
+        [(1, "1"), (4, "4"), (2, "2"), (3, "3"), (0, "0")]
+            .iter()
+            .map(|&item| item.into())
+            .collect::<Vec<requestty::ListItem>>()
+    );
+}
+
+#[test]
+fn test_grab_mode() {
+    let order_select = requestty::Question::order_select("name")
+        .message("order select")
+        .choices(choices(5))
+        .grab_mode();
+
+    let size = (50, 20).into();
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char(' ').into(),
+        KeyCode::Down.into(),
+        KeyCode::Down.into(),
+        KeyCode::Enter.into(),
+        KeyCode::Down.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let indexes = requestty::prompt_one_with(order_select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_items()
+        .unwrap()
+        .into_iter()
+        .map(|a| a.index)
+        .collect::<Vec<_>>();
+
+    assert_eq!(indexes, [1, 2, 0, 3, 4]);
+}
+
+#[test]
+fn test_confirm_before_finish() {
+    let order_select = requestty::Question::order_select("name")
+        .message("order select")
+        .choices(choices(5))
+        .confirm_before_finish();
+
+    let size = (50, 20).into();
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char(' ').into(),
+        KeyCode::Down.into(),
+        KeyCode::Char(' ').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('n').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char(' ').into(),
+        KeyCode::Down.into(),
+        KeyCode::Char(' ').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('y').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let indexes = requestty::prompt_one_with(order_select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_items()
+        .unwrap()
+        .into_iter()
+        .map(|a| a.index)
+        .collect::<Vec<_>>();
+
+    assert_eq!(indexes, [1, 2, 0, 3, 4]);
+}
+
 #[test]
 fn test_filter() {
     let order_select = requestty::Question::order_select("name")
@@ -0,0 +1,54 @@
+use std::fs;
+
+use requestty::{Answer, Question};
+use ui::{
+    backend::TestBackend,
+    events::{KeyCode, TestEvents},
+};
+
+#[test]
+fn test_complete_and_must_exist() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("Cargo.toml"), "").unwrap();
+    let root = format!("{}{}", dir.path().display(), std::path::MAIN_SEPARATOR);
+
+    let prompt = Question::path("path").message("message").must_exist(true);
+
+    let mut backend = TestBackend::new((200, 20).into());
+    let mut events = TestEvents::from_chars(&root)
+        .then(KeyCode::Char('x'))
+        .then(KeyCode::Enter)
+        .then(KeyCode::Backspace)
+        .then(KeyCode::Char('C'))
+        .then(KeyCode::Tab)
+        .then(KeyCode::Enter);
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::String(format!("{}Cargo.toml", root)));
+}
+
+#[test]
+fn test_directory_only() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("file"), "").unwrap();
+    let file = dir.path().join("file").to_str().unwrap().to_owned();
+
+    let prompt = Question::path("path").directory_only(true).build();
+    let res = requestty::PromptModule::new(vec![prompt]).prompt_non_interactive(
+        vec![("path".to_owned(), Answer::String(file))]
+            .into_iter()
+            .collect(),
+    );
+    assert!(res.is_err());
+
+    let dir = dir.path().to_str().unwrap().to_owned();
+    let prompt = Question::path("path").directory_only(true).build();
+    let answers = requestty::PromptModule::new(vec![prompt])
+        .prompt_non_interactive(
+            vec![("path".to_owned(), Answer::String(dir.clone()))]
+                .into_iter()
+                .collect(),
+        )
+        .unwrap();
+    assert_eq!(answers["path"], Answer::String(dir));
+}
@@ -1,4 +1,4 @@
-use requestty::{prompt::*, question::CustomPromptBuilder, Question};
+use requestty::{prompt::*, question::CustomPromptBuilder, OnEsc, Question};
 
 #[derive(Debug)]
 struct Validate<'a> {
@@ -82,6 +82,109 @@ fn test_when() {
     assert!(!prompted_1);
 }
 
+#[test]
+fn test_choices_fn_when() {
+    let called = std::cell::Cell::new(false);
+
+    prompt_all(vec![Question::select("name")
+        .choices_fn(|_| {
+            called.set(true);
+            vec!["choice".into()]
+        })
+        .when(false)
+        .build()]);
+
+    assert!(!called.get());
+}
+
+#[test]
+fn test_choices_fn_answers() {
+    use ui::events::{KeyCode, KeyEvent};
+
+    let answers = requestty::prompt_with(
+        vec![
+            Question::select("category")
+                .choices(vec!["Fruit", "Vegetable"])
+                .build(),
+            Question::select("item")
+                .choices_fn(|answers| {
+                    let category = &answers["category"].as_list_item().unwrap().text;
+                    let items = match category.as_str() {
+                        "Fruit" => vec!["Apple", "Banana"],
+                        _ => vec!["Carrot", "Potato"],
+                    };
+
+                    std::iter::once(requestty::DefaultSeparator)
+                        .chain(items.into_iter().map(Into::into))
+                        .collect()
+                })
+                .build(),
+        ],
+        &mut ui::backend::TestBackend::new((50, 20).into()),
+        &mut ui::events::TestEvents::new(vec![
+            KeyEvent::from(KeyCode::Down),
+            KeyCode::Enter.into(),
+            KeyCode::Down.into(),
+            KeyCode::Enter.into(),
+        ]),
+    )
+    .unwrap();
+
+    assert_eq!(
+        answers["category"],
+        Answer::ListItem((1, "Vegetable").into())
+    );
+    assert_eq!(answers["item"], Answer::ListItem((2, "Potato").into()));
+}
+
+#[test]
+fn test_with_answers() {
+    let mut prompted_0 = false;
+    let mut prompted_1 = false;
+    let mut prompted_2 = false;
+    let mut prompted_3 = false;
+
+    let answers: Answers = vec![
+        ("name-0".to_owned(), Answer::Bool(true)),
+        ("name-2".to_owned(), Answer::Bool(true)),
+    ]
+    .into_iter()
+    .collect();
+
+    let answers = requestty::PromptModule::new(vec![
+        custom_prompt("name-0", "message", &mut prompted_0)
+            .message("message")
+            .build(),
+        custom_prompt("name-1", "message", &mut prompted_1)
+            .message("message")
+            .build(),
+        custom_prompt("name-2", "message", &mut prompted_2)
+            .message("message")
+            .ask_if_answered(true)
+            .build(),
+        custom_prompt("name-3", "message", &mut prompted_3)
+            .message("message")
+            .when(|ans: &Answers| ans.contains_key("name-0"))
+            .build(),
+    ])
+    .with_answers(answers)
+    .prompt_all_with(
+        &mut ui::backend::TestBackend::new((1, 1).into()),
+        &mut ui::events::TestEvents::empty(),
+    )
+    .unwrap();
+
+    assert!(!prompted_0);
+    assert!(prompted_1);
+    assert!(prompted_2);
+    assert!(prompted_3);
+
+    assert_eq!(answers.len(), 4);
+    assert_eq!(answers["name-0"], Answer::Bool(true));
+    assert_eq!(answers["name-1"], Answer::Int(0));
+    assert_eq!(answers["name-2"], Answer::Int(0));
+}
+
 #[test]
 fn test_message() {
     let mut prompted_0 = false;
@@ -97,3 +200,572 @@ fn test_message() {
     assert!(prompted_0);
     assert!(prompted_1);
 }
+
+#[test]
+fn test_narrow_terminal() {
+    use ui::events::{KeyCode, KeyEvent};
+
+    for width in 1..=2 {
+        let questions = vec![
+            Question::select("select")
+                .message("message")
+                .choices(vec!["one", "two"])
+                .build(),
+            Question::raw_select("raw_select")
+                .message("message")
+                .choices(vec!["one", "two"])
+                .build(),
+            Question::multi_select("multi_select")
+                .message("message")
+                .choices(vec!["one", "two"])
+                .build(),
+            Question::order_select("order_select")
+                .message("message")
+                .choices(vec!["one", "two"])
+                .build(),
+            Question::expand("expand")
+                .message("message")
+                .choices(vec![('o', "one"), ('t', "two")])
+                .build(),
+            Question::confirm("confirm").message("message").build(),
+            Question::form("form")
+                .message("message")
+                .field("field", "Field")
+                .build(),
+        ];
+
+        let mut events = ui::events::TestEvents::new(vec![
+            KeyEvent::from(KeyCode::Enter),
+            KeyCode::Char('1').into(),
+            KeyCode::Enter.into(),
+            KeyCode::Enter.into(),
+            KeyCode::Enter.into(),
+            KeyCode::Char('o').into(),
+            KeyCode::Enter.into(),
+            KeyCode::Char('y').into(),
+            KeyCode::Enter.into(),
+            KeyCode::Char('a').into(),
+            KeyCode::Enter.into(),
+        ]);
+
+        let answers = requestty::prompt_with(
+            questions,
+            &mut ui::backend::TestBackend::new((width, 20).into()),
+            &mut events,
+        )
+        .unwrap();
+
+        assert_eq!(answers.len(), 7);
+    }
+}
+
+fn non_interactive_questions() -> Vec<Question<'static>> {
+    vec![
+        Question::input("name")
+            .validate(|name, _| {
+                if name.is_empty() {
+                    Err("The name cannot be empty".into())
+                } else {
+                    Ok(())
+                }
+            })
+            .filter(|name, _| name.to_uppercase())
+            .build(),
+        Question::int("age").default(30).build(),
+        Question::confirm("subscribe")
+            .when(|answers: &Answers| answers["age"].as_int() > Some(18))
+            .build(),
+        Question::select("size")
+            .choices(vec!["Small", "Medium", "Large"])
+            .build(),
+        Question::multi_select("toppings")
+            .choices(vec!["Cheese", "Olives", "Onions"])
+            .build(),
+    ]
+}
+
+#[test]
+fn test_non_interactive() {
+    let provided: Answers = vec![
+        ("name".to_owned(), Answer::String("ferris".into())),
+        ("subscribe".to_owned(), Answer::Bool(true)),
+        ("size".to_owned(), Answer::String("Medium".into())),
+        (
+            "toppings".to_owned(),
+            Answer::ListItems(vec![(0, "Cheese").into(), (2, "Onions").into()]),
+        ),
+    ]
+    .into_iter()
+    .collect();
+
+    let answers = requestty::PromptModule::new(non_interactive_questions())
+        .prompt_non_interactive(provided)
+        .unwrap();
+
+    assert_eq!(answers["name"], Answer::String("FERRIS".into()));
+    assert_eq!(answers["age"], Answer::Int(30));
+    assert_eq!(answers["subscribe"], Answer::Bool(true));
+    assert_eq!(answers["size"], Answer::ListItem((1, "Medium").into()));
+    assert_eq!(
+        answers["toppings"],
+        Answer::ListItems(vec![(0, "Cheese").into(), (2, "Onions").into()])
+    );
+}
+
+#[test]
+fn test_non_interactive_errors() {
+    fn answer(provided: Vec<(&str, Answer)>) -> requestty::Result<Answers> {
+        requestty::PromptModule::new(non_interactive_questions()).prompt_non_interactive(
+            provided
+                .into_iter()
+                .map(|(name, answer)| (name.to_owned(), answer))
+                .collect(),
+        )
+    }
+
+    let valid = || {
+        vec![
+            ("name", Answer::String("ferris".into())),
+            ("age", Answer::Int(10)),
+            ("size", Answer::ListItem((2, "Large").into())),
+        ]
+    };
+
+    // `subscribe` is not asked since `age` is less than 18
+    let answers = answer(valid()).unwrap();
+    assert!(!answers.contains_key("subscribe"));
+    assert_eq!(answers["toppings"], Answer::ListItems(Vec::new()));
+
+    let mut provided = valid();
+    provided[0].1 = Answer::String(String::new());
+    match answer(provided) {
+        Err(requestty::ErrorKind::InvalidAnswer { name, message }) => {
+            assert_eq!(name, "name");
+            assert_eq!(message, "The name cannot be empty");
+        }
+        res => panic!("expected an invalid answer, got {:?}", res),
+    }
+
+    let mut provided = valid();
+    provided[1].1 = Answer::Float(1.5);
+    assert!(matches!(
+        answer(provided),
+        Err(requestty::ErrorKind::InvalidAnswer { name, .. }) if name == "age"
+    ));
+
+    let mut provided = valid();
+    provided[2].1 = Answer::String("Huge".into());
+    assert!(matches!(
+        answer(provided),
+        Err(requestty::ErrorKind::InvalidAnswer { name, .. }) if name == "size"
+    ));
+
+    let mut provided = valid();
+    provided.remove(2);
+    assert!(matches!(
+        answer(provided),
+        Err(requestty::ErrorKind::MissingAnswer(name)) if name == "size"
+    ));
+}
+
+#[derive(Debug)]
+struct Counter;
+
+/// A widget that counts the keys pressed before `Enter`.
+struct CounterWidget(i64);
+
+impl ui::Widget for CounterWidget {
+    fn render<B: Backend>(
+        &mut self,
+        layout: &mut ui::layout::Layout,
+        b: &mut B,
+    ) -> std::io::Result<()> {
+        self.height(layout);
+        write!(b, "{}", self.0)
+    }
+
+    fn height(&mut self, layout: &mut ui::layout::Layout) -> u16 {
+        layout.offset_y += 1;
+        1
+    }
+
+    fn cursor_pos(&mut self, layout: ui::layout::Layout) -> (u16, u16) {
+        layout.offset_cursor((0, 1))
+    }
+
+    fn handle_key(&mut self, _: events::KeyEvent) -> bool {
+        self.0 += 1;
+        true
+    }
+}
+
+impl ui::Prompt for CounterWidget {
+    type ValidateErr = &'static str;
+    type Output = i64;
+
+    fn finish(self) -> Self::Output {
+        self.0
+    }
+}
+
+impl Prompt for Counter {
+    fn ask(
+        self,
+        _: String,
+        _: &Answers,
+        backend: &mut dyn Backend,
+        events: &mut dyn EventIterator,
+    ) -> requestty::Result<Option<Answer>> {
+        Ok(run_widget(CounterWidget(0), backend, events)?.map(Answer::Int))
+    }
+}
+
+#[test]
+fn test_run_widget() {
+    use ui::events::{KeyCode, KeyEvent};
+
+    let answers = requestty::prompt_with(
+        vec![Question::custom("count", Counter).build()],
+        &mut ui::backend::TestBackend::new((20, 5).into()),
+        &mut ui::events::TestEvents::new(vec![
+            KeyEvent::from(KeyCode::Char('a')),
+            KeyCode::Char('b').into(),
+            KeyCode::Up.into(),
+            KeyCode::Enter.into(),
+        ]),
+    )
+    .unwrap();
+
+    assert_eq!(answers["count"], Answer::Int(3));
+}
+
+#[test]
+fn test_scripted_events() {
+    use ui::events::{KeyCode, TestEvents};
+
+    let answers = requestty::prompt_with(
+        vec![Question::custom("count", Counter).build()],
+        &mut ui::backend::TestBackend::new((20, 5).into()),
+        &mut TestEvents::from_chars("hello").then(KeyCode::Enter),
+    )
+    .unwrap();
+
+    assert_eq!(answers["count"], Answer::Int(5));
+}
+
+#[test]
+fn test_observer() {
+    use ui::events::{KeyCode, KeyEvent};
+
+    let mut recorded = Vec::new();
+
+    requestty::PromptModule::new(vec![Question::input("name")
+        .validate(|name, _| {
+            if name.is_empty() {
+                Err("The name cannot be empty".into())
+            } else {
+                Ok(())
+            }
+        })
+        .build()])
+    .observe(|event: requestty::PromptEvent| recorded.push(format!("{:?}", event)))
+    .prompt_all_with(
+        &mut ui::backend::TestBackend::new((50, 5).into()),
+        &mut ui::events::TestEvents::new(vec![
+            KeyEvent::from(KeyCode::Enter),
+            KeyCode::Char('a').into(),
+            KeyCode::Enter.into(),
+        ]),
+    )
+    .unwrap();
+
+    let key = |code| format!("{:?}", KeyEvent::from(code));
+
+    assert_eq!(
+        recorded,
+        vec![
+            r#"Started { name: "name" }"#.to_owned(),
+            format!(r#"Key {{ name: "name", key: {} }}"#, key(KeyCode::Enter)),
+            r#"ValidationFailed { name: "name" }"#.to_owned(),
+            format!(
+                r#"Key {{ name: "name", key: {} }}"#,
+                key(KeyCode::Char('a'))
+            ),
+            format!(r#"Key {{ name: "name", key: {} }}"#, key(KeyCode::Enter)),
+            r#"Answered { name: "name", answer: String("a") }"#.to_owned(),
+        ]
+    );
+}
+
+#[test]
+fn test_go_back() {
+    use ui::events::{KeyCode, KeyEvent};
+
+    let questions = || {
+        vec![
+            Question::input("name").on_esc(OnEsc::GoBack).build(),
+            Question::input("nickname").on_esc(OnEsc::GoBack).build(),
+        ]
+    };
+
+    let mut backend = ui::backend::TestBackend::new((50, 5).into());
+    let mut events = ui::events::TestEvents::new(vec![
+        KeyEvent::from(KeyCode::Char('a')),
+        KeyCode::Enter.into(),
+        KeyCode::Esc.into(),
+        KeyCode::Char('b').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('c').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let mut module = requestty::PromptModule::new(questions());
+
+    assert!(module.prompt_with(&mut backend, &mut events).is_ok());
+    assert!(matches!(
+        module.prompt_with(&mut backend, &mut events),
+        Err(requestty::ErrorKind::GoBack)
+    ));
+
+    let answers = module
+        .with_questions(questions())
+        .prompt_all_with(&mut backend, &mut events)
+        .unwrap();
+
+    assert_eq!(answers.len(), 2);
+    assert_eq!(answers["name"], Answer::String("b".into()));
+    assert_eq!(answers["nickname"], Answer::String("c".into()));
+
+    // There is no question to go back to
+    let res = requestty::prompt_with(
+        questions(),
+        &mut backend,
+        &mut ui::events::TestEvents::new(Some(KeyCode::Esc.into())),
+    );
+
+    assert!(matches!(res, Err(requestty::ErrorKind::Aborted)));
+}
+
+#[test]
+fn test_go_back_from_fn() {
+    use ui::events::{KeyCode, KeyEvent};
+
+    let mut answers = requestty::Answers::default();
+    answers.insert("lang".into(), Answer::String("rust".into()));
+
+    let mut backend = ui::backend::TestBackend::new((50, 5).into());
+    let mut events = ui::events::TestEvents::new(vec![
+        KeyEvent::from(KeyCode::Char('a')),
+        KeyCode::Enter.into(),
+        // email
+        KeyCode::Esc.into(),
+        // name again, nickname is now asked
+        KeyCode::Char('b').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('c').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('d').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let answers = requestty::PromptModule::from_fn(|| {
+        vec![
+            // Already answered, so it is not asked even after going back
+            Question::input("lang").build(),
+            Question::input("name").build(),
+            Question::input("nickname")
+                .when(|answers: &Answers| answers["name"].as_string() == Some("b"))
+                .build(),
+            Question::input("email").on_esc(OnEsc::GoBack).build(),
+        ]
+        .into_iter()
+    })
+    .with_answers(answers)
+    .prompt_all_with(&mut backend, &mut events)
+    .unwrap();
+
+    assert_eq!(answers.len(), 4);
+    assert_eq!(answers["lang"], Answer::String("rust".into()));
+    assert_eq!(answers["name"], Answer::String("b".into()));
+    assert_eq!(answers["nickname"], Answer::String("c".into()));
+    assert_eq!(answers["email"], Answer::String("d".into()));
+
+    // There is no question to go back to
+    let res = requestty::PromptModule::from_fn(|| {
+        Some(Question::input("name").on_esc(OnEsc::GoBack).build()).into_iter()
+    })
+    .prompt_all_with(
+        &mut backend,
+        &mut ui::events::TestEvents::new(Some(KeyCode::Esc.into())),
+    );
+
+    assert!(matches!(res, Err(requestty::ErrorKind::Aborted)));
+}
+
+#[test]
+fn test_skip_question() {
+    use ui::events::{KeyCode, KeyEvent};
+
+    let answers = requestty::PromptModule::new(vec![
+        Question::input("name").build(),
+        Question::input("nickname")
+            .on_esc(OnEsc::SkipQuestion)
+            .build(),
+        Question::input("age").when(false).build(),
+        Question::input("email").build(),
+    ])
+    .prompt_all_with(
+        &mut ui::backend::TestBackend::new((50, 5).into()),
+        &mut ui::events::TestEvents::new(vec![
+            KeyEvent::from(KeyCode::Char('a')),
+            KeyCode::Enter.into(),
+            KeyCode::Esc.into(),
+            KeyCode::Char('b').into(),
+            KeyCode::Enter.into(),
+        ]),
+    )
+    .unwrap();
+
+    assert_eq!(answers.len(), 2);
+    assert_eq!(answers["name"], Answer::String("a".into()));
+    assert_eq!(answers["email"], Answer::String("b".into()));
+
+    assert!(!answers.contains_key("nickname"));
+    assert!(answers.is_skipped("nickname"));
+    assert!(!answers.is_skipped("name"));
+    // Questions which are not asked are not skipped by the user
+    assert!(!answers.is_skipped("age"));
+}
+
+#[test]
+fn test_show_progress() {
+    use ui::events::{KeyCode, TestEvents};
+
+    let mut backend = ui::backend::TestBackend::new((50, 10).into());
+
+    let answers = requestty::PromptModule::new(vec![
+        Question::input("name").build(),
+        Question::input("nickname").when(false).build(),
+        Question::input("age").build(),
+    ])
+    .show_progress(true)
+    .prompt_all_with(
+        &mut backend,
+        &mut TestEvents::from_chars("a")
+            .then(KeyCode::Enter)
+            .then(KeyCode::Char('b'))
+            .then(KeyCode::Enter),
+    )
+    .unwrap();
+
+    assert_eq!(answers.len(), 2);
+
+    // The skipped question has no header, but is still counted
+    assert_eq!(backend.row_text(0), "Question 1 of 3");
+    assert!(backend.row_text(1).ends_with("name: · a"));
+    assert_eq!(backend.row_text(2), "Question 3 of 3");
+    assert!(backend.row_text(3).ends_with("age: · b"));
+
+    // The total is not known for iterators without an exact size
+    let mut backend = ui::backend::TestBackend::new((50, 10).into());
+    requestty::PromptModule::new(
+        vec![Question::input("name").build()]
+            .into_iter()
+            .filter(|_| true),
+    )
+    .show_progress(true)
+    .prompt_all_with(
+        &mut backend,
+        &mut TestEvents::from_chars("").then(KeyCode::Enter),
+    )
+    .unwrap();
+
+    assert_eq!(backend.row_text(0), "Question 1");
+}
+
+#[test]
+fn test_clear_answered() {
+    use ui::events::{KeyCode, TestEvents};
+
+    let mut backend = ui::backend::TestBackend::new((50, 10).into());
+
+    let answers = requestty::PromptModule::new(vec![
+        Question::input("name").build(),
+        Question::input("age").build(),
+    ])
+    .show_progress(true)
+    .clear_answered(true)
+    .prompt_all_with(
+        &mut backend,
+        &mut TestEvents::from_chars("a")
+            .then(KeyCode::Enter)
+            .then(KeyCode::Char('b'))
+            .then(KeyCode::Enter),
+    )
+    .unwrap();
+
+    assert_eq!(answers["name"], Answer::String("a".into()));
+    assert_eq!(answers["age"], Answer::String("b".into()));
+
+    // Both questions and their headers were removed
+    for row in 0..10 {
+        assert_eq!(backend.row_text(row), "");
+    }
+    assert_eq!(backend.get_cursor_pos().unwrap(), (0, 0));
+}
+
+#[test]
+fn test_silent() {
+    use ui::events::{KeyCode, TestEvents};
+
+    let mut backend = ui::backend::TestBackend::new((50, 10).into());
+    let answer = requestty::prompt_one_with(
+        Question::input("name")
+            .transform(|name, _, b| write!(b, "Hello {}", name))
+            .silent(true),
+        &mut backend,
+        &mut TestEvents::from_chars("a").then(KeyCode::Enter),
+    )
+    .unwrap();
+
+    assert_eq!(answer, Answer::String("a".into()));
+
+    // Neither the question nor the transformed answer is left behind
+    for row in 0..10 {
+        assert_eq!(backend.row_text(row), "");
+    }
+    assert_eq!(backend.get_cursor_pos().unwrap(), (0, 0));
+}
+
+#[test]
+fn test_styled_message() {
+    use ui::{
+        events::{KeyCode, KeyEvent},
+        style::{StyledString, Stylize},
+    };
+
+    let question = Question::confirm("overwrite")
+        .message(
+            StyledString::new()
+                .with("Overwrite ")
+                .with("file.rs".light_red())
+                .with("?"),
+        )
+        .build();
+
+    let mut backend = ui::backend::BufferBackend::new((50, 5).into());
+    let answer = requestty::prompt_one_with(
+        question,
+        &mut backend,
+        &mut ui::events::TestEvents::new(vec![
+            KeyEvent::from(KeyCode::Char('y')),
+            KeyCode::Enter.into(),
+        ]),
+    )
+    .unwrap();
+
+    assert_eq!(answer, Answer::Bool(true));
+
+    let output = String::from_utf8(backend.into_bytes()).unwrap();
+    assert!(output.contains("\x1b[1mOverwrite \x1b[22m\x1b[91m\x1b[1mfile.rs\x1b[39m\x1b[22m"));
+}
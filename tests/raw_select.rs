@@ -141,3 +141,22 @@ fn test_on_esc() {
 
     assert!(res.is_empty());
 }
+
+#[test]
+fn test_key_labels() {
+    let raw_select = Question::raw_select("name")
+        .message("message")
+        .choices(vec!["first", "second", "third"])
+        .key_labels(|i| ((b'a' + i as u8) as char).to_string());
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::from_chars("c").then(KeyCode::Enter);
+
+    let ans = requestty::prompt_one_with(raw_select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_item()
+        .unwrap();
+
+    assert_eq!(ans.index, 2);
+    assert_eq!(ans.text, "third");
+}
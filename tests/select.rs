@@ -87,3 +87,23 @@ fn test_on_esc() {
 
     assert!(res.is_empty());
 }
+
+#[test]
+fn test_no_selectable_choices() {
+    let size = (50, 20).into();
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::empty();
+
+    let res = requestty::prompt_one_with(
+        requestty::Question::select("name")
+            .message("message")
+            .choices(vec![
+                Choice::DefaultSeparator,
+                Choice::Separator("Separator".into()),
+            ]),
+        &mut backend,
+        &mut events,
+    );
+
+    assert!(matches!(res, Err(requestty::ErrorKind::NoSelectableChoices)));
+}
@@ -0,0 +1,39 @@
+use requestty::question::Choice;
+use ui::events::{KeyCode, KeyEvent, TestEvents};
+
+mod helpers;
+
+#[test]
+fn test_transform() {
+    let select = requestty::Question::select_lazy("name", 100_000, |i| {
+        if i % 4 == 0 {
+            Choice::Separator(format!("Separator {}", i))
+        } else {
+            Choice::Choice(format!("Choice {}", i))
+        }
+    })
+    .transform(|item, _, b| {
+        b.set_fg(ui::style::Color::Magenta)?;
+        write!(b, "{}: {}", item.index, item.text)?;
+        b.set_fg(ui::style::Color::Reset)
+    })
+    .message("select")
+    .page_size(10);
+
+    let size = (50, 20).into();
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(vec![
+        KeyEvent::from(KeyCode::PageDown),
+        KeyEvent::from(KeyCode::Up),
+        KeyEvent::from(KeyCode::Up),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_item()
+        .unwrap();
+
+    assert_eq!(ans.index, 5);
+    assert_eq!(ans.text, "Choice 5");
+}
@@ -0,0 +1,33 @@
+use requestty::{Answer, Question};
+use ui::events::{KeyCode, TestEvents};
+
+mod helpers;
+
+#[test]
+fn test_validate() {
+    let slider = Question::slider("name")
+        .message("message")
+        .step(5)
+        .default(90)
+        .validate(|volume, _| {
+            if volume <= 80 {
+                Ok(())
+            } else {
+                Err("That is too loud!".to_owned())
+            }
+        });
+
+    let size = (50, 20).into();
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(vec![
+        KeyCode::Right.into(),
+        KeyCode::Enter.into(),
+        KeyCode::PageDown.into(),
+        KeyCode::Left.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(slider, &mut backend, &mut events).unwrap();
+
+    assert_eq!(ans, Answer::Int(40));
+}
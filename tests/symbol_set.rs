@@ -30,3 +30,19 @@ fn test_validate() {
 
     requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
 }
+
+#[test]
+fn test_select() {
+    requestty::symbols::set(requestty::symbols::ASCII);
+
+    let prompt = Question::select("name").message("message").choices(vec![
+        "foo".into(),
+        requestty::DefaultSeparator,
+        "bar".into(),
+    ]);
+
+    let mut backend = helpers::SnapshotOnFlushBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![KeyCode::Down.into(), KeyCode::Enter.into()]);
+
+    requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+}
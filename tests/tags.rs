@@ -0,0 +1,42 @@
+use requestty::{Answer, Question};
+use ui::events::{KeyCode, TestEvents};
+
+mod helpers;
+
+#[test]
+fn test_add_remove() {
+    let tags = Question::tags("name")
+        .message("message")
+        .validate_tag(|tag, _| {
+            if tag.chars().all(|c| c.is_ascii_lowercase()) {
+                Ok(())
+            } else {
+                Err("Tags can only contain lowercase letters".to_owned())
+            }
+        });
+
+    let size = (50, 20).into();
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('r').into(),
+        KeyCode::Char('u').into(),
+        KeyCode::Char('s').into(),
+        KeyCode::Char('t').into(),
+        KeyCode::Char(',').into(),
+        KeyCode::Char('C').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Backspace.into(),
+        KeyCode::Char('c').into(),
+        KeyCode::Char('l').into(),
+        KeyCode::Char('i').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('x').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Backspace.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(tags, &mut backend, &mut events).unwrap();
+
+    assert_eq!(ans, Answer::Strings(vec!["rust".into(), "cli".into()]));
+}
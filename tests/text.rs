@@ -0,0 +1,56 @@
+use requestty::{Answer, Question};
+use ui::events::{KeyCode, TestEvents};
+
+mod helpers;
+
+#[test]
+fn test_validate() {
+    let text = Question::text("text")
+        .message("message")
+        .validate(|text, _| {
+            if text.lines().count() >= 2 {
+                Ok(())
+            } else {
+                Err("Please enter at least 2 lines".to_owned())
+            }
+        });
+
+    let size = (20, 15).into();
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('h').into(),
+        KeyCode::Char('i').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('a').into(),
+        KeyCode::Char(' ').into(),
+        KeyCode::Char('s').into(),
+        KeyCode::Char('e').into(),
+        KeyCode::Char('c').into(),
+        KeyCode::Char('o').into(),
+        KeyCode::Char('n').into(),
+        KeyCode::Char('d').into(),
+        KeyCode::Char(' ').into(),
+        KeyCode::Char('l').into(),
+        KeyCode::Char('i').into(),
+        KeyCode::Char('n').into(),
+        KeyCode::Char('e').into(),
+        KeyCode::Char(' ').into(),
+        KeyCode::Char('t').into(),
+        KeyCode::Char('h').into(),
+        KeyCode::Char('a').into(),
+        KeyCode::Char('t').into(),
+        KeyCode::Char(' ').into(),
+        KeyCode::Char('w').into(),
+        KeyCode::Char('r').into(),
+        KeyCode::Char('a').into(),
+        KeyCode::Char('p').into(),
+        KeyCode::Char('s').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(text, &mut backend, &mut events).unwrap();
+
+    assert_eq!(ans, Answer::String("hi\na second line that wraps".into()));
+}
@@ -0,0 +1,84 @@
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    thread,
+};
+
+use requestty::{
+    prompt::style::{Attributes, Color},
+    theme, Question,
+};
+use ui::events::{KeyCode, TestEvents};
+
+mod helpers;
+
+// The theme is global, so the tests must not change it while another test is rendering
+static THEME_LOCKED: AtomicBool = AtomicBool::new(false);
+
+struct ThemeLock;
+
+impl ThemeLock {
+    fn lock() -> Self {
+        while THEME_LOCKED
+            .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            thread::yield_now();
+        }
+
+        ThemeLock
+    }
+}
+
+impl Drop for ThemeLock {
+    fn drop(&mut self) {
+        theme::set(theme::DEFAULT);
+        THEME_LOCKED.store(false, Ordering::Release);
+    }
+}
+
+#[test]
+fn test_multi_select() {
+    let _lock = ThemeLock::lock();
+
+    theme::set(theme::Theme {
+        cursor: Color::Magenta,
+        selected: Color::Blue,
+        hint: Color::Yellow,
+        disabled: Color::Red,
+        ..theme::DEFAULT
+    });
+
+    let prompt = Question::multi_select("name")
+        .message("message")
+        .choices(vec!["foo", "bar"])
+        .separator("separator")
+        .choice("baz");
+
+    let mut backend = helpers::SnapshotOnFlushBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char(' ').into(),
+        KeyCode::Down.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+}
+
+#[test]
+fn test_answer() {
+    let _lock = ThemeLock::lock();
+
+    theme::set(theme::Theme {
+        answer: Color::Magenta,
+        answer_attributes: Attributes::BOLD,
+        ..theme::DEFAULT
+    });
+
+    let prompt = Question::input("name").message("message");
+
+    let mut backend = helpers::SnapshotOnFlushBackend::new((50, 20).into());
+    let mut events = TestEvents::from_chars("foo").then(KeyCode::Enter);
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans.as_string(), Some("foo"));
+}
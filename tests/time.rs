@@ -0,0 +1,39 @@
+use requestty::{Answer, Question, Time};
+use ui::events::{KeyCode, TestEvents};
+
+mod helpers;
+
+#[test]
+fn test_validate() {
+    let time = Question::time("name")
+        .message("message")
+        .default((9, 58, 0))
+        .validate(|time, _| {
+            if time.minute == 0 {
+                Ok(())
+            } else {
+                Err("Please pick a time on the hour".to_owned())
+            }
+        });
+
+    let size = (50, 20).into();
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(vec![
+        KeyCode::Right.into(),
+        KeyCode::Up.into(),
+        KeyCode::Enter.into(),
+        KeyCode::Up.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(time, &mut backend, &mut events).unwrap();
+
+    assert_eq!(
+        ans,
+        Answer::Time(Time {
+            hour: 10,
+            minute: 0,
+            second: 0
+        })
+    );
+}
@@ -0,0 +1,39 @@
+use requestty::{question::TreeNode, Answer, ListItem, Question};
+use ui::events::{KeyCode, TestEvents};
+
+mod helpers;
+
+#[test]
+fn test_select_nested_leaf() {
+    let tree = Question::tree("name").message("message").choices(vec![
+        TreeNode::new("Fruits").children(vec!["Apple", "Banana"]),
+        TreeNode::new("Vegetables").children(vec!["Carrot", "Potato"]),
+        "Nothing".into(),
+    ]);
+
+    let size = (50, 20).into();
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(vec![
+        KeyCode::Down.into(),
+        KeyCode::Right.into(),
+        KeyCode::Right.into(),
+        KeyCode::Down.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(tree, &mut backend, &mut events).unwrap();
+
+    assert_eq!(
+        ans,
+        Answer::ListItems(vec![
+            ListItem {
+                index: 1,
+                text: "Vegetables".into()
+            },
+            ListItem {
+                index: 1,
+                text: "Potato".into()
+            },
+        ])
+    );
+}